@@ -4,9 +4,9 @@
 
 use std::ops::Deref;
 
-use fj_math::Segment;
+use fj_math::{Point, Segment};
 
-use crate::objects::{Cycle, Surface};
+use crate::objects::{Cycle, EdgeKind, Surface};
 
 use super::{
     edge::{EdgeCache, HalfEdgeApprox},
@@ -74,4 +74,110 @@ impl CycleApprox {
 
         segments
     }
+
+    /// Construct the segments that approximate the edges of the given kind
+    ///
+    /// Each segment belongs to the edge that contains its start point.
+    pub fn segments_of_kind(&self, kind: EdgeKind) -> Vec<Segment<3>> {
+        let mut segments = Vec::new();
+
+        for (half_edge, polyline) in
+            self.half_edges.iter().zip(self.polylines())
+        {
+            if half_edge.kind != kind {
+                continue;
+            }
+
+            for segment in polyline.windows(2) {
+                segments.push(Segment::from([segment[0], segment[1]]));
+            }
+        }
+
+        segments
+    }
+
+    /// Compute the polylines that approximate each of the cycle's edges
+    ///
+    /// Returns one polyline per edge, in global coordinates, in the order of
+    /// [`CycleApprox::half_edges`]. See [`HalfEdgeApprox::polyline`].
+    pub fn polylines(&self) -> Vec<Vec<Point<3>>> {
+        self.half_edges
+            .iter()
+            .enumerate()
+            .map(|(i, half_edge)| {
+                let next = &self.half_edges[(i + 1) % self.half_edges.len()];
+                half_edge.polyline(next)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use fj_math::{Point, Segment};
+
+    use crate::{
+        algorithms::approx::Approx,
+        objects::{Cycle, EdgeKind},
+        operations::{BuildCycle, Insert, UpdateCycle, UpdateHalfEdge},
+        services::Services,
+    };
+
+    #[test]
+    fn polylines() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+
+        let triangle =
+            Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services);
+        let approx = (&triangle, surface.deref()).approx(0.1);
+        assert_eq!(
+            approx.polylines(),
+            [
+                [[0., 0., 0.], [1., 0., 0.]],
+                [[1., 0., 0.], [0., 1., 0.]],
+                [[0., 1., 0.], [0., 0., 0.]],
+            ]
+            .map(|polyline| polyline.map(Point::from).to_vec())
+        );
+
+        // A circle consists of a single edge, which ends where it starts.
+        let circle = Cycle::circle([0., 0.], 1., &mut services);
+        let approx = (&circle, surface.deref()).approx(0.1);
+        let [polyline]: [_; 1] = approx.polylines().try_into().unwrap();
+        assert!(polyline.len() > 3);
+        assert_eq!(polyline.first(), polyline.last());
+        assert_eq!(
+            polyline.windows(2).count(),
+            approx.segments_of_kind(EdgeKind::Real).len()
+        );
+    }
+
+    #[test]
+    fn segments_of_kind() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let cycle =
+            Cycle::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services)
+                .update_nth_half_edge(0, |half_edge| {
+                    half_edge
+                        .set_kind(EdgeKind::Construction)
+                        .insert(&mut services)
+                });
+
+        let approx = (&cycle, surface.deref()).approx(0.1);
+
+        assert_eq!(
+            approx.segments_of_kind(EdgeKind::Construction),
+            [Segment::from([[0., 0., 0.], [1., 0., 0.]])]
+        );
+        assert_eq!(
+            approx.segments_of_kind(EdgeKind::Real),
+            approx.segments()[1..]
+        );
+    }
 }
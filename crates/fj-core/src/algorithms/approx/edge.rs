@@ -3,7 +3,8 @@
 //! The approximation of a curve is its first vertex, combined with the
 //! approximation of its curve. The second vertex is left off, as edge
 //! approximations are usually used to build cycle approximations, and this way,
-//! the caller doesn't have to deal with duplicate vertices.
+//! the caller doesn't have to deal with duplicate vertices. See
+//! [`HalfEdgeApprox::polyline`], for an approximation that includes both.
 
 use std::collections::BTreeMap;
 
@@ -11,7 +12,7 @@ use fj_math::Point;
 
 use crate::{
     geometry::{BoundaryOnCurve, GlobalPath, SurfacePath},
-    objects::{GlobalEdge, HalfEdge, Surface, Vertex},
+    objects::{EdgeKind, GlobalEdge, HalfEdge, Surface, Vertex},
     storage::{Handle, HandleWrapper},
 };
 
@@ -86,7 +87,7 @@ impl Approx for (&HalfEdge, &Surface) {
                 Some(approx) => approx,
                 None => {
                     let approx = approx_edge(
-                        &half_edge.path(),
+                        half_edge.path(),
                         surface,
                         half_edge.boundary(),
                         tolerance,
@@ -112,7 +113,11 @@ impl Approx for (&HalfEdge, &Surface) {
                 .collect()
         };
 
-        HalfEdgeApprox { first, points }
+        HalfEdgeApprox {
+            first,
+            points,
+            kind: half_edge.kind(),
+        }
     }
 }
 
@@ -124,6 +129,9 @@ pub struct HalfEdgeApprox {
 
     /// The approximation of the edge
     pub points: Vec<ApproxPoint<2>>,
+
+    /// The kind of the approximated edge
+    pub kind: EdgeKind,
 }
 
 impl HalfEdgeApprox {
@@ -136,6 +144,21 @@ impl HalfEdgeApprox {
 
         points
     }
+
+    /// Compute the polyline that approximates the edge, in global coordinates
+    ///
+    /// The edge ends where the next edge in its cycle starts, and the
+    /// approximation of that next edge must be provided, to complete the
+    /// polyline. Approximations of edges that are part of the same face
+    /// approximation share their points, so the polyline lines up exactly with
+    /// the triangulation of that face.
+    pub fn polyline(&self, next: &HalfEdgeApprox) -> Vec<Point<3>> {
+        self.points()
+            .into_iter()
+            .chain([next.first.clone()])
+            .map(|point| point.global_form)
+            .collect()
+    }
 }
 
 fn approx_edge(
@@ -144,19 +167,36 @@ fn approx_edge(
     boundary: BoundaryOnCurve,
     tolerance: impl Into<Tolerance>,
 ) -> GlobalEdgeApprox {
-    // There are different cases of varying complexity. Circles are the hard
-    // part here, as they need to be approximated, while lines don't need to be.
+    // There are different cases of varying complexity. Circles, ellipses, and
+    // Bézier curves are the hard part here, as they need to be approximated,
+    // while lines don't need to be.
     //
     // This will probably all be unified eventually, as `SurfacePath` and
     // `GlobalPath` grow APIs that are better suited to implementing this code
     // in a more abstract way.
-    let points = match (path, surface.geometry().u) {
-        (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
+    let points = match (path, &surface.geometry().u) {
+        (
+            SurfacePath::Bezier(_)
+            | SurfacePath::BSpline(_)
+            | SurfacePath::Circle(_)
+            | SurfacePath::Ellipse(_),
+            GlobalPath::Bezier(_)
+            | GlobalPath::BSpline(_)
+            | GlobalPath::Circle(_)
+            | GlobalPath::Ellipse(_)
+            | GlobalPath::Helix(_),
+        ) => {
             todo!(
-                "Approximating a circle on a curved surface not supported yet."
+                "Approximating a curve on a curved surface not supported yet."
             )
         }
-        (SurfacePath::Circle(_), GlobalPath::Line(_)) => {
+        (
+            SurfacePath::Bezier(_)
+            | SurfacePath::BSpline(_)
+            | SurfacePath::Circle(_)
+            | SurfacePath::Ellipse(_),
+            GlobalPath::Line(_),
+        ) => {
             (path, boundary)
                 .approx_with_cache(tolerance, &mut ())
                 .into_iter()
@@ -189,7 +229,7 @@ fn approx_edge(
                     [path.point_from_path_coords(point_curve).u]
                 }));
 
-            let approx_u = (surface.geometry().u, range_u)
+            let approx_u = (surface.geometry().u.clone(), range_u)
                 .approx_with_cache(tolerance, &mut ());
 
             let mut points = Vec::new();
@@ -215,6 +255,20 @@ fn approx_edge(
 }
 
 /// A cache for results of an approximation
+///
+/// Edges that are shared between faces must be approximated identically by
+/// each of those faces, or the resulting mesh won't be watertight. This is
+/// achieved by approximating each edge once, and reusing that approximation
+/// from this cache.
+///
+/// This only works, if the same cache is used for all faces. Approximating a
+/// [`Solid`], [`Shell`], or [`FaceSet`] takes care of that. Faces that are
+/// approximated separately need to be passed the same cache, via
+/// [`Approx::approx_with_cache`].
+///
+/// [`Solid`]: crate::objects::Solid
+/// [`Shell`]: crate::objects::Shell
+/// [`FaceSet`]: crate::objects::FaceSet
 #[derive(Default)]
 pub struct EdgeCache {
     edge_approx: BTreeMap<
@@ -347,7 +401,7 @@ mod tests {
         let boundary = BoundaryOnCurve::from([[0.], [TAU]]);
 
         let surface = Surface::new(SurfaceGeometry {
-            u: path,
+            u: path.clone(),
             v: [0., 0., 1.].into(),
         });
         let half_edge = HalfEdge::line_segment(
@@ -384,7 +438,7 @@ mod tests {
         let approx = (&half_edge, surface.deref()).approx(tolerance);
 
         let expected_approx =
-            (&half_edge.path(), BoundaryOnCurve::from([[0.], [TAU]]))
+            (half_edge.path(), BoundaryOnCurve::from([[0.], [TAU]]))
                 .approx(tolerance)
                 .into_iter()
                 .map(|(_, point_surface)| {
@@ -4,10 +4,12 @@
 
 use std::{collections::BTreeSet, ops::Deref};
 
-use fj_interop::mesh::Color;
+use fj_interop::mesh::{Color, Material};
+use fj_math::Segment;
 
 use crate::{
-    objects::{Face, FaceSet, Handedness},
+    geometry::SurfaceGeometry,
+    objects::{EdgeKind, Face, FaceSet, Handedness},
     validate::ValidationConfig,
 };
 
@@ -100,7 +102,9 @@ impl Approx for &Face {
             exterior,
             interiors,
             color: self.region().color(),
+            material: self.region().material().cloned(),
             coord_handedness: self.coord_handedness(),
+            surface: self.surface().geometry().clone(),
         }
     }
 }
@@ -117,8 +121,14 @@ pub struct FaceApprox {
     /// The color of the approximated face
     pub color: Option<Color>,
 
+    /// The material of the approximated face
+    pub material: Option<Material>,
+
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
+
+    /// The geometry of the approximated face's surface
+    pub surface: SurfaceGeometry,
 }
 
 impl FaceApprox {
@@ -134,4 +144,28 @@ impl FaceApprox {
 
         points
     }
+
+    /// Construct the segments that approximate the edges of the face
+    pub fn segments(&self) -> Vec<Segment<3>> {
+        let mut segments = self.exterior.segments();
+
+        for cycle_approx in &self.interiors {
+            segments.extend(cycle_approx.segments());
+        }
+
+        segments
+    }
+
+    /// Construct the segments that approximate the face's edges of a kind
+    ///
+    /// See [`CycleApprox::segments_of_kind`].
+    pub fn segments_of_kind(&self, kind: EdgeKind) -> Vec<Segment<3>> {
+        let mut segments = self.exterior.segments_of_kind(kind);
+
+        for cycle_approx in &self.interiors {
+            segments.extend(cycle_approx.segments_of_kind(kind));
+        }
+
+        segments
+    }
 }
@@ -17,7 +17,7 @@ use std::{
 
 use fj_math::Point;
 
-pub use self::tolerance::{InvalidTolerance, Tolerance};
+pub use self::tolerance::{InvalidTolerance, RelativeTolerance, Tolerance};
 
 /// Approximate an object
 pub trait Approx: Sized {
@@ -8,6 +8,7 @@
 //! The boundaries of the range are not included in the approximation. This is
 //! done, to give the caller (who knows the boundary anyway) more options on how
 //! to further process the approximation.
+//! Callers that need the boundaries too, can use [`ApproxIncludingBoundary`].
 //!
 //! ## Determinism
 //!
@@ -27,10 +28,14 @@
 //! As a result, path approximation is guaranteed to generate points that can
 //! fit together in a valid mesh, no matter which ranges of a path are being
 //! approximated, and how many times.
+//!
+//! For paths that don't connect to themselves, like helices, the same holds
+//! per turn: the points within each turn are at the same coordinates relative
+//! to the start of that turn.
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{BSpline, Bezier, Circle, Ellipse, Helix, Point, Scalar, Sign};
 
 use crate::geometry::{BoundaryOnCurve, GlobalPath, SurfacePath};
 
@@ -48,9 +53,18 @@ impl Approx for (&SurfacePath, BoundaryOnCurve) {
         let (path, range) = self;
 
         match path {
+            SurfacePath::Bezier(bezier) => {
+                approx_bezier(bezier, range, tolerance.into())
+            }
+            SurfacePath::BSpline(bspline) => {
+                approx_bspline(bspline, range, tolerance.into())
+            }
             SurfacePath::Circle(circle) => {
                 approx_circle(circle, range, tolerance.into())
             }
+            SurfacePath::Ellipse(ellipse) => {
+                approx_ellipse(ellipse, range, tolerance.into())
+            }
             SurfacePath::Line(_) => vec![],
         }
     }
@@ -68,14 +82,86 @@ impl Approx for (GlobalPath, BoundaryOnCurve) {
         let (path, range) = self;
 
         match path {
+            GlobalPath::Bezier(bezier) => {
+                approx_bezier(&bezier, range, tolerance.into())
+            }
+            GlobalPath::BSpline(bspline) => {
+                approx_bspline(&bspline, range, tolerance.into())
+            }
             GlobalPath::Circle(circle) => {
                 approx_circle(&circle, range, tolerance.into())
             }
+            GlobalPath::Ellipse(ellipse) => {
+                approx_ellipse(&ellipse, range, tolerance.into())
+            }
+            GlobalPath::Helix(helix) => {
+                approx_helix(&helix, range, tolerance.into())
+            }
             GlobalPath::Line(_) => vec![],
         }
     }
 }
 
+/// Approximate a path, including the boundary of the range
+///
+/// See the [module documentation](self) for why [`Approx`] leaves out the
+/// boundary. This returns the same points, with the start of the boundary
+/// prepended, and its end appended. Lines are approximated by just those two
+/// points.
+///
+/// If the boundary covers a closed path exactly once, like a full circle, the
+/// first and last point are at the same position. They differ in their path
+/// coordinates though.
+pub trait ApproxIncludingBoundary: Approx {
+    /// Approximate the path, including the boundary of the range
+    fn approx_including_boundary(
+        self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Approximation;
+}
+
+impl ApproxIncludingBoundary for (&SurfacePath, BoundaryOnCurve) {
+    fn approx_including_boundary(
+        self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Approximation {
+        let (path, boundary) = self;
+
+        with_boundary(boundary, self.approx(tolerance), |point| {
+            path.point_from_path_coords(point)
+        })
+    }
+}
+
+impl ApproxIncludingBoundary for (GlobalPath, BoundaryOnCurve) {
+    fn approx_including_boundary(
+        self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Approximation {
+        let (path, boundary) = self;
+        let approx = (path.clone(), boundary).approx(tolerance);
+
+        with_boundary(boundary, approx, |point| {
+            path.point_from_path_coords(point)
+        })
+    }
+}
+
+fn with_boundary<P>(
+    boundary: BoundaryOnCurve,
+    approx: Vec<(Point<1>, P)>,
+    point_from_path_coords: impl Fn(Point<1>) -> P,
+) -> Vec<(Point<1>, P)> {
+    let [start, end] = boundary
+        .inner
+        .map(|point_curve| (point_curve, point_from_path_coords(point_curve)));
+
+    iter::once(start)
+        .chain(approx)
+        .chain(iter::once(end))
+        .collect()
+}
+
 /// Approximate a circle
 ///
 /// `tolerance` specifies how much the approximation is allowed to deviate
@@ -98,20 +184,116 @@ fn approx_circle<const D: usize>(
     points
 }
 
+/// Approximate an ellipse
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the ellipse.
+fn approx_ellipse<const D: usize>(
+    ellipse: &Ellipse<D>,
+    boundary: impl Into<BoundaryOnCurve>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+
+    let params = EllipseApproxParams::for_ellipse(ellipse, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(boundary) {
+        let point_global = ellipse.point_from_ellipse_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// Approximate a cubic Bézier curve
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the curve.
+fn approx_bezier<const D: usize>(
+    bezier: &Bezier<D>,
+    boundary: impl Into<BoundaryOnCurve>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+
+    let params = BezierApproxParams::for_bezier(bezier, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(boundary) {
+        let point_global = bezier.point_from_bezier_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// Approximate a B-spline curve
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the curve.
+fn approx_bspline<const D: usize>(
+    bspline: &BSpline<D>,
+    boundary: impl Into<BoundaryOnCurve>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+
+    let params = BSplineApproxParams::for_bspline(bspline, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(boundary) {
+        let point_global = bspline.point_from_bspline_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// Approximate a helix
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the helix.
+fn approx_helix(
+    helix: &Helix,
+    boundary: impl Into<BoundaryOnCurve>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    let boundary = boundary.into();
+
+    let params = PathApproxParams::for_helix(helix, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(boundary) {
+        let point_global = helix.point_from_helix_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
 struct PathApproxParams {
     increment: Scalar,
 }
 
 impl PathApproxParams {
+    /// The slack (in units of the increment) used when checking boundaries
+    const SLACK: f64 = 1e-9;
+
     pub fn for_circle<const D: usize>(
         circle: &Circle<D>,
         tolerance: impl Into<Tolerance>,
     ) -> Self {
+        let tolerance = tolerance.into();
+        if let Tolerance::MaxSegments(max_segments) = tolerance {
+            return Self::for_max_segments(max_segments);
+        }
+
         let radius = circle.a().magnitude();
+        let tolerance = tolerance.for_feature_size(radius);
 
         let num_vertices_to_approx_full_circle = Scalar::max(
-            Scalar::PI
-                / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
+            Scalar::PI / (Scalar::ONE - (tolerance / radius)).acos(),
             3.,
         )
         .ceil();
@@ -121,6 +303,52 @@ impl PathApproxParams {
         Self { increment }
     }
 
+    /// Approximate a full circle with the given number of segments
+    ///
+    /// Unlike [`PathApproxParams::for_circle`], the result doesn't depend on
+    /// the size of the circle. At least 3 segments are used.
+    pub fn for_max_segments(max_segments: u32) -> Self {
+        let num_vertices_to_approx_full_circle =
+            Scalar::from(f64::from(max_segments.max(3)));
+
+        // Dividing a full circle evenly keeps the approximation deterministic,
+        // as it does for the tolerance-based increment.
+        let increment = Scalar::TAU / num_vertices_to_approx_full_circle;
+
+        Self { increment }
+    }
+
+    pub fn for_helix(helix: &Helix, tolerance: impl Into<Tolerance>) -> Self {
+        let radius = helix.radius();
+
+        // Between two points, the helix and the line segment connecting them
+        // advance along the axis at the same rate. The deviation between them
+        // is purely radial, and the same as for the circle that the helix
+        // projects onto.
+        let circle = Circle::new(helix.center(), helix.a(), helix.b());
+        let num_vertices_for_deviation =
+            Scalar::TAU / Self::for_circle(&circle, tolerance).increment();
+
+        // For a steep helix, that alone would result in long segments along
+        // the axis, and anything swept along the approximation would twist
+        // noticeably between points. Don't advance further than the radius
+        // between two points.
+        let num_vertices_for_advance =
+            (helix.advance().magnitude() / radius).ceil();
+
+        let num_vertices_to_approx_full_turn = Scalar::max(
+            num_vertices_for_deviation.round(),
+            num_vertices_for_advance,
+        );
+
+        // As for circles, the increment divides a full turn evenly. That way,
+        // each turn is approximated by points at the same coordinates,
+        // relative to the start of the turn.
+        let increment = Scalar::TAU / num_vertices_to_approx_full_turn;
+
+        Self { increment }
+    }
+
     pub fn increment(&self) -> Scalar {
         self.increment
     }
@@ -137,8 +365,12 @@ impl PathApproxParams {
 
         // We can't generate a point exactly at the boundaries of the range as
         // part of the approximation. Make sure we stay inside the range.
-        let min = min.floor() + 1.;
-        let max = max.ceil() - 1.;
+        //
+        // A boundary that falls on a multiple of the increment might end up
+        // slightly off, due to floating point inaccuracy. Without some slack,
+        // we'd generate a point right next to that boundary.
+        let min = (min + Self::SLACK).floor() + 1.;
+        let max = (max - Self::SLACK).ceil() - 1.;
 
         let [start, end] = match direction {
             Sign::Negative => [max, min],
@@ -164,15 +396,440 @@ impl PathApproxParams {
     }
 }
 
+/// Approximation parameters for an ellipse
+///
+/// Unlike a circle, an ellipse doesn't have a uniform curvature, so a uniform
+/// increment would either waste points where the ellipse is flat, or violate
+/// the tolerance where it is sharply curved. Instead, the increment is adapted
+/// to the local radius of curvature.
+///
+/// To uphold the determinism guarantee documented at the top of this module,
+/// the coordinates for one full revolution are computed up front, starting at
+/// zero. The approximation of any range is then taken from that same set of
+/// coordinates, repeated every full revolution.
+///
+/// The increments are scaled down, to add up to exactly one revolution.
+/// Otherwise, the last coordinate could end up arbitrarily close to the first
+/// coordinate of the next revolution, resulting in a sliver at the seam.
+struct EllipseApproxParams {
+    coords: Vec<Scalar>,
+}
+
+impl EllipseApproxParams {
+    pub fn for_ellipse<const D: usize>(
+        ellipse: &Ellipse<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        let tolerance = tolerance.into();
+
+        let increment_at = |t: Scalar| {
+            let radius = ellipse.radius_of_curvature([t]);
+            let tolerance = tolerance.for_feature_size(radius);
+
+            // This is the same computation as for a circle, with the radius of
+            // curvature standing in for the circle's radius. The angle is
+            // limited such, that no fewer than 3 vertices would be needed to
+            // approximate a full circle of that radius.
+            let angle = Scalar::TWO
+                * Scalar::max(Scalar::ONE - tolerance / radius, 0.5).acos();
+
+            angle * radius / ellipse.speed([t])
+        };
+
+        let mut increments = Vec::new();
+
+        let mut t = Scalar::ZERO;
+        while t < Scalar::TAU {
+            // The curvature might increase within the increment. Checking the
+            // middle of the increment too keeps us on the safe side.
+            let increment = increment_at(t);
+            let increment = if increment_at(t + increment / 2.) < increment {
+                increment_at(t + increment / 2.)
+            } else {
+                increment
+            };
+
+            increments.push(increment);
+            t += increment;
+        }
+
+        // The increments overshoot a full revolution. Making them smaller can
+        // only make the approximation more accurate.
+        let scale = Scalar::TAU / t;
+        let coords = increments
+            .into_iter()
+            .scan(Scalar::ZERO, |t, increment| {
+                let coord = *t;
+                *t += increment * scale;
+                Some(coord)
+            })
+            .collect();
+
+        Self { coords }
+    }
+
+    pub fn points(
+        &self,
+        boundary: impl Into<BoundaryOnCurve>,
+    ) -> impl Iterator<Item = Point<1>> {
+        let boundary = boundary.into();
+
+        let [a, b] = boundary.inner.map(|point| point.t);
+        let direction = (b - a).sign();
+        let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+        let mut points = Vec::new();
+
+        let mut revolution = (min / Scalar::TAU).floor();
+        let last_revolution = (max / Scalar::TAU).floor();
+
+        while revolution <= last_revolution {
+            for &coord in &self.coords {
+                let t = coord + Scalar::TAU * revolution;
+
+                // Same as for circles, the boundaries of the range are not
+                // part of the approximation.
+                if min < t && t < max {
+                    points.push(Point::from([t]));
+                }
+            }
+
+            revolution += 1.;
+        }
+
+        if direction == Sign::Negative {
+            points.reverse();
+        }
+
+        points.into_iter()
+    }
+}
+
+/// Approximation parameters for a cubic Bézier curve
+///
+/// The curve is subdivided in half recursively, until each part passes the
+/// flatness test (see [`Bezier::is_flat`]). A curve whose control points are
+/// all on the segment between its end points is flat from the start, and is
+/// approximated by its end points alone.
+///
+/// Like for the ellipse, the coordinates are computed up front, for the whole
+/// curve between the coordinates `0` and `1`, to uphold the determinism
+/// guarantee documented at the top of this module. The approximation of any
+/// range is taken from that set of coordinates. Outside of that, no
+/// coordinates are generated.
+struct BezierApproxParams {
+    coords: Vec<Scalar>,
+}
+
+impl BezierApproxParams {
+    /// The maximum number of times the curve is subdivided in half
+    ///
+    /// This limits the approximation to `2^MAX_DEPTH` segments, to keep a tiny
+    /// tolerance from resulting in an excessive number of points.
+    const MAX_DEPTH: u32 = 16;
+
+    pub fn for_bezier<const D: usize>(
+        bezier: &Bezier<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        let [a, b, c, d] = bezier.control_points();
+        let size = a.distance_to(&b) + b.distance_to(&c) + c.distance_to(&d);
+        let tolerance = tolerance.into().for_feature_size(size);
+
+        let mut coords = Vec::new();
+        Self::subdivide(
+            bezier,
+            [Scalar::ZERO, Scalar::ONE],
+            tolerance,
+            0,
+            &mut coords,
+        );
+
+        Self { coords }
+    }
+
+    fn subdivide<const D: usize>(
+        bezier: &Bezier<D>,
+        [start, end]: [Scalar; 2],
+        tolerance: Scalar,
+        depth: u32,
+        coords: &mut Vec<Scalar>,
+    ) {
+        if bezier.is_flat(tolerance) || depth >= Self::MAX_DEPTH {
+            return;
+        }
+
+        let middle = (start + end) / 2.;
+        let [left, right] = bezier.split([0.5]);
+
+        Self::subdivide(&left, [start, middle], tolerance, depth + 1, coords);
+        coords.push(middle);
+        Self::subdivide(&right, [middle, end], tolerance, depth + 1, coords);
+    }
+
+    pub fn points(
+        &self,
+        boundary: impl Into<BoundaryOnCurve>,
+    ) -> impl Iterator<Item = Point<1>> {
+        points_within(&self.coords, boundary)
+    }
+}
+
+/// Approximation parameters for a B-spline curve
+///
+/// This works like [`BezierApproxParams`], except that the subdivision happens
+/// per knot span, using [`BSpline::is_flat_between`]. The knots between the
+/// spans are always part of the approximation, as the curve might have a kink
+/// there. This means a B-spline of degree `1` is approximated by its control
+/// points, and one with just two control points by its end points, exactly like
+/// a line.
+struct BSplineApproxParams {
+    coords: Vec<Scalar>,
+}
+
+impl BSplineApproxParams {
+    /// The maximum number of times each knot span is subdivided in half
+    ///
+    /// See [`BezierApproxParams::MAX_DEPTH`].
+    const MAX_DEPTH: u32 = 16;
+
+    pub fn for_bspline<const D: usize>(
+        bspline: &BSpline<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        let size = bspline
+            .control_points()
+            .windows(2)
+            .fold(Scalar::ZERO, |size, points| {
+                size + points[0].distance_to(&points[1])
+            });
+        let tolerance = tolerance.into().for_feature_size(size);
+
+        let knots = bspline.knots().collect::<Vec<_>>();
+        let mut coords = Vec::new();
+
+        for (i, span) in knots.windows(2).enumerate() {
+            if i > 0 {
+                coords.push(span[0]);
+            }
+
+            Self::subdivide(
+                bspline,
+                [span[0], span[1]],
+                tolerance,
+                0,
+                &mut coords,
+            );
+        }
+
+        Self { coords }
+    }
+
+    fn subdivide<const D: usize>(
+        bspline: &BSpline<D>,
+        [start, end]: [Scalar; 2],
+        tolerance: Scalar,
+        depth: u32,
+        coords: &mut Vec<Scalar>,
+    ) {
+        if bspline.is_flat_between([[start], [end]], tolerance)
+            || depth >= Self::MAX_DEPTH
+        {
+            return;
+        }
+
+        let middle = (start + end) / 2.;
+
+        Self::subdivide(bspline, [start, middle], tolerance, depth + 1, coords);
+        coords.push(middle);
+        Self::subdivide(bspline, [middle, end], tolerance, depth + 1, coords);
+    }
+
+    pub fn points(
+        &self,
+        boundary: impl Into<BoundaryOnCurve>,
+    ) -> impl Iterator<Item = Point<1>> {
+        points_within(&self.coords, boundary)
+    }
+}
+
+/// Select the coordinates that are within the boundary, in its direction
+///
+/// `coords` must be sorted in ascending order.
+fn points_within(
+    coords: &[Scalar],
+    boundary: impl Into<BoundaryOnCurve>,
+) -> impl Iterator<Item = Point<1>> {
+    let boundary = boundary.into();
+
+    let [a, b] = boundary.inner.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    // Same as for circles, the boundaries of the range are not part of the
+    // approximation.
+    let mut points = coords
+        .iter()
+        .filter(|&&t| min < t && t < max)
+        .map(|&t| Point::from([t]))
+        .collect::<Vec<_>>();
+
+    if b < a {
+        points.reverse();
+    }
+
+    points.into_iter()
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::TAU;
 
-    use fj_math::{Circle, Point, Scalar};
+    use fj_math::{Circle, Ellipse, Helix, Point, Scalar};
 
-    use crate::algorithms::approx::{path::BoundaryOnCurve, Tolerance};
+    use crate::{
+        algorithms::approx::{path::BoundaryOnCurve, Approx, Tolerance},
+        geometry::SurfacePath,
+    };
 
-    use super::PathApproxParams;
+    use super::{
+        ApproxIncludingBoundary, EllipseApproxParams, PathApproxParams,
+    };
+
+    #[test]
+    fn approx_including_boundary() {
+        let boundary = BoundaryOnCurve::from([[0.], [1.]]);
+
+        let line = SurfacePath::line_from_points([[0., 0.], [2., 0.]]).0;
+        assert_eq!(
+            (&line, boundary).approx_including_boundary(0.1),
+            vec![
+                (Point::from([0.]), Point::from([0., 0.])),
+                (Point::from([1.]), Point::from([2., 0.])),
+            ]
+        );
+
+        // The points between the boundaries are the same as without them.
+        let circle = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let without = (&circle, boundary).approx(0.1);
+        let with = (&circle, boundary).approx_including_boundary(0.1);
+        assert_eq!(with.len(), without.len() + 2);
+        assert_eq!(with[1..with.len() - 1], without);
+        assert_eq!(with.first(), Some(&(Point::from([0.]), [1., 0.].into())));
+        assert_eq!(
+            with.last().map(|&(point_curve, _)| point_curve),
+            Some(Point::from([1.]))
+        );
+    }
+
+    #[test]
+    fn approx_bezier() {
+        let boundary = BoundaryOnCurve::from([[0.], [1.]]);
+        let tolerance = 0.01;
+
+        // A curve with collinear control points is approximated by just its
+        // end points.
+        let (straight, _) = SurfacePath::bezier_from_control_points([
+            [0., 0.],
+            [0.2, 0.],
+            [1.5, 0.],
+            [2., 0.],
+        ]);
+        assert_eq!(
+            (&straight, boundary).approx_including_boundary(tolerance),
+            vec![
+                (Point::from([0.]), Point::from([0., 0.])),
+                (Point::from([1.]), Point::from([2., 0.])),
+            ]
+        );
+
+        let (curved, _) = SurfacePath::bezier_from_control_points([
+            [0., 0.],
+            [0., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+        let approx = (&curved, boundary).approx_including_boundary(tolerance);
+        assert!(approx.len() > 2);
+
+        assert_within_tolerance(&curved, &approx, tolerance);
+
+        // The approximation of a part of the curve uses the same points as
+        // the approximation of the whole curve, in the requested direction.
+        let all = (&curved, boundary).approx(tolerance);
+        let part = (&curved, BoundaryOnCurve::from([[0.75], [0.25]]))
+            .approx(tolerance);
+        let expected = all
+            .into_iter()
+            .filter(|(point_curve, _)| {
+                Scalar::from(0.25) < point_curve.t
+                    && point_curve.t < Scalar::from(0.75)
+            })
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(part, expected);
+    }
+
+    #[test]
+    fn approx_bspline() {
+        let boundary = BoundaryOnCurve::from([[0.], [1.]]);
+        let tolerance = 0.01;
+
+        // A B-spline of degree 1 is approximated just like a line.
+        let (bspline, _) =
+            SurfacePath::bspline_from_control_points([[0., 0.], [2., 1.]], 1);
+        let (line, _) = SurfacePath::line_from_points([[0., 0.], [2., 1.]]);
+        assert_eq!(
+            (&bspline, boundary).approx_including_boundary(tolerance),
+            (&line, boundary).approx_including_boundary(tolerance),
+        );
+
+        // With more control points, it's approximated by those.
+        let control_points = [[0., 0.], [1., 0.], [1., 1.], [3., 1.]];
+        let (polyline, _) =
+            SurfacePath::bspline_from_control_points(control_points, 1);
+        assert_eq!(
+            (&polyline, boundary)
+                .approx_including_boundary(tolerance)
+                .into_iter()
+                .map(|(_, point_surface)| point_surface)
+                .collect::<Vec<_>>(),
+            control_points.map(Point::from),
+        );
+
+        let (curved, _) =
+            SurfacePath::bspline_from_control_points(control_points, 2);
+        let approx = (&curved, boundary).approx_including_boundary(tolerance);
+        assert!(approx.len() > control_points.len());
+
+        assert_within_tolerance(&curved, &approx, tolerance);
+    }
+
+    /// Check the deviation between each segment of the approximation and the
+    /// path
+    fn assert_within_tolerance(
+        path: &SurfacePath,
+        approx: &[(Point<1>, Point<2>)],
+        tolerance: f64,
+    ) {
+        for window in approx.windows(2) {
+            let [(a_curve, a), (b_curve, b)] = [window[0], window[1]];
+            let segment = b - a;
+
+            for i in 1..10 {
+                let f = f64::from(i) / 10.;
+                let point = path.point_from_path_coords([
+                    a_curve.t + (b_curve.t - a_curve.t) * f
+                ]);
+
+                let s = ((point - a).dot(&segment) / segment.dot(&segment))
+                    .max(Scalar::ZERO)
+                    .min(Scalar::ONE);
+                let distance = point.distance_to(&(a + segment * s));
+
+                assert!(distance <= Scalar::from(tolerance));
+            }
+        }
+    }
 
     #[test]
     fn increment_for_circle() {
@@ -193,6 +850,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn increment_for_circle_with_relative_tolerance() {
+        let tolerance = Tolerance::relative(0.01).unwrap();
+
+        for radius in [1., 100.] {
+            let circle = Circle::from_center_and_radius([0., 0.], radius);
+            let params = PathApproxParams::for_circle(&circle, tolerance);
+
+            // An absolute tolerance of 1% of the radius results in the same
+            // increment, regardless of the radius.
+            assert_eq!(params.increment(), Scalar::TAU / 23.);
+
+            // The chord between two points deviates from the circle by no more
+            // than the tolerance.
+            let deviation =
+                (Scalar::ONE - (params.increment() / 2.).cos()) * radius;
+            assert!(deviation <= Scalar::from(radius * 0.01));
+        }
+    }
+
+    #[test]
+    fn increment_for_circle_with_max_segments() {
+        for radius in [0.01, 1., 100.] {
+            let circle = Circle::from_center_and_radius([0., 0.], radius);
+
+            let params = PathApproxParams::for_circle(
+                &circle,
+                Tolerance::MaxSegments(12),
+            );
+            assert_eq!(params.increment(), Scalar::TAU / 12.);
+
+            let params = PathApproxParams::for_circle(
+                &circle,
+                Tolerance::MaxSegments(1),
+            );
+            assert_eq!(params.increment(), Scalar::TAU / 3.);
+        }
+
+        // The points of any range are a subset of the points of a full circle.
+        let circle = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let tolerance = Tolerance::MaxSegments(8);
+        let full =
+            (&circle, BoundaryOnCurve::from([[0.], [TAU]])).approx(tolerance);
+        assert_eq!(full.len(), 7);
+        for boundary in [[[1.], [4.]], [[TAU], [2.]], [[0.5], [0.7]]] {
+            let partial =
+                (&circle, BoundaryOnCurve::from(boundary)).approx(tolerance);
+            for point in partial {
+                assert!(full.contains(&point), "{point:?}");
+            }
+        }
+    }
+
     #[test]
     fn points_for_circle() {
         // At the chosen values for radius and tolerance (see below), the
@@ -241,4 +951,126 @@ mod tests {
             assert_eq!(points, expected_points);
         }
     }
+
+    #[test]
+    fn points_for_circle_near_boundaries() {
+        // Same radius and tolerance as above, for an increment of `PI / 2`.
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let params = PathApproxParams::for_circle(&circle, 0.375);
+        let increment = params.increment();
+
+        // These boundaries are meant to be multiples of the increment, but are
+        // off by a rounding error. No points must be generated right next to
+        // them, regardless of which side they are off to.
+        let epsilon = Scalar::from(1e-12);
+        for boundary in [
+            [increment - epsilon, increment * 3. + epsilon],
+            [increment + epsilon, increment * 3. - epsilon],
+            [increment * 3. + epsilon, increment - epsilon],
+        ] {
+            let points = params
+                .points(boundary.map(|t| Point::from([t])))
+                .collect::<Vec<_>>();
+            assert_eq!(points, [Point::from([increment * 2.])]);
+        }
+    }
+
+    #[test]
+    fn increment_for_ellipse() {
+        let ellipse = Ellipse::new([0., 0.], [2., 0.], [0., 1.]);
+        let params = EllipseApproxParams::for_ellipse(&ellipse, 0.01);
+
+        // The ellipse is curved most sharply at the ends of its major axis, so
+        // that's where the points should be the densest.
+        let increment_at_major = params.coords[1] - params.coords[0];
+        let increment_at_minor = params
+            .coords
+            .windows(2)
+            .find(|window| window[1] > Scalar::PI / 2.)
+            .map(|window| window[1] - window[0])
+            .unwrap();
+
+        assert!(increment_at_major < increment_at_minor);
+
+        // The seam is at the end of the major axis too, so the last increment,
+        // which ends at the seam, is as small as the first one.
+        let last = params.coords.last().copied().unwrap();
+        let increment_at_seam = Scalar::TAU - last;
+        assert!(
+            (increment_at_seam - increment_at_major).abs()
+                < increment_at_major * 0.1
+        );
+    }
+
+    #[test]
+    fn points_for_ellipse_are_deterministic() {
+        let ellipse = Ellipse::new([0., 0.], [2., 0.], [0., 1.]);
+        let params = EllipseApproxParams::for_ellipse(&ellipse, 0.1);
+
+        let full = params
+            .points([[-TAU], [TAU * 2.]])
+            .filter(|point| point.t > Scalar::ONE && point.t < Scalar::from(7.))
+            .collect::<Vec<_>>();
+        let partial = params.points([[1.], [7.]]).collect::<Vec<_>>();
+        assert_eq!(full, partial);
+
+        let reversed = params.points([[7.], [1.]]).collect::<Vec<_>>();
+        assert_eq!(reversed, partial.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn increment_for_helix() {
+        // A flat helix needs as many vertices per turn as a circle with the
+        // same radius.
+        let helix = Helix::from_axis_radius_and_pitch(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            1.,
+            0.1,
+        );
+        let params = PathApproxParams::for_helix(&helix, 0.1);
+        assert_eq!(params.increment(), Scalar::TAU / 7.);
+
+        // A steep one is subdivided by its advance along the axis.
+        let helix = Helix::from_axis_radius_and_pitch(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            1.,
+            10.,
+        );
+        let params = PathApproxParams::for_helix(&helix, 0.1);
+        assert_eq!(params.increment(), Scalar::TAU / 10.);
+    }
+
+    #[test]
+    fn points_for_helix_are_deterministic_per_turn() {
+        let helix = Helix::from_axis_radius_and_pitch(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            1.,
+            2.,
+        );
+        let params = PathApproxParams::for_helix(&helix, 0.1);
+
+        let full = params
+            .points([[-TAU], [TAU * 3.]])
+            .filter(|point| {
+                point.t > Scalar::ONE && point.t < Scalar::from(13.)
+            })
+            .collect::<Vec<_>>();
+        let partial = params.points([[1.], [13.]]).collect::<Vec<_>>();
+        assert_eq!(full, partial);
+
+        // Every turn is approximated by points at the same coordinates,
+        // relative to the start of the turn.
+        let first_turn = params.points([[0.], [TAU]]).collect::<Vec<_>>();
+        let second_turn = params
+            .points([[TAU], [TAU * 2.]])
+            .map(|point| Point::from([point.t - Scalar::TAU]))
+            .collect::<Vec<_>>();
+        assert_eq!(first_turn.len(), second_turn.len());
+        for (a, b) in first_turn.into_iter().zip(second_turn) {
+            assert!((a.t - b.t).abs() < Scalar::from(1e-12));
+        }
+    }
 }
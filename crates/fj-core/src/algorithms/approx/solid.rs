@@ -22,3 +22,47 @@ impl Approx for &Solid {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Point;
+
+    use crate::{
+        algorithms::{approx::Approx, sweep::Sweep},
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    #[test]
+    fn shared_edges_are_approximated_identically() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let approx = solid.approx(0.01);
+
+        let segments = approx
+            .iter()
+            .flat_map(|face| face.segments())
+            .map(|segment| segment.points())
+            .collect::<BTreeSet<_>>();
+
+        // Every edge of the solid is shared by two faces, which traverse it in
+        // opposite directions. If both faces approximate it using the same
+        // points, every segment has a reverse counterpart.
+        assert!(segments.len() > 3);
+        for [a, b] in &segments {
+            assert!(segments.contains(&[*b, *a]), "No counterpart for {a:?}");
+        }
+    }
+}
@@ -2,13 +2,22 @@
 //!
 //! See [`Tolerance`].
 
-use fj_math::Scalar;
+use fj_math::{Aabb, Scalar};
 
 /// A tolerance value
 ///
 /// A tolerance value is used during approximation. It defines the maximum
 /// allowed deviation of the approximation from the actual shape.
 ///
+/// That deviation can either be defined in absolute terms, or relative to the
+/// size of the feature being approximated. A relative tolerance results in
+/// proportionally finer approximations of smaller features, like small holes,
+/// without having to tune the tolerance value to the model.
+///
+/// Instead of a deviation, a tolerance can also define the number of segments
+/// that approximate a full circle. This bounds the complexity of the
+/// approximation, which is useful for fast previews.
+///
 /// The `Tolerance` type enforces that the tolerance value is always larger than
 /// zero, which is an attribute that the approximation code relies on.
 ///
@@ -22,10 +31,28 @@ use fj_math::Scalar;
 /// documentation doesn't provide any actual reasoning for this requirement, I'm
 /// feeling free to just ignore it.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct Tolerance(Scalar);
+pub enum Tolerance {
+    /// The maximum deviation, in model units
+    Absolute(Scalar),
+
+    /// The maximum deviation, as a fraction of the size of the feature
+    ///
+    /// For curves, the size of the feature is their radius of curvature. A
+    /// relative tolerance of `0.01` allows the approximation of a circle to
+    /// deviate from it by 1% of its radius.
+    Relative(RelativeTolerance),
+
+    /// The maximum number of segments that approximate a full circle
+    ///
+    /// Circles are approximated by exactly this many segments, regardless of
+    /// their size. Other features are approximated with the deviation that
+    /// this number of segments results in for a circle of their size. At least
+    /// 3 segments are used, even if a lower number is specified.
+    MaxSegments(u32),
+}
 
 impl Tolerance {
-    /// Construct a `Tolerance` from a [`Scalar`]
+    /// Construct an absolute `Tolerance` from a [`Scalar`]
     ///
     /// Returns an error, if the passed scalar is not larger than zero.
     pub fn from_scalar(
@@ -37,12 +64,60 @@ impl Tolerance {
             return Err(InvalidTolerance(scalar));
         }
 
-        Ok(Self(scalar))
+        Ok(Self::Absolute(scalar))
     }
 
-    /// Return the [`Scalar`] that defines the tolerance
-    pub fn inner(&self) -> Scalar {
-        self.0
+    /// Construct a relative `Tolerance` from a [`Scalar`]
+    ///
+    /// Returns an error, if the passed scalar is not larger than zero.
+    pub fn relative(
+        scalar: impl Into<Scalar>,
+    ) -> Result<Self, InvalidTolerance> {
+        RelativeTolerance::from_scalar(scalar).map(Self::Relative)
+    }
+
+    /// Construct an absolute `Tolerance` that is suitable for a model
+    ///
+    /// Derives the tolerance from the diagonal of the model's bounding box,
+    /// multiplied by `relative`. A value of `0.001`, for example, results in a
+    /// tolerance of 0.1% of the size of the model.
+    ///
+    /// Returns an error, if `relative` is not larger than zero, or if the
+    /// bounding box has no size, making the resulting tolerance zero.
+    pub fn for_model(
+        aabb: Aabb<3>,
+        relative: impl Into<Scalar>,
+    ) -> Result<Self, InvalidTolerance> {
+        let relative = RelativeTolerance::from_scalar(relative)?;
+        let diagonal = aabb.size().magnitude();
+
+        Self::from_scalar(diagonal * relative.inner())
+    }
+
+    /// Return the [`Scalar`] that defines the tolerance, if it is absolute
+    ///
+    /// Other kinds of tolerance only define a deviation in combination with
+    /// the size of a feature. Use [`Tolerance::for_feature_size`] for those.
+    pub fn inner(&self) -> Option<Scalar> {
+        match self {
+            Self::Absolute(tolerance) => Some(*tolerance),
+            Self::Relative(_) | Self::MaxSegments(_) => None,
+        }
+    }
+
+    /// Compute the maximum deviation for a feature of the given size
+    pub fn for_feature_size(&self, size: impl Into<Scalar>) -> Scalar {
+        match self {
+            Self::Absolute(tolerance) => *tolerance,
+            Self::Relative(tolerance) => tolerance.inner() * size.into(),
+            Self::MaxSegments(max_segments) => {
+                // This is the deviation of a chord from a circle, if the chord
+                // spans the angle of one segment.
+                let num_segments =
+                    Scalar::from(f64::from((*max_segments).max(3)));
+                (Scalar::ONE - (Scalar::PI / num_segments).cos()) * size.into()
+            }
+        }
     }
 }
 
@@ -56,7 +131,82 @@ where
     }
 }
 
+impl From<RelativeTolerance> for Tolerance {
+    fn from(tolerance: RelativeTolerance) -> Self {
+        Self::Relative(tolerance)
+    }
+}
+
+/// A tolerance value, relative to the size of the approximated feature
+///
+/// See [`Tolerance::Relative`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct RelativeTolerance(Scalar);
+
+impl RelativeTolerance {
+    /// Construct a `RelativeTolerance` from a [`Scalar`]
+    ///
+    /// Returns an error, if the passed scalar is not larger than zero.
+    pub fn from_scalar(
+        scalar: impl Into<Scalar>,
+    ) -> Result<Self, InvalidTolerance> {
+        let scalar = scalar.into();
+
+        if scalar <= Scalar::ZERO {
+            return Err(InvalidTolerance(scalar));
+        }
+
+        Ok(Self(scalar))
+    }
+
+    /// Return the [`Scalar`] that defines the tolerance
+    pub fn inner(&self) -> Scalar {
+        self.0
+    }
+}
+
 /// Error converting scalar to tolerance
 #[derive(Debug, thiserror::Error)]
 #[error("Invalid tolerance ({0}); must be above zero")]
 pub struct InvalidTolerance(Scalar);
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Scalar};
+
+    use super::Tolerance;
+
+    #[test]
+    fn from_scalar() {
+        assert!(Tolerance::from_scalar(0.1).is_ok());
+        assert!(Tolerance::from_scalar(0.).is_err());
+        assert!(Tolerance::from_scalar(-0.1).is_err());
+    }
+
+    #[test]
+    fn inner() {
+        assert_eq!(Tolerance::from(0.1).inner(), Some(Scalar::from(0.1)));
+        assert_eq!(Tolerance::relative(0.1).unwrap().inner(), None);
+        assert_eq!(Tolerance::MaxSegments(16).inner(), None);
+    }
+
+    #[test]
+    fn for_model() {
+        let aabb = Aabb {
+            min: [-1., -2., -2.].into(),
+            max: [1., 2., 2.].into(),
+        };
+
+        let tolerance = Tolerance::for_model(aabb, 0.01).unwrap();
+        assert_eq!(tolerance, Tolerance::Absolute(Scalar::from(0.06)));
+
+        assert!(Tolerance::for_model(aabb, 0.).is_err());
+        assert!(Tolerance::for_model(aabb, -0.01).is_err());
+
+        let point = Aabb {
+            min: [1., 2., 3.].into(),
+            max: [1., 2., 3.].into(),
+        };
+        assert!(Tolerance::for_model(point, 0.01).is_err());
+    }
+}
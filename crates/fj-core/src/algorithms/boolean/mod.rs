@@ -0,0 +1,158 @@
+//! Boolean operations on solids
+//!
+//! # Implementation Note
+//!
+//! There is no general algorithm for intersecting arbitrary surfaces yet, so
+//! the operations in this module only support a limited set of configurations.
+//! Those are documented on the respective operation. Configurations that are
+//! not supported result in an error.
+
+mod subtract;
+
+use std::ops::Deref;
+
+use fj_math::{Point, Vector};
+
+use crate::{
+    algorithms::intersect::{
+        ray_face::RayFaceIntersection, HorizontalRayToTheRight, Intersect,
+    },
+    objects::{Face, Solid},
+    validate::ValidationConfig,
+};
+
+pub use self::subtract::{Subtract, SubtractError};
+
+/// The number of points per edge that [`sample_points`] returns
+const SAMPLES_PER_EDGE: u32 = 8;
+
+/// Offsets of the ray origin, perpendicular to the ray, in units of
+/// [`ValidationConfig::distinct_min_distance`]
+///
+/// They are tried in order by [`contains_point`]. The first one leaves the ray
+/// where it is. The others don't line up with each other, or with the axes.
+const RAY_PERTURBATIONS: [[f64; 2]; 4] =
+    [[0., 0.], [0.37, 0.61], [-0.83, 0.29], [0.53, -0.91]];
+
+/// Indicate whether the point is located inside of the solid
+///
+/// Casts a ray from the point and counts how often it crosses the boundary of
+/// the solid. Hits on edges and vertices, and rays that run within a face, are
+/// ambiguous, as the ray might only touch the boundary instead of crossing it.
+/// In that case, the ray is moved by a tiny amount and cast again.
+///
+/// If all rays are ambiguous, the last one decides. A ray that hits an edge
+/// is reported by both faces that share the edge, hence those hits are counted
+/// half. Hitting a vertex doesn't count at all.
+fn contains_point(solid: &Solid, point: Point<3>) -> bool {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let mut num_half_hits = 0;
+
+    for [y, z] in RAY_PERTURBATIONS {
+        let ray = HorizontalRayToTheRight {
+            origin: point + Vector::from([0., y, z]) * epsilon,
+        };
+
+        num_half_hits = 0;
+        let mut is_ambiguous = false;
+
+        for shell in solid.shells() {
+            for face in shell.faces() {
+                match (&ray, face.deref()).intersect() {
+                    Some(RayFaceIntersection::RayHitsFace) => {
+                        num_half_hits += 2;
+                    }
+                    Some(RayFaceIntersection::RayHitsEdge(_)) => {
+                        num_half_hits += 1;
+                        is_ambiguous = true;
+                    }
+                    Some(RayFaceIntersection::RayHitsVertex(_))
+                    | Some(RayFaceIntersection::RayHitsFaceAndAreParallel) => {
+                        is_ambiguous = true;
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        if !is_ambiguous {
+            break;
+        }
+    }
+
+    (num_half_hits / 2) % 2 == 1
+}
+
+/// Sample points on the boundary of a face
+///
+/// Returns points that are evenly distributed over the parameter range of
+/// each of the face's half-edges, in global coordinates.
+fn sample_points(face: &Face) -> Vec<Point<3>> {
+    let surface = face.surface().geometry();
+
+    let mut points = Vec::new();
+
+    for cycle in face.region().all_cycles() {
+        for half_edge in cycle.half_edges() {
+            let [a, b] = half_edge.boundary().inner;
+
+            for i in 0..SAMPLES_PER_EDGE {
+                let t = a.t
+                    + (b.t - a.t)
+                        * (f64::from(i) / f64::from(SAMPLES_PER_EDGE));
+
+                let point_surface =
+                    half_edge.path().point_from_path_coords([t]);
+                points.push(surface.point_from_surface_coords(point_surface));
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    use super::contains_point;
+
+    #[test]
+    fn contains_point_with_ray_along_face() {
+        let mut services = Services::new();
+
+        // A U-shaped solid. A ray cast in x-direction at `y = 1` runs along
+        // the bottom of the notch, and hits the edges next to it.
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [
+                        [0., 0.],
+                        [3., 0.],
+                        [3., 2.],
+                        [2., 2.],
+                        [2., 1.],
+                        [1., 1.],
+                        [1., 2.],
+                        [0., 2.],
+                    ],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        assert!(contains_point(&solid, [0.5, 1., 0.5].into()));
+        assert!(contains_point(&solid, [0.5, 0.5, 0.5].into()));
+        assert!(!contains_point(&solid, [1.5, 1.5, 0.5].into()));
+        assert!(!contains_point(&solid, [-0.5, 1., 0.5].into()));
+    }
+}
@@ -0,0 +1,892 @@
+use std::ops::Deref;
+
+use fj_math::{
+    Aabb, BSpline, Bezier, Circle, Ellipse, Line, Point, Scalar, Vector,
+    Winding,
+};
+
+use crate::{
+    algorithms::{
+        bounding_volume::BoundingVolume,
+        intersect::{face_point::FacePointIntersection, Intersect},
+        sweep::{Sweep, SweepCache},
+        transform::TransformObject,
+    },
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{Cycle, Face, HalfEdge, Region, Shell, Solid},
+    operations::{BuildCycle, BuildHalfEdge, Insert, JoinCycle, Reverse},
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{contains_point, sample_points};
+
+/// Subtract a solid from another
+///
+/// This is the boolean difference of constructive solid geometry. The solid
+/// that is subtracted is called the tool, the solid it is subtracted from is
+/// called the target.
+///
+/// # Implementation Note
+///
+/// Only the following configurations are supported:
+///
+/// - The tool doesn't overlap the target at all. The target is returned
+///   unchanged.
+/// - The tool is a prism, meaning it has two parallel planar caps, and all of
+///   its other faces extend along the normal of those caps. The tool cuts
+///   through planar faces of the target that are parallel to its caps, either
+///   passing through the target entirely (a through-hole), or ending inside of
+///   it (a pocket). The tool's profile must be located completely within the
+///   faces it cuts through.
+/// - The tool is located completely inside of the target. It becomes an
+///   internal void of the target.
+///
+/// Subtracting a tool in any other configuration returns an error.
+pub trait Subtract {
+    /// Subtract the tool from the object
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the tool and the object are in a configuration
+    /// that is not supported (see [`Subtract`]).
+    fn subtract(
+        &self,
+        tool: &Handle<Solid>,
+        services: &mut Services,
+    ) -> Result<Handle<Solid>, SubtractError>;
+}
+
+impl Subtract for Handle<Solid> {
+    fn subtract(
+        &self,
+        tool: &Handle<Solid>,
+        services: &mut Services,
+    ) -> Result<Handle<Solid>, SubtractError> {
+        let (Some(aabb_target), Some(aabb_tool)) = (self.aabb(), tool.aabb())
+        else {
+            return Ok(self.clone());
+        };
+        if !aabbs_overlap(&aabb_target, &aabb_tool) {
+            return Ok(self.clone());
+        }
+
+        // A tool can be a prism in multiple directions, like a cuboid. Only
+        // some of those might result in a cut. If none does, the first reason
+        // for that is the most useful error to return.
+        let mut error = None;
+        'prisms: for prism in Prism::all_from_solid(tool) {
+            let mut is_cut = false;
+            let mut shells = Vec::new();
+
+            for shell in self.shells() {
+                match prism.cut(shell, self, services) {
+                    Ok(Some(shell)) => {
+                        is_cut = true;
+                        shells.push(shell);
+                    }
+                    Ok(None) => shells.push(shell.clone()),
+                    Err(err) => {
+                        error.get_or_insert(err);
+                        continue 'prisms;
+                    }
+                }
+            }
+
+            if is_cut {
+                return Ok(Solid::new(shells).insert(services));
+            }
+        }
+
+        let tool_is_contained = tool
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| sample_points(face))
+            .all(|point| contains_point(self, point));
+
+        if tool_is_contained {
+            // The tool becomes a void within the target. Its faces need to
+            // point into the void, which means away from the material of the
+            // target.
+            let voids = tool
+                .shells()
+                .map(|shell| shell.reverse(services).insert(services))
+                .collect::<Vec<_>>();
+
+            return Ok(Solid::new(self.shells().cloned().chain(voids))
+                .insert(services));
+        }
+
+        let target_is_contained = aabbs_contain(&aabb_tool, &aabb_target)
+            && self
+                .shells()
+                .flat_map(|shell| shell.faces())
+                .flat_map(|face| sample_points(face))
+                .all(|point| contains_point(tool, point));
+
+        if target_is_contained {
+            return Err(SubtractError::ToolEnclosesTarget);
+        }
+
+        Err(error.unwrap_or(SubtractError::UnsupportedConfiguration))
+    }
+}
+
+/// Error subtracting a solid from another
+///
+/// See [`Subtract`].
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum SubtractError {
+    /// The tool encloses the target, which would leave nothing of it
+    #[error("Tool encloses the target completely")]
+    ToolEnclosesTarget,
+
+    /// The tool cuts through a face, but only covers part of it
+    #[error("Tool only partially overlaps a face it cuts through")]
+    PartialOverlap {
+        /// The face that the tool partially overlaps
+        face: Handle<Face>,
+    },
+
+    /// The tool ends within the target, but its end is not fully inside of it
+    #[error("Bottom of the pocket is not fully contained in the target")]
+    PocketBottomNotContained,
+
+    /// The tool cuts through more than two faces of a shell
+    #[error(
+        "Tool cuts through {num_faces} faces, but at most 2 are supported"
+    )]
+    TooManyFaces {
+        /// The number of faces that the tool cuts through
+        num_faces: usize,
+    },
+
+    /// The tool and target are in a configuration that is not supported
+    #[error("Subtracting a solid in this configuration is not supported")]
+    UnsupportedConfiguration,
+}
+
+/// A solid that has two parallel planar caps, connected by straight walls
+struct Prism {
+    /// The cap that the other cap is located above
+    bottom: Handle<Face>,
+
+    /// The normalized direction from the bottom cap to the top cap
+    direction: Vector<3>,
+
+    /// The distance between the caps
+    height: Scalar,
+
+    epsilon: Scalar,
+}
+
+impl Prism {
+    /// Interpret the solid as a prism, in all possible directions
+    fn all_from_solid(solid: &Solid) -> Vec<Self> {
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        let mut prisms = Vec::new();
+
+        let shells = solid.shells().collect::<Vec<_>>();
+        let [shell] = shells.as_slice() else {
+            return prisms;
+        };
+        let faces = shell.faces().into_iter().collect::<Vec<_>>();
+
+        for candidate in &faces {
+            let Some(direction) = normal_of(candidate) else {
+                continue;
+            };
+            let is_parallel = |vector: Vector<3>| {
+                vector.normalize().cross(&direction).magnitude() < epsilon
+            };
+
+            let (caps, walls): (Vec<&Handle<Face>>, Vec<_>) =
+                faces.iter().copied().partition(|face| {
+                    normal_of(face).map(is_parallel).unwrap_or(false)
+                });
+
+            let [a, b] = caps.as_slice() else {
+                continue;
+            };
+            if a.region().interiors().next().is_some()
+                || b.region().interiors().next().is_some()
+            {
+                // Caps with holes would require walls within the prism.
+                continue;
+            }
+
+            let walls_extend_along_direction = walls.iter().all(|face| {
+                let surface = face.surface().geometry();
+
+                match surface.u {
+                    GlobalPath::Line(_) => normal_of(face)
+                        .map(|normal| normal.dot(&direction).abs() < epsilon)
+                        .unwrap_or(false),
+                    GlobalPath::Bezier(_)
+                    | GlobalPath::BSpline(_)
+                    | GlobalPath::Circle(_)
+                    | GlobalPath::Ellipse(_)
+                    | GlobalPath::Helix(_) => is_parallel(surface.v),
+                }
+            });
+            if !walls_extend_along_direction {
+                continue;
+            }
+
+            let [offset_a, offset_b] = [a, b].map(|cap| {
+                cap.surface().geometry().u.origin().coords.dot(&direction)
+            });
+            let (bottom, height) = if offset_a < offset_b {
+                (a, offset_b - offset_a)
+            } else {
+                (b, offset_a - offset_b)
+            };
+
+            if height < epsilon {
+                continue;
+            }
+
+            prisms.push(Self {
+                bottom: (*bottom).clone(),
+                direction,
+                height,
+                epsilon,
+            });
+        }
+
+        prisms
+    }
+
+    /// Compute the distance of a point from the plane of the bottom cap
+    fn offset_of(&self, point: Point<3>) -> Scalar {
+        (point - self.bottom.surface().geometry().u.origin())
+            .dot(&self.direction)
+    }
+
+    /// Cut the prism out of a shell
+    ///
+    /// Returns `None`, if the prism doesn't cut through any face of the shell.
+    /// `solid` is the solid that the shell belongs to.
+    fn cut(
+        &self,
+        shell: &Shell,
+        solid: &Solid,
+        services: &mut Services,
+    ) -> Result<Option<Handle<Shell>>, SubtractError> {
+        let profile = sample_points(&self.bottom);
+
+        // Find all faces that the prism cuts through, and where it does that.
+        let mut crossed = Vec::new();
+        for face in shell.faces() {
+            let Some(normal) = normal_of(face) else {
+                continue;
+            };
+            if normal.cross(&self.direction).magnitude() >= self.epsilon {
+                continue;
+            }
+
+            let offset = self.offset_of(face.surface().geometry().u.origin());
+            if offset <= self.epsilon || offset >= self.height - self.epsilon {
+                continue;
+            }
+
+            let num_inside = profile
+                .iter()
+                .filter(|&&point| {
+                    let point = face
+                        .surface()
+                        .geometry()
+                        .project_global_point(point + self.direction * offset);
+
+                    matches!(
+                        (face.deref(), &point).intersect(),
+                        Some(FacePointIntersection::PointIsInsideFace)
+                    )
+                })
+                .count();
+
+            if num_inside == 0 {
+                continue;
+            }
+            if num_inside < profile.len() {
+                return Err(SubtractError::PartialOverlap {
+                    face: face.clone(),
+                });
+            }
+
+            crossed.push((offset, face.clone()));
+        }
+
+        crossed.sort_by_key(|(offset, _)| *offset);
+
+        let (entry, end) = match crossed.as_slice() {
+            [] => return Ok(None),
+            [(offset_entry, entry), (offset_exit, exit)] => {
+                ((*offset_entry, entry), CutEnd::Face(*offset_exit, exit))
+            }
+            [(offset_entry, entry)] => {
+                // The prism ends within the solid, forming a pocket. We need
+                // to figure out which of its caps is the bottom of the pocket.
+                let is_inside = |offset: Scalar| {
+                    profile.iter().all(|&point| {
+                        contains_point(solid, point + self.direction * offset)
+                    })
+                };
+
+                let end = if is_inside(Scalar::ZERO) {
+                    CutEnd::Cap(Scalar::ZERO)
+                } else if is_inside(self.height) {
+                    CutEnd::Cap(self.height)
+                } else {
+                    return Err(SubtractError::PocketBottomNotContained);
+                };
+
+                ((*offset_entry, entry), end)
+            }
+            crossed => {
+                return Err(SubtractError::TooManyFaces {
+                    num_faces: crossed.len(),
+                })
+            }
+        };
+
+        let (offset_entry, entry) = entry;
+        let offset_end = match end {
+            CutEnd::Face(offset, _) | CutEnd::Cap(offset) => offset,
+        };
+        let path = self.direction * (offset_end - offset_entry);
+
+        // Let's create the hole in the entry face. We start out with a face
+        // that covers it, so we can sweep that face to create the walls of the
+        // hole, and know which half-edges to share with those.
+        let hole = {
+            let half_edges = self
+                .bottom
+                .region()
+                .exterior()
+                .half_edges()
+                .map(|half_edge| {
+                    let path = transfer_path(
+                        half_edge.path(),
+                        self.bottom.surface().geometry(),
+                        entry.surface().geometry(),
+                        self.direction * offset_entry,
+                    );
+
+                    HalfEdge::unjoined(path, half_edge.boundary(), services)
+                        .insert(services)
+                })
+                .collect::<Vec<_>>();
+
+            let cycle = Cycle::new(half_edges);
+
+            // The sweep below requires the face to have the same orientation
+            // as the surface it's defined in.
+            if cycle.winding() == Winding::Cw {
+                cycle.reverse(services)
+            } else {
+                cycle
+            }
+        };
+        let hole = {
+            let region = Region::new(
+                hole.insert(services),
+                [],
+                entry.region().color(),
+                entry.region().material().cloned(),
+            )
+            .insert(services);
+            Face::new(entry.surface().clone(), region).insert(services)
+        };
+
+        let is_negative_sweep = {
+            let normal = normal_of(entry).expect("Crossed faces are planar");
+            normal.dot(&path) < Scalar::ZERO
+        };
+
+        // This is the face that closes the hole at the entry face, oriented in
+        // the same way as the entry face.
+        let hole = if is_negative_sweep {
+            hole
+        } else {
+            hole.reverse(services).insert(services)
+        };
+
+        let mut faces = shell
+            .faces()
+            .into_iter()
+            .filter(|face| face.id() != entry.id())
+            .filter(|face| match end {
+                CutEnd::Face(_, exit) => face.id() != exit.id(),
+                CutEnd::Cap(_) => true,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut cache = SweepCache::default();
+        let mut top_edges = Vec::new();
+        for (half_edge, next) in
+            hole.region().exterior().reverse(services).half_edge_pairs()
+        {
+            let (wall, top_edge) = (
+                half_edge.deref(),
+                next.start_vertex(),
+                entry.surface().deref(),
+                entry.region().color(),
+                entry.region().material(),
+            )
+                .sweep_with_cache(path, &mut cache, services);
+
+            // The sweep creates the walls facing out of the swept volume. But
+            // that volume is empty, so they need to face into it.
+            faces.push(wall.reverse(services).insert(services));
+
+            top_edges.push((
+                top_edge,
+                half_edge.path().clone(),
+                half_edge.boundary(),
+            ));
+        }
+
+        faces.push(with_interior(
+            entry,
+            hole.region().exterior().reverse(services).insert(services),
+            services,
+        ));
+
+        match end {
+            CutEnd::Face(_, exit) => {
+                let top_edges = top_edges
+                    .into_iter()
+                    .map(|(half_edge, path_on_entry, boundary)| {
+                        let path = transfer_path(
+                            &path_on_entry,
+                            entry.surface().geometry(),
+                            exit.surface().geometry(),
+                            path,
+                        );
+                        (half_edge, path, boundary)
+                    })
+                    .collect::<Vec<_>>();
+
+                // The joined cycle has the same orientation as the exit face,
+                // but interior cycles need the opposite one.
+                let cycle = Cycle::empty()
+                    .add_joined_edges(top_edges, services)
+                    .reverse(services)
+                    .insert(services);
+
+                faces.push(with_interior(exit, cycle, services));
+            }
+            CutEnd::Cap(_) => {
+                let cycle = Cycle::empty()
+                    .add_joined_edges(top_edges, services)
+                    .insert(services);
+                let surface = entry.surface().clone().translate(path, services);
+                let region = Region::new(
+                    cycle,
+                    [],
+                    entry.region().color(),
+                    entry.region().material().cloned(),
+                )
+                .insert(services);
+
+                // The face closing off the swept volume faces out of it, but
+                // the bottom of the pocket needs to face into it.
+                let bottom = Face::new(surface, region).reverse(services);
+                faces.push(bottom.insert(services));
+            }
+        }
+
+        Ok(Some(Shell::new(faces).insert(services)))
+    }
+}
+
+/// The end of a cut made by a [`Prism`]
+enum CutEnd<'r> {
+    /// The cut ends at a face, which the prism passes through
+    Face(Scalar, &'r Handle<Face>),
+
+    /// The cut ends at a cap of the prism, located inside of the solid
+    Cap(Scalar),
+}
+
+fn aabbs_overlap(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    let [a_min, a_max, b_min, b_max] =
+        [a.min, a.max, b.min, b.max].map(|point| point.coords.components);
+
+    (0..3).all(|i| a_min[i] <= b_max[i] && b_min[i] <= a_max[i])
+}
+
+/// Indicate whether `b` is located completely within `a`
+fn aabbs_contain(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    let [a_min, a_max, b_min, b_max] =
+        [a.min, a.max, b.min, b.max].map(|point| point.coords.components);
+
+    (0..3).all(|i| a_min[i] <= b_min[i] && b_max[i] <= a_max[i])
+}
+
+/// Compute the normalized normal of a face's surface, if it is a plane
+///
+/// The normal is defined by the surface, not the orientation of the face.
+fn normal_of(face: &Face) -> Option<Vector<3>> {
+    let surface = face.surface().geometry();
+
+    match surface.u {
+        GlobalPath::Line(line) => {
+            Some(line.direction().cross(&surface.v).normalize())
+        }
+        GlobalPath::Bezier(_)
+        | GlobalPath::BSpline(_)
+        | GlobalPath::Circle(_)
+        | GlobalPath::Ellipse(_)
+        | GlobalPath::Helix(_) => None,
+    }
+}
+
+/// Create a copy of the face with an additional interior cycle
+fn with_interior(
+    face: &Face,
+    interior: Handle<Cycle>,
+    services: &mut Services,
+) -> Handle<Face> {
+    let region = Region::new(
+        face.region().exterior().clone(),
+        face.region().interiors().cloned().chain([interior]),
+        face.region().color(),
+        face.region().material().cloned(),
+    )
+    .insert(services);
+
+    Face::new(face.surface().clone(), region).insert(services)
+}
+
+/// Convert a path from one planar surface to another
+///
+/// The path is moved by `offset` in the process. The coordinates on the path
+/// stay the same.
+fn transfer_path(
+    path: &SurfacePath,
+    from: &SurfaceGeometry,
+    to: &SurfaceGeometry,
+    offset: Vector<3>,
+) -> SurfacePath {
+    let point = |point: Point<2>| {
+        to.project_global_point(from.point_from_surface_coords(point) + offset)
+    };
+    let vector = |origin: Point<2>, vector: Vector<2>| {
+        point(origin + vector) - point(origin)
+    };
+
+    // A circle or ellipse can only be transferred, if the conversion between
+    // the coordinate systems preserves angles. Then we can reconstruct the
+    // second axis from the first one, which keeps it precisely perpendicular.
+    let perpendicular = |a: Vector<2>, b: Vector<2>| {
+        let rotated = Vector::from([-a.v, a.u]);
+        let rotated = if rotated.dot(&b) < Scalar::ZERO {
+            -rotated
+        } else {
+            rotated
+        };
+
+        assert!(
+            (rotated * (b.magnitude() / a.magnitude()) - b).magnitude()
+                < ValidationConfig::default().distinct_min_distance,
+            "Transferring round paths between surfaces with incompatible \
+            coordinate systems is not supported"
+        );
+
+        rotated
+    };
+
+    match path {
+        SurfacePath::Bezier(bezier) => {
+            // Bézier curves are preserved by affine maps, so there's no need
+            // for any of the special handling that round paths require.
+            SurfacePath::Bezier(Bezier::from_control_points(
+                bezier.control_points().map(point),
+            ))
+        }
+        SurfacePath::BSpline(bspline) => {
+            SurfacePath::BSpline(BSpline::from_control_points(
+                bspline.control_points().iter().copied().map(point),
+                bspline.degree(),
+            ))
+        }
+        SurfacePath::Line(line) => {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                point(line.origin()),
+                vector(line.origin(), line.direction()),
+            ))
+        }
+        SurfacePath::Circle(circle) => {
+            let a = vector(circle.center(), circle.a());
+            let b = vector(circle.center(), circle.b());
+
+            SurfacePath::Circle(Circle::new(
+                point(circle.center()),
+                a,
+                perpendicular(a, b),
+            ))
+        }
+        SurfacePath::Ellipse(ellipse) => {
+            let major = vector(ellipse.center(), ellipse.major());
+            let minor = vector(ellipse.center(), ellipse.minor());
+
+            SurfacePath::Ellipse(Ellipse::new(
+                point(ellipse.center()),
+                major,
+                perpendicular(major, minor)
+                    * (minor.magnitude() / major.magnitude()),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, transform::TransformObject,
+            triangulate::Triangulate,
+        },
+        objects::{Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{Subtract, SubtractError};
+
+    #[test]
+    fn subtract_through_hole() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let target = cuboid([0., 0., 0.], [2., 2., 2.], &mut services);
+        let tool = cylinder([1., 1., -1.], 0.5, 4., &mut services);
+
+        let difference = target.subtract(&tool, &mut services)?;
+        assert_eq!(num_faces(&difference), 6 + 1);
+        assert_mesh_volume(&difference, 8. - Scalar::PI.into_f64() * 0.5)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_pocket() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let target = cuboid([0., 0., 0.], [2., 2., 2.], &mut services);
+
+        // The tool enters the target from above...
+        let tool = cuboid([0.5, 0.5, 1.], [1., 1., 2.], &mut services);
+        let difference = target.subtract(&tool, &mut services)?;
+        assert_eq!(num_faces(&difference), 6 + 5);
+        assert_mesh_volume(&difference, 8. - 1.)?;
+
+        // ...or from below.
+        let tool = cuboid([0.5, 0.5, -1.], [1., 1., 2.], &mut services);
+        let difference = target.subtract(&tool, &mut services)?;
+        assert_eq!(num_faces(&difference), 6 + 5);
+        assert_mesh_volume(&difference, 8. - 1.)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_internal_void() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let target = cuboid([0., 0., 0.], [2., 2., 2.], &mut services);
+        let tool = cuboid([0.5, 0.5, 0.5], [1., 1., 1.], &mut services);
+
+        let difference = target.subtract(&tool, &mut services)?;
+        assert_eq!(difference.shells().count(), 2);
+        assert_mesh_volume(&difference, 8. - 1.)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_disjoint() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let target = cuboid([0., 0., 0.], [2., 2., 2.], &mut services);
+        let tool = cuboid([3., 0., 0.], [1., 1., 1.], &mut services);
+
+        let difference = target.subtract(&tool, &mut services)?;
+        assert_eq!(difference.id(), target.id());
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_enclosing_tool() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let target = cuboid([0.5, 0.5, 0.5], [1., 1., 1.], &mut services);
+        let tool = cuboid([0., 0., 0.], [2., 2., 2.], &mut services);
+
+        let result = target.subtract(&tool, &mut services);
+        assert!(matches!(result, Err(SubtractError::ToolEnclosesTarget)));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_partial_overlap() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The tool passes through the target, but overhangs its side.
+        let target = cuboid([0., 0., 0.], [2., 2., 2.], &mut services);
+        let tool = cylinder([2., 1., -1.], 0.5, 4., &mut services);
+
+        let result = target.subtract(&tool, &mut services);
+        assert!(matches!(result, Err(SubtractError::PartialOverlap { .. })));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_pocket_bottom_not_contained() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The target is a triangular prism. The tool enters it through the
+        // wall at `x = 0`, and ends at the slanted wall, which it pokes out
+        // of.
+        let target =
+            prism(&[[0., 0.], [2., 0.], [0., 2.]], [0., 0., 2.], &mut services);
+        let tool = cuboid([-1., 0.2, 0.5], [2.7, 0.2, 0.5], &mut services);
+
+        let result = target.subtract(&tool, &mut services);
+        assert!(matches!(
+            result,
+            Err(SubtractError::PocketBottomNotContained)
+        ));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_through_more_than_two_faces() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The target is U-shaped. The tool passes through both of its prongs.
+        let target = prism(
+            &[
+                [0., 0.],
+                [3., 0.],
+                [3., 2.],
+                [2., 2.],
+                [2., 1.],
+                [1., 1.],
+                [1., 2.],
+                [0., 2.],
+            ],
+            [0., 0., 1.],
+            &mut services,
+        );
+        let tool = cuboid([-1., 1.25, 0.25], [5., 0.5, 0.5], &mut services);
+
+        let result = target.subtract(&tool, &mut services);
+        assert!(matches!(
+            result,
+            Err(SubtractError::TooManyFaces { num_faces: 4 })
+        ));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn prism(
+        polygon: &[[f64; 2]],
+        path: [f64; 3],
+        services: &mut Services,
+    ) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(polygon.iter().copied(), services)
+                    .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface).sweep(path, services)
+    }
+
+    fn cuboid(
+        origin: [f64; 3],
+        [x, y, z]: [f64; 3],
+        services: &mut Services,
+    ) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon([[0., 0.], [x, 0.], [x, y], [0., y]], services)
+                    .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface)
+            .sweep([0., 0., z], services)
+            .translate(origin, services)
+    }
+
+    fn cylinder(
+        origin: [f64; 3],
+        radius: f64,
+        height: f64,
+        services: &mut Services,
+    ) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), radius, services)
+                    .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface)
+            .sweep([0., 0., height], services)
+            .translate(origin, services)
+    }
+
+    fn num_faces(solid: &Solid) -> usize {
+        solid
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum()
+    }
+
+    /// Check the volume enclosed by the triangulated solid
+    ///
+    /// This only works, if all faces are oriented correctly.
+    fn assert_mesh_volume(solid: &Solid, expected: f64) -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        let error = (volume.into_f64() - expected).abs();
+        assert!(error < 0.01, "Unexpected volume: {volume}, not {expected}");
+
+        Ok(())
+    }
+}
@@ -5,6 +5,18 @@ use crate::{geometry::SurfacePath, objects::HalfEdge};
 impl super::BoundingVolume<2> for HalfEdge {
     fn aabb(&self) -> Option<Aabb<2>> {
         match self.path() {
+            SurfacePath::Bezier(bezier) => {
+                // The curve is contained within the convex hull of its control
+                // points. That only holds between the coordinates `0` and `1`
+                // though, which is what Bézier curves are usually used for.
+                Some(Aabb::<2>::from_points(bezier.control_points()))
+            }
+            SurfacePath::BSpline(bspline) => {
+                // Same as for the Bézier curve.
+                Some(Aabb::<2>::from_points(
+                    bspline.control_points().iter().copied(),
+                ))
+            }
             SurfacePath::Circle(circle) => {
                 // Just calculate the AABB of the whole circle. This is not the
                 // most precise, but it should do for now.
@@ -17,6 +29,10 @@ impl super::BoundingVolume<2> for HalfEdge {
                     max: circle.center() + center_to_min_max,
                 })
             }
+            SurfacePath::Ellipse(ellipse) => {
+                // Same as for the circle, just use the whole ellipse.
+                Some(ellipse.aabb())
+            }
             SurfacePath::Line(_) => {
                 let points = self.boundary().inner.map(|point_curve| {
                     self.path().point_from_path_coords(point_curve)
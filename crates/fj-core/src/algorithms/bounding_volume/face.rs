@@ -1,4 +1,4 @@
-use fj_math::Aabb;
+use fj_math::{Aabb, Circle, Scalar};
 
 use crate::{geometry::GlobalPath, objects::Face};
 
@@ -7,24 +7,47 @@ impl super::BoundingVolume<3> for Face {
         self.region().exterior().aabb().map(|aabb2| {
             let surface = self.surface().geometry();
 
-            match surface.u {
-                GlobalPath::Circle(circle) => {
-                    // This is not the most precise way to calculate the AABB,
-                    // doing it for the whole circle, but it should do.
-
-                    let aabb_bottom = circle.aabb();
-                    let aabb_top = Aabb {
-                        min: aabb_bottom.min + surface.v,
-                        max: aabb_bottom.max + surface.v,
-                    };
+            // This is not the most precise way to calculate the AABB of a
+            // curved surface, doing it for the whole curve, but it should do.
+            let aabb_bottom = match &surface.u {
+                GlobalPath::Bezier(bezier) => {
+                    Aabb::<3>::from_points(bezier.control_points())
+                }
+                GlobalPath::BSpline(bspline) => Aabb::<3>::from_points(
+                    bspline.control_points().iter().copied(),
+                ),
+                GlobalPath::Circle(circle) => circle.aabb(),
+                GlobalPath::Ellipse(ellipse) => ellipse.aabb(),
+                GlobalPath::Helix(helix) => {
+                    // A helix is infinite, but the face only covers part of
+                    // it. Take the circle that the helix projects onto, and
+                    // move it along with the helix, across that part.
+                    let circle =
+                        Circle::new(helix.center(), helix.a(), helix.b())
+                            .aabb();
+                    let [min, max] = [aabb2.min.u, aabb2.max.u].map(|u| {
+                        let offset = helix.advance() * (u / Scalar::TAU);
+                        Aabb {
+                            min: circle.min + offset,
+                            max: circle.max + offset,
+                        }
+                    });
 
-                    aabb_bottom.merged(&aabb_top)
+                    min.merged(&max)
                 }
-                GlobalPath::Line(_) => Aabb {
-                    min: surface.point_from_surface_coords(aabb2.min),
-                    max: surface.point_from_surface_coords(aabb2.max),
-                },
-            }
+                GlobalPath::Line(_) => {
+                    return Aabb {
+                        min: surface.point_from_surface_coords(aabb2.min),
+                        max: surface.point_from_surface_coords(aabb2.max),
+                    }
+                }
+            };
+            let aabb_top = Aabb {
+                min: aabb_bottom.min + surface.v,
+                max: aabb_bottom.max + surface.v,
+            };
+
+            aabb_bottom.merged(&aabb_top)
         })
     }
 }
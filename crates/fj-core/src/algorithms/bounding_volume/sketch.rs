@@ -0,0 +1,21 @@
+use fj_math::Aabb;
+
+use crate::objects::Sketch;
+
+impl super::BoundingVolume<2> for Sketch {
+    fn aabb(&self) -> Option<Aabb<2>> {
+        let mut aabb: Option<Aabb<2>> = None;
+
+        for region in self.regions() {
+            // The interiors of a region are contained within its exterior, so
+            // the exterior is all that needs to be considered.
+            let new_aabb = region.exterior().aabb();
+            aabb = aabb.map_or(new_aabb, |aabb| match new_aabb {
+                Some(new_aabb) => Some(aabb.merged(&new_aabb)),
+                None => Some(aabb),
+            });
+        }
+
+        aabb
+    }
+}
@@ -1,8 +1,12 @@
 //! Intersection between faces and points in 2D
 
-use fj_math::Point;
+use fj_math::{Aabb, Point};
 
 use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        triangulate::polygon::Polygon,
+    },
     objects::{Face, HalfEdge},
     storage::Handle,
 };
@@ -128,15 +132,75 @@ pub enum FacePointIntersection {
     PointIsOnVertex(Point<2>),
 }
 
+/// Check whether an object contains a point
+pub trait ContainsPoint {
+    /// Check whether the object contains a point
+    ///
+    /// The point is given in surface coordinates. A face contains all points
+    /// that are within its exterior cycle, but not within any of its interior
+    /// cycles. Points on the face's boundary, including the boundaries of its
+    /// holes, are contained too.
+    ///
+    /// Curved edges are approximated with the given tolerance. To make up for
+    /// that, points within `tolerance` of the approximated boundary are
+    /// considered to be on the boundary. A relative tolerance is applied to the
+    /// size of the face.
+    fn contains_point(
+        &self,
+        point: impl Into<Point<2>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> bool;
+}
+
+impl ContainsPoint for Face {
+    fn contains_point(
+        &self,
+        point: impl Into<Point<2>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> bool {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        let approx = self.approx(tolerance);
+        let polygon = Polygon::from(&approx);
+
+        let points = polygon
+            .edges()
+            .flat_map(|edge| edge.points())
+            .collect::<Vec<_>>();
+        if points.is_empty() {
+            return false;
+        }
+        let aabb = Aabb::<2>::from_points(points);
+        let tolerance =
+            tolerance.for_feature_size((aabb.max - aabb.min).magnitude());
+
+        let is_on_boundary = polygon
+            .distance_to_edges(point)
+            .is_some_and(|distance| distance <= tolerance);
+
+        is_on_boundary || polygon.contains_point(point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use fj_math::Point;
+    use fj_math::{Point, Scalar};
     use pretty_assertions::assert_eq;
 
     use crate::{
-        algorithms::intersect::{face_point::FacePointIntersection, Intersect},
-        objects::{Cycle, Face},
-        operations::{BuildCycle, BuildFace, Insert, UpdateFace, UpdateRegion},
+        algorithms::{
+            approx::Tolerance,
+            intersect::{
+                face_point::{ContainsPoint, FacePointIntersection},
+                Intersect,
+            },
+        },
+        objects::{Cycle, Face, Region},
+        operations::{
+            BuildCycle, BuildFace, BuildRegion, Insert, Reverse, UpdateFace,
+            UpdateRegion,
+        },
         services::Services,
     };
 
@@ -383,4 +447,70 @@ mod tests {
 
         services.only_validate(face);
     }
+
+    #[test]
+    fn contains_point_with_hole() {
+        let mut services = Services::new();
+
+        let region = Region::polygon(
+            [[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+            &mut services,
+        )
+        .add_interiors([Cycle::polygon(
+            [[1., 1.], [3., 1.], [3., 3.], [1., 3.]],
+            &mut services,
+        )
+        .reverse(&mut services)
+        .insert(&mut services)])
+        .insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region);
+
+        let tolerance = 0.001;
+        for (point, expected) in [
+            ([0.5, 0.5], true),
+            ([3.5, 2.], true),
+            ([2., 2.], false),
+            ([5., 2.], false),
+            ([-0.5, 2.], false),
+            // Points on the boundary are contained, including the boundary of
+            // the hole.
+            ([0., 2.], true),
+            ([4., 4.], true),
+            ([1., 2.], true),
+            ([3., 3.], true),
+        ] {
+            assert_eq!(
+                face.contains_point(point, tolerance),
+                expected,
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn contains_point_on_curved_boundary() {
+        let mut services = Services::new();
+
+        let region =
+            Region::circle([0., 0.], 1., &mut services).insert(&mut services);
+        let face = Face::new(services.objects.surfaces.xy_plane(), region);
+
+        let tolerance = 0.001;
+
+        // These points are on the circle, but not necessarily within its
+        // approximation.
+        for angle in [0., 0.1, 1., 2.5, 4.] {
+            let (sin, cos) = Scalar::from(angle).sin_cos();
+            assert!(face.contains_point([cos, sin], tolerance), "{angle}");
+        }
+
+        assert!(face.contains_point([0.5, 0.], tolerance));
+        assert!(!face.contains_point([1.01, 0.], tolerance));
+        assert!(!face.contains_point([0., -1.01], tolerance));
+
+        // A relative tolerance is applied to the size of the face.
+        let tolerance = Tolerance::relative(0.001).unwrap();
+        assert!(face.contains_point([0., 1.], tolerance));
+        assert!(!face.contains_point([1.01, 0.], tolerance));
+    }
 }
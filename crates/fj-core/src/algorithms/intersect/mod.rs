@@ -18,7 +18,7 @@ pub use self::{
     curve_face::{CurveFaceIntersection, CurveFaceIntersectionInterval},
     face_face::FaceFaceIntersection,
     line_segment::LineSegmentIntersection,
-    surface_surface::SurfaceSurfaceIntersection,
+    surface_surface::{SurfaceSurfaceIntersection, UnsupportedIntersection},
 };
 
 /// Compute the intersection between a tuple of objects
@@ -19,8 +19,11 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &Handle<HalfEdge>) {
 
         let line = match edge.path() {
             SurfacePath::Line(line) => line,
-            SurfacePath::Circle(_) => {
-                todo!("Casting rays against circles is not supported yet")
+            SurfacePath::Bezier(_)
+            | SurfacePath::BSpline(_)
+            | SurfacePath::Circle(_)
+            | SurfacePath::Ellipse(_) => {
+                todo!("Casting rays against curves is not supported yet")
             }
         };
 
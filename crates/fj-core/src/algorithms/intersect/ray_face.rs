@@ -18,8 +18,12 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Face) {
         let (ray, face) = self;
 
         let plane = match face.surface().geometry().u {
-            GlobalPath::Circle(_) => todo!(
-                "Casting a ray against a swept circle is not supported yet"
+            GlobalPath::Bezier(_)
+            | GlobalPath::BSpline(_)
+            | GlobalPath::Circle(_)
+            | GlobalPath::Ellipse(_)
+            | GlobalPath::Helix(_) => todo!(
+                "Casting a ray against a swept curve is not supported yet"
             ),
             GlobalPath::Line(line) => Plane::from_parametric(
                 line.origin(),
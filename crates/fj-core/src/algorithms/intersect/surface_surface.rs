@@ -1,9 +1,12 @@
-use fj_math::{Line, Plane, Point, Scalar};
+use fj_math::{
+    BSpline, Bezier, Circle, Ellipse, Line, Plane, Point, Scalar, Vector,
+};
 
 use crate::{
     geometry::{GlobalPath, SurfacePath},
     objects::Surface,
     storage::Handle,
+    validate::ValidationConfig,
 };
 
 /// The intersection between two surfaces
@@ -23,7 +26,186 @@ impl SurfaceSurfaceIntersection {
         // coordinates for each surface.
 
         let planes = surfaces.map(|surface| plane_from_surface(&surface));
+        Self::compute_for_planes(planes)
+    }
+
+    /// Compute the intersections between a surface and a plane
+    ///
+    /// In addition to the planes that [`SurfaceSurfaceIntersection::compute`]
+    /// supports, this supports surfaces that are swept along a curve, if the
+    /// plane is perpendicular or parallel to the direction of the sweep. The
+    /// first intersection curve is always a line, on the surface. The second
+    /// one, on the plane, is a line, or the curve that the surface was swept
+    /// along.
+    ///
+    /// A curved surface can intersect a plane in multiple lines, which is why
+    /// this returns a list. Surfaces that are swept along a circle or ellipse
+    /// repeat every `2π` along their u-axis, and so do those lines. Only the
+    /// ones with a u-coordinate between `0` and `2π` are returned.
+    ///
+    /// Returns an error, if the second surface is not a plane, or if computing
+    /// the intersection is not supported.
+    pub fn compute_with_plane(
+        surface: &Surface,
+        plane: &Surface,
+    ) -> Result<Vec<Self>, UnsupportedIntersection> {
+        let plane = match plane.geometry().u {
+            GlobalPath::Line(line) => Plane::from_parametric(
+                line.origin(),
+                line.direction(),
+                plane.geometry().v,
+            ),
+            GlobalPath::Bezier(_)
+            | GlobalPath::BSpline(_)
+            | GlobalPath::Circle(_)
+            | GlobalPath::Ellipse(_)
+            | GlobalPath::Helix(_) => return Err(UnsupportedIntersection),
+        };
+
+        let geometry = surface.geometry();
+        if let GlobalPath::Line(_) = geometry.u {
+            let planes = [plane_from_surface(surface), plane];
+            return Ok(Self::compute_for_planes(planes).into_iter().collect());
+        }
+
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+        let normal = plane.normal();
+        let direction = geometry.v.normalize();
+
+        if normal.cross(&direction).magnitude() < epsilon {
+            // The plane is perpendicular to the direction of the sweep. If the
+            // curve that was swept is parallel to the plane, the intersection
+            // is a copy of that curve, at a constant v-coordinate.
+            let is_parallel = |axes: [Vector<3>; 2]| {
+                let [a, b] = axes;
+                a.cross(&b).normalize().cross(&normal).magnitude() < epsilon
+            };
+            let is_in_plane = |points: &[Point<3>]| {
+                points.iter().all(|point| {
+                    (*point - points[0]).dot(&normal).abs() < epsilon
+                })
+            };
+
+            let origin = geometry.u.point_from_path_coords([0.]);
+            let v = (plane.origin() - origin).dot(&normal)
+                / geometry.v.dot(&normal);
+            let offset = geometry.v * v;
+            let point = |point: Point<3>| plane.project_point(point + offset);
+
+            let curve_on_plane = match &geometry.u {
+                GlobalPath::Circle(circle)
+                    if is_parallel([circle.a(), circle.b()]) =>
+                {
+                    let a = plane.project_vector(circle.a());
+                    let b = plane.project_vector(circle.b());
+                    round_path(point(circle.center()), a, b)?
+                }
+                GlobalPath::Ellipse(ellipse)
+                    if is_parallel([ellipse.major(), ellipse.minor()]) =>
+                {
+                    let major = plane.project_vector(ellipse.major());
+                    let minor = plane.project_vector(ellipse.minor());
+                    round_path(point(ellipse.center()), major, minor)?
+                }
+                GlobalPath::Bezier(bezier)
+                    if is_in_plane(&bezier.control_points()) =>
+                {
+                    SurfacePath::Bezier(Bezier::from_control_points(
+                        bezier.control_points().map(point),
+                    ))
+                }
+                GlobalPath::BSpline(bspline)
+                    if is_in_plane(bspline.control_points()) =>
+                {
+                    SurfacePath::BSpline(BSpline::from_control_points(
+                        bspline.control_points().iter().copied().map(point),
+                        bspline.degree(),
+                    ))
+                }
+                _ => return Err(UnsupportedIntersection),
+            };
+            let curve_on_surface =
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    Point::from([Scalar::ZERO, v]),
+                    Vector::unit_u(),
+                ));
+
+            return Ok(vec![Self {
+                intersection_curves: [curve_on_surface, curve_on_plane],
+            }]);
+        }
+
+        if normal.dot(&direction).abs() < epsilon {
+            // The plane is parallel to the direction of the sweep. The
+            // intersection consists of lines along the v-axis, through the
+            // points where the swept curve crosses the plane.
+            let (center, a, b) = match &geometry.u {
+                GlobalPath::Circle(circle) => {
+                    (circle.center(), circle.a(), circle.b())
+                }
+                GlobalPath::Ellipse(ellipse) => {
+                    (ellipse.center(), ellipse.major(), ellipse.minor())
+                }
+                GlobalPath::Bezier(_)
+                | GlobalPath::BSpline(_)
+                | GlobalPath::Helix(_)
+                | GlobalPath::Line(_) => return Err(UnsupportedIntersection),
+            };
+
+            // A point on the curve is on the plane, where
+            // `a_n * cos(u) + b_n * sin(u) = distance`. The left side of that
+            // equals `r * cos(u - phi)`.
+            let [a_n, b_n] = [a, b].map(|axis| axis.dot(&normal));
+            let distance = (plane.origin() - center).dot(&normal);
+            let r = Vector::from([a_n, b_n]).magnitude();
+            if r < epsilon {
+                // The curve is parallel to the plane. Then the surface is,
+                // too, which means it must be flat.
+                return Err(UnsupportedIntersection);
+            }
+            if distance.abs() > r {
+                return Ok(Vec::new());
+            }
+
+            let phi = b_n.atan2(a_n);
+            let delta = (distance / r).acos();
 
+            let mut coords = vec![phi - delta, phi + delta];
+            if delta == Scalar::ZERO {
+                // The plane touches the surface in a single line.
+                coords.pop();
+            }
+
+            let intersections = coords
+                .into_iter()
+                .map(|u| (u + Scalar::TAU) % Scalar::TAU)
+                .map(|u| {
+                    let origin = geometry.u.point_from_path_coords([u]);
+
+                    let curve_on_surface =
+                        SurfacePath::Line(Line::from_origin_and_direction(
+                            Point::from([u, Scalar::ZERO]),
+                            Vector::unit_v(),
+                        ));
+                    let curve_on_plane =
+                        SurfacePath::Line(Line::from_origin_and_direction(
+                            plane.project_point(origin),
+                            plane.project_vector(geometry.v),
+                        ));
+
+                    Self {
+                        intersection_curves: [curve_on_surface, curve_on_plane],
+                    }
+                })
+                .collect();
+
+            return Ok(intersections);
+        }
+
+        Err(UnsupportedIntersection)
+    }
+
+    fn compute_for_planes(planes: [Plane; 2]) -> Option<Self> {
         let [(a_distance, a_normal), (b_distance, b_normal)] =
             planes.map(|plane| plane.constant_normal_form());
 
@@ -57,6 +239,39 @@ impl SurfaceSurfaceIntersection {
     }
 }
 
+/// Error computing the intersection between two surfaces
+///
+/// See [`SurfaceSurfaceIntersection::compute_with_plane`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Intersecting these surfaces is not supported")]
+pub struct UnsupportedIntersection;
+
+/// Build a circle or ellipse from the axes it has in plane coordinates
+///
+/// The coordinate system of a plane doesn't necessarily preserve angles and
+/// lengths, so a circle in 3D can become an ellipse, or something that can't
+/// be represented at all.
+fn round_path(
+    center: Point<2>,
+    a: Vector<2>,
+    b: Vector<2>,
+) -> Result<SurfacePath, UnsupportedIntersection> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let [length_a, length_b] = [a, b].map(|axis| axis.magnitude());
+    if a.dot(&b).abs() > length_a * length_b * epsilon {
+        return Err(UnsupportedIntersection);
+    }
+
+    if (length_a - length_b).abs() <= length_a * epsilon {
+        Ok(SurfacePath::Circle(Circle::new(center, a, b)))
+    } else if length_a > length_b {
+        Ok(SurfacePath::Ellipse(Ellipse::new(center, a, b)))
+    } else {
+        Err(UnsupportedIntersection)
+    }
+}
+
 fn plane_from_surface(surface: &Surface) -> Plane {
     let (line, path) = {
         let line = match surface.geometry().u {
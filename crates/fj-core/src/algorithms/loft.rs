@@ -0,0 +1,716 @@
+//! Lofting between sketches to create solids
+
+use fj_interop::mesh::{Color, Material};
+use fj_math::{Line, Point, Scalar, Vector, Winding};
+
+use crate::{
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{
+        Curve, Cycle, EdgeKind, Face, GlobalEdge, HalfEdge, Region, Shell,
+        Sketch, Solid, Surface, Vertex,
+    },
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// Loft between two sketches to create a solid
+///
+/// The sketches must be located on parallel surfaces. Each region of the first
+/// sketch is connected to the region of the second sketch at the same index,
+/// and each cycle of a region to the cycle at the same index. The edges of two
+/// connected cycles are paired up, starting with the first edge of each cycle,
+/// and connected by side faces.
+///
+/// Side faces are planar, if the paired edges are parallel. Otherwise, the
+/// side face is split into two triangles.
+///
+/// # Implementation Note
+///
+/// Only sketches on planar surfaces that consist of straight edges are
+/// supported, and anything else results in a [`LoftError`]. A round profile
+/// can be approximated by a polygon with the same number of edges as the
+/// profile it is paired with.
+pub trait Loft {
+    /// Loft from this sketch to another one
+    fn loft(
+        self,
+        other: Self,
+        services: &mut Services,
+    ) -> Result<Handle<Solid>, LoftError>;
+}
+
+impl Loft for (Handle<Sketch>, Handle<Surface>) {
+    fn loft(
+        self,
+        (top_sketch, top_surface): Self,
+        services: &mut Services,
+    ) -> Result<Handle<Solid>, LoftError> {
+        let (bottom_sketch, bottom_surface) = self;
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        let normal = |surface: &Surface| {
+            let geometry = surface.geometry();
+            let GlobalPath::Line(line) = geometry.u else {
+                return Err(LoftError::CurvedSurface);
+            };
+
+            Ok(line.direction().cross(&geometry.v).normalize())
+        };
+        let normal_bottom = normal(&bottom_surface)?;
+        let normal_top = normal(&top_surface)?;
+        if normal_bottom.cross(&normal_top).magnitude() >= epsilon {
+            return Err(LoftError::SurfacesNotParallel);
+        }
+
+        let distance = (top_surface.geometry().u.origin()
+            - bottom_surface.geometry().u.origin())
+        .dot(&normal_bottom);
+        if distance.abs() < epsilon {
+            return Err(LoftError::SurfacesCoincide);
+        }
+        let direction = normal_bottom * distance.sign().to_scalar();
+
+        let bottom_regions = bottom_sketch.regions().collect::<Vec<_>>();
+        let top_regions = top_sketch.regions().collect::<Vec<_>>();
+
+        // Make sure the sketches match up, before creating any objects.
+        if bottom_regions.len() != top_regions.len() {
+            return Err(LoftError::RegionCountMismatch {
+                bottom: bottom_regions.len(),
+                top: top_regions.len(),
+            });
+        }
+        for (region, (bottom, top)) in
+            bottom_regions.iter().zip(&top_regions).enumerate()
+        {
+            let bottom = bottom.all_cycles().collect::<Vec<_>>();
+            let top = top.all_cycles().collect::<Vec<_>>();
+
+            if bottom.len() != top.len() {
+                return Err(LoftError::CycleCountMismatch {
+                    region,
+                    bottom: bottom.len(),
+                    top: top.len(),
+                });
+            }
+
+            for (cycle, (bottom, top)) in bottom.iter().zip(&top).enumerate() {
+                let is_curved = bottom
+                    .half_edges()
+                    .chain(top.half_edges())
+                    .any(|half_edge| {
+                        !matches!(half_edge.path(), SurfacePath::Line(_))
+                    });
+                if is_curved {
+                    return Err(LoftError::CurvedEdge { region, cycle });
+                }
+
+                if bottom.len() != top.len() {
+                    return Err(LoftError::EdgeCountMismatch {
+                        region,
+                        cycle,
+                        bottom: bottom.len(),
+                        top: top.len(),
+                    });
+                }
+            }
+        }
+
+        let shells = bottom_regions
+            .into_iter()
+            .zip(top_regions)
+            .map(|(bottom, top)| {
+                let sections = vec![
+                    section_of(bottom, &bottom_surface, direction),
+                    section_of(top, &top_surface, direction),
+                ];
+
+                connect_sections(
+                    sections,
+                    Some([bottom_surface.clone(), top_surface.clone()]),
+                    bottom.color(),
+                    bottom.material(),
+                    services,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Solid::new(shells).insert(services))
+    }
+}
+
+/// Error lofting between two sketches
+///
+/// See [`Loft`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LoftError {
+    /// The surface of one of the sketches is curved
+    #[error("Can't loft between sketches on curved surfaces")]
+    CurvedSurface,
+
+    /// The surfaces of the sketches are not parallel
+    #[error("Can't loft between sketches on surfaces that are not parallel")]
+    SurfacesNotParallel,
+
+    /// The surfaces of the sketches coincide
+    #[error("Can't loft between sketches on the same surface")]
+    SurfacesCoincide,
+
+    /// The sketches have different numbers of regions
+    #[error(
+        "Can't loft between sketches with different numbers of regions \
+        (bottom: {bottom}, top: {top})"
+    )]
+    RegionCountMismatch {
+        /// The number of regions in the bottom sketch
+        bottom: usize,
+
+        /// The number of regions in the top sketch
+        top: usize,
+    },
+
+    /// Two regions that should be connected have different numbers of cycles
+    #[error(
+        "Can't loft between regions with different numbers of cycles \
+        (region {region}; bottom: {bottom}, top: {top})"
+    )]
+    CycleCountMismatch {
+        /// The index of the regions
+        region: usize,
+
+        /// The number of cycles in the bottom region
+        bottom: usize,
+
+        /// The number of cycles in the top region
+        top: usize,
+    },
+
+    /// Two cycles that should be connected have curved edges
+    #[error(
+        "Can't loft between cycles with curved edges \
+        (region {region}, cycle {cycle})"
+    )]
+    CurvedEdge {
+        /// The index of the regions that contain the cycles
+        region: usize,
+
+        /// The index of the cycles within their regions
+        cycle: usize,
+    },
+
+    /// Two cycles that should be connected have different numbers of edges
+    #[error(
+        "Can't loft between cycles with different numbers of edges \
+        (region {region}, cycle {cycle}; bottom: {bottom}, top: {top})"
+    )]
+    EdgeCountMismatch {
+        /// The index of the regions that contain the cycles
+        region: usize,
+
+        /// The index of the cycles within their regions
+        cycle: usize,
+
+        /// The number of edges in the bottom cycle
+        bottom: usize,
+
+        /// The number of edges in the top cycle
+        top: usize,
+    },
+}
+
+/// Compute the corners of a region's cycles, as a cross-section of a solid
+///
+/// The corners are ordered, such that the exterior winds counter-clockwise
+/// around `direction`, and the interiors clockwise. This is the order that
+/// [`connect_sections`] expects.
+pub(crate) fn section_of(
+    region: &Region,
+    surface: &Surface,
+    direction: Vector<3>,
+) -> Vec<Vec<Point<3>>> {
+    let geometry = surface.geometry();
+    let GlobalPath::Line(line) = geometry.u else {
+        todo!("Cross-sections on curved surfaces are not supported")
+    };
+    let surface_faces_along_direction =
+        line.direction().cross(&geometry.v).dot(&direction) > Scalar::ZERO;
+
+    region
+        .all_cycles()
+        .enumerate()
+        .map(|(i, cycle)| {
+            let mut positions = cycle
+                .half_edges()
+                .map(|half_edge| {
+                    let SurfacePath::Line(_) = half_edge.path() else {
+                        todo!("Cross-sections with curved edges are not supported")
+                    };
+
+                    geometry
+                        .point_from_surface_coords(half_edge.start_position())
+                })
+                .collect::<Vec<_>>();
+
+            let winding = if i == 0 { Winding::Ccw } else { Winding::Cw };
+            let has_winding = cycle.winding() == winding;
+
+            if surface_faces_along_direction != has_winding {
+                // Keep the first corner where it is, so it's still paired
+                // with the first corner of the other sections.
+                positions[1..].reverse();
+            }
+
+            positions
+        })
+        .collect()
+}
+
+/// Connect cross-sections of a solid with side faces
+///
+/// Each section consists of the same number of cycles, which each consist of
+/// a number of corners, as returned by [`section_of`]. Corresponding corners of
+/// consecutive sections are connected. Side faces are planar, if possible, or
+/// split into two triangles otherwise.
+///
+/// If `caps` are provided, the first and last sections are closed off by faces
+/// on those surfaces. Otherwise, the last section is connected to the first.
+pub(crate) fn connect_sections(
+    sections: Vec<Vec<Vec<Point<3>>>>,
+    caps: Option<[Handle<Surface>; 2]>,
+    color: Option<Color>,
+    material: Option<&Material>,
+    services: &mut Services,
+) -> Handle<Shell> {
+    let profiles = sections
+        .into_iter()
+        .map(|cycles| {
+            cycles
+                .into_iter()
+                .map(|positions| Profile::new(positions, services))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut faces = Vec::new();
+
+    let num_connections = if caps.is_some() {
+        profiles.len() - 1
+    } else {
+        profiles.len()
+    };
+    for i in 0..num_connections {
+        let bottom = &profiles[i];
+        let top = &profiles[(i + 1) % profiles.len()];
+
+        for (bottom, top) in bottom.iter().zip(top) {
+            faces.extend(side_faces(bottom, top, color, material, services));
+        }
+    }
+
+    if let Some([bottom_surface, top_surface]) = caps {
+        let first = profiles.first().expect("Need sections to connect");
+        let last = profiles.last().expect("Need sections to connect");
+
+        // The bottom face points against the direction in which the sections
+        // follow each other, so its cycles run the other way.
+        let bottom_cycles = first
+            .iter()
+            .map(|profile| {
+                let n = profile.corners.len();
+                let reversed = (0..n).map(|k| {
+                    let k = (n - k) % n;
+                    let prev = (k + n - 1) % n;
+                    (&profile.corners[k], &profile.edges[prev], true)
+                });
+
+                cycle(bottom_surface.geometry(), reversed, services)
+            })
+            .collect::<Vec<_>>();
+        let top_cycles = last
+            .iter()
+            .map(|profile| {
+                let forward = profile
+                    .corners
+                    .iter()
+                    .zip(&profile.edges)
+                    .map(|(corner, edge)| (corner, edge, false));
+
+                cycle(top_surface.geometry(), forward, services)
+            })
+            .collect::<Vec<_>>();
+
+        for (surface, cycles) in
+            [(bottom_surface, bottom_cycles), (top_surface, top_cycles)]
+        {
+            let mut cycles = cycles.into_iter();
+            let exterior = cycles.next().expect("Region has an exterior");
+            let region =
+                Region::new(exterior, cycles, color, material.cloned())
+                    .insert(services);
+
+            faces.push(Face::new(surface, region).insert(services));
+        }
+    }
+
+    Shell::new(faces).insert(services)
+}
+
+/// Build the faces that connect two profiles
+fn side_faces(
+    bottom: &Profile,
+    top: &Profile,
+    color: Option<Color>,
+    material: Option<&Material>,
+    services: &mut Services,
+) -> Vec<Handle<Face>> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let n = bottom.corners.len();
+    let verticals = (0..n).map(|_| Edge::new(services)).collect::<Vec<_>>();
+
+    let mut faces = Vec::new();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+
+        let [p_i, p_j] = [i, j].map(|k| &bottom.corners[k]);
+        let [q_i, q_j] = [i, j].map(|k| &top.corners[k]);
+
+        let normal = (p_j.0 - p_i.0).cross(&(q_i.0 - p_i.0)).normalize();
+        let is_planar = (q_j.0 - p_i.0).dot(&normal).abs() < epsilon;
+
+        if is_planar {
+            faces.push(planar_face(
+                [
+                    (p_i, &bottom.edges[i], false),
+                    (p_j, &verticals[j], false),
+                    (q_j, &top.edges[i], true),
+                    (q_i, &verticals[i], true),
+                ],
+                color,
+                material,
+                services,
+            ));
+        } else {
+            let diagonal = Edge::new(services);
+
+            faces.push(planar_face(
+                [
+                    (p_i, &bottom.edges[i], false),
+                    (p_j, &diagonal, false),
+                    (q_i, &verticals[i], true),
+                ],
+                color,
+                material,
+                services,
+            ));
+            faces.push(planar_face(
+                [
+                    (p_j, &verticals[j], false),
+                    (q_j, &top.edges[i], true),
+                    (q_i, &diagonal, true),
+                ],
+                color,
+                material,
+                services,
+            ));
+        }
+    }
+
+    faces
+}
+
+/// A cycle of a cross-section, with the objects that the faces share
+struct Profile {
+    /// The corners of the profile
+    corners: Vec<Corner>,
+
+    /// The edges of the profile
+    ///
+    /// Edge `i` connects corner `i` to the corner after it.
+    edges: Vec<Edge>,
+}
+
+impl Profile {
+    fn new(positions: Vec<Point<3>>, services: &mut Services) -> Self {
+        let corners = positions
+            .into_iter()
+            .map(|position| (position, Vertex::new().insert(services)))
+            .collect::<Vec<_>>();
+        let edges = corners.iter().map(|_| Edge::new(services)).collect();
+
+        Self { corners, edges }
+    }
+}
+
+/// The position of a corner of a profile, and the vertex located there
+type Corner = (Point<3>, Handle<Vertex>);
+
+/// An edge of the lofted solid, shared between the two faces it bounds
+struct Edge {
+    curve: Handle<Curve>,
+    global_form: Handle<GlobalEdge>,
+}
+
+impl Edge {
+    fn new(services: &mut Services) -> Self {
+        Self {
+            curve: Curve::new().insert(services),
+            global_form: GlobalEdge::new().insert(services),
+        }
+    }
+}
+
+/// Build a planar face with the provided corners
+///
+/// See [`cycle`].
+fn planar_face<const N: usize>(
+    corners: [(&Corner, &Edge, bool); N],
+    color: Option<Color>,
+    material: Option<&Material>,
+    services: &mut Services,
+) -> Handle<Face> {
+    let [(a, _, _), (b, _, _), (c, _, _)] =
+        [corners[0], corners[1], corners[N - 1]];
+
+    let geometry = SurfaceGeometry {
+        u: GlobalPath::Line(Line::from_origin_and_direction(a.0, b.0 - a.0)),
+        v: c.0 - a.0,
+    };
+
+    let exterior = cycle(&geometry, corners, services);
+    let region =
+        Region::new(exterior, [], color, material.cloned()).insert(services);
+    let surface = Surface::new(geometry).insert(services);
+
+    Face::new(surface, region).insert(services)
+}
+
+/// Build a cycle that connects the provided corners
+///
+/// Each corner comes with the edge that connects it to the next corner. If
+/// that edge is flagged as reversed, its other half-edge runs from the next
+/// corner to this one.
+fn cycle<'r>(
+    surface: &SurfaceGeometry,
+    corners: impl IntoIterator<Item = (&'r Corner, &'r Edge, bool)>,
+    services: &mut Services,
+) -> Handle<Cycle> {
+    let corners = corners.into_iter().collect::<Vec<_>>();
+
+    let half_edges = corners
+        .iter()
+        .enumerate()
+        .map(|(i, &((start, vertex), edge, is_reversed))| {
+            let ((end, _), _, _) = corners[(i + 1) % corners.len()];
+
+            let boundary = if is_reversed { [1., 0.] } else { [0., 1.] }
+                .map(|t| Point::from([t]));
+            let [start, end] =
+                [start, end].map(|point| surface.project_global_point(*point));
+
+            HalfEdge::new(
+                SurfacePath::line_from_points_with_coords([
+                    (boundary[0], start),
+                    (boundary[1], end),
+                ]),
+                boundary,
+                edge.curve.clone(),
+                vertex.clone(),
+                edge.global_form.clone(),
+                EdgeKind::default(),
+            )
+            .insert(services)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges).insert(services)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        geometry::{GlobalPath, SurfaceGeometry},
+        objects::{Region, Sketch, Solid, Surface},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{Loft, LoftError};
+
+    #[test]
+    fn loft_frustum() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let bottom = (
+            sketch([[0., 0.], [2., 0.], [2., 2.], [0., 2.]], &mut services),
+            services.objects.surfaces.xy_plane(),
+        );
+        let top = (
+            sketch(
+                [[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]],
+                &mut services,
+            ),
+            xy_plane_at(1., &mut services),
+        );
+
+        let solid = bottom.loft(top, &mut services)?;
+
+        // All side faces are planar.
+        let num_faces = solid
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum::<usize>();
+        assert_eq!(num_faces, 6);
+
+        let (bottom_area, top_area, height) = (4., 1., 1.);
+        let volume = height / 3.
+            * (bottom_area + top_area + f64::sqrt(bottom_area * top_area));
+        assert!((mesh_volume(&solid)? - volume).abs() < Scalar::from(1e-9));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn loft_twisted() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let bottom = (
+            sketch([[1., 0.], [0., 1.], [-1., 0.], [0., -1.]], &mut services),
+            services.objects.surfaces.xy_plane(),
+        );
+        let top = (
+            sketch([[1., 1.], [-1., 1.], [-1., -1.], [1., -1.]], &mut services),
+            xy_plane_at(1., &mut services),
+        );
+
+        let solid = bottom.loft(top, &mut services)?;
+
+        // None of the side faces are planar, so they are split in two.
+        let num_faces = solid
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum::<usize>();
+        assert_eq!(num_faces, 2 + 4 * 2);
+
+        assert!(mesh_volume(&solid)? > Scalar::ZERO);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn loft_with_edge_count_mismatch() {
+        let mut services = Services::new();
+
+        let bottom = (
+            sketch([[0., 0.], [2., 0.], [2., 2.], [0., 2.]], &mut services),
+            services.objects.surfaces.xy_plane(),
+        );
+        let top = (
+            sketch([[0., 0.], [2., 0.], [1., 2.]], &mut services),
+            xy_plane_at(1., &mut services),
+        );
+
+        let result = bottom.loft(top, &mut services);
+        assert_eq!(
+            result,
+            Err(LoftError::EdgeCountMismatch {
+                region: 0,
+                cycle: 0,
+                bottom: 4,
+                top: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn loft_with_curved_edge() {
+        let mut services = Services::new();
+
+        let circle = Sketch::empty()
+            .add_region(
+                Region::circle([0., 0.], 1., &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let bottom = (circle, services.objects.surfaces.xy_plane());
+        let top = (
+            sketch([[0., 0.], [2., 0.], [1., 2.]], &mut services),
+            xy_plane_at(1., &mut services),
+        );
+
+        let result = bottom.loft(top, &mut services);
+        assert_eq!(
+            result,
+            Err(LoftError::CurvedEdge {
+                region: 0,
+                cycle: 0
+            })
+        );
+    }
+
+    #[test]
+    fn loft_on_curved_surface() {
+        let mut services = Services::new();
+
+        let cylinder = Surface::new(SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(1.),
+            v: Vector::unit_z(),
+        })
+        .insert(&mut services);
+        let bottom = (
+            sketch([[0., 0.], [1., 0.], [0., 1.]], &mut services),
+            cylinder,
+        );
+        let top = (
+            sketch([[0., 0.], [1., 0.], [0., 1.]], &mut services),
+            xy_plane_at(1., &mut services),
+        );
+
+        let result = bottom.loft(top, &mut services);
+        assert_eq!(result, Err(LoftError::CurvedSurface));
+    }
+
+    fn sketch<const N: usize>(
+        points: [[f64; 2]; N],
+        services: &mut Services,
+    ) -> Handle<Sketch> {
+        Sketch::empty()
+            .add_region(Region::polygon(points, services).insert(services))
+            .insert(services)
+    }
+
+    fn xy_plane_at(z: f64, services: &mut Services) -> Handle<Surface> {
+        Surface::new(SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([0., 0., z]),
+                Vector::unit_x(),
+            )),
+            v: Vector::unit_y(),
+        })
+        .insert(services)
+    }
+
+    fn mesh_volume(solid: &Solid) -> anyhow::Result<Scalar> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        Ok(volume)
+    }
+}
@@ -0,0 +1,660 @@
+//! Measure properties of objects
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    iter,
+};
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Aabb, Point, Scalar, Segment, Vector};
+
+use crate::objects::Solid;
+
+use super::{
+    approx::{Approx, Tolerance},
+    bounding_volume::BoundingVolume,
+    triangulate::Triangulate,
+};
+
+/// Measure properties of an object
+pub trait Measure {
+    /// Compute the volume that is enclosed by the object
+    ///
+    /// The object is triangulated, using the provided tolerance, and the
+    /// volume is computed from the resulting triangles. For curved objects,
+    /// the result is only as accurate as the triangulation.
+    ///
+    /// The volume is positive, if the object's faces point outward, as they
+    /// should.
+    fn volume(&self, tolerance: impl Into<Tolerance>) -> Scalar;
+
+    /// Compute the centroid of the volume that is enclosed by the object
+    ///
+    /// This is the object's center of mass, assuming uniform density. It is
+    /// computed from the same triangulation as [`Measure::volume`], with the
+    /// same accuracy.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the object encloses no volume.
+    fn centroid(&self, tolerance: impl Into<Tolerance>) -> Point<3>;
+
+    /// Compute the area of the object's surface
+    ///
+    /// The object is triangulated, using the provided tolerance, and the area
+    /// is computed from the resulting triangles. For curved objects, the result
+    /// is only as accurate as the triangulation.
+    fn surface_area(&self, tolerance: impl Into<Tolerance>) -> Scalar;
+
+    /// Determine whether the object is inside out
+    ///
+    /// An object is inside out, if its faces point inward, which results in a
+    /// negative volume. Such an object can be fixed using [`Reverse`].
+    ///
+    /// The volume is computed with the provided tolerance. See
+    /// [`Measure::volume`].
+    ///
+    /// [`Reverse`]: crate::operations::Reverse
+    fn is_inside_out(&self, tolerance: impl Into<Tolerance>) -> bool {
+        self.volume(tolerance) < Scalar::ZERO
+    }
+
+    /// Compute the axis-aligned bounding box of the object's triangulation
+    ///
+    /// Unlike [`BoundingVolume::aabb`], which is computed from the object's
+    /// geometry and might be much larger than the object, if it has curved
+    /// faces, this is computed from the vertices of the triangulated object.
+    ///
+    /// Returns `None`, if the object is empty.
+    ///
+    /// [`BoundingVolume::aabb`]: super::bounding_volume::BoundingVolume::aabb
+    fn mesh_aabb(&self, tolerance: impl Into<Tolerance>) -> Option<Aabb<3>>;
+
+    /// Compute the minimum distance between this object and another
+    ///
+    /// Both objects are triangulated, using the provided tolerance, and the
+    /// distance is computed between the resulting triangles. For curved
+    /// objects, the result is only as accurate as the triangulation.
+    ///
+    /// Returns zero, if the objects touch or overlap, including the case of one
+    /// object being contained within the other. Returns `None`, if either
+    /// object is empty.
+    fn distance_to(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Scalar>;
+
+    /// Compute the vertices of the object's approximation
+    ///
+    /// Approximates the object with the given tolerance, and returns the points
+    /// of the approximation, without any connectivity between them. Those are
+    /// the vertices of the object, followed by the points that approximate its
+    /// curved edges. Each of those groups is sorted, so the result only
+    /// depends on the shape of the object, not on how it was built.
+    ///
+    /// Points that are within `tolerance` of a point that comes earlier in the
+    /// result, are left out. A relative tolerance is applied to the size of the
+    /// object, as defined by its bounding box.
+    fn vertices(&self, tolerance: impl Into<Tolerance>) -> Vec<Point<3>>;
+
+    /// Determine whether the object has the same shape as another
+    ///
+    /// This doesn't depend on the identity of the objects' parts, or on how
+    /// the objects were built. They are equivalent, if they have the same number
+    /// of shells, faces, edges, and vertices, and if each face of one object
+    /// lines up with a face of the other.
+    ///
+    /// Faces are compared using the segments that approximate their
+    /// boundaries, with the given tolerance. Each segment must have a
+    /// counterpart with the same direction, whose points are within
+    /// `tolerance`, which is applied to the size of the object, if it is
+    /// relative. An object that is inside out is therefore not equivalent to
+    /// its correct version.
+    ///
+    /// # Implementation Note
+    ///
+    /// The objects must be in the same position to be equivalent. Their curved
+    /// edges must start at the same vertices too, or they are approximated
+    /// differently. Finding an alignment between objects is not supported yet.
+    fn is_equivalent_to(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+    ) -> bool;
+}
+
+impl Measure for Solid {
+    fn volume(&self, tolerance: impl Into<Tolerance>) -> Scalar {
+        let mesh = (self, tolerance.into()).triangulate();
+
+        tetrahedra(&mesh).fold(Scalar::ZERO, |sum, (volume, _)| sum + volume)
+    }
+
+    fn centroid(&self, tolerance: impl Into<Tolerance>) -> Point<3> {
+        let mesh = (self, tolerance.into()).triangulate();
+
+        let (volume, moment) = tetrahedra(&mesh).fold(
+            (Scalar::ZERO, Vector::from([0., 0., 0.])),
+            |(volume, moment), (v, centroid)| {
+                (volume + v, moment + centroid.coords * v)
+            },
+        );
+
+        assert!(
+            volume > Scalar::ZERO,
+            "Can't compute centroid of object without volume"
+        );
+
+        Point::origin() + moment / volume
+    }
+
+    fn surface_area(&self, tolerance: impl Into<Tolerance>) -> Scalar {
+        let mesh = (self, tolerance.into()).triangulate();
+        Scalar::from(f64::from(mesh.surface_area()))
+    }
+
+    fn mesh_aabb(&self, tolerance: impl Into<Tolerance>) -> Option<Aabb<3>> {
+        let mesh = (self, tolerance.into()).triangulate();
+        mesh.aabb()
+    }
+
+    fn distance_to(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Scalar> {
+        let tolerance = tolerance.into();
+
+        let a = (self, tolerance).triangulate();
+        let b = (other, tolerance).triangulate();
+
+        // If one of the objects contains the other, their surfaces don't
+        // touch, but the objects still overlap.
+        let contains = |mesh: &Mesh<Point<3>>, other: &Mesh<Point<3>>| {
+            other
+                .vertices()
+                .next()
+                .is_some_and(|point| mesh_contains_point(mesh, point))
+        };
+        if contains(&a, &b) || contains(&b, &a) {
+            return Some(Scalar::ZERO);
+        }
+
+        a.triangles()
+            .flat_map(|t| b.triangles().map(move |u| (t, u)))
+            .map(|(t, u)| t.inner.distance_to(&u.inner))
+            .min()
+    }
+
+    fn vertices(&self, tolerance: impl Into<Tolerance>) -> Vec<Point<3>> {
+        let tolerance = tolerance.into();
+
+        let approx = self.approx(tolerance);
+        let tolerance = max_distance(self, tolerance);
+
+        let mut corners = BTreeSet::new();
+        let mut curve_points = BTreeSet::new();
+
+        for face in &approx {
+            for cycle in iter::once(&face.exterior).chain(&face.interiors) {
+                for half_edge in &cycle.half_edges {
+                    corners.insert(half_edge.first.global_form);
+                    curve_points.extend(
+                        half_edge.points.iter().map(|point| point.global_form),
+                    );
+                }
+            }
+        }
+
+        // Sorting points into cells of the size of the tolerance means we only
+        // need to look at neighboring cells, to find close points.
+        let mut cells = BTreeMap::<_, Vec<Point<3>>>::new();
+        let mut vertices = Vec::new();
+
+        for point in corners.into_iter().chain(curve_points) {
+            let [x, y, z] = point.coords.components.map(|component| {
+                (component / tolerance).floor().into_f64() as i64
+            });
+
+            let mut neighbors = (-1..=1).flat_map(|dx| {
+                (-1..=1).flat_map(move |dy| {
+                    (-1..=1).map(move |dz| [x + dx, y + dy, z + dz])
+                })
+            });
+            let is_duplicate = neighbors.any(|cell| {
+                cells.get(&cell).is_some_and(|points| {
+                    points
+                        .iter()
+                        .any(|other| other.distance_to(&point) <= tolerance)
+                })
+            });
+
+            if !is_duplicate {
+                cells.entry([x, y, z]).or_default().push(point);
+                vertices.push(point);
+            }
+        }
+
+        vertices
+    }
+
+    fn is_equivalent_to(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+    ) -> bool {
+        let tolerance = tolerance.into();
+
+        let counts = |solid: &Solid| {
+            let mut half_edges_per_face = solid
+                .faces()
+                .map(|face| face.half_edges().count())
+                .collect::<Vec<_>>();
+            half_edges_per_face.sort();
+
+            let num_edges = solid
+                .half_edges()
+                .map(|half_edge| half_edge.global_form().id())
+                .collect::<BTreeSet<_>>()
+                .len();
+            let num_vertices = solid
+                .half_edges()
+                .map(|half_edge| half_edge.start_vertex().id())
+                .collect::<BTreeSet<_>>()
+                .len();
+
+            (
+                solid.shells().count(),
+                half_edges_per_face,
+                num_edges,
+                num_vertices,
+            )
+        };
+        if counts(self) != counts(other) {
+            return false;
+        }
+
+        let boundaries = |solid: &Solid| {
+            solid
+                .approx(tolerance)
+                .into_iter()
+                .map(|face| {
+                    iter::once(&face.exterior)
+                        .chain(&face.interiors)
+                        .flat_map(|cycle| cycle.segments())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let max_distance = max_distance(self, tolerance);
+        let is_match = |a: &[Segment<3>], b: &[Segment<3>]| {
+            let mut b = b.to_vec();
+
+            a.len() == b.len()
+                && a.iter().all(|a| {
+                    let Some(i) = b.iter().position(|b| {
+                        a.points()
+                            .into_iter()
+                            .zip(b.points())
+                            .all(|(a, b)| a.distance_to(&b) <= max_distance)
+                    }) else {
+                        return false;
+                    };
+
+                    b.swap_remove(i);
+                    true
+                })
+        };
+
+        // The number of faces is the same, so if each face has a counterpart,
+        // there's a one-to-one correspondence.
+        let mut others = boundaries(other);
+        boundaries(self).into_iter().all(|boundary| {
+            let Some(i) =
+                others.iter().position(|other| is_match(&boundary, other))
+            else {
+                return false;
+            };
+
+            others.swap_remove(i);
+            true
+        })
+    }
+}
+
+/// Compute the distance within which points of a solid are the same
+///
+/// A relative tolerance is applied to the diagonal of the solid's bounding box.
+fn max_distance(solid: &Solid, tolerance: Tolerance) -> Scalar {
+    let size = solid
+        .aabb()
+        .map(|aabb| aabb.size().magnitude())
+        .unwrap_or(Scalar::ZERO);
+
+    tolerance.for_feature_size(size)
+}
+
+/// Decompose the volume enclosed by a closed mesh into tetrahedra
+///
+/// Each triangle forms a tetrahedron with the origin. Where the tetrahedra of a
+/// closed mesh overlap, their signed volumes cancel out, leaving the volume
+/// enclosed by the mesh, no matter where the origin is located.
+///
+/// Returns the signed volume and the centroid of each tetrahedron.
+fn tetrahedra(
+    mesh: &Mesh<Point<3>>,
+) -> impl Iterator<Item = (Scalar, Point<3>)> + '_ {
+    mesh.triangles().map(|triangle| {
+        let [a, b, c] = triangle.inner.points();
+
+        let volume = a.coords.dot(&b.coords.cross(&c.coords)) / 6.;
+        let centroid = Point::origin() + (a.coords + b.coords + c.coords) / 4.;
+
+        (volume, centroid)
+    })
+}
+
+/// Determine whether a closed mesh contains a point
+///
+/// Casts a ray from the point and counts how often it crosses the mesh. An odd
+/// number of crossings means the point is inside.
+fn mesh_contains_point(mesh: &Mesh<Point<3>>, point: Point<3>) -> bool {
+    // This direction is arbitrary, but not aligned with any axis. This makes it
+    // unlikely that the ray hits the mesh exactly at an edge or vertex, which
+    // would count as multiple crossings.
+    let direction = Vector::from([0.6, 0.48, 0.64]);
+
+    let num_crossings = mesh
+        .triangles()
+        .filter(|triangle| {
+            triangle
+                .inner
+                .cast_local_ray(point, direction, f64::MAX, true)
+                .is_some()
+        })
+        .count();
+
+    num_crossings % 2 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_approx_eq, Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, transform::TransformObject,
+        },
+        objects::{Cycle, Region, Sketch, Solid},
+        operations::{
+            BuildCycle, BuildRegion, BuildSketch, Insert, Reverse,
+            UpdateRegion, UpdateSketch,
+        },
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::Measure;
+
+    #[test]
+    fn volume_of_cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let cuboid = cuboid([1., 2., 3.], &mut services);
+
+        assert_eq!(cuboid.volume(tolerance), Scalar::from(6.));
+
+        // The volume doesn't depend on where the cuboid is located.
+        let cuboid = cuboid.translate([10., -20., 30.], &mut services);
+        assert!((cuboid.volume(tolerance) - 6.).abs() < Scalar::from(1e-9));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn centroid_of_cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let cuboid = cuboid([1., 2., 3.], &mut services)
+            .translate([10., -20., 30.], &mut services);
+
+        assert_approx_eq!(
+            cuboid.centroid(tolerance),
+            Point::from([10., -20., 31.5]),
+            1e-9,
+        );
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn inside_out_cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let cuboid = cuboid([1., 1., 1.], &mut services);
+        assert!(!cuboid.is_inside_out(tolerance));
+
+        let inside_out = cuboid.reverse(&mut services).insert(&mut services);
+        assert!(inside_out.is_inside_out(tolerance));
+        assert_approx_eq!(inside_out.volume(tolerance), -Scalar::ONE, 1e-12);
+
+        let fixed = inside_out.reverse(&mut services).insert(&mut services);
+        assert!(!fixed.is_inside_out(tolerance));
+        assert_approx_eq!(fixed.volume(tolerance), Scalar::ONE, 1e-12);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn surface_area_of_spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let (outer, inner, height) = (1., 0.5, 1.);
+        let spacer = spacer(outer, inner, height, &mut services);
+
+        let walls = Scalar::TAU * (outer + inner) * height;
+        let caps = Scalar::TAU * (outer * outer - inner * inner);
+        let expected = walls + caps;
+
+        // The circles are approximated by polygons, which have less area.
+        let error = expected - spacer.surface_area(tolerance);
+        assert!(error >= Scalar::ZERO && error < Scalar::from(0.05));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn mesh_aabb_of_spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = 0.001;
+        let spacer = spacer(1., 0.5, 2., &mut services);
+
+        // The vertices of the triangulation are not necessarily located at
+        // the extremes of the circles, but they're within the tolerance.
+        let aabb = spacer
+            .mesh_aabb(Tolerance::from_scalar(tolerance)?)
+            .expect("Spacer is not empty");
+        let expected = [[-1., -1., 0.], [1., 1., 2.]];
+        for (actual, expected) in [aabb.min, aabb.max].into_iter().zip(expected)
+        {
+            for (actual, expected) in
+                actual.coords.components.into_iter().zip(expected)
+            {
+                assert!((actual - expected).abs() <= Scalar::from(tolerance));
+            }
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn distance_between_cuboids() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let a = cuboid([1., 1., 1.], &mut services);
+
+        let b = cuboid([1., 1., 1.], &mut services)
+            .translate([3., 0., 0.5], &mut services);
+        assert_eq!(a.distance_to(&b, tolerance), Some(Scalar::TWO));
+
+        let overlapping = cuboid([1., 1., 1.], &mut services)
+            .translate([0.5, 0.5, 0.5], &mut services);
+        assert_eq!(a.distance_to(&overlapping, tolerance), Some(Scalar::ZERO));
+
+        // The surfaces of the cuboids don't touch, but one is contained within
+        // the other.
+        let contained = cuboid([0.2, 0.2, 0.2], &mut services)
+            .translate([0., 0., 0.4], &mut services);
+        assert_eq!(a.distance_to(&contained, tolerance), Some(Scalar::ZERO));
+        assert_eq!(contained.distance_to(&a, tolerance), Some(Scalar::ZERO));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn vertices_of_cuboid() {
+        let mut services = Services::new();
+
+        let prism = |points: [[f64; 2]; 4], services: &mut Services| {
+            let sketch = Sketch::empty()
+                .add_region(Region::polygon(points, services).insert(services))
+                .insert(services);
+            let surface = services.objects.surfaces.xy_plane();
+            (sketch, surface).sweep([0., 0., 1.], services)
+        };
+
+        let a = prism([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &mut services);
+        let b = prism([[1., 1.], [0., 1.], [0., 0.], [1., 0.]], &mut services);
+
+        let tolerance = 0.001;
+        let vertices = a.vertices(tolerance);
+        assert_eq!(vertices.len(), 8);
+        assert!(vertices.contains(&Point::from([1., 1., 1.])));
+
+        // The same shape results in the same vertices, regardless of how it
+        // was built.
+        assert_eq!(vertices, b.vertices(tolerance));
+
+        // Points closer than the tolerance are merged.
+        assert_eq!(a.vertices(2.).len(), 1);
+
+        // A relative tolerance is applied to the size of the solid.
+        let relative = |scalar| Tolerance::relative(scalar).unwrap();
+        assert_eq!(a.vertices(relative(0.001)), vertices);
+        assert_eq!(a.vertices(relative(2.)).len(), 1);
+    }
+
+    #[test]
+    fn vertices_of_curved_solid() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cylinder = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let tolerance = 0.01;
+        let vertices = cylinder.vertices(tolerance);
+
+        // The circles at the bottom and top are approximated, and share their
+        // points with the side wall.
+        assert!(vertices.len() > 8);
+        assert_eq!(vertices.len() % 2, 0);
+        for vertex in vertices {
+            let radius =
+                Point::from([vertex.x, vertex.y]).distance_to(&Point::origin());
+            assert!((radius - 1.).abs() <= Scalar::from(tolerance));
+        }
+    }
+
+    #[test]
+    fn is_equivalent_to() {
+        let mut services = Services::new();
+
+        let mut prism = |points: [[f64; 2]; 4], height: f64| {
+            let sketch = Sketch::empty()
+                .add_region(
+                    Region::polygon(points, &mut services)
+                        .insert(&mut services),
+                )
+                .insert(&mut services);
+            let surface = services.objects.surfaces.xy_plane();
+            (sketch, surface).sweep([0., 0., height], &mut services)
+        };
+
+        let a = prism([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], 1.);
+        let b = prism([[1., 1.], [0., 1.], [0., 0.], [1., 0.]], 1.);
+        let c = prism([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], 2.);
+        let d = prism([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], 1. + 1e-9);
+
+        let tolerance = 1e-6;
+        assert!(a.is_equivalent_to(&b, tolerance));
+        assert!(b.is_equivalent_to(&a, tolerance));
+        assert!(!a.is_equivalent_to(&c, tolerance));
+        assert!(a.is_equivalent_to(&d, tolerance));
+
+        let inside_out = a.reverse(&mut services);
+        assert!(!a.is_equivalent_to(&inside_out, tolerance));
+    }
+    fn cuboid([x, y, z]: [f64; 3], services: &mut Services) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [
+                        [-x / 2., -y / 2.],
+                        [x / 2., -y / 2.],
+                        [x / 2., y / 2.],
+                        [-x / 2., y / 2.],
+                    ],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+
+        let surface = services.objects.surfaces.xy_plane();
+        (sketch, surface).sweep([0., 0., z], services)
+    }
+
+    fn spacer(
+        outer: f64,
+        inner: f64,
+        height: f64,
+        services: &mut Services,
+    ) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), outer, services)
+                    .add_interiors([Cycle::circle(
+                        Point::origin(),
+                        inner,
+                        services,
+                    )
+                    .reverse(services)
+                    .insert(services)])
+                    .insert(services),
+            )
+            .insert(services);
+
+        let surface = services.objects.surfaces.xy_plane();
+        (sketch, surface).sweep([0., 0., height], services)
+    }
+}
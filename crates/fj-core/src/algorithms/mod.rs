@@ -11,8 +11,13 @@
 //! [`operations`]: crate::operations
 
 pub mod approx;
+pub mod boolean;
 pub mod bounding_volume;
 pub mod intersect;
+pub mod loft;
+pub mod measure;
+pub mod revolve;
+pub mod section;
 pub mod sweep;
 pub mod transform;
 pub mod triangulate;
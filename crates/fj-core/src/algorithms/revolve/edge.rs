@@ -0,0 +1,315 @@
+use fj_interop::mesh::{Color, Material};
+use fj_math::{Circle, Line, Point, Scalar};
+
+use crate::{
+    geometry::{BoundaryOnCurve, GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{
+        Curve, Cycle, EdgeKind, Face, GlobalEdge, HalfEdge, Region, Surface,
+        Vertex,
+    },
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+};
+
+use super::{Revolution, Revolve, RevolveCache};
+
+impl Revolve
+    for (
+        &HalfEdge,
+        &Handle<Vertex>,
+        &Surface,
+        Option<Color>,
+        Option<&Material>,
+    )
+{
+    /// The face swept out by the edge, and the edge at the end of the
+    /// revolution
+    ///
+    /// An edge on the axis doesn't sweep out a face. A full revolution ends
+    /// where it started, so there is no separate edge at its end.
+    type Revolved = (Option<Handle<Face>>, Option<Handle<HalfEdge>>);
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        services: &mut Services,
+    ) -> Self::Revolved {
+        let (edge, next_vertex, surface, color, material) = self;
+        let angle = angle.into();
+        let revolution = Revolution::new(axis, angle);
+
+        let SurfacePath::Line(_) = edge.path() else {
+            todo!("Revolving curved edges is not supported yet")
+        };
+
+        let [a, b] = edge.boundary().inner;
+        let [start, end] = [a, b].map(|point| {
+            let point_surface = edge.path().point_from_path_coords(point);
+            surface.geometry().point_from_surface_coords(point_surface)
+        });
+
+        let (arc_start, vertex_start_revolved) =
+            (edge.start_vertex().clone(), start)
+                .revolve_with_cache(axis, angle, cache, services);
+        let (arc_end, vertex_end_revolved) = (next_vertex.clone(), end)
+            .revolve_with_cache(axis, angle, cache, services);
+
+        if arc_start.is_none() && arc_end.is_none() {
+            // The edge is located on the axis. It doesn't sweep out a face, but
+            // it still bounds the faces at the start and end of the revolution.
+            let top_edge = (!revolution.is_full()).then(|| {
+                HalfEdge::new(
+                    edge.path().clone(),
+                    edge.boundary().reverse(),
+                    edge.curve().clone(),
+                    next_vertex.clone(),
+                    edge.global_form().clone(),
+                    edge.kind(),
+                )
+                .insert(services)
+            });
+
+            return (None, top_edge);
+        }
+
+        // Let's figure out what kind of surface the edge sweeps out. Only
+        // surfaces that can be represented by our current surface geometry are
+        // supported.
+        let direction = end - start;
+        let along_axis = direction.dot(&revolution.direction);
+        let is_parallel = (direction - revolution.direction * along_axis)
+            .magnitude()
+            < revolution.epsilon;
+        let is_perpendicular = along_axis.abs() < revolution.epsilon;
+
+        let angle = revolution.angle;
+        let line = |points: [(Point<1>, Point<2>); 2]| {
+            SurfacePath::line_from_points_with_coords(points)
+        };
+
+        // Each of these is the path and boundary of one side of the face:
+        // The original edge at the bottom, the arc swept out by the end
+        // vertex, the revolved edge at the top, and the arc swept out by the
+        // start vertex.
+        let (geometry, bottom, up, top, down) = if is_parallel {
+            // The edge sweeps out a cylinder. The u-axis of its surface is the
+            // circle that the start vertex moves on, the v-axis points along
+            // the edge.
+            let radial = revolution.radial_of(start);
+            let circle = Circle::new(
+                revolution.foot_of(start),
+                radial,
+                revolution.direction.cross(&radial),
+            );
+
+            let geometry = SurfaceGeometry {
+                u: GlobalPath::Circle(circle),
+                v: direction,
+            };
+
+            let [bottom_start, bottom_end, top_start, top_end] = [
+                [0., 0.],
+                [0., 1.],
+                [angle.into_f64(), 1.],
+                [angle.into_f64(), 0.],
+            ]
+            .map(Point::from);
+            let [zero, angle] = [Scalar::ZERO, angle].map(|t| Point::from([t]));
+
+            (
+                geometry,
+                (line([(a, bottom_start), (b, bottom_end)]), [a, b]),
+                Some((
+                    line([(zero, bottom_end), (angle, top_start)]),
+                    [zero, angle],
+                )),
+                (line([(b, top_start), (a, top_end)]), [b, a]),
+                Some((
+                    line([(angle, top_end), (zero, bottom_start)]),
+                    [angle, zero],
+                )),
+            )
+        } else if is_perpendicular {
+            // The edge sweeps out a planar disk, or a part of one. The surface
+            // is spanned by the direction from the axis to the edge, and the
+            // direction into which the edge starts moving.
+            let radius_of = |arc: &Option<_>, point| {
+                if arc.is_some() {
+                    revolution.radial_of(point).magnitude()
+                } else {
+                    // Vertices on the axis must end up exactly at the origin of
+                    // the surface. Otherwise, they won't line up with the
+                    // revolved edge.
+                    Scalar::ZERO
+                }
+            };
+            let radius_start = radius_of(&arc_start, start);
+            let radius_end = radius_of(&arc_end, end);
+
+            let radial = if radius_end > radius_start {
+                revolution.radial_of(end)
+            } else {
+                revolution.radial_of(start)
+            }
+            .normalize();
+
+            let geometry = SurfaceGeometry {
+                u: GlobalPath::Line(Line::from_origin_and_direction(
+                    revolution.foot_of(start),
+                    radial,
+                )),
+                v: revolution.direction.cross(&radial),
+            };
+
+            let (sin, cos) = angle.sin_cos();
+            let [bottom_start, bottom_end, top_start, top_end] = [
+                [radius_start, Scalar::ZERO],
+                [radius_end, Scalar::ZERO],
+                [radius_end * cos, radius_end * sin],
+                [radius_start * cos, radius_start * sin],
+            ]
+            .map(Point::from);
+            let [zero, angle] = [Scalar::ZERO, angle].map(|t| Point::from([t]));
+
+            let arc = |radius| {
+                SurfacePath::circle_from_center_and_radius(
+                    Point::origin(),
+                    radius,
+                )
+            };
+
+            (
+                geometry,
+                (line([(a, bottom_start), (b, bottom_end)]), [a, b]),
+                arc_end.is_some().then(|| (arc(radius_end), [zero, angle])),
+                (line([(b, top_start), (a, top_end)]), [b, a]),
+                arc_start
+                    .is_some()
+                    .then(|| (arc(radius_start), [angle, zero])),
+            )
+        } else {
+            todo!(
+                "Revolving edges that are neither parallel nor perpendicular \
+                to the axis is not supported yet"
+            )
+        };
+
+        let half_edge =
+            |(path, boundary): (SurfacePath, [Point<1>; 2]),
+             start_vertex: Handle<Vertex>,
+             (curve, global_form): (Handle<Curve>, Handle<GlobalEdge>),
+             kind: EdgeKind,
+             services: &mut Services| {
+                HalfEdge::new(
+                    path,
+                    BoundaryOnCurve::from(boundary),
+                    curve,
+                    start_vertex,
+                    global_form,
+                    kind,
+                )
+                .insert(services)
+            };
+
+        let edge_bottom = half_edge(
+            bottom,
+            edge.start_vertex().clone(),
+            (edge.curve().clone(), edge.global_form().clone()),
+            edge.kind(),
+            services,
+        );
+        // The arcs that the vertices sweep out are new edges, regardless of
+        // the kind of the revolved edge.
+        let edge_up = up.zip(arc_end).map(|(up, arc)| {
+            half_edge(up, next_vertex.clone(), arc, EdgeKind::Real, services)
+        });
+        let edge_down = down.zip(arc_start).map(|(down, arc)| {
+            half_edge(
+                down,
+                vertex_start_revolved,
+                arc,
+                EdgeKind::Real,
+                services,
+            )
+        });
+
+        let surface = Surface::new(geometry).insert(services);
+
+        if revolution.is_full() && is_perpendicular {
+            // A full revolution of an edge perpendicular to the axis sweeps out
+            // a disk, possibly with a hole. Those are bounded by the arcs of
+            // the vertices only, as the edge itself would form a seam that
+            // doesn't bound anything.
+            let mut arcs = edge_up
+                .into_iter()
+                .chain(edge_down)
+                .map(|arc| {
+                    let radius = match arc.path() {
+                        SurfacePath::Circle(circle) => circle.radius(),
+                        _ => unreachable!("Arcs are circles on a plane"),
+                    };
+                    let cycle = Cycle::new([arc]).insert(services);
+
+                    (radius, cycle)
+                })
+                .collect::<Vec<_>>();
+            arcs.sort_by_key(|(radius, _)| -*radius);
+
+            let mut cycles = arcs.into_iter().map(|(_, cycle)| cycle);
+            let exterior = cycles
+                .next()
+                .expect("Edge not on axis must have at least one arc");
+
+            let region =
+                Region::new(exterior, cycles, color, material.cloned())
+                    .insert(services);
+            let face = Face::new(surface, region).insert(services);
+
+            return (Some(face), None);
+        }
+
+        let edge_top = if revolution.is_full() {
+            // The revolved edge is the original edge.
+            half_edge(
+                top,
+                vertex_end_revolved,
+                (edge.curve().clone(), edge.global_form().clone()),
+                edge.kind(),
+                services,
+            )
+        } else {
+            half_edge(
+                top,
+                vertex_end_revolved,
+                (
+                    Curve::new().insert(services),
+                    GlobalEdge::new().insert(services),
+                ),
+                edge.kind(),
+                services,
+            )
+        };
+
+        let exterior = Cycle::new(
+            [
+                Some(edge_bottom),
+                edge_up,
+                Some(edge_top.clone()),
+                edge_down,
+            ]
+            .into_iter()
+            .flatten(),
+        )
+        .insert(services);
+
+        let region = Region::new(exterior, [], color, material.cloned())
+            .insert(services);
+        let face = Face::new(surface, region).insert(services);
+
+        let top_edge = (!revolution.is_full()).then_some(edge_top);
+        (Some(face), top_edge)
+    }
+}
@@ -0,0 +1,147 @@
+use std::ops::Deref;
+
+use fj_math::{Line, Scalar};
+
+use crate::{
+    algorithms::transform::TransformObject,
+    geometry::GlobalPath,
+    objects::{Cycle, Face, Region, Shell},
+    operations::{BuildCycle, Insert, JoinCycle, Reverse},
+    services::Services,
+    storage::Handle,
+};
+
+use super::{Revolution, Revolve, RevolveCache};
+
+impl Revolve for Handle<Face> {
+    type Revolved = Handle<Shell>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        services: &mut Services,
+    ) -> Self::Revolved {
+        let angle = angle.into();
+        let revolution = Revolution::new(axis, angle);
+
+        let mut faces = Vec::new();
+
+        let is_negative_revolution = {
+            let u = match self.surface().geometry().u {
+                GlobalPath::Bezier(_)
+                | GlobalPath::BSpline(_)
+                | GlobalPath::Circle(_)
+                | GlobalPath::Ellipse(_)
+                | GlobalPath::Helix(_) => todo!(
+                    "Revolving faces defined in round surfaces is not \
+                    supported"
+                ),
+                GlobalPath::Line(line) => line.direction(),
+            };
+            let v = self.surface().geometry().v;
+
+            let normal = u.cross(&v);
+
+            // All points of the face move into the same direction at the start
+            // of the revolution, as long as the face is in a plane with the
+            // axis. We can just pick any point that is not on the axis to
+            // figure out that direction.
+            let point_off_axis = self
+                .region()
+                .exterior()
+                .half_edges()
+                .map(|half_edge| {
+                    self.surface()
+                        .geometry()
+                        .point_from_surface_coords(half_edge.start_position())
+                })
+                .find(|&point| !revolution.is_on_axis(point))
+                .expect("Can't revolve face that is located on the axis");
+            let direction = revolution
+                .direction
+                .cross(&revolution.radial_of(point_off_axis));
+
+            normal.dot(&direction) < Scalar::ZERO
+        };
+
+        let bottom_face = {
+            if is_negative_revolution {
+                self.clone()
+            } else {
+                self.clone().reverse(services).insert(services)
+            }
+        };
+
+        // A full revolution ends where it started, so there are no faces to
+        // cap it off.
+        if !revolution.is_full() {
+            faces.push(bottom_face.clone());
+        }
+
+        let mut exterior = None;
+        let mut interiors = Vec::new();
+
+        for (i, cycle) in bottom_face.region().all_cycles().cloned().enumerate()
+        {
+            let cycle = cycle.reverse(services);
+
+            let mut top_edges = Vec::new();
+            for (half_edge, next) in cycle.half_edge_pairs() {
+                let (face, top_edge) = (
+                    half_edge.deref(),
+                    next.start_vertex(),
+                    self.surface().deref(),
+                    self.region().color(),
+                    self.region().material(),
+                )
+                    .revolve_with_cache(axis, angle, cache, services);
+
+                faces.extend(face);
+
+                if let Some(top_edge) = top_edge {
+                    top_edges.push((
+                        top_edge,
+                        half_edge.path().clone(),
+                        half_edge.boundary(),
+                    ));
+                }
+            }
+
+            if revolution.is_full() {
+                continue;
+            }
+
+            let top_cycle = Cycle::empty()
+                .add_joined_edges(top_edges, services)
+                .insert(services);
+
+            if i == 0 {
+                exterior = Some(top_cycle);
+            } else {
+                interiors.push(top_cycle);
+            };
+        }
+
+        if !revolution.is_full() {
+            let top_surface = bottom_face
+                .surface()
+                .clone()
+                .transform(&revolution.transform(), services);
+
+            let region = Region::new(
+                exterior.unwrap(),
+                interiors,
+                self.region().color(),
+                self.region().material().cloned(),
+            )
+            .insert(services);
+
+            let top_face = Face::new(top_surface, region).insert(services);
+            faces.push(top_face);
+        }
+
+        Shell::new(faces).insert(services)
+    }
+}
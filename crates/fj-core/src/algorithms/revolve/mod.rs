@@ -0,0 +1,198 @@
+//! Revolving objects around an axis to create new objects
+
+mod edge;
+mod face;
+mod sketch;
+mod vertex;
+
+use std::collections::BTreeMap;
+
+use fj_math::{Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    objects::{Curve, GlobalEdge, Vertex},
+    services::Services,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+/// Revolve an object around an axis to create another object
+///
+/// This is the rotational counterpart of [`Sweep`]. Instead of moving an
+/// object along a straight path, the object is rotated around `axis`, by
+/// `angle` (in radians). Positive angles rotate counter-clockwise, when looking
+/// at the axis from the tip of its direction vector.
+///
+/// The angle must not be zero and must not exceed a full revolution in either
+/// direction. A full revolution creates a closed solid without any cap faces.
+/// Any smaller angle creates cap faces at the start and end of the revolution.
+///
+/// [`Sweep`]: crate::algorithms::sweep::Sweep
+pub trait Revolve: Sized {
+    /// The object that is created by revolving the implementing object
+    type Revolved;
+
+    /// Revolve the object around the given axis
+    fn revolve(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Self::Revolved {
+        let mut cache = RevolveCache::default();
+        self.revolve_with_cache(axis, angle, &mut cache, services)
+    }
+
+    /// Revolve the object around the given axis, using the provided cache
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        services: &mut Services,
+    ) -> Self::Revolved;
+}
+
+/// A cache used for revolving
+///
+/// See [`Revolve`].
+#[derive(Default)]
+pub struct RevolveCache {
+    /// Cache for curves
+    pub curves: BTreeMap<ObjectId, Handle<Curve>>,
+
+    /// Cache for vertices
+    pub vertices: BTreeMap<ObjectId, Handle<Vertex>>,
+
+    /// Cache for global edges
+    pub global_edges: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+}
+
+/// A normalized description of a revolution
+///
+/// Negative angles are normalized by flipping the axis, which means the angle
+/// is always positive and the direction of the axis is always a unit vector.
+struct Revolution {
+    origin: Point<3>,
+    direction: Vector<3>,
+    angle: Scalar,
+    epsilon: Scalar,
+}
+
+impl Revolution {
+    fn new(axis: Line<3>, angle: Scalar) -> Self {
+        assert!(
+            angle != Scalar::ZERO && angle.abs() <= Scalar::TAU,
+            "angle of revolution must be within [-2pi, 2pi] radians and must \
+            not be zero"
+        );
+
+        let direction = axis.direction().normalize();
+        let (direction, angle) = if angle < Scalar::ZERO {
+            (-direction, -angle)
+        } else {
+            (direction, angle)
+        };
+
+        Self {
+            origin: axis.origin(),
+            direction,
+            angle,
+            epsilon: ValidationConfig::default().distinct_min_distance,
+        }
+    }
+
+    /// Indicate whether this is a full revolution
+    fn is_full(&self) -> bool {
+        self.angle == Scalar::TAU
+    }
+
+    /// Compute the point on the axis that is closest to the given point
+    fn foot_of(&self, point: Point<3>) -> Point<3> {
+        self.origin
+            + self.direction * (point - self.origin).dot(&self.direction)
+    }
+
+    /// Compute the vector from the axis to the given point
+    ///
+    /// The vector is perpendicular to the axis.
+    fn radial_of(&self, point: Point<3>) -> Vector<3> {
+        point - self.foot_of(point)
+    }
+
+    /// Indicate whether the given point is located on the axis
+    fn is_on_axis(&self, point: Point<3>) -> bool {
+        self.radial_of(point).magnitude() < self.epsilon
+    }
+
+    /// Compute the transform that rotates an object by the full angle
+    fn transform(&self) -> Transform {
+        Transform::translation(self.origin.coords)
+            * Transform::rotation(self.direction * self.angle)
+            * Transform::translation(-self.origin.coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    use super::Revolve;
+
+    #[test]
+    fn revolve_full() -> anyhow::Result<()> {
+        // A rectangle that is offset from the axis results in a tube.
+        revolve([[1., 0.], [2., 0.], [2., 1.], [1., 1.]], Scalar::TAU)
+    }
+
+    #[test]
+    fn revolve_partial() -> anyhow::Result<()> {
+        revolve([[1., 0.], [2., 0.], [2., 1.], [1., 1.]], Scalar::PI / 2.)?;
+        revolve([[1., 0.], [2., 0.], [2., 1.], [1., 1.]], -Scalar::PI / 2.)
+    }
+
+    #[test]
+    fn revolve_profile_touching_axis() -> anyhow::Result<()> {
+        // A rectangle with one edge on the axis results in a cylinder.
+        revolve([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], Scalar::TAU)?;
+        revolve([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], Scalar::PI / 2.)?;
+
+        // A stepped profile on the axis results in a stepped shaft.
+        revolve(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            Scalar::TAU,
+        )
+    }
+
+    fn revolve<const N: usize>(
+        points: [[f64; 2]; N],
+        angle: impl Into<Scalar>,
+    ) -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(points, &mut services).insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let axis =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_y());
+
+        let solid = (sketch, surface).revolve(axis, angle, &mut services);
+
+        let tolerance = Tolerance::from_scalar(0.1)?;
+        let mesh = (&*solid, tolerance).triangulate();
+        assert!(mesh.triangles().next().is_some());
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use fj_math::{Line, Scalar};
+
+use crate::{
+    objects::{Sketch, Solid, Surface},
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+};
+
+use super::{Revolve, RevolveCache};
+
+impl Revolve for (Handle<Sketch>, Handle<Surface>) {
+    type Revolved = Handle<Solid>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        services: &mut Services,
+    ) -> Self::Revolved {
+        let angle = angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.0.faces(self.1, services) {
+            let shell = face.revolve_with_cache(axis, angle, cache, services);
+            shells.push(shell);
+        }
+
+        Solid::new(shells).insert(services)
+    }
+}
@@ -0,0 +1,59 @@
+use fj_math::{Line, Point, Scalar};
+
+use crate::{
+    objects::{Curve, GlobalEdge, Vertex},
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+};
+
+use super::{Revolution, Revolve, RevolveCache};
+
+impl Revolve for (Handle<Vertex>, Point<3>) {
+    /// The curve and global edge of the arc traced by the vertex, as well as
+    /// the vertex at the end of the revolution
+    ///
+    /// A vertex on the axis does not trace an arc, and stays where it is.
+    type Revolved =
+        (Option<(Handle<Curve>, Handle<GlobalEdge>)>, Handle<Vertex>);
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        services: &mut Services,
+    ) -> Self::Revolved {
+        let (vertex, position) = self;
+        let revolution = Revolution::new(axis, angle.into());
+
+        if revolution.is_on_axis(position) {
+            return (None, vertex);
+        }
+
+        let curve = cache
+            .curves
+            .entry(vertex.id())
+            .or_insert_with(|| Curve::new().insert(services))
+            .clone();
+
+        let global_edge = cache
+            .global_edges
+            .entry(vertex.id())
+            .or_insert_with(|| GlobalEdge::new().insert(services))
+            .clone();
+
+        // After a full revolution, the vertex ends up where it started.
+        let end = if revolution.is_full() {
+            vertex
+        } else {
+            cache
+                .vertices
+                .entry(vertex.id())
+                .or_insert_with(|| Vertex::new().insert(services))
+                .clone()
+        };
+
+        (Some((curve, global_edge)), end)
+    }
+}
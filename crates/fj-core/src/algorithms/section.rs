@@ -0,0 +1,791 @@
+//! Compute the cross-section of an object with a plane
+
+use fj_math::{Line, Point, PolyChain, Scalar, Vector};
+
+use crate::{
+    geometry::{GlobalPath, SurfacePath},
+    objects::{
+        Cycle, Face, HalfEdge, Handedness, Region, Shell, Sketch, Solid,
+        Surface,
+    },
+    operations::{BuildHalfEdge, Insert},
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{
+    bounding_volume::BoundingVolume,
+    intersect::{face_point::ContainsPoint, SurfaceSurfaceIntersection},
+    triangulate::polygon::Polygon,
+};
+
+/// The number of points per curved segment that are used to approximate a
+/// cycle of the section, when checking which cycles contain each other
+const SAMPLES_PER_CURVE: u32 = 16;
+
+/// Compute the cross-section of an object with a plane
+pub trait Section {
+    /// Compute the cross-section of the object with the provided plane
+    ///
+    /// The section is returned as a [`Sketch`], in the coordinates of the
+    /// plane. It can be applied to the same plane, to get the faces of the
+    /// section in their original position (see [`Sketch::faces`]).
+    ///
+    /// Each face of the object is intersected with the plane, and the
+    /// resulting curves are linked into the cycles of the section. Those curves
+    /// are exact. Where the plane cuts through a planar face, the section has
+    /// a line. Where it cuts across a cylinder, the section has a circle.
+    ///
+    /// A plane can cut an object into any number of disjoint regions, each of
+    /// which might have holes. Where the plane only touches the object, without
+    /// cutting into it, there is no section. A face of the object that lies
+    /// within the plane is part of the section, if the object is located behind
+    /// the plane, opposite the direction of its normal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the provided surface is not a plane, or if
+    /// intersecting a face of the object with the plane is not supported (see
+    /// [`SurfaceSurfaceIntersection::compute_with_plane`]). Faces that the
+    /// plane cuts through must only be bounded by lines and circles.
+    fn section(
+        &self,
+        plane: &Surface,
+        services: &mut Services,
+    ) -> Result<Sketch, SectionError>;
+}
+
+impl Section for Solid {
+    fn section(
+        &self,
+        plane: &Surface,
+        services: &mut Services,
+    ) -> Result<Sketch, SectionError> {
+        let GlobalPath::Line(line) = plane.geometry().u else {
+            return Err(SectionError::NotAPlane);
+        };
+        let normal = plane.geometry().normal_at(Point::origin());
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        let mut segments = Vec::new();
+        for shell in self.shells() {
+            for face in shell.faces() {
+                // Most faces are located completely in front of, or behind,
+                // the plane. There's no need to intersect those.
+                let Some(aabb) = face.aabb() else {
+                    continue;
+                };
+                let distances = aabb
+                    .vertices()
+                    .map(|vertex| (vertex - line.origin()).dot(&normal));
+                if distances.iter().all(|&distance| distance > epsilon)
+                    || distances.iter().all(|&distance| distance < -epsilon)
+                {
+                    continue;
+                }
+
+                intersect_face(face, shell, plane, epsilon, &mut segments)?;
+            }
+        }
+
+        let mut cycles = Vec::new();
+        for segments in link(segments, epsilon)? {
+            let area = segments
+                .iter()
+                .map(|segment| segment.path.area_and_moment(segment.boundary).0)
+                .fold(Scalar::ZERO, |sum, area| sum + area);
+            let perimeter = segments
+                .iter()
+                .map(|segment| segment.path.length(segment.boundary))
+                .fold(Scalar::ZERO, |sum, length| sum + length);
+
+            // Where faces touch the plane, without cutting through it, a cycle
+            // can collapse into a line.
+            if area.abs() * 2. / perimeter < epsilon {
+                continue;
+            }
+
+            cycles.push((segments, area));
+        }
+
+        // The segments are oriented so the section is on their left, which
+        // makes the exterior cycles of its regions counter-clockwise, and the
+        // interior cycles clockwise. Each interior cycle belongs to the
+        // smallest exterior cycle that contains it.
+        let polygons = cycles
+            .iter()
+            .map(|(segments, _)| {
+                Polygon::new().with_exterior(
+                    PolyChain::from_points(sample_points(segments)).close(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut interiors = vec![Vec::new(); cycles.len()];
+        for (segments, area) in &cycles {
+            if *area > Scalar::ZERO {
+                continue;
+            }
+
+            let point = segments[0].point_at_middle();
+            let exterior = cycles
+                .iter()
+                .zip(&polygons)
+                .enumerate()
+                .filter(|(_, ((_, area), polygon))| {
+                    *area > Scalar::ZERO && polygon.contains_point(point)
+                })
+                .min_by_key(|(_, ((_, area), _))| *area)
+                .map(|(i, _)| i);
+
+            if let Some(i) = exterior {
+                interiors[i]
+                    .push(build_cycle(segments, services).insert(services));
+            }
+        }
+
+        let regions = cycles
+            .iter()
+            .zip(interiors)
+            .filter(|((_, area), _)| *area > Scalar::ZERO)
+            .map(|((segments, _), interiors)| {
+                let exterior = build_cycle(segments, services).insert(services);
+                Region::new(exterior, interiors, None, None).insert(services)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Sketch::new(regions))
+    }
+}
+
+/// Error computing the cross-section of an object
+///
+/// See [`Section`].
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum SectionError {
+    /// The surface to compute the section with is not a plane
+    #[error("Can only compute section with a plane")]
+    NotAPlane,
+
+    /// Intersecting a face with the plane is not supported
+    #[error("Intersecting a face with the plane is not supported")]
+    UnsupportedFace {
+        /// The face that can't be intersected with the plane
+        face: Handle<Face>,
+    },
+
+    /// The curves of the section don't form closed cycles
+    ///
+    /// This can happen, if the object is not closed.
+    #[error("Section does not consist of closed cycles")]
+    NotClosed,
+}
+
+/// A part of a cycle of the section, in the coordinates of the plane
+#[derive(Clone)]
+struct Segment {
+    path: SurfacePath,
+    boundary: [Point<1>; 2],
+}
+
+impl Segment {
+    fn start(&self) -> Point<2> {
+        self.path.point_from_path_coords(self.boundary[0])
+    }
+
+    fn end(&self) -> Point<2> {
+        self.path.point_from_path_coords(self.boundary[1])
+    }
+
+    fn point_at_middle(&self) -> Point<2> {
+        let [a, b] = self.boundary;
+        self.path.point_from_path_coords([(a.t + b.t) / 2.])
+    }
+}
+
+/// Compute the segments of the section that are located on a face
+///
+/// The segments are oriented, such that the section is on their left, when
+/// looking at the plane from the front.
+fn intersect_face(
+    face: &Handle<Face>,
+    shell: &Shell,
+    plane: &Surface,
+    epsilon: Scalar,
+    segments: &mut Vec<Segment>,
+) -> Result<(), SectionError> {
+    let surface = face.surface().geometry();
+    let normal = plane.geometry().normal_at(Point::origin());
+
+    if let GlobalPath::Line(_) = surface.u {
+        let face_normal = surface.normal_at(Point::origin());
+        if face_normal.cross(&normal).magnitude() < epsilon {
+            // The face is parallel to the plane. If it lies within the plane,
+            // its edges are taken care of by the neighboring faces.
+            return Ok(());
+        }
+    }
+
+    let unsupported = |face: &Handle<Face>| SectionError::UnsupportedFace {
+        face: face.clone(),
+    };
+
+    let half_edges = face.half_edges().collect::<Vec<_>>();
+    let edges_are_supported = half_edges.iter().all(|half_edge| {
+        matches!(
+            half_edge.path(),
+            SurfacePath::Line(_) | SurfacePath::Circle(_)
+        )
+    });
+    if !edges_are_supported {
+        return Err(unsupported(face));
+    }
+
+    let intersections =
+        SurfaceSurfaceIntersection::compute_with_plane(face.surface(), plane)
+            .map_err(|_| unsupported(face))?;
+
+    for intersection in intersections {
+        let [SurfacePath::Line(line), curve_on_plane] =
+            intersection.intersection_curves
+        else {
+            return Err(unsupported(face));
+        };
+
+        // Lines along the v-axis of a surface that repeats along its u-axis,
+        // repeat too. Only the copies within the face can intersect it.
+        let mut lines = vec![line];
+        let is_periodic =
+            matches!(surface.u, GlobalPath::Circle(_) | GlobalPath::Ellipse(_));
+        if is_periodic && line.direction().u == Scalar::ZERO {
+            let [min, max] = half_edges
+                .iter()
+                .map(|half_edge| half_edge.start_position().u)
+                .fold([Scalar::MAX, -Scalar::MAX], |[min, max], u| {
+                    [if u < min { u } else { min }, max.max(u)]
+                });
+
+            lines.clear();
+            let mut offset =
+                ((min - line.origin().u) / Scalar::TAU).floor() * Scalar::TAU;
+            while line.origin().u + offset <= max {
+                lines.push(Line::from_origin_and_direction(
+                    line.origin() + Vector::from([offset, Scalar::ZERO]),
+                    line.direction(),
+                ));
+                offset += Scalar::TAU;
+            }
+        }
+
+        for line in lines {
+            intersect_face_with_line(
+                face,
+                shell,
+                line,
+                &curve_on_plane,
+                normal,
+                segments,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute the segments of the section along a line on a face
+///
+/// `line` is the intersection of the face's surface and the plane, in surface
+/// coordinates, and `curve_on_plane` is the same curve in the coordinates of
+/// the plane.
+fn intersect_face_with_line(
+    face: &Handle<Face>,
+    shell: &Shell,
+    line: Line<2>,
+    curve_on_plane: &SurfacePath,
+    normal: Vector<3>,
+    segments: &mut Vec<Segment>,
+) -> Result<(), SectionError> {
+    let surface = face.surface().geometry();
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+    let path = SurfacePath::Line(line);
+    let epsilon_on_line = epsilon / line.direction().magnitude();
+
+    // Find all coordinates on the line where it crosses the boundary of the
+    // face. Edges that lie on the line don't cross it, but where they start
+    // and end, the line might.
+    let mut coords = Vec::new();
+    let mut coincident_edges = Vec::new();
+
+    for half_edge in face.half_edges() {
+        let [a, b] = half_edge.boundary().inner;
+        let [min, max] = if a < b { [a.t, b.t] } else { [b.t, a.t] };
+
+        if let SurfacePath::Line(edge) = half_edge.path() {
+            let ends = [a, b].map(|point| edge.point_from_line_coords(point));
+            let ends_on_line = ends.map(|point| {
+                let t = line.point_to_line_coords(point);
+                (t.t, (line.point_from_line_coords(t) - point).magnitude())
+            });
+
+            if ends_on_line.iter().all(|&(_, distance)| distance < epsilon) {
+                let [(a, _), (b, _)] = ends_on_line;
+                coords.extend([a, b]);
+                let range = if a < b { [a, b] } else { [b, a] };
+                coincident_edges.push((half_edge, range));
+                continue;
+            }
+        }
+
+        let (periods, epsilon_on_edge) = match half_edge.path() {
+            SurfacePath::Circle(circle) => {
+                // Coordinates on circles are angles, which repeat.
+                (
+                    vec![-Scalar::TAU, Scalar::ZERO, Scalar::TAU],
+                    epsilon / circle.radius(),
+                )
+            }
+            path => (
+                vec![Scalar::ZERO],
+                epsilon / path.tangent_from_path_coords(a).magnitude(),
+            ),
+        };
+
+        for (t, s, _) in path.intersect(half_edge.path()) {
+            let is_within_edge = periods.iter().any(|&period| {
+                let s = s.t + period;
+                s >= min - epsilon_on_edge && s <= max + epsilon_on_edge
+            });
+
+            if is_within_edge {
+                coords.push(t.t);
+            }
+        }
+    }
+
+    coords.sort();
+    coords.dedup_by(|a, b| (*a - *b).abs() < epsilon_on_line);
+
+    for window in coords.windows(2) {
+        let [start, end] = [window[0], window[1]];
+
+        let t = (start + end) / 2.;
+        let point = line.point_from_line_coords([t]);
+        let point_global = surface.point_from_surface_coords(point);
+        let face_normal = normal_of(face, point);
+
+        let tangent = surface.u.tangent_from_path_coords([point.u])
+            * line.direction().u
+            + surface.v * line.direction().v;
+
+        let coincident_edge = coincident_edges
+            .iter()
+            .find(|(_, [min, max])| t > *min && t < *max);
+
+        if let Some((half_edge, _)) = coincident_edge {
+            // The segment lies on an edge of the face. It is only part of the
+            // section, if the object is cut there. That is the case, if one
+            // of the faces that meet at the edge extends behind the plane, and
+            // the other one doesn't.
+            //
+            // Only the face behind the plane creates the segment, so it isn't
+            // created twice.
+            let Some(other_face) = face_on_other_side(shell, half_edge) else {
+                continue;
+            };
+            let other_surface = other_face.surface().geometry();
+            if !matches!(
+                other_surface.u,
+                GlobalPath::Line(_) | GlobalPath::Circle(_)
+            ) {
+                return Err(SectionError::UnsupportedFace {
+                    face: other_face.clone(),
+                });
+            }
+            let other_normal = normal_of(
+                other_face,
+                other_surface.project_global_point(point_global),
+            );
+
+            let edge_tangent = {
+                let direction = half_edge
+                    .path()
+                    .tangent_from_path_coords(half_edge.boundary().inner[0]);
+                let direction = if half_edge.boundary().inner[0]
+                    < half_edge.boundary().inner[1]
+                {
+                    direction
+                } else {
+                    -direction
+                };
+                (surface.u.tangent_from_path_coords([point.u]) * direction.u
+                    + surface.v * direction.v)
+                    .normalize()
+            };
+
+            // Faces are located to the left of their half-edges.
+            let into_face = face_normal.cross(&edge_tangent);
+            let into_other_face = other_normal.cross(&-edge_tangent);
+
+            let face_is_behind = into_face.dot(&normal) < -epsilon;
+            let other_face_is_behind = into_other_face.dot(&normal) < -epsilon;
+            if !face_is_behind || other_face_is_behind {
+                continue;
+            }
+        } else if !face.contains_point(point, epsilon) {
+            continue;
+        }
+
+        // Looking at the plane from the front, the outside of the face points
+        // to the right of this direction, which means the section is to the
+        // left of it.
+        let direction = normal.cross(&face_normal);
+        if direction.magnitude() < epsilon {
+            // The face touches the plane here, without cutting through it.
+            continue;
+        }
+
+        let boundary = if direction.dot(&tangent) < Scalar::ZERO {
+            [end, start]
+        } else {
+            [start, end]
+        };
+
+        segments.push(Segment {
+            path: curve_on_plane.clone(),
+            boundary: boundary.map(|t| Point::from([t])),
+        });
+    }
+
+    Ok(())
+}
+
+/// Compute the normal of a face, which points to its front side
+fn normal_of(face: &Face, point: Point<2>) -> Vector<3> {
+    let normal = face.surface().geometry().normal_at(point);
+
+    match face.coord_handedness() {
+        Handedness::RightHanded => normal,
+        Handedness::LeftHanded => -normal,
+    }
+}
+
+/// Find the face on the other side of a half-edge
+///
+/// That face might be the same as the half-edge's, if the half-edge is the
+/// seam of a closed surface, like a cylinder.
+fn face_on_other_side<'r>(
+    shell: &'r Shell,
+    half_edge: &Handle<HalfEdge>,
+) -> Option<&'r Handle<Face>> {
+    shell.faces().into_iter().find(|face| {
+        face.half_edges().any(|other| {
+            other.curve().id() == half_edge.curve().id()
+                && other.id() != half_edge.id()
+        })
+    })
+}
+
+/// Link segments into closed cycles
+fn link(
+    mut segments: Vec<Segment>,
+    epsilon: Scalar,
+) -> Result<Vec<Vec<Segment>>, SectionError> {
+    let mut cycles = Vec::new();
+
+    while let Some(first) = segments.pop() {
+        let start = first.start();
+        let mut end = first.end();
+        let mut cycle = vec![first];
+
+        while (end - start).magnitude() >= epsilon {
+            let next = segments
+                .iter()
+                .position(|segment| {
+                    (segment.start() - end).magnitude() < epsilon
+                })
+                .ok_or(SectionError::NotClosed)?;
+
+            let segment = segments.swap_remove(next);
+            end = segment.end();
+            cycle.push(segment);
+        }
+
+        cycles.push(cycle);
+    }
+
+    Ok(cycles)
+}
+
+/// Build a cycle from linked segments
+fn build_cycle(segments: &[Segment], services: &mut Services) -> Cycle {
+    // The segments were computed from different faces, so where they meet,
+    // their ends don't match exactly. Lines can be adjusted to end exactly
+    // where curves start and end, so that's where they meet.
+    let is_line =
+        |segment: &Segment| matches!(segment.path, SurfacePath::Line(_));
+    let junctions = segments
+        .iter()
+        .zip(segments.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            if is_line(a) && !is_line(b) {
+                b.start()
+            } else {
+                a.end()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let half_edges = segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let (path, boundary) = if is_line(segment) {
+                let start =
+                    junctions[(i + junctions.len() - 1) % junctions.len()];
+                let end = junctions[i];
+                SurfacePath::line_from_points([start, end])
+            } else {
+                (segment.path.clone(), segment.boundary)
+            };
+
+            HalfEdge::unjoined(path, boundary, services).insert(services)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges)
+}
+
+/// Sample points along a cycle of the section
+fn sample_points(segments: &[Segment]) -> Vec<Point<2>> {
+    segments
+        .iter()
+        .flat_map(|segment| {
+            let num_samples = if let SurfacePath::Line(_) = segment.path {
+                1
+            } else {
+                SAMPLES_PER_CURVE
+            };
+            let [a, b] = segment.boundary;
+
+            (0..num_samples).map(move |i| {
+                let t =
+                    a.t + (b.t - a.t) * (f64::from(i) / f64::from(num_samples));
+                segment.path.point_from_path_coords([t])
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_approx_eq, Line, Point, Scalar, Vector, Winding};
+
+    use crate::{
+        algorithms::{
+            revolve::Revolve, sweep::Sweep, transform::TransformObject,
+        },
+        geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+        objects::{Region, Sketch, Surface},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    use super::{Section, SectionError};
+
+    #[test]
+    fn section_of_cube() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cube = (sketch, surface.clone()).sweep([0., 0., 1.], &mut services);
+
+        let section = |z: f64, services: &mut Services| {
+            let plane = surface.clone().translate([0., 0., z], services);
+            cube.section(&plane, services)
+        };
+
+        let section_through_middle = section(0.5, &mut services)?;
+        assert_eq!(section_through_middle.regions().count(), 1);
+        assert_approx_eq!(
+            section_through_middle.centroid(),
+            Point::from([0.5, 0.5]),
+            1e-12,
+        );
+
+        // The cube is behind the top face, so that is part of the section. The
+        // bottom face is not.
+        let top = section(1., &mut services)?;
+        assert_eq!(top.regions().count(), 1);
+        assert_eq!(section(0., &mut services)?.regions().count(), 0);
+        assert_eq!(section(2., &mut services)?.regions().count(), 0);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn section_of_tube() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let axis =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_y());
+        let tube = (sketch, surface).revolve(axis, Scalar::TAU, &mut services);
+
+        // Cutting along the axis results in two separate rectangles.
+        let plane = services.objects.surfaces.xy_plane();
+        let section = tube.section(&plane, &mut services)?;
+        assert_eq!(section.regions().count(), 2);
+        for region in section.regions() {
+            assert_eq!(region.interiors().count(), 0);
+        }
+
+        // Cutting across the axis results in a ring.
+        let plane = services
+            .objects
+            .surfaces
+            .xz_plane()
+            .translate([0., 0.5, 0.], &mut services);
+        let section = tube.section(&plane, &mut services)?;
+        assert_eq!(section.regions().count(), 1);
+        for region in section.regions() {
+            assert_eq!(region.interiors().count(), 1);
+        }
+        assert_approx_eq!(section.centroid(), Point::origin(), 1e-9);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn section_of_cylinder() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle([1., 2.], 0.5, &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cylinder =
+            (sketch, surface.clone()).sweep([0., 0., 1.], &mut services);
+
+        // Cutting across the cylinder results in a circle, not a polygon.
+        let plane = surface.translate([0., 0., 0.5], &mut services);
+        let section = cylinder.section(&plane, &mut services)?;
+        let regions = section.regions().collect::<Vec<_>>();
+        let [region] = regions.as_slice() else {
+            panic!("Expected one region: {regions:#?}");
+        };
+        let half_edges = region.exterior().half_edges().collect::<Vec<_>>();
+        let [half_edge] = half_edges.as_slice() else {
+            panic!("Expected one half-edge: {half_edges:#?}");
+        };
+        let SurfacePath::Circle(circle) = half_edge.path() else {
+            panic!("Expected a circle: {:?}", half_edge.path());
+        };
+        assert_approx_eq!(circle.center(), Point::from([1., 2.]), 1e-12);
+        assert_approx_eq!(circle.radius(), Scalar::from(0.5), 1e-12);
+        assert_eq!(region.exterior().winding(), Winding::Ccw);
+
+        // Cutting along the axis results in a rectangle.
+        let plane = services
+            .objects
+            .surfaces
+            .xz_plane()
+            .translate([0., 2., 0.], &mut services);
+        let section = cylinder.section(&plane, &mut services)?;
+        assert_eq!(section.regions().count(), 1);
+        assert_approx_eq!(section.centroid(), Point::from([1., 0.5]), 1e-9);
+        for region in section.regions() {
+            for half_edge in region.exterior().half_edges() {
+                assert!(matches!(half_edge.path(), SurfacePath::Line(_)));
+            }
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn section_through_edges() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cube = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        // The plane cuts diagonally through the cube, along two of its edges.
+        let plane = Surface::new(SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::origin(),
+                Vector::unit_y(),
+            )),
+            v: Vector::from([1., 0., 1.]),
+        })
+        .insert(&mut services);
+        let section = cube.section(&plane, &mut services)?;
+        assert_eq!(section.regions().count(), 1);
+        assert_approx_eq!(section.centroid(), Point::from([0.5, 0.5]), 1e-9);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn section_with_curved_surface() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cylinder = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let curved = cylinder
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .find(|face| {
+                matches!(face.surface().geometry().u, GlobalPath::Circle(_))
+            })
+            .map(|face| face.surface().clone())
+            .expect("Cylinder has a curved face");
+
+        let result = cylinder.section(&curved, &mut services);
+        assert!(matches!(result, Err(SectionError::NotAPlane)));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+use fj_math::{Point, Scalar, Transform};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        loft::{connect_sections, section_of},
+    },
+    geometry::{BoundaryOnCurve, GlobalPath},
+    objects::{Sketch, Solid, Surface},
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// Sweep an object along a curved path to create another object
+///
+/// Unlike [`Sweep`], which moves an object along a straight line, this moves
+/// an object along a [`GlobalPath`]. The object is expected to be located at
+/// the start of the path. While it moves along the path, it is rotated to
+/// follow the direction of the path.
+///
+/// # Implementation Note
+///
+/// The path is approximated, and the swept object consists of segments that
+/// connect the object at each point of the approximation. The object's
+/// orientation at each of those points is determined by parallel transport,
+/// meaning it is rotated as little as possible, to follow the direction of
+/// the path. This keeps it from twisting around the path.
+///
+/// [`Sweep`]: super::Sweep
+pub trait SweepAlong {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given range of the path
+    ///
+    /// If the range covers a full revolution of a circle or ellipse, the
+    /// object ends where it started, and the swept object is closed.
+    fn sweep_along(
+        self,
+        path: GlobalPath,
+        range: impl Into<BoundaryOnCurve>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Self::Swept;
+}
+
+impl SweepAlong for (Handle<Sketch>, Handle<Surface>) {
+    type Swept = Handle<Solid>;
+
+    fn sweep_along(
+        self,
+        path: GlobalPath,
+        range: impl Into<BoundaryOnCurve>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Self::Swept {
+        let (sketch, surface) = self;
+        let range = range.into();
+        let [start, end] = range.inner;
+
+        let is_closed = match path {
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+                (end.t - start.t).abs() == Scalar::TAU
+            }
+            GlobalPath::Bezier(_) | GlobalPath::BSpline(_) => {
+                path.point_from_path_coords(start)
+                    == path.point_from_path_coords(end)
+            }
+            GlobalPath::Helix(_) | GlobalPath::Line(_) => false,
+        };
+
+        let mut stations = vec![start];
+        stations.extend(
+            (path.clone(), range)
+                .approx(tolerance)
+                .into_iter()
+                .map(|(point, _)| point),
+        );
+        if !is_closed {
+            stations.push(end);
+        }
+
+        let transforms = parallel_transport(&path, &stations);
+
+        let direction = path.tangent_from_path_coords(start).normalize();
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        let GlobalPath::Line(line) = surface.geometry().u else {
+            todo!("Sweeping sketches on curved surfaces is not supported")
+        };
+        let normal = line.direction().cross(&surface.geometry().v).normalize();
+        assert!(
+            normal.dot(&direction).abs() >= epsilon,
+            "Can't sweep a sketch along a path that starts out parallel to it"
+        );
+
+        let mut shells = Vec::new();
+        for region in sketch.regions() {
+            let section = section_of(region, &surface, direction);
+
+            let sections = transforms
+                .iter()
+                .map(|transform| {
+                    section
+                        .iter()
+                        .map(|cycle| {
+                            cycle
+                                .iter()
+                                .map(|point| transform.transform_point(point))
+                                .collect()
+                        })
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+
+            let caps = (!is_closed).then(|| {
+                let last = transforms
+                    .last()
+                    .expect("Path has been approximated by multiple points");
+                let end_surface =
+                    Surface::new(surface.geometry().clone().transform(last))
+                        .insert(services);
+
+                [surface.clone(), end_surface]
+            });
+
+            shells.push(connect_sections(
+                sections,
+                caps,
+                region.color(),
+                region.material(),
+                services,
+            ));
+        }
+
+        Solid::new(shells).insert(services)
+    }
+}
+
+/// Compute the transforms that move an object along the path
+///
+/// Returns one transform per station, each of which moves an object from the
+/// first station to the respective one. The rotation between two stations is
+/// the smallest one that maps the direction of the path at the one station to
+/// the direction at the other.
+fn parallel_transport(
+    path: &GlobalPath,
+    stations: &[Point<1>],
+) -> Vec<Transform> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let origin = path.point_from_path_coords(stations[0]);
+
+    let mut rotation = Transform::identity();
+    let mut transforms = Vec::new();
+
+    for (i, &station) in stations.iter().enumerate() {
+        if i > 0 {
+            let [prev, next] = [stations[i - 1], station]
+                .map(|point| path.tangent_from_path_coords(point).normalize());
+
+            let axis = prev.cross(&next);
+            let sin = axis.magnitude();
+            if sin > epsilon {
+                let angle = sin.atan2(prev.dot(&next));
+                rotation =
+                    Transform::rotation(axis.normalize() * angle) * rotation;
+            }
+        }
+
+        let position = path.point_from_path_coords(station);
+        transforms.push(
+            Transform::translation(position.coords)
+                * rotation
+                * Transform::translation(-origin.coords),
+        );
+    }
+
+    transforms
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        geometry::GlobalPath,
+        objects::{Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::SweepAlong;
+
+    #[test]
+    fn sweep_along_line() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch =
+            sketch([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let path = GlobalPath::Line(Line::from_origin_and_direction(
+            Point::origin(),
+            Vector::from([0., 0., 2.]),
+        ));
+
+        let solid = (sketch, surface).sweep_along(
+            path,
+            [[0.], [1.]],
+            Tolerance::from_scalar(0.001)?,
+            &mut services,
+        );
+
+        assert_eq!(num_faces(&solid), 6);
+        assert_mesh_volume(&solid, 2.)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_arc() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A square, centered on the start of the path and perpendicular to it.
+        let sketch = sketch(
+            [[1.5, -0.5], [2.5, -0.5], [2.5, 0.5], [1.5, 0.5]],
+            &mut services,
+        );
+        let surface = services.objects.surfaces.xz_plane();
+        let path = GlobalPath::circle_from_radius(2.);
+
+        let solid = (sketch, surface).sweep_along(
+            path,
+            [[Scalar::ZERO], [Scalar::PI / 2.]],
+            Tolerance::from_scalar(0.05)?,
+            &mut services,
+        );
+
+        // The center of the square travels a quarter circle with a radius of
+        // 2.
+        assert_mesh_volume(&solid, Scalar::PI.into_f64())?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_full_circle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = sketch(
+            [[1.5, -0.5], [2.5, -0.5], [2.5, 0.5], [1.5, 0.5]],
+            &mut services,
+        );
+        let surface = services.objects.surfaces.xz_plane();
+        let path = GlobalPath::circle_from_radius(2.);
+
+        let solid = (sketch, surface).sweep_along(
+            path,
+            [[Scalar::ZERO], [Scalar::TAU]],
+            Tolerance::from_scalar(0.05)?,
+            &mut services,
+        );
+
+        // There are no caps, as the solid is closed.
+        assert_eq!(num_faces(&solid) % 4, 0);
+        assert_mesh_volume(&solid, Scalar::TAU.into_f64() * 2.)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn sketch<const N: usize>(
+        points: [[f64; 2]; N],
+        services: &mut Services,
+    ) -> Handle<Sketch> {
+        Sketch::empty()
+            .add_region(Region::polygon(points, services).insert(services))
+            .insert(services)
+    }
+
+    fn num_faces(solid: &Solid) -> usize {
+        solid
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum()
+    }
+
+    fn assert_mesh_volume(solid: &Solid, expected: f64) -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        // The path is approximated, which makes the volume a bit smaller.
+        let error = (volume.into_f64() - expected).abs();
+        assert!(
+            error < expected * 0.05,
+            "Unexpected volume: {volume}, not {expected}"
+        );
+
+        Ok(())
+    }
+}
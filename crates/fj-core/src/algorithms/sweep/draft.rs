@@ -0,0 +1,282 @@
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        loft::{connect_sections, section_of},
+        transform::TransformObject,
+    },
+    geometry::GlobalPath,
+    objects::{Region, Sketch, Solid, Surface},
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// Sweep an object along a straight path, while tapering it
+///
+/// This works like [`Sweep`], except that the object shrinks or grows linearly
+/// along the path, as is required for parts that need draft. The object is
+/// scaled about the centroid of its exterior.
+///
+/// A positive draft angle shrinks the object, a negative one grows it. The
+/// amount of scaling is chosen such, that the side faces closest to the
+/// centroid have the draft angle. For objects whose edges all have the same
+/// distance to the centroid, like squares or other regular polygons, this
+/// applies to all side faces. For other objects, the side faces that are
+/// farther from the centroid are steeper.
+///
+/// [`Sweep`]: super::Sweep
+pub trait SweepWithDraft {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, with the given draft angle
+    ///
+    /// The draft angle is in radians, and must be larger than -90° and smaller
+    /// than 90°.
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        draft_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self::Swept, DraftError>;
+}
+
+impl SweepWithDraft for (Handle<Sketch>, Handle<Surface>) {
+    type Swept = Handle<Solid>;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        draft_angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self::Swept, DraftError> {
+        let (sketch, surface) = self;
+        let path = path.into();
+        let draft_angle = draft_angle.into();
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        if draft_angle.abs() >= Scalar::PI / 2. {
+            return Err(DraftError::InvalidAngle(draft_angle));
+        }
+
+        let geometry = surface.geometry();
+        let GlobalPath::Line(line) = geometry.u else {
+            todo!("Sweeping sketches on curved surfaces is not supported")
+        };
+        let normal = line.direction().cross(&geometry.v).normalize();
+        let height = path.dot(&normal).abs();
+        assert!(
+            height >= epsilon,
+            "Can't sweep a sketch along a path that is parallel to it"
+        );
+
+        let (sin, cos) = draft_angle.sin_cos();
+        let offset = height * sin / cos;
+
+        // Compute everything before creating any objects, so nothing is left
+        // behind, if the profile collapses.
+        let sections = sketch
+            .regions()
+            .map(|region| {
+                let bottom = section_of(region, &surface, path);
+
+                let centroid = centroid_of(region, &surface);
+                let distance = bottom[0]
+                    .iter()
+                    .zip(bottom[0].iter().cycle().skip(1))
+                    .map(|(&a, &b)| distance_to_line(centroid, [a, b]))
+                    .min()
+                    .expect("Exterior has edges");
+
+                let scale = Scalar::ONE - offset / distance;
+                if scale * distance < epsilon {
+                    return Err(DraftError::ProfileCollapses);
+                }
+
+                let top = bottom
+                    .iter()
+                    .map(|cycle| {
+                        cycle
+                            .iter()
+                            .map(|&point| {
+                                centroid + (point - centroid) * scale + path
+                            })
+                            .collect()
+                    })
+                    .collect();
+
+                Ok((region, bottom, top))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let top_surface = surface.clone().translate(path, services);
+
+        let shells = sections
+            .into_iter()
+            .map(|(region, bottom, top)| {
+                connect_sections(
+                    vec![bottom, top],
+                    Some([surface.clone(), top_surface.clone()]),
+                    region.color(),
+                    region.material(),
+                    services,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Solid::new(shells).insert(services))
+    }
+}
+
+/// Error sweeping with draft
+///
+/// See [`SweepWithDraft`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DraftError {
+    /// The draft angle is not within the valid range
+    #[error("Invalid draft angle ({0}); must be within (-pi/2, pi/2)")]
+    InvalidAngle(Scalar),
+
+    /// The draft is so steep that the profile collapses
+    #[error(
+        "Draft angle is too steep; profile collapses before reaching the end \
+        of the sweep"
+    )]
+    ProfileCollapses,
+}
+
+/// Compute the centroid of the area enclosed by the region's exterior
+fn centroid_of(region: &Region, surface: &Surface) -> Point<3> {
+    let points = region
+        .exterior()
+        .half_edges()
+        .map(|half_edge| half_edge.start_position())
+        .collect::<Vec<_>>();
+
+    let mut area = Scalar::ZERO;
+    let mut centroid = Vector::from([0., 0.]);
+
+    for (&a, &b) in points.iter().zip(points.iter().cycle().skip(1)) {
+        let cross = a.coords.cross2d(&b.coords);
+
+        area += cross;
+        centroid = centroid + (a.coords + b.coords) * cross;
+    }
+
+    let centroid = Point {
+        coords: centroid / (area * 3.),
+    };
+    surface.geometry().point_from_surface_coords(centroid)
+}
+
+/// Compute the distance of a point to the line through the two other points
+fn distance_to_line(point: Point<3>, [a, b]: [Point<3>; 2]) -> Scalar {
+    let direction = (b - a).normalize();
+    (point - a).cross(&direction).magnitude()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        objects::{Region, Sketch, Solid, Surface},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{DraftError, SweepWithDraft};
+
+    #[test]
+    fn sweep_with_positive_draft() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let (sketch, surface) = square(&mut services);
+        let solid = (sketch, surface).sweep_with_draft(
+            [0., 0., 1.],
+            0.25_f64.atan(),
+            &mut services,
+        )?;
+
+        // The 2x2 square shrinks to a 1.5x1.5 square.
+        assert_mesh_volume(&solid, (4. + 2.25 + 3.) / 3.)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_with_negative_draft() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let (sketch, surface) = square(&mut services);
+        let solid = (sketch, surface).sweep_with_draft(
+            [0., 0., -1.],
+            -(0.25_f64.atan()),
+            &mut services,
+        )?;
+
+        // The 2x2 square grows to a 2.5x2.5 square.
+        assert_mesh_volume(&solid, (4. + 6.25 + 5.) / 3.)?;
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_with_collapsing_draft() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A draft of 45° collapses the 2x2 square into a point after a
+        // distance of 1.
+        for distance in [1., 2.] {
+            let (sketch, surface) = square(&mut services);
+            let result = (sketch, surface).sweep_with_draft(
+                [0., 0., distance],
+                Scalar::PI / 4.,
+                &mut services,
+            );
+            assert_eq!(result, Err(DraftError::ProfileCollapses));
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn square(services: &mut Services) -> (Handle<Sketch>, Handle<Surface>) {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface)
+    }
+
+    fn assert_mesh_volume(solid: &Solid, expected: f64) -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        let error = (volume.into_f64() - expected).abs();
+        assert!(error < 1e-9, "Unexpected volume: {volume}, not {expected}");
+
+        Ok(())
+    }
+}
@@ -1,16 +1,27 @@
-use fj_interop::{ext::ArrayExt, mesh::Color};
+use fj_interop::{
+    ext::ArrayExt,
+    mesh::{Color, Material},
+};
 use fj_math::{Point, Scalar, Vector};
 
 use crate::{
     objects::{Cycle, Face, HalfEdge, Region, Surface, Vertex},
-    operations::{BuildHalfEdge, Insert, UpdateCycle, UpdateHalfEdge},
+    operations::{BuildHalfEdge, Insert, Reverse, UpdateCycle, UpdateHalfEdge},
     services::Services,
     storage::Handle,
 };
 
 use super::{Sweep, SweepCache};
 
-impl Sweep for (&HalfEdge, &Handle<Vertex>, &Surface, Option<Color>) {
+impl Sweep
+    for (
+        &HalfEdge,
+        &Handle<Vertex>,
+        &Surface,
+        Option<Color>,
+        Option<&Material>,
+    )
+{
     type Swept = (Handle<Face>, Handle<HalfEdge>);
 
     fn sweep_with_cache(
@@ -19,9 +30,18 @@ impl Sweep for (&HalfEdge, &Handle<Vertex>, &Surface, Option<Color>) {
         cache: &mut SweepCache,
         services: &mut Services,
     ) -> Self::Swept {
-        let (edge, next_vertex, surface, color) = self;
+        let (edge, next_vertex, surface, color, material) = self;
         let path = path.into();
 
+        if let Some((face, top_edge)) =
+            cache.side_faces.get(&edge.global_form().id()).cloned()
+        {
+            return reuse_side_face(
+                edge, surface, face, top_edge, color, material, services,
+            );
+        }
+        let global_edge = edge.global_form().id();
+
         let surface =
             (edge.path(), surface).sweep_with_cache(path, cache, services);
 
@@ -125,14 +145,92 @@ impl Sweep for (&HalfEdge, &Handle<Vertex>, &Surface, Option<Color>) {
                 },
             );
 
-        let region = Region::new(exterior.unwrap().insert(services), [], color)
-            .insert(services);
+        let region = Region::new(
+            exterior.unwrap().insert(services),
+            [],
+            color,
+            material.cloned(),
+        )
+        .insert(services);
 
         let face = Face::new(surface, region);
 
         // And we're done creating the face! All that's left to do is build our
         // return values.
         let face = face.insert(services);
+        cache
+            .side_faces
+            .insert(global_edge, (face.clone(), edge_top.clone()));
         (face, edge_top)
     }
 }
+
+/// Reuse a side face, that was created by sweeping another half-edge
+///
+/// The other half-edge shares its global edge with `edge`. If it runs in the
+/// same direction, the side face is returned as-is. Otherwise, the half-edges
+/// border adjacent faces, and the side face is the wall between the shapes
+/// swept from those. Each of those shapes needs the wall to face out of it,
+/// so a reversed face is returned, which shares the surface and all edges with
+/// the original one.
+fn reuse_side_face(
+    edge: &HalfEdge,
+    surface: &Surface,
+    face: Handle<Face>,
+    top_edge: Handle<HalfEdge>,
+    color: Option<Color>,
+    material: Option<&Material>,
+    services: &mut Services,
+) -> (Handle<Face>, Handle<HalfEdge>) {
+    // The bottom edge of the side face is the first one of its cycle. It is
+    // defined on the side face's surface, but coincides with the half-edge
+    // that was swept.
+    let bottom_edge = face
+        .region()
+        .exterior()
+        .half_edges()
+        .next()
+        .expect("Side face has edges");
+
+    // Comparing vertices wouldn't tell us the direction of closed half-edges,
+    // like circles. Compare points along the half-edges instead.
+    let point = point_along(edge, surface, 0.25);
+    let same_direction =
+        point.distance_to(&point_along(bottom_edge, face.surface(), 0.25))
+            < point.distance_to(&point_along(
+                bottom_edge,
+                face.surface(),
+                0.75,
+            ));
+    if same_direction {
+        return (face, top_edge);
+    }
+
+    let exterior = face.region().exterior().reverse(services);
+    let top_edge = exterior
+        .half_edges()
+        .find(|half_edge| {
+            half_edge.global_form().id() == top_edge.global_form().id()
+        })
+        .expect("Reversed cycle contains reversed top edge")
+        .clone();
+
+    let region =
+        Region::new(exterior.insert(services), [], color, material.cloned())
+            .insert(services);
+    let face = Face::new(face.surface().clone(), region).insert(services);
+
+    (face, top_edge)
+}
+
+/// Compute the point at a fraction of the way along a half-edge
+fn point_along(
+    half_edge: &HalfEdge,
+    surface: &Surface,
+    fraction: f64,
+) -> Point<3> {
+    let [a, b] = half_edge.boundary().inner;
+    let point_curve = a + (b - a) * fraction;
+    let point_surface = half_edge.path().point_from_path_coords(point_curve);
+    surface.geometry().point_from_surface_coords(point_surface)
+}
@@ -11,7 +11,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{Sweep, SweepCache, SweepWithCaps, WithCaps};
 
 impl Sweep for Handle<Face> {
     type Swept = Handle<Shell>;
@@ -22,83 +22,115 @@ impl Sweep for Handle<Face> {
         cache: &mut SweepCache,
         services: &mut Services,
     ) -> Self::Swept {
-        let path = path.into();
-
-        let mut faces = Vec::new();
+        sweep_face(self, path.into(), cache, services).swept
+    }
+}
 
-        let is_negative_sweep = {
-            let u = match self.surface().geometry().u {
-                GlobalPath::Circle(_) => todo!(
-                    "Sweeping from faces defined in round surfaces is not \
-                    supported"
-                ),
-                GlobalPath::Line(line) => line.direction(),
-            };
-            let v = self.surface().geometry().v;
+impl SweepWithCaps for Handle<Face> {
+    type Swept = Handle<Shell>;
 
-            let normal = u.cross(&v);
+    fn sweep_with_caps(
+        self,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> WithCaps<Self::Swept> {
+        let mut cache = SweepCache::default();
+        sweep_face(self, path.into(), &mut cache, services)
+    }
+}
 
-            normal.dot(&path) < Scalar::ZERO
+/// Sweep a face, returning the swept shell together with its caps
+pub(super) fn sweep_face(
+    face: Handle<Face>,
+    path: Vector<3>,
+    cache: &mut SweepCache,
+    services: &mut Services,
+) -> WithCaps<Handle<Shell>> {
+    let mut faces = Vec::new();
+
+    let is_negative_sweep = {
+        let u = match face.surface().geometry().u {
+            GlobalPath::Bezier(_)
+            | GlobalPath::BSpline(_)
+            | GlobalPath::Circle(_)
+            | GlobalPath::Ellipse(_)
+            | GlobalPath::Helix(_) => todo!(
+                "Sweeping from faces defined in round surfaces is not \
+                supported"
+            ),
+            GlobalPath::Line(line) => line.direction(),
         };
+        let v = face.surface().geometry().v;
 
-        let bottom_face = {
-            if is_negative_sweep {
-                self.clone()
-            } else {
-                self.clone().reverse(services).insert(services)
-            }
-        };
-        faces.push(bottom_face.clone());
-
-        let top_surface =
-            bottom_face.surface().clone().translate(path, services);
-
-        let mut exterior = None;
-        let mut interiors = Vec::new();
-
-        for (i, cycle) in bottom_face.region().all_cycles().cloned().enumerate()
-        {
-            let cycle = cycle.reverse(services);
-
-            let mut top_edges = Vec::new();
-            for (half_edge, next) in cycle.half_edge_pairs() {
-                let (face, top_edge) = (
-                    half_edge.deref(),
-                    next.start_vertex(),
-                    self.surface().deref(),
-                    self.region().color(),
-                )
-                    .sweep_with_cache(path, cache, services);
-
-                faces.push(face);
-
-                top_edges.push((
-                    top_edge,
-                    half_edge.path(),
-                    half_edge.boundary(),
-                ));
-            }
-
-            let top_cycle = Cycle::empty()
-                .add_joined_edges(top_edges, services)
-                .insert(services);
-
-            if i == 0 {
-                exterior = Some(top_cycle);
-            } else {
-                interiors.push(top_cycle);
-            };
+        let normal = u.cross(&v);
+
+        normal.dot(&path) < Scalar::ZERO
+    };
+
+    let bottom_face = {
+        if is_negative_sweep {
+            face.clone()
+        } else {
+            face.clone().reverse(services).insert(services)
+        }
+    };
+    faces.push(bottom_face.clone());
+
+    let top_surface = bottom_face.surface().clone().translate(path, services);
+
+    let mut exterior = None;
+    let mut interiors = Vec::new();
+
+    for (i, cycle) in bottom_face.region().all_cycles().cloned().enumerate() {
+        let cycle = cycle.reverse(services);
+
+        let mut top_edges = Vec::new();
+        for (half_edge, next) in cycle.half_edge_pairs() {
+            let (face, top_edge) = (
+                half_edge.deref(),
+                next.start_vertex(),
+                face.surface().deref(),
+                face.region().color(),
+                face.region().material(),
+            )
+                .sweep_with_cache(path, cache, services);
+
+            faces.push(face);
+
+            top_edges.push((
+                top_edge,
+                half_edge.path().clone(),
+                half_edge.boundary(),
+            ));
         }
 
-        let region =
-            Region::new(exterior.unwrap(), interiors, self.region().color())
-                .insert(services);
+        let top_cycle = Cycle::empty()
+            .add_joined_edges(top_edges, services)
+            .insert(services);
+
+        if i == 0 {
+            exterior = Some(top_cycle);
+        } else {
+            interiors.push(top_cycle);
+        };
+    }
+
+    let region = Region::new(
+        exterior.unwrap(),
+        interiors,
+        face.region().color(),
+        face.region().material().cloned(),
+    )
+    .insert(services);
 
-        let top_face = Face::new(top_surface, region);
+    let top_face = Face::new(top_surface, region);
 
-        let top_face = top_face.insert(services);
-        faces.push(top_face);
+    let top_face = top_face.insert(services);
+    faces.push(top_face.clone());
 
-        Shell::new(faces).insert(services)
+    WithCaps {
+        swept: Shell::new(faces).insert(services),
+        bottom: vec![bottom_face],
+        top: vec![top_face],
     }
 }
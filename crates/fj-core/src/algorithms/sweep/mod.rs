@@ -1,5 +1,7 @@
 //! Sweeping objects along a path to create new objects
 
+mod along;
+mod draft;
 mod edge;
 mod face;
 mod path;
@@ -11,11 +13,16 @@ use std::collections::BTreeMap;
 use fj_math::Vector;
 
 use crate::{
-    objects::{Curve, GlobalEdge, Vertex},
+    objects::{Curve, Face, GlobalEdge, HalfEdge, Vertex},
     services::Services,
     storage::{Handle, ObjectId},
 };
 
+pub use self::{
+    along::SweepAlong,
+    draft::{DraftError, SweepWithDraft},
+};
+
 /// Sweep an object along a path to create another object
 pub trait Sweep: Sized {
     /// The object that is created by sweeping the implementing object
@@ -40,6 +47,44 @@ pub trait Sweep: Sized {
     ) -> Self::Swept;
 }
 
+/// Sweep an object along a path, keeping track of the faces that cap it
+///
+/// This works like [`Sweep`], except that the faces at the start and the end
+/// of the sweep are returned too. That way, they can be used for further
+/// operations, without searching for them in the swept object.
+pub trait SweepWithCaps {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path
+    fn sweep_with_caps(
+        self,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> WithCaps<Self::Swept>;
+}
+
+/// An object created by sweeping, together with the faces that cap it
+///
+/// See [`SweepWithCaps`].
+pub struct WithCaps<T> {
+    /// The object created by sweeping
+    pub swept: T,
+
+    /// The faces at the start of the sweep
+    ///
+    /// There's one bottom face for each face that was swept. Like all faces of
+    /// the swept object, they face outward, which is against the direction of
+    /// the sweep.
+    pub bottom: Vec<Handle<Face>>,
+
+    /// The faces at the end of the sweep
+    ///
+    /// There's one top face for each face that was swept, in the same order as
+    /// the bottom faces.
+    pub top: Vec<Handle<Face>>,
+}
+
 /// A cache used for sweeping
 ///
 /// See [`Sweep`].
@@ -53,4 +98,11 @@ pub struct SweepCache {
 
     /// Cache for global edges
     pub global_edges: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+
+    /// Cache for side faces, and the top edges of those
+    ///
+    /// Keyed by the global edge of the half-edge that was swept to create the
+    /// side face. Half-edges that share a global edge, like the half-edges of
+    /// two adjacent faces of a sketch, share a side face that way.
+    pub side_faces: BTreeMap<ObjectId, (Handle<Face>, Handle<HalfEdge>)>,
 }
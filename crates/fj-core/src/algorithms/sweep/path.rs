@@ -1,4 +1,4 @@
-use fj_math::{Circle, Line, Vector};
+use fj_math::{BSpline, Bezier, Circle, Ellipse, Line, Vector};
 
 use crate::{
     geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
@@ -10,7 +10,7 @@ use crate::{
 
 use super::{Sweep, SweepCache};
 
-impl Sweep for (SurfacePath, &Surface) {
+impl Sweep for (&SurfacePath, &Surface) {
     type Swept = Handle<Surface>;
 
     fn sweep_with_cache(
@@ -22,7 +22,11 @@ impl Sweep for (SurfacePath, &Surface) {
         let (curve, surface) = self;
 
         match surface.geometry().u {
-            GlobalPath::Circle(_) => {
+            GlobalPath::Bezier(_)
+            | GlobalPath::BSpline(_)
+            | GlobalPath::Circle(_)
+            | GlobalPath::Ellipse(_)
+            | GlobalPath::Helix(_) => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `GlobalPath`, which we are computing below.
                 // That computation might or might not work with an arbitrary
@@ -46,6 +50,24 @@ impl Sweep for (SurfacePath, &Surface) {
         }
 
         let u = match curve {
+            SurfacePath::Bezier(bezier) => {
+                let control_points = bezier.control_points().map(|point| {
+                    surface.geometry().point_from_surface_coords(point)
+                });
+
+                GlobalPath::Bezier(Bezier::from_control_points(control_points))
+            }
+            SurfacePath::BSpline(bspline) => {
+                let control_points =
+                    bspline.control_points().iter().map(|&point| {
+                        surface.geometry().point_from_surface_coords(point)
+                    });
+
+                GlobalPath::BSpline(BSpline::from_control_points(
+                    control_points,
+                    bspline.degree(),
+                ))
+            }
             SurfacePath::Circle(circle) => {
                 let center = surface
                     .geometry()
@@ -59,6 +81,21 @@ impl Sweep for (SurfacePath, &Surface) {
 
                 GlobalPath::Circle(circle)
             }
+            SurfacePath::Ellipse(ellipse) => {
+                let center = surface
+                    .geometry()
+                    .point_from_surface_coords(ellipse.center());
+                let major = surface
+                    .geometry()
+                    .vector_from_surface_coords(ellipse.major());
+                let minor = surface
+                    .geometry()
+                    .vector_from_surface_coords(ellipse.minor());
+
+                let ellipse = Ellipse::new(center, major, minor);
+
+                GlobalPath::Ellipse(ellipse)
+            }
             SurfacePath::Line(line) => {
                 let origin =
                     surface.geometry().point_from_surface_coords(line.origin());
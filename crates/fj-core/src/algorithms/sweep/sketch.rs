@@ -7,7 +7,7 @@ use crate::{
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{face::sweep_face, Sweep, SweepCache, SweepWithCaps, WithCaps};
 
 impl Sweep for (Handle<Sketch>, Handle<Surface>) {
     type Swept = Handle<Solid>;
@@ -18,14 +18,173 @@ impl Sweep for (Handle<Sketch>, Handle<Surface>) {
         cache: &mut SweepCache,
         services: &mut Services,
     ) -> Self::Swept {
-        let path = path.into();
+        sweep_sketch(self, path.into(), cache, services).swept
+    }
+}
+
+impl SweepWithCaps for (Handle<Sketch>, Handle<Surface>) {
+    type Swept = Handle<Solid>;
+
+    fn sweep_with_caps(
+        self,
+        path: impl Into<Vector<3>>,
+        services: &mut Services,
+    ) -> WithCaps<Self::Swept> {
+        let mut cache = SweepCache::default();
+        sweep_sketch(self, path.into(), &mut cache, services)
+    }
+}
+
+/// Sweep a sketch, returning the swept solid together with its caps
+fn sweep_sketch(
+    (sketch, surface): (Handle<Sketch>, Handle<Surface>),
+    path: Vector<3>,
+    cache: &mut SweepCache,
+    services: &mut Services,
+) -> WithCaps<Handle<Solid>> {
+    let mut shells = Vec::new();
+    let mut bottom = Vec::new();
+    let mut top = Vec::new();
+
+    for face in sketch.faces(surface, services) {
+        let swept = sweep_face(face, path, cache, services);
+
+        shells.push(swept.swept);
+        bottom.extend(swept.bottom);
+        top.extend(swept.top);
+    }
+
+    WithCaps {
+        swept: Solid::new(shells).insert(services),
+        bottom,
+        top,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        objects::{Cycle, Face, Region, Sketch},
+        operations::{
+            BuildCycle, BuildRegion, BuildSketch, Insert, JoinCycle,
+            UpdateSketch,
+        },
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{Sweep, SweepWithCaps};
+
+    #[test]
+    fn adjacent_faces_share_wall() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let a = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let b = {
+            // The left edge of `b` is the right edge of `a`.
+            let exterior = Cycle::polygon(
+                [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+                &mut services,
+            )
+            .join_to(a.exterior(), 3..=3, 1..=1, &mut services)
+            .insert(&mut services);
+
+            Region::new(exterior, [], None, None).insert(&mut services)
+        };
+
+        let sketch = Sketch::empty()
+            .add_region(a)
+            .add_region(b)
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let solid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let [walls_a, walls_b] = {
+            let shells = solid.shells().collect::<Vec<_>>();
+            [shells[0], shells[1]].map(|shell| {
+                shell
+                    .faces()
+                    .into_iter()
+                    .map(|face| {
+                        let global_edges = face
+                            .region()
+                            .exterior()
+                            .half_edges()
+                            .map(|half_edge| half_edge.global_form().id())
+                            .collect::<BTreeSet<_>>();
+                        (global_edges, face.surface().id())
+                    })
+                    .collect::<BTreeSet<_>>()
+            })
+        };
+
+        // The wall between the shells is defined only once. Both shells contain
+        // a face that is bounded by its edges, and is on its surface, but each
+        // of those faces is oriented to face out of its own shell.
+        assert_eq!(walls_a.intersection(&walls_b).count(), 1);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn caps() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .add_region(
+                Region::polygon([[2., 0.], [3., 0.], [3., 1.]], &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let swept =
+            (sketch, surface).sweep_with_caps([0., 0., 1.], &mut services);
+
+        assert_eq!(swept.bottom.len(), 2);
+        assert_eq!(swept.top.len(), 2);
+
+        let shells = swept.swept.shells().collect::<Vec<_>>();
+        for (i, (bottom, top)) in
+            swept.bottom.iter().zip(&swept.top).enumerate()
+        {
+            // Each pair of caps is part of the shell swept from the same
+            // region of the sketch.
+            let contains = |face: &Handle<Face>| {
+                shells[i].faces().into_iter().any(|f| f.id() == face.id())
+            };
+            assert!(contains(bottom));
+            assert!(contains(top));
+
+            for (face, z) in [(bottom, 0.), (top, 1.)] {
+                let point = face
+                    .surface()
+                    .geometry()
+                    .point_from_surface_coords([0., 0.]);
+                assert_eq!(point.z, Scalar::from(z));
+            }
 
-        let mut shells = Vec::new();
-        for face in self.0.faces(self.1, services) {
-            let shell = face.sweep_with_cache(path, cache, services);
-            shells.push(shell);
+            // The caps are on parallel surfaces, and face out of the solid,
+            // so in opposite directions.
+            assert_ne!(bottom.coord_handedness(), top.coord_handedness());
         }
 
-        Solid::new(shells).insert(services)
+        services.drop_and_validate()?;
+        Ok(())
     }
 }
@@ -16,7 +16,7 @@ impl TransformObject for HalfEdge {
     ) -> Self {
         // Don't need to transform the path, as that's defined in surface
         // coordinates.
-        let path = self.path();
+        let path = self.path().clone();
         let boundary = self.boundary();
         let curve = self
             .curve()
@@ -31,7 +31,14 @@ impl TransformObject for HalfEdge {
             .clone()
             .transform_with_cache(transform, services, cache);
 
-        Self::new(path, boundary, curve, start_vertex, global_form)
+        Self::new(
+            path,
+            boundary,
+            curve,
+            start_vertex,
+            global_form,
+            self.kind(),
+        )
     }
 }
 
@@ -1,8 +1,7 @@
 use fj_math::Transform;
 
 use crate::{
-    objects::{Face, FaceSet, Region},
-    operations::Insert,
+    objects::{Face, FaceSet},
     services::Services,
 };
 
@@ -15,23 +14,14 @@ impl TransformObject for Face {
         services: &mut Services,
         cache: &mut TransformCache,
     ) -> Self {
-        // Color does not need to be transformed.
-        let color = self.region().color();
-
         let surface = self
             .surface()
             .clone()
             .transform_with_cache(transform, services, cache);
-        let exterior = self
+        let region = self
             .region()
-            .exterior()
             .clone()
             .transform_with_cache(transform, services, cache);
-        let interiors = self.region().interiors().cloned().map(|interior| {
-            interior.transform_with_cache(transform, services, cache)
-        });
-
-        let region = Region::new(exterior, interiors, color).insert(services);
 
         Self::new(surface, region)
     }
@@ -4,17 +4,20 @@ mod curve;
 mod cycle;
 mod edge;
 mod face;
+mod region;
 mod shell;
+mod sketch;
 mod solid;
 mod surface;
 mod vertex;
 
 use std::collections::BTreeMap;
 
-use fj_math::{Transform, Vector};
+use fj_math::{Line, Plane, Scalar, Transform, Vector};
 use type_map::TypeMap;
 
 use crate::{
+    geometry::TransformPathError,
     operations::Insert,
     services::Services,
     storage::{Handle, ObjectId},
@@ -31,9 +34,35 @@ use crate::{
 /// hasn't been done so far, is that no one has put in the work yet.
 pub trait TransformObject: Sized {
     /// Transform the object
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform can't be applied to the object. See
+    /// [`TransformObject::try_transform`].
     fn transform(self, transform: &Transform, services: &mut Services) -> Self {
+        self.try_transform(transform, services)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Transform the object, if possible
+    ///
+    /// A transform that scales non-uniformly can't be applied to every curved
+    /// surface. If the object has such a surface, an error is returned, and no
+    /// transformed version of the object is inserted. See
+    /// [`TransformPathError`].
+    fn try_transform(
+        self,
+        transform: &Transform,
+        services: &mut Services,
+    ) -> Result<Self, TransformPathError> {
         let mut cache = TransformCache::default();
-        self.transform_with_cache(transform, services, &mut cache)
+        let transformed =
+            self.transform_with_cache(transform, services, &mut cache);
+
+        match cache.error {
+            Some(err) => Err(err),
+            None => Ok(transformed),
+        }
     }
 
     /// Transform the object using the provided cache
@@ -65,6 +94,36 @@ pub trait TransformObject: Sized {
     ) -> Self {
         self.transform(&Transform::rotation(axis_angle), services)
     }
+
+    /// Rotate the object about an axis
+    ///
+    /// Unlike [`TransformObject::rotate`], the axis doesn't need to pass
+    /// through the origin. The angle is in radians, and the rotation follows
+    /// the right-hand rule around the direction of the axis.
+    ///
+    /// Convenience wrapper around [`TransformObject::transform`].
+    fn rotate_about(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Self {
+        let origin = axis.origin().coords;
+        let axis_angle = axis.direction().normalize() * angle.into();
+
+        let transform = Transform::translation(origin)
+            * Transform::rotation(axis_angle)
+            * Transform::translation(-origin);
+
+        self.transform(&transform, services)
+    }
+
+    /// Mirror the object across a plane
+    ///
+    /// Convenience wrapper around [`TransformObject::transform`].
+    fn mirror(self, plane: &Plane, services: &mut Services) -> Self {
+        self.transform(&Transform::mirror(plane), services)
+    }
 }
 
 impl<T> TransformObject for Handle<T>
@@ -77,14 +136,23 @@ where
         services: &mut Services,
         cache: &mut TransformCache,
     ) -> Self {
+        if cache.error.is_some() {
+            return self;
+        }
         if let Some(object) = cache.get(&self) {
             return object.clone();
         }
 
         let transformed = self
             .clone_object()
-            .transform_with_cache(transform, services, cache)
-            .insert(services);
+            .transform_with_cache(transform, services, cache);
+
+        // If the object couldn't be transformed, it's no use inserting the
+        // partially transformed version.
+        if cache.error.is_some() {
+            return self;
+        }
+        let transformed = transformed.insert(services);
 
         cache.insert(self.clone(), transformed.clone());
 
@@ -96,12 +164,15 @@ where
 ///
 /// See [`TransformObject`].
 #[derive(Default)]
-pub struct TransformCache(TypeMap);
+pub struct TransformCache {
+    objects: TypeMap,
+    error: Option<TransformPathError>,
+}
 
 impl TransformCache {
     fn get<T: 'static>(&mut self, key: &Handle<T>) -> Option<&Handle<T>> {
         let map = self
-            .0
+            .objects
             .entry::<BTreeMap<ObjectId, Handle<T>>>()
             .or_insert_with(BTreeMap::new);
 
@@ -110,10 +181,229 @@ impl TransformCache {
 
     fn insert<T: 'static>(&mut self, key: Handle<T>, value: Handle<T>) {
         let map = self
-            .0
+            .objects
             .entry::<BTreeMap<ObjectId, Handle<T>>>()
             .or_insert_with(BTreeMap::new);
 
         map.insert(key.id(), value);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{
+        assert_approx_eq, Line, Plane, Point, Scalar, Transform, Vector,
+    };
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, bounding_volume::BoundingVolume, sweep::Sweep,
+            triangulate::Triangulate,
+        },
+        geometry::{GlobalPath, TransformPathError},
+        objects::{Cycle, Region, Sketch, Solid},
+        operations::{
+            BuildCycle, BuildRegion, BuildSketch, Insert, Reverse,
+            UpdateRegion, UpdateSketch,
+        },
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::TransformObject;
+
+    #[test]
+    fn mirror_spacer() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // This is the `spacer` model, offset from the mirror plane.
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::from([2., 0.]), 1., &mut services)
+                    .add_interiors([Cycle::circle(
+                        Point::from([2., 0.]),
+                        0.5,
+                        &mut services,
+                    )
+                    .reverse(&mut services)
+                    .insert(&mut services)])
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let spacer = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let yz_plane = Plane::from_parametric(
+            Point::origin(),
+            Vector::unit_y(),
+            Vector::unit_z(),
+        );
+        let mirrored = spacer.mirror(&yz_plane, &mut services);
+
+        // If the faces of the mirrored solid were pointing inward, its volume
+        // would come out negative.
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (&*mirrored, tolerance).triangulate();
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+        let expected = Scalar::PI * (1. - 0.25);
+        assert!((volume - expected).abs() < Scalar::from(0.01));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn translate_sketch() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        let (sketch, surface) =
+            (sketch, surface).translate([0., 0., 2.], &mut services);
+        let cube = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (&*cube, tolerance).triangulate();
+        let aabb = mesh.aabb().expect("Mesh should not be empty");
+        assert_eq!(aabb.min, Point::from([0., 0., 2.]));
+        assert_eq!(aabb.max, Point::from([1., 1., 3.]));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn rotate_cube_about_its_centerline() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cube = (sketch, surface)
+            .sweep([0., 0., 1.], &mut services)
+            .translate([0., 0., 2.], &mut services);
+
+        let centerline = Line::from_origin_and_direction(
+            Point::from([0.5, 0.5, 2.5]),
+            Vector::unit_z(),
+        );
+        let rotated =
+            cube.rotate_about(centerline, Scalar::PI / 4., &mut services);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (&*rotated, tolerance).triangulate();
+        let aabb = mesh.aabb().expect("Mesh should not be empty");
+        let half_diagonal = 0.5_f64.sqrt();
+        assert_approx_eq!(
+            aabb.min,
+            Point::from([0.5 - half_diagonal, 0.5 - half_diagonal, 2.]),
+            1e-12,
+        );
+        assert_approx_eq!(
+            aabb.max,
+            Point::from([0.5 + half_diagonal, 0.5 + half_diagonal, 3.]),
+            1e-12,
+        );
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn scale_cylinder_non_uniformly() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cylinder = cylinder(&mut services);
+        let scaled =
+            cylinder.transform(&Transform::scale([2., 1., 3.]), &mut services);
+
+        // The curved face must not stay circular.
+        let curved_faces = scaled
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .filter(|face| {
+                matches!(face.surface().geometry().u, GlobalPath::Ellipse(_))
+            })
+            .count();
+        assert_eq!(curved_faces, 1);
+
+        let aabb = scaled.aabb().expect("Solid should not be empty");
+        assert!(
+            aabb.min.distance_to(&Point::from([-2., -1., 0.]))
+                < Scalar::from(0.01)
+        );
+        assert!(
+            aabb.max.distance_to(&Point::from([2., 1., 3.]))
+                < Scalar::from(0.01)
+        );
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn scale_rotated_cylinder_non_uniformly() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // After the rotation, the axes of the circle are no longer aligned with
+        // the scaling. Scaling them would skew the circle.
+        let cylinder = cylinder(&mut services)
+            .rotate(Vector::unit_z() * (Scalar::PI / 4.), &mut services);
+        let result = cylinder
+            .clone()
+            .try_transform(&Transform::scale([2., 1., 1.]), &mut services);
+        assert!(matches!(result, Err(TransformPathError::Skew { .. })));
+
+        // Scaling along the axis of the cylinder is still fine.
+        let cylinder = cylinder
+            .try_transform(&Transform::scale([1., 1., 2.]), &mut services)?;
+        let cylinder =
+            cylinder.transform(&Transform::scale([3., 3., 1.]), &mut services);
+
+        let radii = cylinder
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .filter_map(|face| match face.surface().geometry().u {
+                GlobalPath::Circle(circle) => Some(circle.radius()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(radii.len(), 1);
+        assert!((radii[0] - Scalar::from(3.)).abs() < Scalar::from(1e-12));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn cylinder(services: &mut Services) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., services).insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+        (sketch, surface).sweep([0., 0., 1.], services)
+    }
+}
@@ -0,0 +1,51 @@
+use fj_math::Transform;
+
+use crate::{
+    objects::Region,
+    operations::{Insert, Reverse},
+    services::Services,
+};
+
+use super::{TransformCache, TransformObject};
+
+impl TransformObject for Region {
+    fn transform_with_cache(
+        self,
+        transform: &Transform,
+        services: &mut Services,
+        cache: &mut TransformCache,
+    ) -> Self {
+        // Color and material do not need to be transformed.
+        let color = self.color();
+        let material = self.material().cloned();
+
+        let exterior = self
+            .exterior()
+            .clone()
+            .transform_with_cache(transform, services, cache);
+        let interiors = self
+            .interiors()
+            .cloned()
+            .map(|interior| {
+                interior.transform_with_cache(transform, services, cache)
+            })
+            .collect::<Vec<_>>();
+
+        // A transform that reverses orientation, like a reflection, also flips
+        // the surface that the region is placed on. Without reversing the
+        // cycles too, the region would end up pointing the other way.
+        let (exterior, interiors) = if transform.is_orientation_reversing() {
+            let exterior = exterior.reverse(services).insert(services);
+            let interiors = interiors
+                .into_iter()
+                .map(|interior| interior.reverse(services).insert(services))
+                .collect();
+
+            (exterior, interiors)
+        } else {
+            (exterior, interiors)
+        };
+
+        Self::new(exterior, interiors, color, material)
+    }
+}
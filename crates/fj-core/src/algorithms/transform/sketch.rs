@@ -0,0 +1,35 @@
+use fj_math::Transform;
+
+use crate::{
+    objects::{Sketch, Surface},
+    operations::Insert,
+    services::Services,
+    storage::Handle,
+};
+
+use super::{TransformCache, TransformObject};
+
+/// Transform a sketch, along with the surface it is placed on
+///
+/// The regions of a [`Sketch`] are defined in surface coordinates, which is
+/// why a sketch can't be transformed on its own. Transforming it along with its
+/// surface places it somewhere else in 3D space, without changing its shape
+/// within the surface. The result can be swept, just like the original.
+impl TransformObject for (Handle<Sketch>, Handle<Surface>) {
+    fn transform_with_cache(
+        self,
+        transform: &Transform,
+        services: &mut Services,
+        cache: &mut TransformCache,
+    ) -> Self {
+        let (sketch, surface) = self;
+
+        let surface = surface.transform_with_cache(transform, services, cache);
+        let regions = sketch.regions().cloned().map(|region| {
+            region.transform_with_cache(transform, services, cache)
+        });
+        let sketch = Sketch::new(regions).insert(services);
+
+        (sketch, surface)
+    }
+}
@@ -9,9 +9,14 @@ impl TransformObject for Surface {
         self,
         transform: &Transform,
         _: &mut Services,
-        _: &mut TransformCache,
+        cache: &mut TransformCache,
     ) -> Self {
-        let geometry = self.geometry().transform(transform);
-        Self::new(geometry)
+        match self.geometry().clone().try_transform(transform) {
+            Ok(geometry) => Self::new(geometry),
+            Err(err) => {
+                cache.error = Some(err);
+                self
+            }
+        }
     }
 }
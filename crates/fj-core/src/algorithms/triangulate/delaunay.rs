@@ -1,33 +1,59 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
 use fj_math::{Point, Scalar, Triangle, Winding};
-use spade::HasPosition;
+use spade::{HasPosition, PositionInTriangulation, Triangulation as _};
 
-use crate::{algorithms::approx::cycle::CycleApprox, objects::Handedness};
+use crate::{geometry::SurfaceGeometry, objects::Handedness};
 
-/// Create a Delaunay triangulation of all points
-pub fn triangulate(
-    cycles: impl IntoIterator<Item = CycleApprox>,
-    coord_handedness: Handedness,
-) -> Vec<[TriangulationPoint; 3]> {
-    use spade::Triangulation as _;
+use super::{polygon::Polygon, TriangulationConfig};
+
+type ConstrainedDelaunayTriangulation =
+    spade::ConstrainedDelaunayTriangulation<TriangulationPoint>;
+type InnerFace<'a> = spade::handles::FaceHandle<
+    'a,
+    spade::handles::InnerTag,
+    TriangulationPoint,
+    (),
+    spade::CdtEdge<()>,
+    (),
+>;
 
-    let mut triangulation = spade::ConstrainedDelaunayTriangulation::<_>::new();
+/// Create a constrained Delaunay triangulation of the polygon
+///
+/// Each cycle must be closed, meaning its last point must be equal to its first
+/// one. The edges between subsequent points of a cycle are constraints of the
+/// triangulation, which means they are guaranteed to be edges of the resulting
+/// triangles. Cycles must not intersect each other, nor share any edges.
+///
+/// Only triangles within the polygon are returned. See [`triangles_inside`]
+/// for how those are determined.
+///
+/// If the config requests a minimum angle, the triangulation is refined by
+/// inserting additional points into the inside of the polygon. Those points are
+/// placed on `surface`.
+pub fn triangulate<Cycle>(
+    cycles: impl IntoIterator<Item = Cycle>,
+    coord_handedness: Handedness,
+    surface: &SurfaceGeometry,
+    polygon: &Polygon,
+    config: TriangulationConfig,
+) -> Vec<[TriangulationPoint; 3]>
+where
+    Cycle: IntoIterator<Item = TriangulationPoint>,
+{
+    let mut triangulation = ConstrainedDelaunayTriangulation::new();
 
     let mut points = BTreeMap::new();
 
-    for cycle_approx in cycles {
+    for cycle in cycles {
         let mut handle_prev = None;
 
-        for point in cycle_approx.points() {
+        for point in cycle {
             let handle = match points.get(&point) {
                 Some(handle) => *handle,
                 None => {
                     let handle = triangulation
-                        .insert(TriangulationPoint {
-                            point_surface: point.local_form,
-                            point_global: point.global_form,
-                        })
+                        .insert(point)
                         .expect("Inserted invalid point into triangulation");
 
                     points.insert(point, handle);
@@ -44,16 +70,30 @@ pub fn triangulate(
         }
     }
 
+    if let Some(min_angle) = config.min_angle {
+        refine(
+            &mut triangulation,
+            surface,
+            polygon,
+            min_angle,
+            config.max_added_points,
+        );
+    }
+
     let mut triangles = Vec::new();
-    for triangle in triangulation.inner_faces() {
+    for triangle in triangles_inside(&triangulation) {
         let [v0, v1, v2] = triangle.vertices().map(|vertex| *vertex.data());
-        let triangle_winding = Triangle::<2>::from_points([
+        let Ok(triangle) = Triangle::<2>::from_points([
             v0.point_surface,
             v1.point_surface,
             v2.point_surface,
-        ])
-        .expect("invalid triangle")
-        .winding();
+        ]) else {
+            // The triangulation uses exact predicates, so it doesn't contain
+            // degenerate triangles. But a sliver can still end up with an area
+            // of zero, when computed here. It wouldn't cover anything.
+            continue;
+        };
+        let triangle_winding = triangle.winding();
 
         let required_winding = match coord_handedness {
             Handedness::LeftHanded => Winding::Cw,
@@ -72,6 +112,153 @@ pub fn triangulate(
     triangles
 }
 
+/// Iterate over the triangles that are within the polygon
+///
+/// Whether a triangle is within the polygon is determined from the topology of
+/// the triangulation, not by geometric tests, which could be thrown off by thin
+/// triangles at sharp features of the polygon.
+///
+/// Starting from the triangles at the convex hull, the triangulation is
+/// traversed, counting the number of constraint edges that have to be crossed
+/// to reach each triangle. Since those edges make up the boundary of the
+/// polygon, the triangles for which that number is odd are inside of it.
+fn triangles_inside(
+    triangulation: &ConstrainedDelaunayTriangulation,
+) -> impl Iterator<Item = InnerFace<'_>> {
+    let mut crossings = vec![None; triangulation.num_all_faces()];
+    let mut queue = VecDeque::new();
+
+    for edge in triangulation.convex_hull() {
+        let Some(face) = edge.rev().face().as_inner() else {
+            continue;
+        };
+        queue.push_back((face, u32::from(edge.is_constraint_edge())));
+    }
+
+    // Crossing a constraint edge adds one to the count, while crossing any
+    // other edge doesn't. Triangles reached without crossing a constraint edge
+    // are processed first, so each triangle is reached with the lowest
+    // possible count first.
+    while let Some((face, num_crossings)) = queue.pop_front() {
+        let crossings = &mut crossings[face.fix().index()];
+        if crossings.is_some() {
+            continue;
+        }
+        *crossings = Some(num_crossings);
+
+        for edge in face.adjacent_edges() {
+            let Some(neighbor) = edge.rev().face().as_inner() else {
+                continue;
+            };
+
+            if edge.is_constraint_edge() {
+                queue.push_back((neighbor, num_crossings + 1));
+            } else {
+                queue.push_front((neighbor, num_crossings));
+            }
+        }
+    }
+
+    triangulation.inner_faces().filter(move |face| {
+        crossings[face.fix().index()]
+            .is_some_and(|num_crossings| num_crossings % 2 == 1)
+    })
+}
+
+/// Refine the triangulation, until its triangles reach the minimum angle
+///
+/// This is a simplified variant of Ruppert's algorithm: The circumcenter of the
+/// worst triangle within the polygon is inserted, which removes that triangle
+/// from the triangulation. Unlike Ruppert's algorithm, this never splits the
+/// constraint edges, as that would change the boundary of the face. Any
+/// circumcenter that would encroach upon a constraint edge is skipped instead,
+/// which means the minimum angle is not guaranteed to be reached.
+fn refine(
+    triangulation: &mut ConstrainedDelaunayTriangulation,
+    surface: &SurfaceGeometry,
+    polygon: &Polygon,
+    min_angle: Scalar,
+    max_added_points: usize,
+) {
+    let constraint_edges = triangulation
+        .undirected_edges()
+        .filter(|edge| edge.is_constraint_edge())
+        .map(|edge| edge.vertices().map(|vertex| vertex.data().point_surface))
+        .collect::<Vec<_>>();
+
+    let mut skipped = BTreeSet::new();
+    let mut num_added_points = 0;
+
+    while num_added_points < max_added_points {
+        let worst = triangulation
+            .inner_faces()
+            .filter_map(|face| {
+                let points =
+                    face.vertices().map(|vertex| vertex.data().point_surface);
+                let triangle = Triangle::from_points(points).ok()?;
+
+                let angle = triangle.min_angle();
+                let center = face.circumcenter();
+                let center = Point::from([center.x, center.y]);
+
+                if angle >= min_angle
+                    || skipped.contains(&center)
+                    || !polygon.contains_triangle(triangle)
+                {
+                    return None;
+                }
+
+                Some((angle, center))
+            })
+            .min();
+        let Some((_, center)) = worst else {
+            break;
+        };
+
+        let encroaches = constraint_edges
+            .iter()
+            .any(|&[a, b]| (a - center).dot(&(b - center)) <= Scalar::ZERO);
+        if !polygon.contains_point(center)
+            || encroaches
+            || !is_inside_triangle(triangulation, center)
+        {
+            skipped.insert(center);
+            continue;
+        }
+
+        triangulation
+            .insert(TriangulationPoint {
+                point_surface: center,
+                point_global: surface.point_from_surface_coords(center),
+            })
+            .expect("Inserted invalid point into triangulation");
+        num_added_points += 1;
+    }
+}
+
+/// Determine whether a point is inside of a triangle of the triangulation
+///
+/// Returns `false`, if the point coincides with an existing vertex.
+fn is_inside_triangle(
+    triangulation: &ConstrainedDelaunayTriangulation,
+    point: Point<2>,
+) -> bool {
+    let position = spade::Point2 {
+        x: point.u,
+        y: point.v,
+    };
+
+    match triangulation.locate(position) {
+        PositionInTriangulation::OnFace(_) => true,
+        PositionInTriangulation::OnEdge(edge) => triangulation
+            .directed_edge(edge)
+            .face()
+            .as_inner()
+            .is_some(),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct TriangulationPoint {
     pub point_surface: Point<2>,
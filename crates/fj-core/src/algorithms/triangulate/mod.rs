@@ -1,12 +1,25 @@
 //! Shape triangulation
 
 mod delaunay;
-mod polygon;
+pub(crate) mod polygon;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use fj_interop::mesh::Mesh;
-use fj_math::Point;
+use fj_math::{Point, PolyChain, Scalar, Segment, Vector};
+
+use crate::{
+    geometry::{GlobalPath, SurfaceGeometry},
+    objects::Handedness,
+};
 
-use self::polygon::Polygon;
+use self::{delaunay::TriangulationPoint, polygon::Polygon};
 
 use super::approx::{face::FaceApprox, Approx, Tolerance};
 
@@ -14,16 +27,202 @@ use super::approx::{face::FaceApprox, Approx, Tolerance};
 pub trait Triangulate: Sized {
     /// Triangulate the shape
     fn triangulate(self) -> Mesh<Point<3>> {
+        self.triangulate_with_config(TriangulationConfig::default())
+            .expect("Default configuration doesn't check coverage")
+    }
+
+    /// Triangulate the shape, using the provided configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CoverageError`], if [`TriangulationConfig::check_coverage`]
+    /// is set, and the triangles of a face don't cover the whole face.
+    fn triangulate_with_config(
+        self,
+        config: TriangulationConfig,
+    ) -> Result<Mesh<Point<3>>, CoverageError> {
+        let mut mesh = Mesh::new();
+        self.triangulate_into_mesh(&mut mesh, config)?;
+        Ok(mesh)
+    }
+
+    /// Triangulate the shape, reporting progress and allowing cancellation
+    ///
+    /// `progress` is called after each face has been triangulated. If
+    /// `cancel` is cancelled, triangulation stops at the next face, and
+    /// [`TriangulationError::Cancelled`] is returned instead of an incomplete
+    /// mesh.
+    ///
+    /// Triangulation might be parallelized (see the `parallel` feature), so
+    /// `progress` might be called from another thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the triangulation is cancelled, or if coverage is
+    /// checked and fails (see [`Triangulate::triangulate_with_config`]).
+    fn triangulate_with_progress(
+        self,
+        config: TriangulationConfig,
+        mut progress: impl FnMut(TriangulationProgress) + Send,
+        cancel: &CancellationToken,
+    ) -> Result<Mesh<Point<3>>, TriangulationError> {
         let mut mesh = Mesh::new();
-        self.triangulate_into_mesh(&mut mesh);
-        mesh
+        self.triangulate_into_mesh_with_progress(
+            &mut mesh,
+            config,
+            &mut progress,
+            cancel,
+        )?;
+        Ok(mesh)
     }
 
     /// Triangulate a partial shape into the provided mesh
     ///
     /// This is a low-level method, intended for implementation of
     /// `Triangulate`. Most callers should prefer [`Triangulate::triangulate`].
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>);
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+    ) -> Result<(), CoverageError>;
+
+    /// Triangulate a partial shape into the provided mesh, reporting progress
+    ///
+    /// This is a low-level method, intended for implementation of
+    /// `Triangulate`. Most callers should prefer
+    /// [`Triangulate::triangulate_with_progress`].
+    ///
+    /// The default implementation triangulates the shape in one go, as a
+    /// single face. Implementations for shapes that consist of multiple faces
+    /// should override it. If an error is returned, the mesh might contain
+    /// part of the triangulation.
+    fn triangulate_into_mesh_with_progress(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+        progress: &mut (dyn FnMut(TriangulationProgress) + Send),
+        cancel: &CancellationToken,
+    ) -> Result<(), TriangulationError> {
+        cancel.check()?;
+
+        self.triangulate_into_mesh(mesh, config)?;
+        progress(TriangulationProgress {
+            num_faces_done: 1,
+            num_faces: 1,
+        });
+
+        Ok(())
+    }
+}
+
+/// Configuration for [`Triangulate::triangulate_with_config`]
+#[derive(Clone, Copy, Debug)]
+pub struct TriangulationConfig {
+    /// The minimum angle that triangles should have, in radians
+    ///
+    /// If this is set, the triangulation of each face is refined, until none
+    /// of its triangles has a smaller angle. This is done by inserting points
+    /// into the interior of the face, which causes edges to be flipped.
+    ///
+    /// The points on the boundary of the face, which come from the
+    /// approximation of its edges, are never moved, and no points are added
+    /// to the boundary. Otherwise, the triangulation wouldn't match that of
+    /// neighboring faces anymore. Triangles close to the boundary might
+    /// therefore not reach the minimum angle.
+    ///
+    /// Angles larger than about 20 degrees might prevent refinement from
+    /// converging, causing it to stop at [`Self::max_added_points`].
+    pub min_angle: Option<Scalar>,
+
+    /// The maximum number of points that refinement adds to a single face
+    pub max_added_points: usize,
+
+    /// Check that the triangles of each face cover the whole face
+    ///
+    /// After the Delaunay triangulation, any triangles that are not part of
+    /// the face are removed. A bug in that process can leave parts of the face
+    /// without triangles. If this is set, triangulation returns a
+    /// [`CoverageError`] in that case, instead of silently producing an
+    /// incomplete mesh.
+    pub check_coverage: bool,
+}
+
+impl Default for TriangulationConfig {
+    fn default() -> Self {
+        Self {
+            min_angle: None,
+            max_added_points: 1000,
+            check_coverage: false,
+        }
+    }
+}
+
+/// The progress of a triangulation
+///
+/// See [`Triangulate::triangulate_with_progress`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TriangulationProgress {
+    /// The number of faces that have been triangulated
+    pub num_faces_done: usize,
+
+    /// The number of faces that are being triangulated in total
+    pub num_faces: usize,
+}
+
+/// A token that can be used to cancel a triangulation
+///
+/// See [`Triangulate::triangulate_with_progress`]. Clones of a token share its
+/// state, so a triangulation can be cancelled from another thread, using a
+/// clone of the token that was passed to it.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel any triangulation that uses this token, or a clone of it
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Indicate whether the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            return Err(Cancelled);
+        }
+
+        Ok(())
+    }
+}
+
+/// A triangulation was cancelled
+///
+/// See [`CancellationToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Triangulation was cancelled")]
+pub struct Cancelled;
+
+/// Error triangulating a shape, while reporting progress
+///
+/// See [`Triangulate::triangulate_with_progress`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TriangulationError {
+    /// The triangulation was cancelled
+    #[error("Triangulation was cancelled")]
+    Cancelled(#[from] Cancelled),
+
+    /// The triangles of a face don't cover the whole face
+    #[error("Triangulation doesn't cover the whole face")]
+    Coverage(#[from] CoverageError),
 }
 
 impl<T> Triangulate for (T, Tolerance)
@@ -31,60 +230,445 @@ where
     T: Approx,
     T::Approximation: IntoIterator<Item = FaceApprox>,
 {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+    ) -> Result<(), CoverageError> {
         let (approx, tolerance) = self;
 
-        let approx = approx.approx(tolerance);
+        let approx = approx
+            .approx(tolerance)
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+
+        approx.triangulate_into_mesh(mesh, config)
+    }
+
+    fn triangulate_into_mesh_with_progress(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+        progress: &mut (dyn FnMut(TriangulationProgress) + Send),
+        cancel: &CancellationToken,
+    ) -> Result<(), TriangulationError> {
+        let (approx, tolerance) = self;
+
+        // Approximation shares a cache between all faces, so it can't be split
+        // up per face. Check for cancellation before and after.
+        cancel.check()?;
+        let approx = approx
+            .approx(tolerance)
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        cancel.check()?;
+
+        approx
+            .triangulate_into_mesh_with_progress(mesh, config, progress, cancel)
+    }
+}
+
+impl Triangulate for BTreeSet<FaceApprox> {
+    #[cfg(not(feature = "parallel"))]
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+    ) -> Result<(), CoverageError> {
+        for approx in self {
+            approx.triangulate_into_mesh(mesh, config)?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+    ) -> Result<(), CoverageError> {
+        use rayon::prelude::*;
+
+        let approx = self.into_iter().collect::<Vec<_>>();
 
-        for approx in approx {
-            approx.triangulate_into_mesh(mesh);
+        // Each face is triangulated into its own mesh. Those are merged in the
+        // order of the faces, so the result is the same as that of triangulating
+        // the faces one after the other.
+        let meshes = approx
+            .into_par_iter()
+            .map(|approx| {
+                let mut mesh = Mesh::new();
+                approx.triangulate_into_mesh(&mut mesh, config)?;
+                Ok(mesh)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for face_mesh in meshes {
+            mesh.merge(&face_mesh);
         }
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn triangulate_into_mesh_with_progress(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+        progress: &mut (dyn FnMut(TriangulationProgress) + Send),
+        cancel: &CancellationToken,
+    ) -> Result<(), TriangulationError> {
+        let num_faces = self.len();
+
+        for (i, approx) in self.into_iter().enumerate() {
+            cancel.check()?;
+
+            approx.triangulate_into_mesh(mesh, config)?;
+            progress(TriangulationProgress {
+                num_faces_done: i + 1,
+                num_faces,
+            });
+        }
+
+        // The token might have been cancelled while the last face was being
+        // triangulated. Report that consistently.
+        cancel.check()?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn triangulate_into_mesh_with_progress(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+        progress: &mut (dyn FnMut(TriangulationProgress) + Send),
+        cancel: &CancellationToken,
+    ) -> Result<(), TriangulationError> {
+        use std::sync::Mutex;
+
+        use rayon::prelude::*;
+
+        let approx = self.into_iter().collect::<Vec<_>>();
+        let num_faces = approx.len();
+
+        // Faces are finished in any order. Only the number of finished faces
+        // is reported, so that doesn't matter.
+        let num_faces_done = Mutex::new((0, progress));
+
+        let meshes = approx
+            .into_par_iter()
+            .map(|approx| {
+                cancel.check()?;
+
+                let mut mesh = Mesh::new();
+                approx.triangulate_into_mesh(&mut mesh, config)?;
+
+                let mut guard = num_faces_done
+                    .lock()
+                    .expect("Thread reporting progress panicked");
+                let (num_faces_done, progress) = &mut *guard;
+                *num_faces_done += 1;
+                progress(TriangulationProgress {
+                    num_faces_done: *num_faces_done,
+                    num_faces,
+                });
+
+                Ok::<_, TriangulationError>(mesh)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        cancel.check()?;
+
+        for face_mesh in meshes {
+            mesh.merge(&face_mesh);
+        }
+
+        Ok(())
     }
 }
 
 impl Triangulate for FaceApprox {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
-        let face_as_polygon = Polygon::new()
-            .with_exterior(
-                self.exterior
-                    .points()
-                    .into_iter()
-                    .map(|point| point.local_form),
-            )
-            .with_interiors(self.interiors.iter().map(|interior| {
-                interior.points().into_iter().map(|point| point.local_form)
-            }));
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        config: TriangulationConfig,
+    ) -> Result<(), CoverageError> {
+        let face_as_polygon = Polygon::from(&self);
 
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
-        let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
-        triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
-        });
+        let cycles =
+            [self.exterior]
+                .into_iter()
+                .chain(self.interiors)
+                .map(|cycle| {
+                    cycle.points().into_iter().map(|point| TriangulationPoint {
+                        point_surface: point.local_form,
+                        point_global: point.global_form,
+                    })
+                });
+        let triangles = delaunay::triangulate(
+            cycles,
+            self.coord_handedness,
+            &self.surface,
+            &face_as_polygon,
+            config,
+        );
+
+        if config.check_coverage {
+            let triangles = triangles
+                .iter()
+                .map(|triangle| triangle.map(|point| point.point_surface));
+
+            check_coverage(&face_as_polygon, triangles)?;
+        }
 
         let color = self.color.unwrap_or_default();
 
         for triangle in triangles {
             let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, color);
+
+            // A triangle that is valid in surface coordinates can still
+            // degenerate, once it's converted to global coordinates, for
+            // example at the apex of a cone. It doesn't cover any area, so
+            // skipping it doesn't leave a gap in the mesh.
+            let Ok(triangle) = fj_math::Triangle::from_points(points) else {
+                continue;
+            };
+
+            mesh.push_triangle_with_material(
+                triangle,
+                color,
+                self.material.as_ref(),
+            );
         }
+
+        Ok(())
+    }
+}
+
+/// Triangulate a polygon, given as raw 2D contours
+///
+/// The polygon is bounded by `exterior`, and each of the `holes` is cut out of
+/// it. Neither the exterior nor the holes need to repeat their first point at
+/// the end. A hole may touch the exterior or another hole at a single vertex,
+/// as long as that vertex is part of both contours.
+///
+/// Returns triangles, as indices into the combined list of points of
+/// `exterior`, followed by the points of each hole, in order. If a point is
+/// part of multiple contours, the triangles refer to its first occurrence in
+/// that list. The triangles are wound counter-clockwise.
+pub fn triangulate_polygon(
+    exterior: &[Point<2>],
+    holes: &[Vec<Point<2>>],
+) -> Vec<[usize; 3]> {
+    let contours = [exterior]
+        .into_iter()
+        .chain(holes.iter().map(Vec::as_slice))
+        .collect::<Vec<_>>();
+
+    let polygon = Polygon::new()
+        .with_exterior(PolyChain::from_points(exterior.iter().copied()).close())
+        .with_interiors(
+            holes.iter().map(|hole| {
+                PolyChain::from_points(hole.iter().copied()).close()
+            }),
+        );
+
+    let mut indices = BTreeMap::new();
+    for (index, point) in contours.iter().copied().flatten().enumerate() {
+        indices.entry(*point).or_insert(index);
     }
+
+    let cycles = contours.iter().map(|contour| {
+        contour
+            .iter()
+            .chain(contour.first())
+            .map(|&point| TriangulationPoint {
+                point_surface: point,
+                point_global: point.to_xyz(),
+            })
+    });
+
+    // The points are located in the xy-plane, as their global form shows.
+    let surface = SurfaceGeometry {
+        u: GlobalPath::x_axis(),
+        v: Vector::unit_y(),
+    };
+
+    delaunay::triangulate(
+        cycles,
+        Handedness::RightHanded,
+        &surface,
+        &polygon,
+        TriangulationConfig::default(),
+    )
+    .into_iter()
+    .map(|triangle| triangle.map(|point| indices[&point.point_surface]))
+    .collect()
+}
+
+/// Check that the provided triangles cover the whole polygon
+fn check_coverage(
+    polygon: &Polygon,
+    triangles: impl IntoIterator<Item = [Point<2>; 3]>,
+) -> Result<(), CoverageError> {
+    // Allow for some floating-point error, relative to the size of the face.
+    const TOLERANCE: f64 = 1e-6;
+
+    // Edges are compared regardless of their direction.
+    let normalize =
+        |[a, b]: [Point<2>; 2]| if a <= b { [a, b] } else { [b, a] };
+
+    let mut covered_area = Scalar::ZERO;
+    let mut num_triangles_by_edge = BTreeMap::new();
+
+    for [a, b, c] in triangles {
+        covered_area += (b - a).cross2d(&(c - a)).abs() / 2.;
+
+        for edge in [[a, b], [b, c], [c, a]] {
+            *num_triangles_by_edge.entry(normalize(edge)).or_insert(0) += 1;
+        }
+    }
+
+    let face_edges = polygon
+        .edges()
+        .map(|edge| normalize(edge.points()))
+        .collect::<BTreeSet<_>>();
+
+    // Any edge of the face must be the edge of one triangle. Any other edge
+    // must be shared by two triangles. Edges that don't meet this requirement
+    // border a region of the face that is not covered.
+    let mut uncovered_edges = face_edges
+        .iter()
+        .filter(|edge| !num_triangles_by_edge.contains_key(*edge))
+        .copied()
+        .collect::<Vec<_>>();
+    uncovered_edges.extend(num_triangles_by_edge.into_iter().filter_map(
+        |(edge, num_triangles)| {
+            (num_triangles == 1 && !face_edges.contains(&edge)).then_some(edge)
+        },
+    ));
+
+    let face_area = polygon.area();
+    let area_matches =
+        (face_area - covered_area).abs() <= face_area * TOLERANCE;
+
+    if area_matches && uncovered_edges.is_empty() {
+        return Ok(());
+    }
+
+    Err(CoverageError {
+        face_area,
+        covered_area,
+        uncovered_edges: uncovered_edges
+            .into_iter()
+            .map(Segment::from_points)
+            .collect(),
+    })
+}
+
+/// The triangles of a face don't cover the whole face
+///
+/// See [`TriangulationConfig::check_coverage`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error(
+    "Triangles don't cover the whole face (face area: {face_area}, covered \
+    area: {covered_area}); uncovered region is bordered by edges \
+    {uncovered_edges:?}"
+)]
+pub struct CoverageError {
+    /// The area of the face
+    pub face_area: Scalar,
+
+    /// The combined area of all triangles of the face
+    pub covered_area: Scalar,
+
+    /// The edges that border the uncovered region, in surface coordinates
+    ///
+    /// These are edges of the face that are not an edge of any triangle, and
+    /// edges of triangles that don't border another triangle or the boundary
+    /// of the face.
+    pub uncovered_edges: Vec<Segment<2>>,
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_interop::mesh::Mesh;
-    use fj_math::{Point, Scalar};
+    use std::{f64::consts::TAU, ops::Deref};
+
+    use fj_interop::mesh::{Color, Material, Mesh};
+    use fj_math::{Point, PolyChain, Scalar, Vector};
 
     use crate::{
-        algorithms::approx::{Approx, Tolerance},
-        objects::{Cycle, Face},
-        operations::{BuildCycle, BuildFace, Insert, UpdateFace, UpdateRegion},
+        algorithms::{
+            approx::{Approx, Tolerance},
+            sweep::Sweep,
+        },
+        objects::{Cycle, Face, Region, Shell, Sketch},
+        operations::{
+            BuildCycle, BuildFace, BuildRegion, BuildShell, BuildSketch,
+            Insert, UpdateFace, UpdateRegion, UpdateSketch,
+        },
         services::Services,
     };
 
-    use super::Triangulate;
+    use super::{
+        check_coverage, polygon::Polygon, triangulate_polygon,
+        CancellationToken, Cancelled, Triangulate, TriangulationConfig,
+        TriangulationError, TriangulationProgress,
+    };
+
+    #[test]
+    fn progress_and_cancellation() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let shell: &Shell = &tetrahedron.shell;
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let mut reports = Vec::new();
+        let mesh = (shell, tolerance).triangulate_with_progress(
+            TriangulationConfig::default(),
+            |progress| reports.push(progress),
+            &CancellationToken::new(),
+        )?;
+        assert_eq!(
+            mesh.triangles().collect::<Vec<_>>(),
+            (shell, tolerance)
+                .triangulate()
+                .triangles()
+                .collect::<Vec<_>>(),
+            "Reporting progress must not change the result"
+        );
+        assert_eq!(
+            reports,
+            (1..=4)
+                .map(|num_faces_done| TriangulationProgress {
+                    num_faces_done,
+                    num_faces: 4,
+                })
+                .collect::<Vec<_>>()
+        );
+
+        // Cancelling from within the callback works the same as cancelling
+        // from another thread.
+        let cancel = CancellationToken::new();
+        let result = (shell, tolerance).triangulate_with_progress(
+            TriangulationConfig::default(),
+            |_| cancel.cancel(),
+            &cancel,
+        );
+        assert!(matches!(
+            result,
+            Err(TriangulationError::Cancelled(Cancelled))
+        ));
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
 
     #[test]
     fn simple() -> anyhow::Result<()> {
@@ -197,8 +781,9 @@ mod tests {
         //      \a/
 
         // Naive Delaunay triangulation will create a triangle (c, d, e), which
-        // is not part of the polygon. The higher-level triangulation will
-        // filter that out, but it will result in missing triangles.
+        // is not part of the polygon. The edges of the polygon are constraints
+        // of the triangulation, so that doesn't happen, and no triangles of
+        // the polygon go missing.
 
         let a = [1., 0.];
         let b = [2., 8.];
@@ -235,8 +820,323 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn refinement() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // Points on a circle have no well-defined Delaunay triangulation, and
+        // triangulating them without refinement results in thin triangles.
+        let num_points = 24;
+        let points = (0..num_points)
+            .map(|i| {
+                let angle = TAU / num_points as f64 * i as f64;
+                [angle.cos(), angle.sin()]
+            })
+            .collect::<Vec<_>>();
+
+        let face =
+            Face::unbound(services.objects.surfaces.xy_plane(), &mut services)
+                .update_region(|region| {
+                    region
+                        .update_exterior(|_| {
+                            Cycle::polygon(points.clone(), &mut services)
+                                .insert(&mut services)
+                        })
+                        .insert(&mut services)
+                });
+        services.only_validate(&face);
+
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+        let min_angle = Scalar::from_degrees(20.);
+
+        let unrefined = face.approx(tolerance).triangulate();
+        let refined = face.approx(tolerance).triangulate_with_config(
+            TriangulationConfig {
+                min_angle: Some(min_angle),
+                check_coverage: true,
+                ..TriangulationConfig::default()
+            },
+        )?;
+
+        let min_angle_of = |mesh: &Mesh<Point<3>>| {
+            mesh.triangles()
+                .map(|triangle| triangle.inner.min_angle())
+                .min()
+                .expect("Mesh should not be empty")
+        };
+        assert!(min_angle_of(&unrefined) < min_angle);
+        assert!(min_angle_of(&refined) > min_angle_of(&unrefined));
+
+        // All boundary points must be preserved, and no new points may be
+        // added to the boundary.
+        let boundary_points = refined
+            .vertices()
+            .filter(|point| {
+                (point.coords.magnitude() - Scalar::ONE).abs()
+                    < Scalar::from(1e-9)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(boundary_points.len(), num_points);
+        for point in points {
+            assert!(boundary_points.contains(&Point::from(point).to_xyz()));
+        }
+        assert!(refined.vertex_count() > unrefined.vertex_count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn refinement_on_curved_surface() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle(Point::origin(), 1., &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cylinder = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (cylinder.deref(), tolerance).triangulate_with_config(
+            TriangulationConfig {
+                min_angle: Some(Scalar::from_degrees(20.)),
+                ..TriangulationConfig::default()
+            },
+        )?;
+
+        // Points that refinement adds to the side face must be located on the
+        // cylinder, not on the flat triangles of the unrefined mesh.
+        let points_on_side = mesh
+            .vertices()
+            .filter(|point| point.z > Scalar::ZERO && point.z < Scalar::ONE)
+            .collect::<Vec<_>>();
+        assert!(!points_on_side.is_empty());
+        for point in points_on_side {
+            let radius = Vector::from([point.x, point.y]).magnitude();
+            assert!((radius - Scalar::ONE).abs() < Scalar::from(1e-9));
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn coverage_check() {
+        let [a, b, c, d] =
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]].map(Point::from);
+        let polygon = Polygon::new()
+            .with_exterior(PolyChain::from_points([a, b, c, d]).close());
+
+        assert!(check_coverage(&polygon, [[a, b, c], [a, c, d]]).is_ok());
+
+        let err = check_coverage(&polygon, [[a, b, c]]).unwrap_err();
+        assert_eq!(err.face_area, Scalar::from(4.));
+        assert_eq!(err.covered_area, Scalar::from(2.));
+
+        // The missing triangle is bordered by two edges of the face, and the
+        // edge of the triangle that is present.
+        assert_eq!(err.uncovered_edges.len(), 3);
+        for [p, q] in [[c, d], [d, a], [a, c]] {
+            assert!(err.uncovered_edges.iter().any(|edge| {
+                edge.points() == [p, q] || edge.points() == [q, p]
+            }));
+        }
+    }
+
+    #[test]
+    fn coverage_check_of_face() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // The hole is outside of the exterior, so the triangulation covers
+        // both of them, while the area of the face is zero.
+        let face =
+            Face::unbound(services.objects.surfaces.xy_plane(), &mut services)
+                .update_region(|region| {
+                    region
+                        .update_exterior(|_| {
+                            Cycle::polygon(
+                                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                                &mut services,
+                            )
+                            .insert(&mut services)
+                        })
+                        .add_interiors([Cycle::polygon(
+                            [[2., 0.], [2., 1.], [3., 1.], [3., 0.]],
+                            &mut services,
+                        )
+                        .insert(&mut services)])
+                        .insert(&mut services)
+                });
+        services.only_validate(&face);
+
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+        let config = TriangulationConfig {
+            check_coverage: true,
+            ..TriangulationConfig::default()
+        };
+
+        let err = face
+            .approx(tolerance)
+            .triangulate_with_config(config)
+            .unwrap_err();
+        assert_eq!(err.face_area, Scalar::ZERO);
+        assert_eq!(err.covered_area, Scalar::from(2.));
+
+        let result = face.approx(tolerance).triangulate_with_progress(
+            config,
+            |_| {},
+            &CancellationToken::new(),
+        );
+        assert_eq!(result.err(), Some(TriangulationError::Coverage(err)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn polygon_with_hole_touching_exterior() {
+        let exterior =
+            [[0., 0.], [2., 0.], [4., 0.], [4., 4.], [0., 4.]].map(Point::from);
+        let holes = [vec![[2., 0.], [1., 2.], [3., 2.]]
+            .into_iter()
+            .map(Point::from)
+            .collect::<Vec<_>>()];
+
+        let triangles = triangulate_polygon(&exterior, &holes);
+
+        // The point the hole shares with the exterior is referred to by its
+        // index in the exterior, never by its index in the hole.
+        assert!(triangles.iter().flatten().any(|&index| index == 1));
+        assert!(triangles.iter().flatten().all(|&index| index != 5));
+
+        let points = exterior
+            .iter()
+            .chain(&holes[0])
+            .copied()
+            .collect::<Vec<_>>();
+        let triangles = triangles
+            .into_iter()
+            .map(|triangle| triangle.map(|index| points[index]))
+            .collect::<Vec<_>>();
+
+        for &[a, b, c] in &triangles {
+            assert!((b - a).cross2d(&(c - a)) > Scalar::ZERO);
+        }
+
+        let polygon = Polygon::new()
+            .with_exterior(PolyChain::from_points(exterior).close())
+            .with_interiors(
+                holes.map(|hole| PolyChain::from_points(hole).close()),
+            );
+        assert_eq!(polygon.area(), Scalar::from(14.));
+        assert!(check_coverage(&polygon, triangles).is_ok());
+    }
+
+    #[test]
+    fn polygon_with_sharp_spikes() {
+        // A comb, with thin spikes that are much longer than they are wide.
+        // Triangles that span multiple spikes are outside of the polygon.
+        let mut exterior = vec![Point::from([0., 0.]), Point::from([10., 0.])];
+        for i in (0..10).rev() {
+            let x = f64::from(i);
+            exterior.push(Point::from([x + 1., 1.]));
+            exterior.push(Point::from([x + 0.5, 100.]));
+        }
+        exterior.push(Point::from([0., 1.]));
+
+        let triangles = triangulate_polygon(&exterior, &[])
+            .into_iter()
+            .map(|triangle| triangle.map(|index| exterior[index]))
+            .collect::<Vec<_>>();
+
+        let polygon = Polygon::new()
+            .with_exterior(PolyChain::from_points(exterior).close());
+        assert!(check_coverage(&polygon, triangles).is_ok());
+    }
+
+    #[test]
+    fn color() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let face =
+            Face::unbound(services.objects.surfaces.xy_plane(), &mut services)
+                .update_region(|region| {
+                    region
+                        .update_exterior(|_| {
+                            Cycle::polygon(
+                                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                                &mut services,
+                            )
+                            .insert(&mut services)
+                        })
+                        .insert(&mut services)
+                });
+        services.only_validate(&face);
+
+        let triangles = triangulate(face.clone())?;
+        assert!(triangles
+            .triangles()
+            .all(|triangle| triangle.color == Color::default()));
+
+        let color = Color([0, 255, 0, 255]);
+        let face = face.set_color(color, &mut services);
+        services.only_validate(&face);
+
+        let triangles = triangulate(face)?;
+        assert!(triangles
+            .triangles()
+            .all(|triangle| triangle.color == color));
+
+        Ok(())
+    }
+
+    #[test]
+    fn material() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let face =
+            Face::unbound(services.objects.surfaces.xy_plane(), &mut services)
+                .update_region(|region| {
+                    region
+                        .update_exterior(|_| {
+                            Cycle::polygon(
+                                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                                &mut services,
+                            )
+                            .insert(&mut services)
+                        })
+                        .insert(&mut services)
+                });
+        services.only_validate(&face);
+
+        let triangles = triangulate(face.clone())?;
+        assert!(triangles.materials().is_empty());
+        assert!(triangles
+            .triangles()
+            .all(|triangle| triangle.material.is_none()));
+
+        let material = Material::new("brass", 0.3, 1.);
+        let face = face.set_material(material.clone(), &mut services);
+        services.only_validate(&face);
+
+        let triangles = triangulate(face)?;
+        assert_eq!(triangles.materials(), [material]);
+        assert!(triangles
+            .triangles()
+            .all(|triangle| triangle.material == Some(0)));
+
+        Ok(())
+    }
+
     fn triangulate(face: Face) -> anyhow::Result<Mesh<Point<3>>> {
         let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
-        Ok(face.approx(tolerance).triangulate())
+        let mesh = face.approx(tolerance).triangulate_with_config(
+            TriangulationConfig {
+                check_coverage: true,
+                ..TriangulationConfig::default()
+            },
+        )?;
+        Ok(mesh)
     }
 }
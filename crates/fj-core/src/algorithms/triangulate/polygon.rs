@@ -1,8 +1,11 @@
 use fj_interop::ext::SliceExt;
-use fj_math::{Point, PolyChain, Segment, Triangle};
+use fj_math::{Point, PolyChain, Scalar, Segment, Triangle};
 
-use crate::algorithms::intersect::{
-    ray_segment::RaySegmentIntersection, HorizontalRayToTheRight, Intersect,
+use crate::algorithms::{
+    approx::face::FaceApprox,
+    intersect::{
+        ray_segment::RaySegmentIntersection, HorizontalRayToTheRight, Intersect,
+    },
 };
 
 #[derive(Default)]
@@ -90,6 +93,23 @@ impl Polygon {
         true
     }
 
+    /// Iterate over all edges of the polygon, exterior and interior
+    pub fn edges(&self) -> impl Iterator<Item = Segment<2>> + '_ {
+        [&self.exterior]
+            .into_iter()
+            .chain(&self.interiors)
+            .flat_map(|chain| chain.segments())
+    }
+
+    /// Compute the area of the polygon, excluding the area of its holes
+    pub fn area(&self) -> Scalar {
+        self.interiors
+            .iter()
+            .fold(area_enclosed_by(&self.exterior), |area, interior| {
+                area - area_enclosed_by(interior)
+            })
+    }
+
     pub fn contains_exterior_edge(&self, edge: Segment<2>) -> bool {
         self.exterior.segments().contains(&edge)
             || self.exterior.segments().contains(&edge.reverse())
@@ -201,6 +221,66 @@ impl Polygon {
 
         num_hits % 2 == 1
     }
+
+    /// Compute the distance between a point and the polygon's edges
+    ///
+    /// Returns `None`, if the polygon has no edges.
+    pub fn distance_to_edges(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Option<Scalar> {
+        let point = point.into();
+
+        self.edges()
+            .map(|edge| {
+                let [a, b] = edge.points();
+                let ab = b - a;
+
+                let length_squared = ab.dot(&ab);
+                let t = if length_squared == Scalar::ZERO {
+                    Scalar::ZERO
+                } else {
+                    ((point - a).dot(&ab) / length_squared)
+                        .max(Scalar::ZERO)
+                        .min(Scalar::ONE)
+                };
+
+                point.distance_to(&(a + ab * t))
+            })
+            .min()
+    }
+}
+
+impl From<&FaceApprox> for Polygon {
+    fn from(approx: &FaceApprox) -> Self {
+        Self::new()
+            .with_exterior(
+                approx
+                    .exterior
+                    .points()
+                    .into_iter()
+                    .map(|point| point.local_form),
+            )
+            .with_interiors(approx.interiors.iter().map(|interior| {
+                interior.points().into_iter().map(|point| point.local_form)
+            }))
+    }
+}
+
+/// Compute the area enclosed by a closed polygonal chain
+///
+/// Uses the shoelace formula. The result is positive, regardless of winding.
+fn area_enclosed_by(chain: &PolyChain<2>) -> Scalar {
+    let twice_signed_area =
+        chain
+            .segments()
+            .into_iter()
+            .fold(Scalar::ZERO, |area, segment| {
+                let [a, b] = segment.points();
+                area + a.u * b.v - b.u * a.v
+            });
+
+    twice_signed_area.abs() / 2.
 }
 
 #[cfg(test)]
@@ -8,6 +8,6 @@ mod surface;
 pub use self::{
     boundary::BoundaryOnCurve,
     bounding_vertices::BoundingVertices,
-    path::{GlobalPath, SurfacePath},
+    path::{GlobalPath, SurfacePath, TransformPathError},
     surface::SurfaceGeometry,
 };
@@ -2,14 +2,36 @@
 //!
 //! See [`SurfacePath`] and [`GlobalPath`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{
+    Arc, BSpline, Bezier, Circle, CollinearPoints, Ellipse, Helix, Line,
+    LineIntersection, Point, Scalar, Transform, Vector,
+};
+
+use super::BoundaryOnCurve;
 
 /// A path through surface (2D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum SurfacePath {
+    /// A cubic Bézier curve
+    ///
+    /// Only the range between the path coordinates `0` and `1` is covered by
+    /// the control points. Outside of that, the polynomial that defines the
+    /// curve is extrapolated.
+    Bezier(Bezier<2>),
+
+    /// A non-rational B-spline curve with a clamped, uniform knot vector
+    ///
+    /// Same as for [`SurfacePath::Bezier`], the curve starts at path
+    /// coordinate `0` and ends at `1`. A B-spline of degree `1` with two
+    /// control points is equivalent to a [`SurfacePath::Line`] through them.
+    BSpline(BSpline<2>),
+
     /// A circle
     Circle(Circle<2>),
 
+    /// An ellipse
+    Ellipse(Ellipse<2>),
+
     /// A line
     Line(Line<2>),
 }
@@ -23,6 +45,82 @@ impl SurfacePath {
         Self::Circle(Circle::from_center_and_radius(center, radius))
     }
 
+    /// Build an arc that starts at the first point and ends at the last
+    ///
+    /// Returns the circle that passes through all three points, together with
+    /// the boundary that covers the arc from the first point, through the
+    /// middle one, to the last.
+    ///
+    /// Returns an error, if the points are collinear.
+    pub fn arc_from_three_points(
+        points: [impl Into<Point<2>>; 3],
+    ) -> Result<(Self, BoundaryOnCurve), CollinearPoints> {
+        let [p0, pm, p1] = points;
+        let arc = Arc::from_three_points(p0, pm, p1)?;
+
+        Ok(Self::from_arc(arc))
+    }
+
+    /// Build an arc from its end points and the angle it spans
+    ///
+    /// Returns the circle that the arc is on, together with the boundary that
+    /// covers the arc. A positive angle means the arc goes counter-clockwise,
+    /// a negative angle means it goes clockwise.
+    pub fn arc_from_endpoints_and_angle(
+        points: [impl Into<Point<2>>; 2],
+        angle_rad: impl Into<Scalar>,
+    ) -> (Self, BoundaryOnCurve) {
+        let [start, end] = points;
+        let arc = Arc::from_endpoints_and_angle(start, end, angle_rad.into());
+
+        Self::from_arc(arc)
+    }
+
+    fn from_arc(arc: Arc) -> (Self, BoundaryOnCurve) {
+        let path = Self::circle_from_center_and_radius(arc.center, arc.radius);
+        let boundary =
+            BoundaryOnCurve::from([[arc.start_angle], [arc.end_angle]]);
+
+        (path, boundary)
+    }
+
+    /// Build a cubic Bézier curve from its four control points
+    ///
+    /// Also returns the coordinates of the end points on the path.
+    pub fn bezier_from_control_points(
+        control_points: [impl Into<Point<2>>; 4],
+    ) -> (Self, [Point<1>; 2]) {
+        let bezier = Bezier::from_control_points(control_points);
+        let coords = [[0.], [1.]].map(Point::from);
+
+        (Self::Bezier(bezier), coords)
+    }
+
+    /// Build a B-spline curve from its control points and degree
+    ///
+    /// Also returns the coordinates of the end points on the path. See
+    /// [`BSpline::from_control_points`] for the requirements on the arguments.
+    pub fn bspline_from_control_points(
+        control_points: impl IntoIterator<Item = impl Into<Point<2>>>,
+        degree: usize,
+    ) -> (Self, [Point<1>; 2]) {
+        let bspline = BSpline::from_control_points(control_points, degree);
+        let coords = [[0.], [1.]].map(Point::from);
+
+        (Self::BSpline(bspline), coords)
+    }
+
+    /// Build an ellipse from the given center and axes
+    ///
+    /// See [`Ellipse::new`] for the requirements on `major` and `minor`.
+    pub fn ellipse_from_center_and_axes(
+        center: impl Into<Point<2>>,
+        major: impl Into<Vector<2>>,
+        minor: impl Into<Vector<2>>,
+    ) -> Self {
+        Self::Ellipse(Ellipse::new(center, major, minor))
+    }
+
     /// Build a line that represents the u-axis of the surface its on
     pub fn u_axis() -> Self {
         let a = Point::origin();
@@ -58,24 +156,249 @@ impl SurfacePath {
         Self::Line(Line::from_points_with_line_coords(points))
     }
 
+    /// Create a new path that is reversed
+    ///
+    /// The reversed path covers the same points, but runs in the opposite
+    /// direction. Coordinate `t` on the reversed path is coordinate `c - t` on
+    /// the original one, where `c` is `0` for circles and ellipses, and `1` for
+    /// all other paths.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Bezier(bezier) => Self::Bezier(bezier.reverse()),
+            Self::BSpline(bspline) => Self::BSpline(bspline.reverse()),
+            Self::Circle(circle) => Self::Circle(circle.reverse()),
+            Self::Ellipse(ellipse) => Self::Ellipse(ellipse.reverse()),
+            Self::Line(line) => Self::Line(line.reverse()),
+        }
+    }
+
     /// Convert a point on the path into surface coordinates
     pub fn point_from_path_coords(
         &self,
         point: impl Into<Point<1>>,
     ) -> Point<2> {
         match self {
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords(point),
+            Self::BSpline(bspline) => bspline.point_from_bspline_coords(point),
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
+
+    /// Compute the tangent of the path at the given point
+    ///
+    /// The tangent is the derivative of the path with respect to its
+    /// coordinate, and is not normalized. For a line, it's the line's
+    /// direction. For a circle, its length is the circle's radius.
+    pub fn tangent_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<2> {
+        let point = point.into();
+
+        match self {
+            Self::Bezier(bezier) => bezier.tangent_from_bezier_coords(point),
+            Self::BSpline(bspline) => {
+                bspline.tangent_from_bspline_coords(point)
+            }
+            Self::Circle(circle) => {
+                let (sin, cos) = point.t.sin_cos();
+                circle.a() * -sin + circle.b() * cos
+            }
+            Self::Ellipse(ellipse) => {
+                let (sin, cos) = point.t.sin_cos();
+                ellipse.major() * -sin + ellipse.minor() * cos
+            }
+            Self::Line(line) => line.direction(),
+        }
+    }
+
+    /// Compute the normal of the path at the given point
+    ///
+    /// The normal is normalized and points to the left of the path, meaning it
+    /// is the normalized tangent, rotated counter-clockwise by 90°. For a
+    /// counter-clockwise circle, it points towards the center.
+    pub fn normal_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<2> {
+        let tangent = self.tangent_from_path_coords(point);
+        Vector::from([-tangent.v, tangent.u]).normalize()
+    }
+
+    /// Compute the length of the path within the given boundary
+    ///
+    /// The length is exact for lines and circles, and computed numerically for
+    /// all other paths. It is never negative, regardless of the direction of
+    /// the boundary.
+    pub fn length(&self, boundary: impl Into<BoundaryOnCurve>) -> Scalar {
+        let boundary = boundary.into();
+        let [a, b] = boundary.inner;
+
+        if a == b {
+            return Scalar::ZERO;
+        }
+
+        match self {
+            Self::Circle(circle) => circle.radius() * (b.t - a.t).abs(),
+            Self::Line(_) => {
+                let [a, b] =
+                    [a, b].map(|point| self.point_from_path_coords(point));
+                (b - a).magnitude()
+            }
+            Self::BSpline(bspline) => integrate(
+                |t| self.tangent_from_path_coords([t]).magnitude(),
+                boundary,
+                bspline.knots(),
+            ),
+            Self::Bezier(_) | Self::Ellipse(_) => integrate(
+                |t| self.tangent_from_path_coords([t]).magnitude(),
+                boundary,
+                [],
+            ),
+        }
+    }
+
+    /// Compute the points where this path intersects another
+    ///
+    /// Returns the path coordinates of each intersection on this path and on
+    /// the other one, as well as its surface coordinates. The intersections are
+    /// ordered by their path coordinates on this path. Path coordinates on
+    /// circles are between `0` (inclusive) and `2π` (exclusive).
+    ///
+    /// Paths that touch, like a line that is tangent to a circle, intersect in
+    /// a single point. Paths that coincide intersect everywhere, which can't be
+    /// represented as a list of points, so no intersections are returned for
+    /// them. Paths that are within a tolerance of touching or coinciding, which
+    /// is relative to their size and position, are treated as such.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if either path is not a line or circle. Intersections of other
+    /// paths are not supported yet.
+    pub fn intersect(
+        &self,
+        other: &SurfacePath,
+    ) -> Vec<(Point<1>, Point<1>, Point<2>)> {
+        let mut intersections = match (self, other) {
+            (Self::Line(a), Self::Line(b)) => match a.intersect(b) {
+                LineIntersection::Point {
+                    point,
+                    line_coords: [t, s],
+                } => vec![(t, s, point)],
+                LineIntersection::Coincident | LineIntersection::Parallel => {
+                    vec![]
+                }
+            },
+            (Self::Line(line), Self::Circle(circle)) => {
+                intersect_line_and_circle(line, circle)
+            }
+            (Self::Circle(circle), Self::Line(line)) => {
+                intersect_line_and_circle(line, circle)
+                    .into_iter()
+                    .map(|(t, s, point)| (s, t, point))
+                    .collect()
+            }
+            (Self::Circle(a), Self::Circle(b)) => intersect_circles(a, b),
+            (Self::Bezier(_) | Self::BSpline(_) | Self::Ellipse(_), _)
+            | (_, Self::Bezier(_) | Self::BSpline(_) | Self::Ellipse(_)) => {
+                todo!(
+                    "Intersecting paths other than lines and circles is not \
+                    supported"
+                )
+            }
+        };
+
+        intersections.sort();
+        intersections
+    }
+
+    /// Compute the area between the path and the origin, and its first moment
+    ///
+    /// This is the signed area that the line from the origin to a point on the
+    /// path sweeps over, while that point moves along the path, within the
+    /// given boundary. The area is positive, where the path runs
+    /// counter-clockwise around the origin.
+    ///
+    /// The first moment is the area multiplied by its centroid. Summed over all
+    /// paths of a closed cycle, the area is the area enclosed by the cycle, and
+    /// the first moment divided by that area is the cycle's centroid.
+    ///
+    /// The result is exact for lines, and computed numerically for all other
+    /// paths.
+    pub fn area_and_moment(
+        &self,
+        boundary: impl Into<BoundaryOnCurve>,
+    ) -> (Scalar, Vector<2>) {
+        let boundary = boundary.into();
+        let [a, b] = boundary.inner;
+
+        if let Self::Line(_) = self {
+            // The area is that of the triangle between the origin and the two
+            // points, and the triangle's centroid is the average of all three.
+            let [a, b] = [a, b].map(|point| self.point_from_path_coords(point));
+            let area = (a.u * b.v - a.v * b.u) / 2.;
+            return (area, (a.coords + b.coords) * area / 3.);
+        }
+
+        // For the other paths, the same applies to the infinitesimal triangles
+        // between the origin, a point on the path, and the next one.
+        let breaks = match self {
+            Self::BSpline(bspline) => bspline.knots().collect(),
+            _ => Vec::new(),
+        };
+        let integrate = |f: &dyn Fn(Vector<2>, Scalar) -> Scalar| {
+            let integral = integrate(
+                |t| {
+                    let point = self.point_from_path_coords([t]).coords;
+                    let tangent = self.tangent_from_path_coords([t]);
+                    f(point, point.u * tangent.v - point.v * tangent.u)
+                },
+                boundary,
+                breaks.iter().copied(),
+            );
+
+            if a <= b {
+                integral
+            } else {
+                -integral
+            }
+        };
+
+        let area = integrate(&|_, cross| cross / 2.);
+        let moment = Vector::from([
+            integrate(&|point, cross| point.u * cross / 3.),
+            integrate(&|point, cross| point.v * cross / 3.),
+        ]);
+
+        (area, moment)
+    }
 }
 
 /// A path through global (3D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum GlobalPath {
+    /// A cubic Bézier curve
+    ///
+    /// See [`SurfacePath::Bezier`].
+    Bezier(Bezier<3>),
+
+    /// A non-rational B-spline curve with a clamped, uniform knot vector
+    ///
+    /// See [`SurfacePath::BSpline`].
+    BSpline(BSpline<3>),
+
     /// A circle
     Circle(Circle<3>),
 
+    /// An ellipse
+    Ellipse(Ellipse<3>),
+
+    /// A helix
+    Helix(Helix),
+
     /// A line
     Line(Line<3>),
 }
@@ -125,18 +448,44 @@ impl GlobalPath {
     /// Access the origin of the path's coordinate system
     pub fn origin(&self) -> Point<3> {
         match self {
+            Self::Bezier(bezier) => bezier.control_points()[0],
+            Self::BSpline(bspline) => bspline.control_points()[0],
             Self::Circle(circle) => circle.center() + circle.a(),
+            Self::Ellipse(ellipse) => ellipse.center() + ellipse.major(),
+            Self::Helix(helix) => helix.center() + helix.a(),
             Self::Line(line) => line.origin(),
         }
     }
 
+    /// Create a new path that is reversed
+    ///
+    /// The reversed path covers the same points, but runs in the opposite
+    /// direction. Coordinate `t` on the reversed path is coordinate `c - t` on
+    /// the original one, where `c` is `0` for circles, ellipses, and helices,
+    /// and `1` for all other paths.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Bezier(bezier) => Self::Bezier(bezier.reverse()),
+            Self::BSpline(bspline) => Self::BSpline(bspline.reverse()),
+            Self::Circle(circle) => Self::Circle(circle.reverse()),
+            Self::Ellipse(ellipse) => Self::Ellipse(ellipse.reverse()),
+            Self::Helix(helix) => Self::Helix(helix.reverse()),
+            Self::Line(line) => Self::Line(line.reverse()),
+        }
+    }
+
     /// Convert a point on the path into global coordinates
     pub fn point_from_path_coords(
         &self,
         point: impl Into<Point<1>>,
     ) -> Point<3> {
         match self {
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords(point),
+            Self::BSpline(bspline) => bspline.point_from_bspline_coords(point),
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
+            Self::Helix(helix) => helix.point_from_helix_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
@@ -147,19 +496,1079 @@ impl GlobalPath {
         vector: impl Into<Vector<1>>,
     ) -> Vector<3> {
         match self {
+            Self::Bezier(bezier) => {
+                bezier.point_from_bezier_coords(Point {
+                    coords: vector.into(),
+                }) - bezier.control_points()[0]
+            }
+            Self::BSpline(bspline) => {
+                bspline.point_from_bspline_coords(Point {
+                    coords: vector.into(),
+                }) - bspline.control_points()[0]
+            }
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
+            Self::Ellipse(ellipse) => {
+                ellipse.vector_from_ellipse_coords(vector)
+            }
+            Self::Helix(helix) => helix.vector_from_helix_coords(vector),
             Self::Line(line) => line.vector_from_line_coords(vector),
         }
     }
 
+    /// Project a point onto the path
+    ///
+    /// Returns the path coordinate of the point on the path that is closest to
+    /// the provided point, as well as the distance between the two.
+    ///
+    /// If the point is located at the center of a circle, all points on the
+    /// circle are equally close. The point at coordinate `0` is returned then.
+    pub fn project_point(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> (Point<1>, Scalar) {
+        let point = point.into();
+
+        let coord = match self {
+            Self::Circle(circle) => {
+                let vector = point - circle.center();
+                let [u, v] = [circle.a(), circle.b()]
+                    .map(|axis| vector.dot(&axis) / circle.radius());
+
+                if u == Scalar::ZERO && v == Scalar::ZERO {
+                    Point::from([Scalar::ZERO])
+                } else {
+                    let angle = v.atan2(u);
+                    let angle = if angle < Scalar::ZERO {
+                        angle + Scalar::TAU
+                    } else {
+                        angle
+                    };
+
+                    Point::from([angle])
+                }
+            }
+            Self::Ellipse(ellipse) => {
+                project_point_onto_ellipse(ellipse, point)
+            }
+            Self::Bezier(_) | Self::BSpline(_) => {
+                todo!(
+                    "Projecting points onto free-form curves is not supported \
+                    yet"
+                )
+            }
+            Self::Helix(_) => {
+                todo!("Projecting points onto helices is not supported yet")
+            }
+            Self::Line(line) => line.point_to_line_coords(point),
+        };
+
+        let distance = (self.point_from_path_coords(coord) - point).magnitude();
+        (coord, distance)
+    }
+
+    /// Compute the tangent of the path at the given point
+    ///
+    /// The tangent is the derivative of the path with respect to its
+    /// coordinate, and is not normalized. For a line, it's the line's
+    /// direction. For a circle, its length is the circle's radius.
+    pub fn tangent_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<3> {
+        let point = point.into();
+
+        match self {
+            Self::Bezier(bezier) => bezier.tangent_from_bezier_coords(point),
+            Self::BSpline(bspline) => {
+                bspline.tangent_from_bspline_coords(point)
+            }
+            Self::Circle(circle) => {
+                let (sin, cos) = point.t.sin_cos();
+                circle.a() * -sin + circle.b() * cos
+            }
+            Self::Ellipse(ellipse) => {
+                let (sin, cos) = point.t.sin_cos();
+                ellipse.major() * -sin + ellipse.minor() * cos
+            }
+            Self::Helix(helix) => {
+                let (sin, cos) = point.t.sin_cos();
+                helix.a() * -sin
+                    + helix.b() * cos
+                    + helix.advance() / Scalar::TAU
+            }
+            Self::Line(line) => line.direction(),
+        }
+    }
+
+    /// Compute the length of the path within the given boundary
+    ///
+    /// See [`SurfacePath::length`]. Helices have a constant speed, so their
+    /// length is exact too.
+    pub fn length(&self, boundary: impl Into<BoundaryOnCurve>) -> Scalar {
+        let boundary = boundary.into();
+        let [a, b] = boundary.inner;
+
+        if a == b {
+            return Scalar::ZERO;
+        }
+
+        match self {
+            Self::Circle(circle) => circle.radius() * (b.t - a.t).abs(),
+            Self::Helix(_) => {
+                self.tangent_from_path_coords(a).magnitude() * (b.t - a.t).abs()
+            }
+            Self::Line(_) => {
+                let [a, b] =
+                    [a, b].map(|point| self.point_from_path_coords(point));
+                (b - a).magnitude()
+            }
+            Self::BSpline(bspline) => integrate(
+                |t| self.tangent_from_path_coords([t]).magnitude(),
+                boundary,
+                bspline.knots(),
+            ),
+            Self::Bezier(_) | Self::Ellipse(_) => integrate(
+                |t| self.tangent_from_path_coords([t]).magnitude(),
+                boundary,
+                [],
+            ),
+        }
+    }
+
+    /// Compute points on the path that are spaced by equal arc length
+    ///
+    /// Returns path coordinates, starting with the first point of the
+    /// boundary, and followed by one point every `spacing` along the path. The
+    /// last point is always the second point of the boundary, even if
+    /// `spacing` doesn't evenly divide the length of the path within the
+    /// boundary. In that case, the last point is closer to its predecessor than
+    /// `spacing`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `spacing` is not positive.
+    pub fn points_by_arc_length(
+        &self,
+        boundary: impl Into<BoundaryOnCurve>,
+        spacing: impl Into<Scalar>,
+    ) -> Vec<Point<1>> {
+        let boundary = boundary.into();
+        let spacing = spacing.into();
+
+        assert!(
+            spacing > Scalar::ZERO,
+            "Spacing of points along path must be positive"
+        );
+
+        let [a, b] = boundary.inner;
+        let direction = (b.t - a.t).sign().to_scalar();
+
+        let num_steps =
+            (self.length(boundary) / spacing).ceil().into_f64() as u32;
+
+        let mut points = vec![a];
+        let mut t = a.t;
+
+        for i in 1..num_steps {
+            t = match self {
+                Self::Circle(_) | Self::Helix(_) | Self::Line(_) => {
+                    // These paths have a constant speed, so path coordinates
+                    // are proportional to arc length.
+                    let speed = self.tangent_from_path_coords(a).magnitude();
+                    a.t + direction * spacing * f64::from(i) / speed
+                }
+                Self::Bezier(_) | Self::BSpline(_) | Self::Ellipse(_) => {
+                    advance_by_arc_length(self, [t, b.t], spacing)
+                }
+            };
+
+            points.push(Point::from([t]));
+        }
+
+        if a != b {
+            points.push(b);
+        }
+
+        points
+    }
+
     /// Transform the path
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform can't be applied to the path. See
+    /// [`GlobalPath::try_transform`].
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
-        match self {
-            Self::Circle(curve) => {
-                Self::Circle(transform.transform_circle(&curve))
+        self.try_transform(transform)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Transform the path, if possible
+    ///
+    /// A transform that scales non-uniformly can turn a circle into an ellipse,
+    /// or an ellipse into a circle. The path coordinates stay the same, which
+    /// is only possible, if the transform doesn't skew the circle or ellipse,
+    /// and its first axis remains the longer one (see [`Ellipse::new`]).
+    /// Returns an error otherwise.
+    pub fn try_transform(
+        self,
+        transform: &Transform,
+    ) -> Result<Self, TransformPathError> {
+        let path = match self {
+            Self::Bezier(curve) => {
+                Self::Bezier(transform.transform_bezier(&curve))
+            }
+            Self::BSpline(curve) => {
+                Self::BSpline(transform.transform_bspline(&curve))
+            }
+            Self::Circle(curve) => self.circle_or_ellipse(
+                transform.transform_point(&curve.center()),
+                transform.transform_vector(&curve.a()),
+                transform.transform_vector(&curve.b()),
+            )?,
+            Self::Ellipse(curve) => self.circle_or_ellipse(
+                transform.transform_point(&curve.center()),
+                transform.transform_vector(&curve.major()),
+                transform.transform_vector(&curve.minor()),
+            )?,
+            Self::Helix(curve) => {
+                let [a, b, advance] = [curve.a(), curve.b(), curve.advance()]
+                    .map(|vector| transform.transform_vector(&vector));
+
+                // Unlike a circle, a helix can't turn into something else. Its
+                // circle must stay a circle, and its advance perpendicular to
+                // that.
+                let is_perpendicular = |u: Vector<3>, v: Vector<3>| {
+                    u.dot(&v).abs()
+                        <= u.magnitude() * v.magnitude() * RELATIVE_EPSILON
+                };
+                let is_circle = (a.magnitude() - b.magnitude()).abs()
+                    <= a.magnitude() * RELATIVE_EPSILON
+                    && is_perpendicular(a, b);
+                if !is_circle
+                    || !is_perpendicular(a, advance)
+                    || !is_perpendicular(b, advance)
+                {
+                    return Err(TransformPathError::Helix { path: self });
+                }
+
+                Self::Helix(Helix::new(
+                    transform.transform_point(&curve.center()),
+                    a,
+                    b,
+                    advance,
+                ))
             }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
+        };
+
+        Ok(path)
+    }
+
+    /// Build the circle or ellipse that results from transforming this path
+    ///
+    /// The path coordinate `0` is at `a`, and a quarter turn later is `b`.
+    fn circle_or_ellipse(
+        self,
+        center: Point<3>,
+        a: Vector<3>,
+        b: Vector<3>,
+    ) -> Result<Self, TransformPathError> {
+        let [a_len, b_len] = [a, b].map(|vector| vector.magnitude());
+
+        if a.dot(&b).abs() > a_len * b_len * RELATIVE_EPSILON {
+            return Err(TransformPathError::Skew { path: self });
+        }
+        if (a_len - b_len).abs() <= a_len * RELATIVE_EPSILON {
+            return Ok(Self::Circle(Circle::new(center, a, b)));
+        }
+        if a_len < b_len {
+            return Err(TransformPathError::SecondAxisLonger { path: self });
+        }
+
+        Ok(Self::Ellipse(Ellipse::new(center, a, b)))
+    }
+}
+
+/// The tolerance for checking the axes of transformed paths
+///
+/// A transform that also rotates introduces rounding errors, so axes that
+/// should be perpendicular or of equal length might be slightly off. This is
+/// the same tolerance, relative to the size of the path, that `Circle` and
+/// `Ellipse` accept.
+const RELATIVE_EPSILON: f64 = 1e-12;
+
+/// Error transforming a [`GlobalPath`]
+///
+/// See [`GlobalPath::try_transform`].
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum TransformPathError {
+    /// The transform would skew a circle or ellipse
+    #[error(
+        "Can't transform circle or ellipse in a way that skews it\n\
+        - Path: {path:#?}"
+    )]
+    Skew {
+        /// The path that could not be transformed
+        path: GlobalPath,
+    },
+
+    /// The transform would make the second axis of an ellipse the longer one
+    #[error(
+        "Can't transform ellipse in a way that makes its second axis the \
+        longer one\n\
+        - Path: {path:#?}"
+    )]
+    SecondAxisLonger {
+        /// The path that could not be transformed
+        path: GlobalPath,
+    },
+
+    /// The transform would make a helix non-circular, or skew it
+    #[error(
+        "Can't transform helix in a way that makes it non-circular or skews \
+        it\n\
+        - Path: {path:#?}"
+    )]
+    Helix {
+        /// The path that could not be transformed
+        path: GlobalPath,
+    },
+}
+
+/// The tolerance for intersections of paths, relative to their size
+const INTERSECTION_TOLERANCE: f64 = 1e-12;
+
+/// Compute the intersections of a line and a circle
+///
+/// Returns the path coordinates on the line, on the circle, and the point.
+fn intersect_line_and_circle(
+    line: &Line<2>,
+    circle: &Circle<2>,
+) -> Vec<(Point<1>, Point<1>, Point<2>)> {
+    let direction = line.direction();
+    let radius = circle.radius();
+
+    // The intersections are symmetric around the point on the line that is
+    // closest to the center of the circle.
+    let t_closest = (circle.center() - line.origin()).dot(&direction)
+        / direction.dot(&direction);
+    let closest = line.point_from_line_coords([t_closest]);
+    let distance = (closest - circle.center()).magnitude();
+
+    let tolerance = tolerance_for([
+        radius,
+        circle.center().coords.magnitude(),
+        line.origin().coords.magnitude(),
+    ]);
+
+    // Checking this before computing the intersections directly makes sure
+    // that a tangent line results in exactly one intersection, instead of
+    // two very close ones, or none.
+    if (distance - radius).abs() <= tolerance {
+        return vec![(
+            Point::from([t_closest]),
+            circle_coords(circle, closest),
+            closest,
+        )];
+    }
+    if distance > radius {
+        return vec![];
+    }
+
+    let half_chord =
+        Scalar::from((radius * radius - distance * distance).into_f64().sqrt())
+            / direction.magnitude();
+
+    [t_closest - half_chord, t_closest + half_chord]
+        .into_iter()
+        .map(|t| {
+            let point = line.point_from_line_coords([t]);
+            (Point::from([t]), circle_coords(circle, point), point)
+        })
+        .collect()
+}
+
+/// Compute the intersections of two circles
+///
+/// Returns the path coordinates on both circles, and the point.
+fn intersect_circles(
+    a: &Circle<2>,
+    b: &Circle<2>,
+) -> Vec<(Point<1>, Point<1>, Point<2>)> {
+    let [ra, rb] = [a.radius(), b.radius()];
+    let between = b.center() - a.center();
+    let distance = between.magnitude();
+
+    let tolerance = tolerance_for([
+        ra,
+        rb,
+        a.center().coords.magnitude(),
+        b.center().coords.magnitude(),
+    ]);
+
+    // Concentric circles either coincide or don't intersect at all.
+    if distance <= tolerance {
+        return vec![];
+    }
+
+    let direction = between / distance;
+    let intersection = |point: Point<2>| {
+        (circle_coords(a, point), circle_coords(b, point), point)
+    };
+
+    // As for a line and a circle, checking for circles that touch first makes
+    // sure that they result in exactly one intersection.
+    let touch_outside = (distance - (ra + rb)).abs() <= tolerance;
+    let touch_inside = (distance - (ra - rb).abs()).abs() <= tolerance;
+    if touch_outside || touch_inside {
+        // The circles touch on the line through their centers. Seen from the
+        // center of `a`, that's towards the center of `b`, unless `b` encloses
+        // `a`.
+        let direction = if touch_inside && rb > ra {
+            -direction
+        } else {
+            direction
+        };
+        return vec![intersection(a.center() + direction * ra)];
+    }
+    if distance > ra + rb || distance < (ra - rb).abs() {
+        return vec![];
+    }
+
+    // The distance from the center of `a` to the line through the
+    // intersections, and the distance of the intersections from that line.
+    let along = (ra * ra - rb * rb + distance * distance) / (distance * 2.);
+    let across = Scalar::from(
+        (ra * ra - along * along)
+            .max(Scalar::ZERO)
+            .into_f64()
+            .sqrt(),
+    );
+
+    let perpendicular = Vector::from([-direction.v, direction.u]);
+    let middle = a.center() + direction * along;
+
+    vec![
+        intersection(middle + perpendicular * across),
+        intersection(middle - perpendicular * across),
+    ]
+}
+
+/// Compute the absolute tolerance for intersections of paths of the given sizes
+fn tolerance_for(sizes: impl IntoIterator<Item = Scalar>) -> Scalar {
+    sizes.into_iter().fold(Scalar::ONE, Scalar::max) * INTERSECTION_TOLERANCE
+}
+
+/// Compute the path coordinate of a point on a circle
+///
+/// This takes the vectors that define the circle into account, which means it
+/// also works for circles that run clockwise, or start at any angle.
+fn circle_coords(circle: &Circle<2>, point: Point<2>) -> Point<1> {
+    let offset = point - circle.center();
+    let t = offset.dot(&circle.b()).atan2(offset.dot(&circle.a()));
+    let t = if t < Scalar::ZERO { t + Scalar::TAU } else { t };
+    Point::from([t])
+}
+
+/// Integrate a function of the path coordinate over the given boundary
+///
+/// Used to integrate the speed of a path, to get its length, among other
+/// things. The result is the same, regardless of the direction of the
+/// boundary.
+///
+/// Uses composite Gauss-Legendre quadrature. The function might not be smooth
+/// at the provided `breaks`, like at the knots of a B-spline, so the boundary
+/// is split there, and each part is integrated separately.
+fn integrate(
+    f: impl Fn(Scalar) -> Scalar,
+    boundary: BoundaryOnCurve,
+    breaks: impl IntoIterator<Item = Scalar>,
+) -> Scalar {
+    // Nodes and weights of the 5-point Gauss-Legendre rule on `[-1, 1]`.
+    const NODES: [f64; 5] = [
+        -0.906_179_845_938_664,
+        -0.538_469_310_105_683_1,
+        0.,
+        0.538_469_310_105_683_1,
+        0.906_179_845_938_664,
+    ];
+    const WEIGHTS: [f64; 5] = [
+        0.236_926_885_056_189_1,
+        0.478_628_670_499_366_5,
+        0.568_888_888_888_888_9,
+        0.478_628_670_499_366_5,
+        0.236_926_885_056_189_1,
+    ];
+
+    // The number of intervals each part is split into, per full turn of path
+    // coordinates, or less.
+    const INTERVALS_PER_TURN: f64 = 32.;
+
+    let [a, b] = boundary.inner.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut stops = vec![min];
+    stops.extend(breaks.into_iter().filter(|&t| min < t && t < max));
+    stops.push(max);
+
+    let mut integral = Scalar::ZERO;
+
+    for part in stops.windows(2) {
+        let [start, end] = [part[0], part[1]];
+
+        let num_intervals = (((end - start) / Scalar::TAU).ceil()
+            * INTERVALS_PER_TURN)
+            .into_f64() as u32;
+        let half_width = (end - start) / f64::from(num_intervals) / 2.;
+
+        for i in 0..num_intervals {
+            let center = start + half_width * f64::from(2 * i + 1);
+
+            for (node, weight) in NODES.into_iter().zip(WEIGHTS) {
+                integral += f(center + half_width * node) * half_width * weight;
+            }
+        }
+    }
+
+    integral
+}
+
+/// Find the path coordinate at the given arc length from `start`, towards `end`
+///
+/// Uses Newton's method, falling back to bisection whenever a step would leave
+/// the range that is known to contain the result. `end` must be at least
+/// `distance` away from `start`, along the path.
+fn advance_by_arc_length(
+    path: &GlobalPath,
+    [start, end]: [Scalar; 2],
+    distance: Scalar,
+) -> Scalar {
+    const MAX_ITERATIONS: u32 = 64;
+
+    let direction = (end - start).sign().to_scalar();
+    let tolerance = distance * 1e-12;
+
+    // The result is searched in terms of the distance from `start` in path
+    // coordinates, which makes the arc length increase monotonically with it.
+    let t_at = |s: Scalar| start + direction * s;
+    let [mut lower, mut upper] = [Scalar::ZERO, (end - start).abs()];
+
+    let mut s = {
+        let speed = path.tangent_from_path_coords([start]).magnitude();
+
+        if speed > Scalar::ZERO && distance / speed < upper {
+            distance / speed
+        } else {
+            (lower + upper) / 2.
+        }
+    };
+
+    for _ in 0..MAX_ITERATIONS {
+        let error = path.length([[start], [t_at(s)]]) - distance;
+
+        if error.abs() <= tolerance {
+            break;
+        }
+
+        if error < Scalar::ZERO {
+            lower = s;
+        } else {
+            upper = s;
+        }
+
+        let speed = path.tangent_from_path_coords([t_at(s)]).magnitude();
+        let newton = (speed > Scalar::ZERO).then(|| s - error / speed);
+
+        s = match newton {
+            Some(next) if lower < next && next < upper => next,
+            _ => (lower + upper) / 2.,
+        };
+    }
+
+    t_at(s)
+}
+
+/// Project a point onto an ellipse
+///
+/// There's no closed-form solution for this. A rough approximation is found by
+/// sampling the ellipse, and then refined, using Newton's method to find a root
+/// of the derivative of the squared distance.
+fn project_point_onto_ellipse(
+    ellipse: &Ellipse<3>,
+    point: Point<3>,
+) -> Point<1> {
+    const NUM_SAMPLES: u32 = 64;
+    const NUM_ITERATIONS: u32 = 16;
+
+    let offset_at = |t: Scalar| ellipse.point_from_ellipse_coords([t]) - point;
+
+    let mut t = (0..NUM_SAMPLES)
+        .map(|i| Scalar::TAU * f64::from(i) / f64::from(NUM_SAMPLES))
+        .min_by_key(|&t| offset_at(t).magnitude())
+        .expect("Sampled ellipse at least once");
+
+    for _ in 0..NUM_ITERATIONS {
+        let (sin, cos) = t.sin_cos();
+        let first = ellipse.major() * -sin + ellipse.minor() * cos;
+        let second = ellipse.major() * -cos + ellipse.minor() * -sin;
+
+        let offset = offset_at(t);
+        let derivative = offset.dot(&first);
+        let second_derivative = first.dot(&first) + offset.dot(&second);
+
+        if second_derivative <= Scalar::ZERO {
+            break;
+        }
+
+        let next = t - derivative / second_derivative;
+        if offset_at(next).magnitude() > offset.magnitude() {
+            break;
+        }
+        t = next;
+    }
+
+    let t = t % Scalar::TAU;
+    let t = if t < Scalar::ZERO { t + Scalar::TAU } else { t };
+    Point::from([t])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_math::{
+        assert_approx_eq, Bezier, Circle, Ellipse, Helix, Point, Scalar, Vector,
+    };
+
+    use crate::geometry::BoundaryOnCurve;
+
+    use super::{GlobalPath, SurfacePath};
+
+    #[test]
+    fn arc_from_three_points() {
+        let (path, boundary) =
+            SurfacePath::arc_from_three_points([[1., 0.], [0., 1.], [-1., 0.]])
+                .expect("Points are not collinear");
+
+        let [start, end] = boundary
+            .inner
+            .map(|point| path.point_from_path_coords(point));
+        let middle = path.point_from_path_coords([(boundary.inner[0].t
+            + boundary.inner[1].t)
+            / 2.]);
+        for (actual, expected) in [start, middle, end].into_iter().zip([
+            [1., 0.],
+            [0., 1.],
+            [-1., 0.],
+        ]) {
+            assert!(
+                (actual - Point::from(expected)).magnitude() < 1e-12.into()
+            );
+        }
+
+        assert!(SurfacePath::arc_from_three_points([
+            [0., 0.],
+            [1., 0.],
+            [3., 0.]
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn tangent_of_line() {
+        let (path, _) = SurfacePath::line_from_points([[1., 1.], [3., 1.]]);
+
+        assert_eq!(
+            path.tangent_from_path_coords([0.5]),
+            Vector::from([2., 0.])
+        );
+        assert_eq!(path.normal_from_path_coords([0.5]), Vector::from([0., 1.]));
+    }
+
+    #[test]
+    fn tangent_of_circle() {
+        let path = GlobalPath::circle_from_radius(2.);
+        let tangent =
+            path.tangent_from_path_coords(Point::from([Scalar::PI / 2.]));
+        assert!(
+            (tangent - Vector::from([-2., 0., 0.])).magnitude() < 1e-12.into()
+        );
+
+        // The normal points towards the center.
+        let path = SurfacePath::circle_from_center_and_radius([1., 1.], 2.);
+        let normal = path.normal_from_path_coords([Scalar::ZERO]);
+        assert!((normal - Vector::from([-1., 0.])).magnitude() < 1e-12.into());
+    }
+
+    #[test]
+    fn length() {
+        let full_turn = BoundaryOnCurve::from([[0.], [TAU]]);
+
+        let (line, _) = SurfacePath::line_from_points([[1., 1.], [4., 5.]]);
+        assert_eq!(line.length([[0.], [1.]]), Scalar::from(5.));
+        assert_eq!(line.length([[1.], [-1.]]), Scalar::from(10.));
+
+        let circle = SurfacePath::circle_from_center_and_radius([1., 1.], 2.);
+        assert!(
+            (circle.length(full_turn) - Scalar::TAU * 2.).abs() < 1e-12.into()
+        );
+
+        // The perimeter of an ellipse has no closed-form solution. This is a
+        // reference value, computed to more digits than we need here.
+        let ellipse = SurfacePath::ellipse_from_center_and_axes(
+            [0., 0.],
+            [2., 0.],
+            [0., 1.],
+        );
+        assert!(
+            (ellipse.length(full_turn) - 9.688_448_220_547_675).abs()
+                < 1e-9.into()
+        );
+
+        // Evenly spaced control points result in a constant speed.
+        let (bezier, boundary) = SurfacePath::bezier_from_control_points([
+            [0., 0.],
+            [1., 0.],
+            [2., 0.],
+            [3., 0.],
+        ]);
+        assert!((bezier.length(boundary) - 3.).abs() < 1e-12.into());
+
+        // The length of a B-spline of degree 1 is the length of its control
+        // polygon, as the boundary is split at the knots.
+        let (bspline, boundary) = SurfacePath::bspline_from_control_points(
+            [[0., 0.], [3., 0.], [3., 1.], [3., 4.]],
+            1,
+        );
+        assert!((bspline.length(boundary) - 7.).abs() < 1e-12.into());
+
+        let helix = GlobalPath::Helix(Helix::new(
+            [0., 0., 0.],
+            [3., 0., 0.],
+            [0., 3., 0.],
+            [0., 0., 4. * TAU],
+        ));
+        assert!(
+            (helix.length(full_turn) - Scalar::TAU * 5.).abs() < 1e-12.into()
+        );
+
+        for path in [line, circle, ellipse, bezier, bspline] {
+            assert_eq!(path.length([[0.5], [0.5]]), Scalar::ZERO);
+        }
+        assert_eq!(helix.length([[0.5], [0.5]]), Scalar::ZERO);
+    }
+
+    #[test]
+    fn area_and_moment() {
+        let full_turn = BoundaryOnCurve::from([[0.], [TAU]]);
+
+        // A line and the origin form a triangle, with its centroid at a third
+        // of the sum of the points.
+        let (line, boundary) =
+            SurfacePath::line_from_points([[1., 0.], [1., 3.]]);
+        let (area, moment) = line.area_and_moment(boundary);
+        assert_eq!(area, Scalar::from(1.5));
+        assert_approx_eq!(moment / area, Vector::from([2. / 3., 1.]), 1e-12);
+
+        let circle = SurfacePath::circle_from_center_and_radius([1., 2.], 2.);
+        let (area, moment) = circle.area_and_moment(full_turn);
+        assert_approx_eq!(area, Scalar::PI * 4., 1e-12);
+        assert_approx_eq!(moment / area, Vector::from([1., 2.]), 1e-12);
+
+        // Running the other way reverses the sign.
+        let (area, _) = circle.area_and_moment([[TAU], [0.]]);
+        assert_approx_eq!(area, -Scalar::PI * 4., 1e-12);
+    }
+
+    #[test]
+    fn project_point() {
+        let (line, _) =
+            GlobalPath::line_from_points([[0., 0., 0.], [2., 0., 0.]]);
+        let (coord, distance) = line.project_point([1., 1., 0.]);
+        assert_eq!(coord, Point::from([0.5]));
+        assert_eq!(distance, Scalar::ONE);
+
+        // A circle in the xz-plane.
+        let circle = GlobalPath::Circle(Circle::new(
+            [0., 0., 0.],
+            [2., 0., 0.],
+            [0., 0., 2.],
+        ));
+        let (coord, distance) = circle.project_point([0., 1., 3.]);
+        assert!((coord.t - Scalar::PI / 2.).abs() < 1e-12.into());
+        assert!((distance - 2_f64.sqrt()).abs() < 1e-12.into());
+
+        // The center of a circle is equally close to all its points.
+        let (coord, distance) = circle.project_point([0., 0., 0.]);
+        assert_eq!(coord, Point::from([0.]));
+        assert_eq!(distance, Scalar::from(2.));
+
+        let ellipse = GlobalPath::Ellipse(Ellipse::new(
+            [0., 0., 0.],
+            [2., 0., 0.],
+            [0., 1., 0.],
+        ));
+        let (coord, distance) = ellipse.project_point([0., 2., 0.]);
+        assert!((coord.t - Scalar::PI / 2.).abs() < 1e-9.into());
+        assert!((distance - 1.).abs() < 1e-9.into());
+    }
+    #[test]
+    fn points_by_arc_length() {
+        let circle = GlobalPath::Circle(Circle::from_center_and_radius(
+            Point::origin(),
+            2.,
+        ));
+        let points = circle.points_by_arc_length([[0.], [TAU]], 1.);
+
+        // The circumference is `4π`, so there are 12 full steps, and a shorter
+        // one at the end.
+        assert_eq!(points.len(), 14);
+        assert_eq!(points.first(), Some(&Point::from([0.])));
+        assert_eq!(points.last(), Some(&Point::from([TAU])));
+        for (i, point) in points[..13].iter().enumerate() {
+            let expected = Scalar::from(i as f64 / 2.);
+            assert!((point.t - expected).abs() < 1e-12.into());
+        }
+
+        let ellipse = GlobalPath::Ellipse(Ellipse::new(
+            Point::origin(),
+            [2., 0., 0.],
+            [0., 1., 0.],
+        ));
+        let bezier = GlobalPath::Bezier(Bezier::from_control_points([
+            [0., 0., 0.],
+            [0., 3., 0.],
+            [1., 3., 1.],
+            [4., 0., 0.],
+        ]));
+        for (path, boundary, spacing) in [
+            (&ellipse, [[0.], [TAU]], 0.7),
+            (&ellipse, [[2.], [-1.]], 0.3),
+            (&bezier, [[0.], [1.]], 0.5),
+            (&bezier, [[1.], [0.25]], 0.4),
+        ] {
+            let points = path.points_by_arc_length(boundary, spacing);
+
+            assert_eq!(points.first(), Some(&Point::from(boundary[0])));
+            assert_eq!(points.last(), Some(&Point::from(boundary[1])));
+
+            let lengths = points
+                .windows(2)
+                .map(|segment| path.length([segment[0], segment[1]]))
+                .collect::<Vec<_>>();
+            let (last, full) =
+                lengths.split_last().expect("Expected multiple points");
+
+            for length in full {
+                assert!((*length - spacing).abs() < 1e-9.into());
+            }
+            assert!(*last > Scalar::ZERO && *last <= Scalar::from(spacing));
+        }
+
+        let points = ellipse.points_by_arc_length([[1.], [1.]], 0.5);
+        assert_eq!(points, vec![Point::from([1.])]);
+    }
+
+    #[test]
+    fn intersect_lines() {
+        let (a, _) = SurfacePath::line_from_points([[0., 0.], [2., 0.]]);
+        let (b, _) = SurfacePath::line_from_points([[1., -1.], [1., 1.]]);
+        let (c, _) = SurfacePath::line_from_points([[0., 1.], [2., 1.]]);
+
+        assert_eq!(
+            a.intersect(&b),
+            vec![(
+                Point::from([0.5]),
+                Point::from([0.5]),
+                Point::from([1., 0.])
+            )]
+        );
+        assert_eq!(a.intersect(&c), vec![]);
+        assert_eq!(a.intersect(&a), vec![]);
+    }
+
+    #[test]
+    fn intersect_line_and_circle() {
+        let circle = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+
+        let secant = SurfacePath::line_from_points_with_coords([
+            ([0.], [-2., 0.]),
+            ([1.], [-1., 0.]),
+        ]);
+        let intersections = secant.intersect(&circle);
+        assert_intersections(
+            &intersections,
+            &[([1.], [TAU / 2.], [-1., 0.]), ([3.], [0.], [1., 0.])],
+        );
+        assert_eq!(
+            circle.intersect(&secant),
+            intersections
+                .iter()
+                .rev()
+                .map(|&(t, s, point)| (s, t, point))
+                .collect::<Vec<_>>()
+        );
+
+        // A tangent touches the circle, which must result in exactly one
+        // intersection, even if floating-point errors put it a tiny bit into or
+        // out of the circle.
+        for y in [1., 1. + 1e-15, 1. - 1e-15] {
+            let (tangent, _) =
+                SurfacePath::line_from_points([[-1., y], [1., y]]);
+            assert_intersections(
+                &tangent.intersect(&circle),
+                &[([0.5], [TAU / 4.], [0., 1.])],
+            );
+        }
+
+        let (outside, _) = SurfacePath::line_from_points([[-1., 2.], [1., 2.]]);
+        assert_eq!(outside.intersect(&circle), vec![]);
+    }
+
+    #[test]
+    fn intersect_circles() {
+        let a = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+
+        let b = SurfacePath::circle_from_center_and_radius([1., 0.], 1.);
+        let y = 3_f64.sqrt() / 2.;
+        assert_intersections(
+            &a.intersect(&b),
+            &[
+                ([TAU / 6.], [TAU / 3.], [0.5, y]),
+                ([TAU * 5. / 6.], [TAU * 2. / 3.], [0.5, -y]),
+            ],
+        );
+
+        let touching_outside =
+            SurfacePath::circle_from_center_and_radius([3., 0.], 2.);
+        assert_intersections(
+            &a.intersect(&touching_outside),
+            &[([0.], [TAU / 2.], [1., 0.])],
+        );
+
+        let touching_inside =
+            SurfacePath::circle_from_center_and_radius([1., 0.], 2.);
+        assert_intersections(
+            &a.intersect(&touching_inside),
+            &[([TAU / 2.], [TAU / 2.], [-1., 0.])],
+        );
+
+        let apart = SurfacePath::circle_from_center_and_radius([3., 0.], 1.);
+        let enclosed =
+            SurfacePath::circle_from_center_and_radius([0.2, 0.], 0.5);
+        let concentric =
+            SurfacePath::circle_from_center_and_radius([0., 0.], 2.);
+        assert_eq!(a.intersect(&apart), vec![]);
+        assert_eq!(a.intersect(&enclosed), vec![]);
+        assert_eq!(a.intersect(&concentric), vec![]);
+        assert_eq!(a.intersect(&a), vec![]);
+    }
+
+    #[test]
+    fn reverse() {
+        let surface_paths = [
+            (
+                SurfacePath::bezier_from_control_points([
+                    [0., 0.],
+                    [1., 2.],
+                    [3., 1.],
+                    [4., 0.],
+                ])
+                .0,
+                1.,
+            ),
+            (
+                SurfacePath::bspline_from_control_points(
+                    [[0., 0.], [1., 2.], [2., -1.], [3., 1.]],
+                    2,
+                )
+                .0,
+                1.,
+            ),
+            (SurfacePath::circle_from_center_and_radius([1., 2.], 3.), 0.),
+            (
+                SurfacePath::ellipse_from_center_and_axes(
+                    [1., 2.],
+                    [3., 0.],
+                    [0., 1.],
+                ),
+                0.,
+            ),
+            (SurfacePath::line_from_points([[1., 2.], [3., 5.]]).0, 1.),
+        ];
+        for (path, c) in surface_paths {
+            let reversed = path.clone().reverse();
+
+            for t in [-0.5, 0., 0.3, 1., 2.] {
+                let distance = reversed
+                    .point_from_path_coords([t])
+                    .distance_to(&path.point_from_path_coords([c - t]));
+                assert!(distance < 1e-12.into(), "{path:?}, t = {t}");
+            }
+        }
+
+        let global_paths = [
+            (
+                GlobalPath::Bezier(Bezier::from_control_points([
+                    [0., 0., 0.],
+                    [1., 2., 3.],
+                    [3., 1., 0.],
+                    [4., 0., 1.],
+                ])),
+                1.,
+            ),
+            (GlobalPath::circle_from_radius(2.), 0.),
+            (
+                GlobalPath::Ellipse(Ellipse::new(
+                    [1., 2., 3.],
+                    [3., 0., 0.],
+                    [0., 1., 0.],
+                )),
+                0.,
+            ),
+            (
+                GlobalPath::Helix(Helix::new(
+                    [1., 2., 3.],
+                    [2., 0., 0.],
+                    [0., 2., 0.],
+                    [0., 0., 1.],
+                )),
+                0.,
+            ),
+            (
+                GlobalPath::line_from_points([[1., 2., 3.], [3., 5., 0.]]).0,
+                1.,
+            ),
+        ];
+        for (path, c) in global_paths {
+            let reversed = path.clone().reverse();
+
+            for t in [-0.5, 0., 0.3, 1., 2.] {
+                let distance = reversed
+                    .point_from_path_coords([t])
+                    .distance_to(&path.point_from_path_coords([c - t]));
+                assert!(distance < 1e-12.into(), "{path:?}, t = {t}");
+            }
+        }
+    }
+
+    fn assert_intersections(
+        intersections: &[(Point<1>, Point<1>, Point<2>)],
+        expected: &[([f64; 1], [f64; 1], [f64; 2])],
+    ) {
+        assert_eq!(intersections.len(), expected.len(), "{intersections:?}");
+
+        for (&(t, s, point), &(t_expected, s_expected, point_expected)) in
+            intersections.iter().zip(expected)
+        {
+            let tolerance = Scalar::from(1e-12);
+            assert!((t - Point::from(t_expected)).magnitude() < tolerance);
+            assert!((s - Point::from(s_expected)).magnitude() < tolerance);
+            assert!(
+                point.distance_to(&Point::from(point_expected)) < tolerance
+            );
         }
     }
 }
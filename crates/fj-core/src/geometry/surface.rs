@@ -1,11 +1,11 @@
 //! The geometry that defines a surface
 
-use fj_math::{Line, Plane, Point, Transform, Vector};
+use fj_math::{Line, Plane, Point, Scalar, Transform, Vector};
 
-use super::GlobalPath;
+use super::{GlobalPath, TransformPathError};
 
 /// The geometry that defines a surface
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct SurfaceGeometry {
     /// The u-axis of the surface
     pub u: GlobalPath,
@@ -25,6 +25,66 @@ impl SurfaceGeometry {
             + self.path_to_line().vector_from_line_coords([point.v])
     }
 
+    /// Convert a point in model coordinates to surface coordinates
+    ///
+    /// This is the inverse of [`SurfaceGeometry::point_from_surface_coords`],
+    /// for planes, and for cylinders, which are surfaces with a circle as their
+    /// u-axis. A point that is not on the surface is projected onto it. For a
+    /// plane or a right cylinder, that results in the closest point on the
+    /// surface.
+    ///
+    /// For a cylinder, the u-coordinate is an angle, which is always returned
+    /// in the range `[0, 2pi)`. Surface coordinates that are outside of that
+    /// range, refer to the same point as their equivalent within it, so they
+    /// don't survive a round trip through model coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the u-axis of the surface is neither a line nor a circle.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let point = point.into();
+
+        match &self.u {
+            GlobalPath::Circle(circle) => {
+                // Move the point along the v-axis, into the plane of the
+                // circle. How far it needs to move is the v-coordinate.
+                let normal = circle.a().cross(&circle.b());
+                let v = (point - circle.center()).dot(&normal)
+                    / self.v.dot(&normal);
+                let point_in_plane = point - self.v * v - circle.center();
+
+                // `Circle::point_to_circle_coords` assumes the circle to be
+                // parallel to the xy-plane. This works for any circle.
+                let u = point_in_plane
+                    .dot(&circle.b())
+                    .atan2(point_in_plane.dot(&circle.a()));
+                let u = if u < Scalar::ZERO {
+                    // Adding a tiny negative angle to 2pi can round to 2pi.
+                    (u + Scalar::TAU) % Scalar::TAU
+                } else {
+                    u
+                };
+
+                Point::from([u, v])
+            }
+            GlobalPath::Line(line) => {
+                let plane = Plane::from_parametric(
+                    line.origin(),
+                    line.direction(),
+                    self.v,
+                );
+                plane.project_point(point)
+            }
+            _ => todo!(
+                "Converting point to coordinates of surface that is neither \
+                plane nor cylinder is not supported"
+            ),
+        }
+    }
+
     /// Convert a vector in surface coordinates to model coordinates
     pub fn vector_from_surface_coords(
         &self,
@@ -35,33 +95,68 @@ impl SurfaceGeometry {
             + self.path_to_line().vector_from_line_coords([vector.v])
     }
 
+    /// Compute the normal of the surface at the given point
+    ///
+    /// The normal is normalized, and points in the direction of the cross
+    /// product of the u- and v-axis at that point. This is the direction that
+    /// the front side of a face with a right-handed coordinate system faces
+    /// (see [`Face::coord_handedness`]).
+    ///
+    /// For a plane, the normal is the same everywhere. For a cylinder, which
+    /// is a surface with a circle as its u-axis, it points away from the axis
+    /// of the cylinder, if the circle runs counter-clockwise when looking
+    /// against the v-axis.
+    ///
+    /// [`Face::coord_handedness`]: crate::objects::Face::coord_handedness
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Vector<3> {
+        let point = point.into();
+        self.u
+            .tangent_from_path_coords([point.u])
+            .cross(&self.v)
+            .normalize()
+    }
+
     fn path_to_line(&self) -> Line<3> {
         Line::from_origin_and_direction(self.u.origin(), self.v)
     }
 
     /// Project the global point into the surface
+    ///
+    /// See [`SurfaceGeometry::point_to_surface_coords`].
     pub fn project_global_point(&self, point: impl Into<Point<3>>) -> Point<2> {
-        let GlobalPath::Line(line) = self.u else {
-            todo!("Projecting point into non-plane surface is not supported")
-        };
-
-        let plane =
-            Plane::from_parametric(line.origin(), line.direction(), self.v);
-        plane.project_point(point)
+        self.point_to_surface_coords(point)
     }
 
     /// Transform the surface geometry
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform can't be applied to the surface. See
+    /// [`SurfaceGeometry::try_transform`].
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
-        let u = self.u.transform(transform);
+        self.try_transform(transform)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Transform the surface geometry, if possible
+    ///
+    /// See [`GlobalPath::try_transform`].
+    pub fn try_transform(
+        self,
+        transform: &Transform,
+    ) -> Result<Self, TransformPathError> {
+        let u = self.u.try_transform(transform)?;
         let v = transform.transform_vector(&self.v);
-        Self { u, v }
+        Ok(Self { u, v })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::{PI, TAU};
+
+    use fj_math::{assert_approx_eq, Circle, Line, Point, Scalar, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::geometry::{GlobalPath, SurfaceGeometry};
@@ -82,6 +177,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn point_to_surface_coords() {
+        let plane = SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([1., 1., 1.]),
+                Vector::from([0., 2., 0.]),
+            )),
+            v: Vector::from([0., 1., 2.]),
+        };
+        for point in [[2., 4.], [-1., 0.5]] {
+            let point = Point::from(point);
+            let global = plane.point_from_surface_coords(point);
+            assert_approx_eq!(
+                plane.point_to_surface_coords(global),
+                point,
+                1e-12
+            );
+
+            // Points off the plane are projected onto it.
+            let offset = plane.normal_at(point) * 3.;
+            assert_approx_eq!(
+                plane.point_to_surface_coords(global + offset),
+                point,
+                1e-12,
+            );
+        }
+
+        let cylinder = SurfaceGeometry {
+            u: GlobalPath::Circle(Circle::new(
+                [1., 2., 3.],
+                [0., 2., 0.],
+                [0., 0., 2.],
+            )),
+            v: Vector::from([3., 0., 0.]),
+        };
+        for point in [[0., 0.], [1., 2.], [4., -1.], [PI, 0.5]] {
+            let point = Point::from(point);
+            let global = cylinder.point_from_surface_coords(point);
+            assert_approx_eq!(
+                cylinder.point_to_surface_coords(global),
+                point,
+                1e-12,
+            );
+
+            // Points off the cylinder are projected onto it.
+            let offset = cylinder.normal_at(point) * 3.;
+            assert_approx_eq!(
+                cylinder.point_to_surface_coords(global + offset),
+                point,
+                1e-12,
+            );
+        }
+
+        // The u-coordinate wraps around, and always ends up in `[0, 2pi)`.
+        let point = cylinder.point_from_surface_coords([-1., 0.5]);
+        assert_approx_eq!(
+            cylinder.point_to_surface_coords(point),
+            Point::from([TAU - 1., 0.5]),
+            1e-12,
+        );
+        let point = cylinder.point_from_surface_coords([TAU, 0.5]);
+        assert_approx_eq!(
+            cylinder.point_to_surface_coords(point),
+            Point::from([0., 0.5]),
+            1e-12,
+        );
+    }
+
     #[test]
     fn vector_from_surface_coords() {
         let surface = SurfaceGeometry {
@@ -97,4 +260,36 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn normal_at() {
+        let plane = SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([1., 0., 0.]),
+                Vector::from([0., 2., 0.]),
+            )),
+            v: Vector::from([0., 0., 2.]),
+        };
+        for point in [[0., 0.], [2., -3.], [-10., 5.]] {
+            assert_eq!(plane.normal_at(point), Vector::from([1., 0., 0.]));
+        }
+
+        let cylinder = SurfaceGeometry {
+            u: GlobalPath::Circle(Circle::from_center_and_radius(
+                [1., 2., 3.],
+                2.,
+            )),
+            v: Vector::from([0., 0., 3.]),
+        };
+        for point in [[0., 0.], [1., 2.], [4., -1.]] {
+            let normal = cylinder.normal_at(point);
+
+            // The normal points away from the axis.
+            let point_global = cylinder.point_from_surface_coords(point);
+            let offset = point_global - Point::from([1., 2., 3.]);
+            let radial = Vector::from([offset.x, offset.y, Scalar::ZERO]);
+            assert!((normal - radial.normalize()).magnitude() < 1e-12.into());
+            assert_eq!(normal.dot(&cylinder.v), Scalar::ZERO);
+        }
+    }
 }
@@ -90,4 +90,5 @@ pub mod operations;
 pub mod queries;
 pub mod services;
 pub mod storage;
+pub mod text;
 pub mod validate;
@@ -0,0 +1,543 @@
+//! JSON dump of the object graph
+//!
+//! See [`Solid::to_json`].
+
+use std::collections::BTreeSet;
+
+use fj_math::{Point, Vector};
+use serde::Serialize;
+
+use crate::{
+    geometry::{GlobalPath, SurfacePath},
+    objects::{
+        Curve, Cycle, EdgeKind, Face, GlobalEdge, HalfEdge, Region, Shell,
+        Solid, Surface, Vertex,
+    },
+    storage::{Handle, ObjectId},
+};
+
+impl Solid {
+    /// Dump the solid's object graph as JSON
+    ///
+    /// The JSON document has a top-level array for each kind of object, from
+    /// `shells` down to `vertices`. Each object has an `id`, as returned by
+    /// [`Handle::id`], and objects refer to each other by those ids. An object
+    /// that is shared, like an edge between two faces, is listed only once.
+    /// The `solid` entry refers to the shells.
+    ///
+    /// Objects are listed in the order in which they are encountered, when
+    /// walking the solid from its shells down. That order only depends on the
+    /// structure of the solid, not on how it was built, which keeps dumps of
+    /// the same shape comparable across kernel versions. The ids identify the
+    /// objects within one dump. They are not stable across program runs.
+    ///
+    /// Numbers that JSON can't represent, like `NaN`, are written as `null`.
+    ///
+    /// Vertices don't have a position of their own. The dump provides one,
+    /// computed from the first half-edge that starts at the vertex, on the
+    /// surface of its face.
+    pub fn to_json(&self) -> String {
+        let mut dump = Dump::default();
+
+        let shells = self
+            .shells()
+            .map(|shell| dump.shell(shell))
+            .collect::<Vec<_>>();
+
+        let document = Document {
+            solid: SolidEntry { shells },
+            shells: dump.shells.entries,
+            faces: dump.faces.entries,
+            surfaces: dump.surfaces.entries,
+            regions: dump.regions.entries,
+            cycles: dump.cycles.entries,
+            half_edges: dump.half_edges.entries,
+            curves: dump.curves.entries,
+            global_edges: dump.global_edges.entries,
+            vertices: dump.vertices.entries,
+        };
+
+        let mut json = serde_json::to_string_pretty(&document)
+            .expect("Serializing a dump can't fail");
+        json.push('\n');
+
+        json
+    }
+}
+
+#[derive(Default)]
+struct Dump {
+    shells: Entries<Shell, ShellEntry>,
+    faces: Entries<Face, FaceEntry>,
+    surfaces: Entries<Surface, SurfaceEntry>,
+    regions: Entries<Region, RegionEntry>,
+    cycles: Entries<Cycle, CycleEntry>,
+    half_edges: Entries<HalfEdge, HalfEdgeEntry>,
+    curves: Entries<Curve, ObjectEntry>,
+    global_edges: Entries<GlobalEdge, ObjectEntry>,
+    vertices: Entries<Vertex, VertexEntry>,
+}
+
+impl Dump {
+    fn shell(&mut self, shell: &Handle<Shell>) -> Id {
+        if self.shells.contains(shell) {
+            return Id::from(shell);
+        }
+
+        let faces = shell.faces().iter().map(|face| self.face(face)).collect();
+
+        self.shells.insert(shell, |id| ShellEntry { id, faces })
+    }
+
+    fn face(&mut self, face: &Handle<Face>) -> Id {
+        if self.faces.contains(face) {
+            return Id::from(face);
+        }
+
+        let surface = self.surface(face.surface());
+        let region = self.region(face.region(), face.surface());
+
+        self.faces.insert(face, |id| FaceEntry {
+            id,
+            surface,
+            region,
+        })
+    }
+
+    fn surface(&mut self, surface: &Handle<Surface>) -> Id {
+        if self.surfaces.contains(surface) {
+            return Id::from(surface);
+        }
+
+        let geometry = surface.geometry();
+        let u = PathEntry::from(&geometry.u);
+        let v = coords(geometry.v);
+
+        self.surfaces
+            .insert(surface, |id| SurfaceEntry { id, u, v })
+    }
+
+    fn region(
+        &mut self,
+        region: &Handle<Region>,
+        surface: &Handle<Surface>,
+    ) -> Id {
+        if self.regions.contains(region) {
+            return Id::from(region);
+        }
+
+        let exterior = self.cycle(region.exterior(), surface);
+        let interiors = region
+            .interiors()
+            .map(|cycle| self.cycle(cycle, surface))
+            .collect();
+
+        self.regions.insert(region, |id| RegionEntry {
+            id,
+            exterior,
+            interiors,
+        })
+    }
+
+    fn cycle(
+        &mut self,
+        cycle: &Handle<Cycle>,
+        surface: &Handle<Surface>,
+    ) -> Id {
+        if self.cycles.contains(cycle) {
+            return Id::from(cycle);
+        }
+
+        let half_edges = cycle
+            .half_edges()
+            .map(|half_edge| self.half_edge(half_edge, surface))
+            .collect();
+
+        self.cycles
+            .insert(cycle, |id| CycleEntry { id, half_edges })
+    }
+
+    fn half_edge(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        surface: &Handle<Surface>,
+    ) -> Id {
+        if self.half_edges.contains(half_edge) {
+            return Id::from(half_edge);
+        }
+
+        let path = PathEntry::from(half_edge.path());
+        let boundary =
+            half_edge.boundary().inner.map(|point| point.t.into_f64());
+        let kind = KindEntry::from(half_edge.kind());
+
+        let curve = if self.curves.contains(half_edge.curve()) {
+            Id::from(half_edge.curve())
+        } else {
+            self.curves
+                .insert(half_edge.curve(), |id| ObjectEntry { id })
+        };
+        let global_edge = if self.global_edges.contains(half_edge.global_form())
+        {
+            Id::from(half_edge.global_form())
+        } else {
+            self.global_edges
+                .insert(half_edge.global_form(), |id| ObjectEntry { id })
+        };
+        let start_vertex = if self.vertices.contains(half_edge.start_vertex()) {
+            Id::from(half_edge.start_vertex())
+        } else {
+            let position = surface
+                .geometry()
+                .point_from_surface_coords(half_edge.start_position());
+            self.vertices
+                .insert(half_edge.start_vertex(), |id| VertexEntry {
+                    id,
+                    position: point(position),
+                })
+        };
+
+        self.half_edges.insert(half_edge, |id| HalfEdgeEntry {
+            id,
+            path,
+            boundary,
+            curve,
+            start_vertex,
+            global_edge,
+            kind,
+        })
+    }
+}
+
+/// The dump entries for all objects of one kind
+struct Entries<T, E> {
+    ids: BTreeSet<ObjectId>,
+    entries: Vec<E>,
+    _object: std::marker::PhantomData<T>,
+}
+
+impl<T, E> Entries<T, E> {
+    fn contains(&self, handle: &Handle<T>) -> bool {
+        self.ids.contains(&handle.id())
+    }
+
+    fn insert(
+        &mut self,
+        handle: &Handle<T>,
+        entry: impl FnOnce(Id) -> E,
+    ) -> Id {
+        let id = Id::from(handle);
+        self.ids.insert(handle.id());
+        self.entries.push(entry(id));
+        id
+    }
+}
+
+impl<T, E> Default for Entries<T, E> {
+    fn default() -> Self {
+        Self {
+            ids: BTreeSet::new(),
+            entries: Vec::new(),
+            _object: std::marker::PhantomData,
+        }
+    }
+}
+
+/// The id of an object in the dump
+#[derive(Clone, Copy, Serialize)]
+#[serde(transparent)]
+struct Id(u64);
+
+impl<T> From<&Handle<T>> for Id {
+    fn from(handle: &Handle<T>) -> Self {
+        Self(handle.id().0)
+    }
+}
+
+#[derive(Serialize)]
+struct Document {
+    solid: SolidEntry,
+    shells: Vec<ShellEntry>,
+    faces: Vec<FaceEntry>,
+    surfaces: Vec<SurfaceEntry>,
+    regions: Vec<RegionEntry>,
+    cycles: Vec<CycleEntry>,
+    half_edges: Vec<HalfEdgeEntry>,
+    curves: Vec<ObjectEntry>,
+    global_edges: Vec<ObjectEntry>,
+    vertices: Vec<VertexEntry>,
+}
+
+#[derive(Serialize)]
+struct SolidEntry {
+    shells: Vec<Id>,
+}
+
+#[derive(Serialize)]
+struct ShellEntry {
+    id: Id,
+    faces: Vec<Id>,
+}
+
+#[derive(Serialize)]
+struct FaceEntry {
+    id: Id,
+    surface: Id,
+    region: Id,
+}
+
+#[derive(Serialize)]
+struct SurfaceEntry {
+    id: Id,
+    u: PathEntry,
+    v: Vec<f64>,
+}
+
+#[derive(Serialize)]
+struct RegionEntry {
+    id: Id,
+    exterior: Id,
+    interiors: Vec<Id>,
+}
+
+#[derive(Serialize)]
+struct CycleEntry {
+    id: Id,
+    half_edges: Vec<Id>,
+}
+
+#[derive(Serialize)]
+struct HalfEdgeEntry {
+    id: Id,
+    path: PathEntry,
+    boundary: [f64; 2],
+    curve: Id,
+    start_vertex: Id,
+    global_edge: Id,
+    kind: KindEntry,
+}
+
+/// An object that has no data besides its identity
+#[derive(Serialize)]
+struct ObjectEntry {
+    id: Id,
+}
+
+#[derive(Serialize)]
+struct VertexEntry {
+    id: Id,
+    position: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum PathEntry {
+    Bezier {
+        control_points: Vec<Vec<f64>>,
+    },
+    BSpline {
+        degree: usize,
+        control_points: Vec<Vec<f64>>,
+    },
+    Circle {
+        center: Vec<f64>,
+        a: Vec<f64>,
+        b: Vec<f64>,
+    },
+    Ellipse {
+        center: Vec<f64>,
+        major: Vec<f64>,
+        minor: Vec<f64>,
+    },
+    Helix {
+        center: Vec<f64>,
+        a: Vec<f64>,
+        b: Vec<f64>,
+        advance: Vec<f64>,
+    },
+    Line {
+        origin: Vec<f64>,
+        direction: Vec<f64>,
+    },
+}
+
+impl From<&SurfacePath> for PathEntry {
+    fn from(path: &SurfacePath) -> Self {
+        match path {
+            SurfacePath::Bezier(bezier) => Self::Bezier {
+                control_points: points(bezier.control_points()),
+            },
+            SurfacePath::BSpline(bspline) => Self::BSpline {
+                degree: bspline.degree(),
+                control_points: points(
+                    bspline.control_points().iter().copied(),
+                ),
+            },
+            SurfacePath::Circle(circle) => Self::Circle {
+                center: point(circle.center()),
+                a: coords(circle.a()),
+                b: coords(circle.b()),
+            },
+            SurfacePath::Ellipse(ellipse) => Self::Ellipse {
+                center: point(ellipse.center()),
+                major: coords(ellipse.major()),
+                minor: coords(ellipse.minor()),
+            },
+            SurfacePath::Line(line) => Self::Line {
+                origin: point(line.origin()),
+                direction: coords(line.direction()),
+            },
+        }
+    }
+}
+
+impl From<&GlobalPath> for PathEntry {
+    fn from(path: &GlobalPath) -> Self {
+        match path {
+            GlobalPath::Bezier(bezier) => Self::Bezier {
+                control_points: points(bezier.control_points()),
+            },
+            GlobalPath::BSpline(bspline) => Self::BSpline {
+                degree: bspline.degree(),
+                control_points: points(
+                    bspline.control_points().iter().copied(),
+                ),
+            },
+            GlobalPath::Circle(circle) => Self::Circle {
+                center: point(circle.center()),
+                a: coords(circle.a()),
+                b: coords(circle.b()),
+            },
+            GlobalPath::Ellipse(ellipse) => Self::Ellipse {
+                center: point(ellipse.center()),
+                major: coords(ellipse.major()),
+                minor: coords(ellipse.minor()),
+            },
+            GlobalPath::Helix(helix) => Self::Helix {
+                center: point(helix.center()),
+                a: coords(helix.a()),
+                b: coords(helix.b()),
+                advance: coords(helix.advance()),
+            },
+            GlobalPath::Line(line) => Self::Line {
+                origin: point(line.origin()),
+                direction: coords(line.direction()),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+enum KindEntry {
+    Real,
+    Construction,
+}
+
+impl From<EdgeKind> for KindEntry {
+    fn from(kind: EdgeKind) -> Self {
+        match kind {
+            EdgeKind::Real => Self::Real,
+            EdgeKind::Construction => Self::Construction,
+        }
+    }
+}
+
+fn points<const D: usize>(
+    points: impl IntoIterator<Item = Point<D>>,
+) -> Vec<Vec<f64>> {
+    points.into_iter().map(point).collect()
+}
+
+fn point<const D: usize>(point: Point<D>) -> Vec<f64> {
+    coords(point.coords)
+}
+
+fn coords<const D: usize>(vector: Vector<D>) -> Vec<f64> {
+    vector
+        .components
+        .map(|component| component.into_f64())
+        .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use serde_json::Value;
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    #[test]
+    fn to_json() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut cube = |points: [[f64; 2]; 4]| {
+            let sketch = Sketch::empty()
+                .add_region(
+                    Region::polygon(points, &mut services)
+                        .insert(&mut services),
+                )
+                .insert(&mut services);
+            let surface = services.objects.surfaces.xy_plane();
+            (sketch, surface).sweep([0., 0., 1.], &mut services)
+        };
+
+        let a = cube([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+        let b = cube([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+
+        let dump = serde_json::from_str::<Value>(&a.to_json())?;
+        let entries = |kind: &str| dump[kind].as_array().unwrap().clone();
+        let ids = |kind: &str| {
+            entries(kind)
+                .iter()
+                .map(|entry| entry["id"].as_u64().unwrap())
+                .collect::<BTreeSet<_>>()
+        };
+
+        assert_eq!(
+            dump["solid"]["shells"],
+            Value::from(Vec::from_iter(ids("shells")))
+        );
+        assert_eq!(entries("half_edges").len(), 24);
+        assert_eq!(entries("vertices").len(), 8);
+
+        // Shared objects are listed once, and referred to by their id.
+        let global_edges = entries("half_edges")
+            .iter()
+            .map(|half_edge| half_edge["global_edge"].as_u64().unwrap())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(global_edges.len(), 12);
+        assert_eq!(global_edges, ids("global_edges"));
+
+        // Apart from the ids, the dump doesn't depend on the identity of the
+        // objects.
+        let without_ids = |json: &str| -> anyhow::Result<Value> {
+            let mut dump = serde_json::from_str::<Value>(json)?;
+            strip_ids(&mut dump);
+            Ok(dump)
+        };
+        assert_eq!(without_ids(&a.to_json())?, without_ids(&b.to_json())?);
+
+        Ok(())
+    }
+
+    /// Replace all ids with `null`
+    ///
+    /// Coordinates are written as floating-point numbers, which leaves the ids
+    /// as the only integers in the dump of a solid without B-splines.
+    fn strip_ids(value: &mut Value) {
+        match value {
+            Value::Number(number) if number.is_u64() => *value = Value::Null,
+            Value::Array(values) => values.iter_mut().for_each(strip_ids),
+            Value::Object(entries) => {
+                entries.values_mut().for_each(strip_ids);
+            }
+            _ => {}
+        }
+    }
+}
@@ -71,9 +71,9 @@ impl Cycle {
     /// two possible windings, depending on the direction you look at the
     /// surface that the cycle is defined on from.
     pub fn winding(&self) -> Winding {
-        // The cycle could be made up of one or two circles. If that is the
-        // case, the winding of the cycle is determined by the winding of the
-        // first circle.
+        // The cycle could be made up of one or two circles or ellipses. If that
+        // is the case, the winding of the cycle is determined by the winding of
+        // the first of those.
         if self.half_edges.len() < 3 {
             let first = self
                 .half_edges()
@@ -83,13 +83,18 @@ impl Cycle {
             let [a, b] = first.boundary().inner;
             let edge_direction_positive = a < b;
 
-            let circle = match first.path() {
-                SurfacePath::Circle(circle) => circle,
-                SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
+            let [a, b] = match first.path() {
+                SurfacePath::Circle(circle) => [circle.a(), circle.b()],
+                SurfacePath::Ellipse(ellipse) => {
+                    [ellipse.major(), ellipse.minor()]
+                }
+                SurfacePath::Bezier(_)
+                | SurfacePath::BSpline(_)
+                | SurfacePath::Line(_) => unreachable!(
+                    "Invalid cycle: less than 3 edges, but not all are curved"
                 ),
             };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+            let cross_positive = a.cross2d(&b) > Scalar::ZERO;
 
             if edge_direction_positive == cross_positive {
                 return Winding::Ccw;
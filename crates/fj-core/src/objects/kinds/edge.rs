@@ -45,6 +45,7 @@ pub struct HalfEdge {
     curve: HandleWrapper<Curve>,
     start_vertex: HandleWrapper<Vertex>,
     global_form: HandleWrapper<GlobalEdge>,
+    kind: EdgeKind,
 }
 
 impl HalfEdge {
@@ -55,6 +56,7 @@ impl HalfEdge {
         curve: Handle<Curve>,
         start_vertex: Handle<Vertex>,
         global_form: Handle<GlobalEdge>,
+        kind: EdgeKind,
     ) -> Self {
         Self {
             path,
@@ -62,12 +64,13 @@ impl HalfEdge {
             curve: curve.into(),
             start_vertex: start_vertex.into(),
             global_form: global_form.into(),
+            kind,
         }
     }
 
     /// Access the curve that defines the half-edge's geometry
-    pub fn path(&self) -> SurfacePath {
-        self.path
+    pub fn path(&self) -> &SurfacePath {
+        &self.path
     }
 
     /// Access the boundary points of the half-edge on the curve
@@ -99,6 +102,36 @@ impl HalfEdge {
     pub fn global_form(&self) -> &Handle<GlobalEdge> {
         &self.global_form
     }
+
+    /// Access the kind of the half-edge
+    pub fn kind(&self) -> EdgeKind {
+        self.kind
+    }
+
+    /// Indicate whether the half-edge is construction geometry
+    ///
+    /// See [`EdgeKind::Construction`].
+    pub fn is_construction(&self) -> bool {
+        self.kind == EdgeKind::Construction
+    }
+}
+
+/// The kind of a [`HalfEdge`]
+///
+/// The kind doesn't change what a half-edge means geometrically, and
+/// validation treats all kinds the same. It tells consumers of the
+/// approximation, like exporters or the viewer, how to present the edge.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum EdgeKind {
+    /// An edge that is part of the shape
+    #[default]
+    Real,
+
+    /// An edge that only serves as a reference, like a centerline
+    ///
+    /// Exporters can leave construction edges out, and the viewer draws them
+    /// differently from real edges.
+    Construction,
 }
 
 /// An undirected edge, defined in global (3D) coordinates
@@ -3,7 +3,7 @@ use std::collections::{btree_set, BTreeSet};
 use fj_math::Winding;
 
 use crate::{
-    objects::{Region, Surface},
+    objects::{HalfEdge, Region, Surface},
     storage::Handle,
 };
 
@@ -53,6 +53,16 @@ impl Face {
         &self.region
     }
 
+    /// Access the half-edges that bound the face
+    ///
+    /// Returns the half-edges of the exterior cycle, followed by those of the
+    /// interior cycles.
+    pub fn half_edges(&self) -> impl Iterator<Item = &Handle<HalfEdge>> {
+        self.region
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+    }
+
     /// Determine handed-ness of the face's front-side coordinate system
     ///
     /// A face is defined on a surface, which has a coordinate system. Since
@@ -82,6 +92,11 @@ impl FaceSet {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Access the faces in the set
+    pub fn iter(&self) -> btree_set::Iter<'_, Handle<Face>> {
+        self.inner.iter()
+    }
 }
 
 impl Extend<Handle<Face>> for FaceSet {
@@ -1,5 +1,5 @@
 //! A single, continues 2d region
-use fj_interop::mesh::Color;
+use fj_interop::mesh::{Color, Material};
 
 use crate::{objects::Cycle, storage::Handle};
 
@@ -16,6 +16,7 @@ pub struct Region {
     exterior: Handle<Cycle>,
     interiors: Vec<Handle<Cycle>>,
     color: Option<Color>,
+    material: Option<Material>,
 }
 
 impl Region {
@@ -24,11 +25,13 @@ impl Region {
         exterior: Handle<Cycle>,
         interiors: impl IntoIterator<Item = Handle<Cycle>>,
         color: Option<Color>,
+        material: Option<Material>,
     ) -> Self {
         Self {
             exterior,
             interiors: interiors.into_iter().collect(),
             color,
+            material,
         }
     }
 
@@ -53,4 +56,9 @@ impl Region {
     pub fn color(&self) -> Option<Color> {
         self.color
     }
+
+    /// Access the material of the region
+    pub fn material(&self) -> Option<&Material> {
+        self.material.as_ref()
+    }
 }
@@ -1,5 +1,7 @@
 use std::collections::BTreeSet;
 
+use fj_math::{Point, Scalar, Vector};
+
 use crate::{
     objects::{Face, FaceSet, Region, Surface},
     operations::Insert,
@@ -39,4 +41,102 @@ impl Sketch {
             })
             .collect()
     }
+
+    /// Compute the centroid of the sketch
+    ///
+    /// This is the centroid of the area that the sketch's regions cover, not
+    /// the average of its vertices. Larger regions pull the centroid towards
+    /// them more, and holes pull it away.
+    ///
+    /// The result is exact for sketches that are bounded by lines, and
+    /// computed numerically for all others.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the sketch has no area.
+    pub fn centroid(&self) -> Point<2> {
+        let mut area = Scalar::ZERO;
+        let mut moment = Vector::from([0., 0.]);
+
+        for region in &self.regions {
+            let (region_area, region_moment) = region
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges())
+                .map(|half_edge| {
+                    half_edge.path().area_and_moment(half_edge.boundary())
+                })
+                .fold(
+                    (Scalar::ZERO, Vector::from([0., 0.])),
+                    |(area, moment), (a, m)| (area + a, moment + m),
+                );
+
+            // The area is negative, if the exterior of the region runs
+            // clockwise. Then the interiors run counter-clockwise, and all
+            // signs are reversed.
+            let sign = region_area.sign().to_scalar();
+            area += region_area * sign;
+            moment = moment + region_moment * sign;
+        }
+
+        assert!(
+            area > Scalar::ZERO,
+            "Can't compute centroid of empty sketch"
+        );
+
+        Point::origin() + moment / area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{assert_approx_eq, Point};
+
+    use crate::{
+        objects::{Cycle, Region, Sketch},
+        operations::{BuildCycle, BuildRegion, Insert, Reverse, UpdateRegion},
+        services::Services,
+    };
+
+    #[test]
+    fn centroid() {
+        let mut services = Services::new();
+
+        let square = |[x, y]: [f64; 2], size: f64, services: &mut Services| {
+            Region::polygon(
+                [[x, y], [x + size, y], [x + size, y + size], [x, y + size]],
+                services,
+            )
+            .insert(services)
+        };
+
+        let sketch = Sketch::new([square([0., 0.], 1., &mut services)]);
+        assert_approx_eq!(sketch.centroid(), Point::from([0.5, 0.5]), 1e-12);
+
+        // The direction of the exterior doesn't matter.
+        let clockwise = Region::polygon(
+            [[0., 0.], [0., 1.], [1., 1.], [1., 0.]],
+            &mut services,
+        )
+        .insert(&mut services);
+        let sketch = Sketch::new([clockwise]);
+        assert_approx_eq!(sketch.centroid(), Point::from([0.5, 0.5]), 1e-12);
+
+        // The large square has 9 times the area of the small one, so the
+        // centroid is much closer to its center.
+        let sketch = Sketch::new([
+            square([0., 0.], 3., &mut services),
+            square([4., 0.], 1., &mut services),
+        ]);
+        assert_approx_eq!(sketch.centroid(), Point::from([1.8, 1.4]), 1e-12);
+
+        // A centered hole doesn't move the centroid of a circle.
+        let hole = Cycle::circle([1., 2.], 1., &mut services)
+            .reverse(&mut services)
+            .insert(&mut services);
+        let disc = Region::circle([1., 2.], 2., &mut services)
+            .add_interiors([hole])
+            .insert(&mut services);
+        let centroid = Sketch::new([disc]).centroid();
+        assert_approx_eq!(centroid, Point::from([1., 2.]), 1e-9);
+    }
 }
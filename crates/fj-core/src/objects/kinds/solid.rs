@@ -1,6 +1,9 @@
 use std::collections::BTreeSet;
 
-use crate::{objects::Shell, storage::Handle};
+use crate::{
+    objects::{Face, HalfEdge, Shell},
+    storage::Handle,
+};
 
 /// A 3-dimensional shape, built from [`Shell`]s. Many Solids will contains only
 /// one shell, but if the Solid contains cavities they will be represented by a
@@ -27,4 +30,60 @@ impl Solid {
     pub fn shells(&self) -> impl Iterator<Item = &Handle<Shell>> {
         self.shells.iter()
     }
+
+    /// Access the faces of all of the solid's shells
+    pub fn faces(&self) -> impl Iterator<Item = &Handle<Face>> {
+        self.shells().flat_map(|shell| shell.faces())
+    }
+
+    /// Access the half-edges that bound all of the solid's faces
+    ///
+    /// Most edges are shared between two faces, and each of those faces has its
+    /// own half-edge. Use [`HalfEdge::global_form`] to find the half-edges that
+    /// belong to the same edge.
+    pub fn half_edges(&self) -> impl Iterator<Item = &Handle<HalfEdge>> {
+        self.faces().flat_map(|face| face.half_edges())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+    };
+
+    #[test]
+    fn faces_and_half_edges() {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cube = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        assert_eq!(cube.faces().count(), 6);
+        for face in cube.faces() {
+            assert_eq!(face.half_edges().count(), 4);
+        }
+
+        // Each edge of the cube is shared by two faces.
+        assert_eq!(cube.half_edges().count(), 24);
+        let edges = cube
+            .half_edges()
+            .map(|half_edge| half_edge.global_form().id())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(edges.len(), 12);
+    }
 }
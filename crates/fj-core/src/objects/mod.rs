@@ -39,6 +39,7 @@
 //!
 //! [`Handle`]: crate::storage::Handle
 
+mod dump;
 mod kinds;
 mod object;
 mod set;
@@ -48,7 +49,7 @@ pub use self::{
     kinds::{
         curve::Curve,
         cycle::{Cycle, HalfEdgesOfCycle},
-        edge::{GlobalEdge, HalfEdge},
+        edge::{EdgeKind, GlobalEdge, HalfEdge},
         face::{Face, FaceSet, Handedness},
         region::Region,
         shell::Shell,
@@ -4,7 +4,7 @@ use crate::{
         Sketch, Solid, Surface, Vertex,
     },
     storage::{Handle, HandleWrapper, ObjectId},
-    validate::{Validate, ValidationError},
+    validate::{Validate, ValidationConfig, ValidationError},
 };
 
 macro_rules! object {
@@ -32,11 +32,37 @@ macro_rules! object {
                 }
             }
 
-            /// Validate the object
+            /// Validate the object using default configuration
             pub fn validate(&self, errors: &mut Vec<ValidationError>) {
+                self.validate_with_config(&ValidationConfig::default(), errors)
+            }
+
+            /// Validate the object
+            pub fn validate_with_config(
+                &self,
+                config: &ValidationConfig,
+                errors: &mut Vec<ValidationError>,
+            ) {
+                match self {
+                    $(
+                        Self::$ty(object) => {
+                            object.validate_with_config(config, errors)
+                        }
+                    )*
+                }
+            }
+
+            /// Remove the object from its respective store
+            ///
+            /// See [`Store::remove`] for details.
+            ///
+            /// [`Store::remove`]: crate::storage::Store::remove
+            pub fn remove(self, objects: &mut Objects) {
                 match self {
                     $(
-                        Self::$ty(object) => object.validate(errors),
+                        Self::$ty(handle) => {
+                            objects.$store.remove(&handle.0);
+                        }
                     )*
                 }
             }
@@ -2,12 +2,12 @@ use fj_math::Vector;
 
 use crate::{
     geometry::{GlobalPath, SurfaceGeometry},
-    storage::{Handle, Store},
+    storage::{Handle, Iter, Store},
 };
 
 use super::{
-    Curve, Cycle, Face, GlobalEdge, HalfEdge, Region, Shell, Sketch, Solid,
-    Surface, Vertex,
+    BehindHandle, Curve, Cycle, Face, GlobalEdge, HalfEdge, Object, Region,
+    Shell, Sketch, Solid, Surface, Vertex,
 };
 
 /// The available object stores
@@ -52,6 +52,33 @@ impl Objects {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Iterate over all objects in the stores
+    pub fn all(&self) -> impl Iterator<Item = Object<BehindHandle>> + '_ {
+        let curves = self.curves.iter().map(Object::from);
+        let cycles = self.cycles.iter().map(Object::from);
+        let faces = self.faces.iter().map(Object::from);
+        let global_edges = self.global_edges.iter().map(Object::from);
+        let half_edges = self.half_edges.iter().map(Object::from);
+        let regions = self.regions.iter().map(Object::from);
+        let shells = self.shells.iter().map(Object::from);
+        let sketches = self.sketches.iter().map(Object::from);
+        let solids = self.solids.iter().map(Object::from);
+        let surfaces = self.surfaces.iter().map(Object::from);
+        let vertices = self.vertices.iter().map(Object::from);
+
+        curves
+            .chain(cycles)
+            .chain(faces)
+            .chain(global_edges)
+            .chain(half_edges)
+            .chain(regions)
+            .chain(shells)
+            .chain(sketches)
+            .chain(solids)
+            .chain(surfaces)
+            .chain(vertices)
+    }
 }
 
 /// Store for [`Surface`]s
@@ -75,6 +102,16 @@ impl Surfaces {
         self.store.insert(handle, surface);
     }
 
+    /// Remove an object from the store
+    pub fn remove(&mut self, handle: &Handle<Surface>) {
+        self.store.remove(handle);
+    }
+
+    /// Iterate over all objects in the store
+    pub fn iter(&self) -> Iter<Surface> {
+        self.store.iter()
+    }
+
     /// Access the xy-plane
     pub fn xy_plane(&self) -> Handle<Surface> {
         self.xy_plane.clone()
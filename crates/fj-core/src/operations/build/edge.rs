@@ -1,9 +1,9 @@
 use fj_interop::ext::ArrayExt;
-use fj_math::{Arc, Point, Scalar};
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
     geometry::{BoundaryOnCurve, SurfacePath},
-    objects::{Curve, GlobalEdge, HalfEdge, Vertex},
+    objects::{Curve, EdgeKind, GlobalEdge, HalfEdge, Vertex},
     operations::Insert,
     services::Services,
 };
@@ -20,7 +20,14 @@ pub trait BuildHalfEdge {
         let start_vertex = Vertex::new().insert(services);
         let global_form = GlobalEdge::new().insert(services);
 
-        HalfEdge::new(path, boundary, curve, start_vertex, global_form)
+        HalfEdge::new(
+            path,
+            boundary,
+            curve,
+            start_vertex,
+            global_form,
+            EdgeKind::default(),
+        )
     }
 
     /// Create an arc
@@ -39,12 +46,10 @@ pub trait BuildHalfEdge {
             panic!("arc angle must be in the range (-2pi, 2pi) radians");
         }
 
-        let arc = Arc::from_endpoints_and_angle(start, end, angle_rad);
-
-        let path =
-            SurfacePath::circle_from_center_and_radius(arc.center, arc.radius);
-        let boundary =
-            [arc.start_angle, arc.end_angle].map(|coord| Point::from([coord]));
+        let (path, boundary) = SurfacePath::arc_from_endpoints_and_angle(
+            [start.into(), end.into()],
+            angle_rad,
+        );
 
         HalfEdge::unjoined(path, boundary, services)
     }
@@ -62,6 +67,24 @@ pub trait BuildHalfEdge {
         HalfEdge::unjoined(path, boundary, services)
     }
 
+    /// Create an ellipse
+    ///
+    /// See [`fj_math::Ellipse::new`] for the requirements on `major` and
+    /// `minor`.
+    fn ellipse(
+        center: impl Into<Point<2>>,
+        major: impl Into<Vector<2>>,
+        minor: impl Into<Vector<2>>,
+        services: &mut Services,
+    ) -> HalfEdge {
+        let path =
+            SurfacePath::ellipse_from_center_and_axes(center, major, minor);
+        let boundary =
+            [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
+
+        HalfEdge::unjoined(path, boundary, services)
+    }
+
     /// Create a line segment
     fn line_segment(
         points_surface: [impl Into<Point<2>>; 2],
@@ -14,10 +14,11 @@ use crate::{
 
 /// Build a [`Face`]
 pub trait BuildFace {
-    /// Build a face with an empty exterior, no interiors, and no color
+    /// Build a face with an empty exterior, no interiors, no color, and no
+    /// material
     fn unbound(surface: Handle<Surface>, services: &mut Services) -> Face {
         let exterior = Cycle::empty().insert(services);
-        let region = Region::new(exterior, [], None).insert(services);
+        let region = Region::new(exterior, [], None, None).insert(services);
         Face::new(surface, region)
     }
 
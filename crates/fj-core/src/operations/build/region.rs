@@ -13,8 +13,9 @@ pub trait BuildRegion {
         let exterior = Cycle::empty().insert(services);
         let interiors = [];
         let color = None;
+        let material = None;
 
-        Region::new(exterior, interiors, color)
+        Region::new(exterior, interiors, color, material)
     }
 
     /// Build a circle
@@ -24,7 +25,7 @@ pub trait BuildRegion {
         services: &mut Services,
     ) -> Region {
         let exterior = Cycle::circle(center, radius, services).insert(services);
-        Region::new(exterior, [], None)
+        Region::new(exterior, [], None, None)
     }
 
     /// Build a polygon
@@ -35,7 +36,7 @@ pub trait BuildRegion {
         Ps::IntoIter: Clone + ExactSizeIterator,
     {
         let exterior = Cycle::polygon(points, services).insert(services);
-        Region::new(exterior, [], None)
+        Region::new(exterior, [], None, None)
     }
 }
 
@@ -1,10 +1,18 @@
-use fj_math::Point;
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
-    objects::{Face, Shell},
+    algorithms::approx::Tolerance,
+    geometry::SurfacePath,
+    objects::{
+        Curve, Cycle, EdgeKind, Face, GlobalEdge, HalfEdge, Region, Shell,
+        Surface, Vertex,
+    },
     operations::{
-        update::region::UpdateRegion, BuildFace, Insert, IsInserted,
-        IsInsertedNo, IsInsertedYes, JoinCycle, Polygon, UpdateFace,
+        update::region::UpdateRegion, BuildFace, BuildSurface, Insert,
+        IsInserted, IsInsertedNo, IsInsertedYes, JoinCycle, Polygon,
+        UpdateFace,
     },
     services::Services,
 };
@@ -117,10 +125,255 @@ pub trait BuildShell {
             cbd,
         }
     }
+
+    /// Build a sphere from the provided center and radius
+    ///
+    /// The sphere is approximated by a geodesic polyhedron, as there is no
+    /// surface geometry yet that could represent it exactly. Starting from an
+    /// icosahedron, the triangles are subdivided until none of them deviates
+    /// from the sphere by more than the tolerance. For a relative tolerance,
+    /// the size of the feature is the radius of the sphere.
+    ///
+    /// All faces are triangles, whose counter-clockwise sides are outside.
+    fn sphere(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Shell {
+        let center = center.into();
+        let radius = radius.into();
+        let tolerance = tolerance.into().for_feature_size(radius);
+
+        assert!(radius > Scalar::ZERO, "Radius of sphere must be positive");
+
+        let (points, triangles) = geodesic_sphere(radius, tolerance);
+        let points = points
+            .into_iter()
+            .map(|point| center + point.coords * radius)
+            .collect::<Vec<_>>();
+
+        polyhedron(&points, triangles.into_iter().map(Vec::from), services)
+    }
+
+    /// Build a cone from the provided radius and height
+    ///
+    /// The base of the cone is centered on the origin of the xy-plane, and its
+    /// apex is located at `height` on the z-axis. All faces that meet at the
+    /// apex share a single vertex there.
+    ///
+    /// # Implementation Note
+    ///
+    /// There is no surface geometry yet that could represent the lateral
+    /// surface of a cone, so the cone is approximated by a pyramid with a
+    /// regular polygon as its base. The number of sides of that polygon is
+    /// chosen, such that it deviates from the circle at the base of the cone
+    /// by no more than the tolerance. For a relative tolerance, the size of the
+    /// feature is the radius of the cone.
+    fn cone(
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Shell {
+        let radius = radius.into();
+        let height = height.into();
+        let tolerance = tolerance.into().for_feature_size(radius);
+
+        assert!(radius > Scalar::ZERO, "Radius of cone must be positive");
+        assert!(height > Scalar::ZERO, "Height of cone must be positive");
+
+        let num_sides = Scalar::max(
+            Scalar::PI / (Scalar::ONE - (tolerance / radius)).acos(),
+            3.,
+        )
+        .ceil()
+        .into_u64() as usize;
+
+        let points = (0..num_sides)
+            .map(|i| {
+                let angle = Scalar::TAU / num_sides as f64 * i as f64;
+                let (sin, cos) = angle.sin_cos();
+                Point::from([radius * cos, radius * sin, Scalar::ZERO])
+            })
+            .chain([Point::from([Scalar::ZERO, Scalar::ZERO, height])])
+            .collect::<Vec<_>>();
+        let apex = num_sides;
+
+        // The base is seen from below, so its points are reversed.
+        let base = (0..num_sides).rev().collect::<Vec<_>>();
+        let sides = (0..num_sides).map(|i| vec![i, (i + 1) % num_sides, apex]);
+
+        polyhedron(&points, [base].into_iter().chain(sides), services)
+    }
 }
 
 impl BuildShell for Shell {}
 
+/// Build a polyhedron from the provided points and faces
+///
+/// Each face is a planar, convex polygon, defined by the indices of its points.
+/// The points must be listed counter-clockwise, as seen from the outside.
+/// Faces that share two consecutive points, share the edge between them.
+fn polyhedron(
+    points: &[Point<3>],
+    faces: impl IntoIterator<Item = Vec<usize>>,
+    services: &mut Services,
+) -> Shell {
+    let vertices = points
+        .iter()
+        .map(|_| Vertex::new().insert(services))
+        .collect::<Vec<_>>();
+    let mut curves = BTreeMap::new();
+    let mut global_edges = BTreeMap::new();
+
+    let faces = faces
+        .into_iter()
+        .map(|face| {
+            let (surface, _) = Surface::plane_from_points(
+                [face[0], face[1], face[2]].map(|index| points[index]),
+            );
+            let points_surface = face
+                .iter()
+                .map(|&index| {
+                    surface.geometry().project_global_point(points[index])
+                })
+                .collect::<Vec<_>>();
+
+            let half_edges = (0..face.len())
+                .map(|i| {
+                    let j = (i + 1) % face.len();
+                    let [start, end] = [face[i], face[j]];
+
+                    // Each edge is shared by two faces, which traverse it in
+                    // opposite directions.
+                    let key = [start.min(end), start.max(end)];
+                    let curve = curves
+                        .entry(key)
+                        .or_insert_with(|| Curve::new().insert(services))
+                        .clone();
+                    let global_form = global_edges
+                        .entry(key)
+                        .or_insert_with(|| GlobalEdge::new().insert(services))
+                        .clone();
+
+                    let boundary = [[0.], [1.]].map(Point::from);
+                    let path = SurfacePath::line_from_points_with_coords([
+                        (boundary[0], points_surface[i]),
+                        (boundary[1], points_surface[j]),
+                    ]);
+
+                    HalfEdge::new(
+                        path,
+                        boundary,
+                        curve,
+                        vertices[start].clone(),
+                        global_form,
+                        EdgeKind::default(),
+                    )
+                    .insert(services)
+                })
+                .collect::<Vec<_>>();
+
+            let exterior = Cycle::new(half_edges).insert(services);
+            let region = Region::new(exterior, [], None, None).insert(services);
+            Face::new(surface.insert(services), region).insert(services)
+        })
+        .collect::<Vec<_>>();
+
+    Shell::new(faces)
+}
+
+/// Compute the points and triangles of a geodesic polyhedron
+///
+/// The points are located on the unit sphere. The polyhedron is subdivided,
+/// until its faces deviate from a sphere of the given radius by no more than
+/// `tolerance`.
+fn geodesic_sphere(
+    radius: Scalar,
+    tolerance: Scalar,
+) -> (Vec<Point<3>>, Vec<[usize; 3]>) {
+    let t = (1. + 5_f64.sqrt()) / 2.;
+
+    let mut points = [
+        [-1., t, 0.],
+        [1., t, 0.],
+        [-1., -t, 0.],
+        [1., -t, 0.],
+        [0., -1., t],
+        [0., 1., t],
+        [0., -1., -t],
+        [0., 1., -t],
+        [t, 0., -1.],
+        [t, 0., 1.],
+        [-t, 0., -1.],
+        [-t, 0., 1.],
+    ]
+    .map(|point| Point::origin() + Vector::from(point).normalize())
+    .to_vec();
+    let mut triangles = vec![
+        [0, 11, 5],
+        [0, 5, 1],
+        [0, 1, 7],
+        [0, 7, 10],
+        [0, 10, 11],
+        [1, 5, 9],
+        [5, 11, 4],
+        [11, 10, 2],
+        [10, 7, 6],
+        [7, 1, 8],
+        [3, 9, 4],
+        [3, 4, 2],
+        [3, 2, 6],
+        [3, 6, 8],
+        [3, 8, 9],
+        [4, 9, 5],
+        [2, 4, 11],
+        [6, 2, 10],
+        [8, 6, 7],
+        [9, 8, 1],
+    ];
+
+    loop {
+        // The point of a triangle that is farthest from the sphere, is where
+        // the triangle's plane is closest to the center.
+        let deviation = triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let [a, b, c] = [a, b, c].map(|index| points[index]);
+                let normal = (b - a).cross(&(c - a)).normalize();
+                Scalar::ONE - normal.dot(&a.coords)
+            })
+            .fold(Scalar::ZERO, |max, deviation| max.max(deviation))
+            * radius;
+
+        if deviation <= tolerance {
+            break;
+        }
+
+        // Split each triangle into four, by adding a point at the middle of
+        // each edge.
+        let mut middles = BTreeMap::new();
+        let mut middle = |a: usize, b: usize| {
+            *middles.entry([a.min(b), a.max(b)]).or_insert_with(|| {
+                let middle = points[a].coords + points[b].coords;
+                points.push(Point::origin() + middle.normalize());
+                points.len() - 1
+            })
+        };
+
+        triangles = triangles
+            .into_iter()
+            .flat_map(|[a, b, c]| {
+                let [ab, bc, ca] = [middle(a, b), middle(b, c), middle(c, a)];
+                [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+            })
+            .collect();
+    }
+
+    (points, triangles)
+}
+
 /// A tetrahedron
 ///
 /// A tetrahedron is constructed from 4 points and has 4 faces. For the purpose
@@ -144,3 +397,111 @@ pub struct TetrahedronShell<I: IsInserted = IsInsertedNo> {
     /// The face formed by the points `c`, `b`, and `d`.
     pub cbd: Polygon<3, IsInsertedYes>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, f64::consts::PI};
+
+    use fj_interop::mesh::Mesh;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        objects::Shell,
+        operations::Insert,
+        services::Services,
+    };
+
+    use super::BuildShell;
+
+    #[test]
+    fn sphere() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let radius = 2.;
+        let expected = 4. / 3. * PI * radius * radius * radius;
+
+        let mut previous_error = f64::INFINITY;
+        let mut previous_num_faces = 0;
+
+        for (i, tolerance) in [0.2, 0.05, 0.01].into_iter().enumerate() {
+            let sphere =
+                Shell::sphere([1., 2., 3.], radius, tolerance, &mut services);
+
+            let mesh =
+                (&sphere, Tolerance::from_scalar(tolerance)?).triangulate();
+            let num_faces = mesh.triangles().count();
+            let volume = mesh_volume(&mesh);
+
+            // The polyhedron is contained in the sphere, and itself contains
+            // the sphere whose radius is smaller by the tolerance.
+            let error = expected - volume;
+            assert!(error > 0.);
+            assert!(error < 4. * PI * radius * radius * tolerance);
+
+            assert!(error < previous_error);
+            assert!(num_faces > previous_num_faces);
+            previous_error = error;
+            previous_num_faces = num_faces;
+
+            // The time it takes to validate a shell grows much faster than
+            // the number of its faces. Only validate the coarsest one.
+            if i == 0 {
+                let _ = sphere.insert(&mut services);
+            }
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn cone() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let [radius, height] = [2., 3.];
+        let expected = PI * radius * radius * height / 3.;
+
+        let mut previous_error = f64::INFINITY;
+
+        for tolerance in [0.2, 0.05, 0.01] {
+            let cone = Shell::cone(radius, height, tolerance, &mut services);
+
+            // The faces around the apex meet in a single vertex.
+            let num_vertices = cone
+                .faces()
+                .into_iter()
+                .flat_map(|face| face.region().exterior().half_edges())
+                .map(|half_edge| half_edge.start_vertex().id())
+                .collect::<BTreeSet<_>>()
+                .len();
+            assert_eq!(num_vertices, cone.faces().into_iter().count());
+
+            let mesh =
+                (&cone, Tolerance::from_scalar(tolerance)?).triangulate();
+            let error = expected - mesh_volume(&mesh);
+
+            // The base of the pyramid contains the circle whose radius is
+            // smaller by the tolerance, and is contained by the actual circle.
+            assert!(error > 0.);
+            assert!(error < 2. * PI * radius * tolerance * height / 3.);
+            assert!(error < previous_error);
+            previous_error = error;
+
+            let _ = cone.insert(&mut services);
+        }
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn mesh_volume(mesh: &Mesh<Point<3>>) -> f64 {
+        mesh.triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume)
+            .into_f64()
+    }
+}
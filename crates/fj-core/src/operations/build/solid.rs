@@ -1,6 +1,7 @@
-use fj_math::Point;
+use fj_math::{Point, Scalar};
 
 use crate::{
+    algorithms::approx::Tolerance,
     objects::{Shell, Solid},
     operations::{
         build::shell::BuildShell, Insert, IsInsertedYes, TetrahedronShell,
@@ -28,6 +29,34 @@ pub trait BuildSolid {
 
         Tetrahedron { solid, shell }
     }
+
+    /// Build a sphere from the provided center and radius
+    ///
+    /// See [`BuildShell::sphere`] for more information.
+    fn sphere(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Solid {
+        let shell =
+            Shell::sphere(center, radius, tolerance, services).insert(services);
+        Solid::empty().add_shells([shell])
+    }
+
+    /// Build a cone from the provided radius and height
+    ///
+    /// See [`BuildShell::cone`] for more information.
+    fn cone(
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Solid {
+        let shell =
+            Shell::cone(radius, height, tolerance, services).insert(services);
+        Solid::empty().add_shells([shell])
+    }
 }
 
 impl BuildSolid for Solid {}
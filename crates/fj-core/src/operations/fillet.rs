@@ -0,0 +1,524 @@
+use std::collections::BTreeSet;
+
+use fj_math::{Point, Scalar, Vector, Winding};
+
+use crate::{
+    algorithms::sweep::Sweep,
+    geometry::{GlobalPath, SurfacePath},
+    objects::{Cycle, Face, HalfEdge, Region, Solid},
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{BuildHalfEdge, Insert, Reverse};
+
+/// Round off the edges of a [`Solid`]
+///
+/// Only solids that are straight extrusions of a polygon are supported so far,
+/// and only the edges between the bottom and top faces of those can be
+/// filleted. The plane of the bottom and top faces must be defined by a surface
+/// whose axes are perpendicular and of equal length, which is the case for any
+/// sketch surface. Any other input results in a [`FilletError`].
+pub trait Fillet: Sized {
+    /// Replace the provided edges with round blends of the given radius
+    ///
+    /// An edge is selected by providing any of its half-edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the solid or the edges are not supported (see
+    /// [`Fillet`]), or if the radius is not positive or too large for the faces
+    /// adjacent to the edges.
+    fn fillet(
+        &self,
+        edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        radius: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, FilletError>;
+}
+
+impl Fillet for Solid {
+    fn fillet(
+        &self,
+        edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        radius: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Result<Self, FilletError> {
+        let radius = radius.into();
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        if radius <= Scalar::ZERO {
+            return Err(FilletError::InvalidRadius { radius });
+        }
+
+        let selected = edges
+            .into_iter()
+            .map(|half_edge| half_edge.global_form().id())
+            .collect::<BTreeSet<_>>();
+        if selected.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let shells = self.shells().collect::<Vec<_>>();
+        let [shell] = shells.as_slice() else {
+            return Err(FilletError::NotAnExtrusion);
+        };
+
+        // Find the selected edges in the solid, so we know where they are.
+        let mut selected_segments = Vec::new();
+        for face in shell.faces() {
+            let half_edges = face
+                .region()
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges())
+                .filter(|half_edge| {
+                    selected.contains(&half_edge.global_form().id())
+                });
+
+            for half_edge in half_edges {
+                let SurfacePath::Line(_) = half_edge.path() else {
+                    return Err(FilletError::UnsupportedEdge);
+                };
+
+                let [a, b] = half_edge.boundary().inner.map(|point| {
+                    face.surface().geometry().point_from_surface_coords(
+                        half_edge.path().point_from_path_coords(point),
+                    )
+                });
+                selected_segments.push([a, b]);
+            }
+        }
+
+        let Some(&[a, b]) = selected_segments.first() else {
+            return Err(FilletError::EdgesNotInSolid);
+        };
+        let direction = (b - a).normalize();
+
+        let is_parallel = |vector: Vector<3>| {
+            vector.normalize().cross(&direction).magnitude() < epsilon
+        };
+        if !selected_segments.iter().all(|&[a, b]| is_parallel(b - a)) {
+            return Err(FilletError::UnsupportedEdge);
+        }
+
+        // The selected edges must be the edges between the walls of an
+        // extrusion. Let's find its bottom and top faces.
+        let mut caps = Vec::new();
+        for face in shell.faces() {
+            let surface = face.surface().geometry();
+            let GlobalPath::Line(line) = surface.u else {
+                return Err(FilletError::NotAnExtrusion);
+            };
+            let normal = line.direction().cross(&surface.v);
+
+            if is_parallel(normal) {
+                caps.push(face.clone());
+            } else if normal.normalize().dot(&direction).abs() >= epsilon {
+                return Err(FilletError::UnsupportedEdge);
+            }
+        }
+
+        let [a, b] = caps.as_slice() else {
+            return Err(FilletError::NotAnExtrusion);
+        };
+        let [offset_a, offset_b] = [a, b].map(|cap| {
+            cap.surface().geometry().u.origin().coords.dot(&direction)
+        });
+        let (bottom, height) = if offset_a < offset_b {
+            (a, offset_b - offset_a)
+        } else {
+            (b, offset_a - offset_b)
+        };
+
+        // We're going to fillet the corners of the bottom face, then extrude
+        // the result again. This only works, if circles in the surface are
+        // also circles in 3D.
+        let surface = bottom.surface().geometry();
+        let GlobalPath::Line(line) = surface.u else {
+            unreachable!("Caps have been checked to be planar")
+        };
+        let scale = line.direction().magnitude();
+        if (surface.v.magnitude() - scale).abs() >= epsilon
+            || line.direction().dot(&surface.v).abs() >= epsilon
+        {
+            return Err(FilletError::DistortedSurface);
+        }
+        let radius_surface = radius / scale;
+
+        let polygons = bottom
+            .region()
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .half_edges()
+                    .map(|half_edge| {
+                        let SurfacePath::Line(_) = half_edge.path() else {
+                            return Err(FilletError::NotAnExtrusion);
+                        };
+
+                        let position = half_edge.start_position();
+                        let position_global =
+                            surface.point_from_surface_coords(position);
+                        let is_selected =
+                            selected_segments.iter().any(|segment| {
+                                segment.iter().any(|&point| {
+                                    (point - position_global).magnitude()
+                                        < epsilon
+                                })
+                            });
+
+                        Ok((position, is_selected))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cycles = polygons
+            .iter()
+            .map(|corners| {
+                fillet_polygon(corners, radius_surface, services)
+                    .map(|cycle| cycle.insert(services))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut cycles = cycles.into_iter();
+        let exterior = cycles.next().expect("Region has an exterior");
+        let region = Region::new(
+            exterior,
+            cycles,
+            bottom.region().color(),
+            bottom.region().material().cloned(),
+        )
+        .insert(services);
+        let face = Face::new(bottom.surface().clone(), region);
+
+        // Sweeping expects the face to be oriented like its surface.
+        let face = if face.region().exterior().winding() == Winding::Cw {
+            face.reverse(services)
+        } else {
+            face
+        };
+
+        let shell = face.insert(services).sweep(direction * height, services);
+
+        Ok(Solid::new([shell]))
+    }
+}
+
+/// Error filleting the edges of a solid
+///
+/// See [`Fillet`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum FilletError {
+    /// The fillet radius is not positive
+    #[error("Fillet radius must be positive, but is {radius}")]
+    InvalidRadius {
+        /// The invalid radius
+        radius: Scalar,
+    },
+
+    /// None of the selected edges are part of the solid
+    #[error("Selected edges are not part of the solid")]
+    EdgesNotInSolid,
+
+    /// The solid is not a straight extrusion of a polygon
+    #[error("Solid is not a straight extrusion of a polygon")]
+    NotAnExtrusion,
+
+    /// The extrusion was created from a distorted surface
+    #[error("Extrusion was created from a distorted surface")]
+    DistortedSurface,
+
+    /// The selected edges don't connect the bottom and top faces
+    #[error(
+        "Selected edges don't connect the bottom and top faces of an \
+        extrusion"
+    )]
+    UnsupportedEdge,
+
+    /// The fillet radius is too large for the faces adjacent to the edges
+    #[error("Fillet radius is too large for the adjacent faces")]
+    RadiusTooLarge,
+}
+
+/// Create a cycle from a polygon, rounding off the selected corners
+///
+/// Each corner is defined by its position and whether it is selected.
+fn fillet_polygon(
+    corners: &[(Point<2>, bool)],
+    radius: Scalar,
+    services: &mut Services,
+) -> Result<Cycle, FilletError> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+    let n = corners.len();
+
+    // For each corner, the distance from the corner to the points where the
+    // fillet touches the adjacent edges.
+    let distances = (0..n)
+        .map(|i| {
+            let (prev, _) = corners[(i + n - 1) % n];
+            let (position, is_selected) = corners[i];
+            let (next, _) = corners[(i + 1) % n];
+
+            if !is_selected {
+                return Scalar::ZERO;
+            }
+
+            let (sin, cos) = half_angle(prev, position, next).sin_cos();
+            radius * cos / sin
+        })
+        .collect::<Vec<_>>();
+
+    // The fillets at both ends of each edge must fit on it. Check that, before
+    // creating any objects.
+    let remaining = (0..n)
+        .map(|i| {
+            let (position, _) = corners[i];
+            let (next, _) = corners[(i + 1) % n];
+            (next - position).magnitude()
+                - distances[i]
+                - distances[(i + 1) % n]
+        })
+        .collect::<Vec<_>>();
+    if remaining.iter().any(|&remaining| remaining <= -epsilon) {
+        return Err(FilletError::RadiusTooLarge);
+    }
+
+    // For each corner, the points where the fillet touches the adjacent edges,
+    // and the arc between them.
+    let fillets = (0..n)
+        .map(|i| {
+            let (prev, _) = corners[(i + n - 1) % n];
+            let (position, is_selected) = corners[i];
+            let (next, _) = corners[(i + 1) % n];
+
+            if !is_selected {
+                return (position, position, None);
+            }
+
+            let to_prev = (prev - position).normalize();
+            let to_next = (next - position).normalize();
+
+            let half_angle = half_angle(prev, position, next);
+            let (sin, _) = half_angle.sin_cos();
+
+            let start = position + to_prev * distances[i];
+            let center =
+                position + (to_prev + to_next).normalize() * (radius / sin);
+
+            // The fillet needs to turn into the same direction as the corner
+            // it replaces.
+            let turn = (position - prev).cross2d(&(next - position));
+            let angle = if turn > Scalar::ZERO {
+                Scalar::PI - half_angle * 2.
+            } else {
+                -(Scalar::PI - half_angle * 2.)
+            };
+
+            let start_angle = {
+                let from_center = start - center;
+                from_center.v.atan2(from_center.u)
+            };
+            let path =
+                SurfacePath::circle_from_center_and_radius(center, radius);
+            let arc = HalfEdge::unjoined(
+                path,
+                [start_angle, start_angle + angle]
+                    .map(|coord| Point::from([coord])),
+                services,
+            )
+            .insert(services);
+
+            // The adjacent edges need to connect to the arc precisely where
+            // it actually starts and ends.
+            let [start, end] = arc
+                .boundary()
+                .inner
+                .map(|point| arc.path().point_from_path_coords(point));
+
+            (start, end, Some(arc))
+        })
+        .collect::<Vec<_>>();
+
+    let mut half_edges = Vec::new();
+    for i in 0..n {
+        let (_, end, arc) = fillets[i].clone();
+        let (start_of_next, _, _) = fillets[(i + 1) % n];
+
+        half_edges.extend(arc);
+
+        if remaining[i] > epsilon {
+            half_edges.push(
+                HalfEdge::line_segment([end, start_of_next], None, services)
+                    .insert(services),
+            );
+        }
+    }
+
+    Ok(Cycle::new(half_edges))
+}
+
+/// Compute half of the interior angle of a polygon corner
+fn half_angle(prev: Point<2>, position: Point<2>, next: Point<2>) -> Scalar {
+    let to_prev = (prev - position).normalize();
+    let to_next = (next - position).normalize();
+
+    to_prev
+        .dot(&to_next)
+        .clamp(-Scalar::ONE, Scalar::ONE)
+        .acos()
+        / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, triangulate::Triangulate,
+        },
+        objects::{HalfEdge, Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{Fillet, FilletError};
+
+    #[test]
+    fn fillet_vertical_edges_of_cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = cuboid(&mut services);
+        let edges = edges_parallel_to(&cuboid, Vector::unit_z());
+        assert_eq!(edges.len(), 4);
+
+        let radius = 0.5;
+        let filleted = cuboid.fillet(edges, radius, &mut services)?;
+
+        let num_faces = filleted
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum::<usize>();
+        assert_eq!(num_faces, 6 + 4);
+
+        // Each fillet removes the material between a square and a quarter
+        // circle, over the full height.
+        let removed = (radius * radius) * (4. - Scalar::PI.into_f64()) * 2.;
+        assert_mesh_volume(&filleted, 8. - removed)?;
+
+        let _ = filleted.insert(&mut services);
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_single_edge() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = cuboid(&mut services);
+        let edges = edges_parallel_to(&cuboid, Vector::unit_z());
+
+        let radius = 1.;
+        let filleted =
+            cuboid.fillet(edges.into_iter().take(1), radius, &mut services)?;
+
+        let removed =
+            (radius * radius) * (1. - Scalar::PI.into_f64() / 4.) * 2.;
+        assert_mesh_volume(&filleted, 8. - removed)?;
+
+        let _ = filleted.insert(&mut services);
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_with_invalid_radius() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = cuboid(&mut services);
+        let edges = edges_parallel_to(&cuboid, Vector::unit_z());
+
+        assert_eq!(
+            cuboid.fillet(edges.clone(), 0., &mut services),
+            Err(FilletError::InvalidRadius {
+                radius: Scalar::ZERO
+            })
+        );
+
+        // The fillets of two adjacent corners don't fit on the edge between
+        // them.
+        assert_eq!(
+            cuboid.fillet(edges, 1.5, &mut services),
+            Err(FilletError::RadiusTooLarge)
+        );
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn cuboid(services: &mut Services) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface).sweep([0., 0., 2.], services)
+    }
+
+    /// Find one half-edge for each edge of the solid that is parallel to
+    /// `direction`
+    fn edges_parallel_to(
+        solid: &Solid,
+        direction: Vector<3>,
+    ) -> Vec<Handle<HalfEdge>> {
+        let mut edges = Vec::<Handle<HalfEdge>>::new();
+
+        for face in solid.shells().flat_map(|shell| shell.faces()) {
+            for half_edge in face.region().exterior().half_edges() {
+                let [a, b] = half_edge.boundary().inner.map(|point| {
+                    face.surface().geometry().point_from_surface_coords(
+                        half_edge.path().point_from_path_coords(point),
+                    )
+                });
+                let is_parallel =
+                    (b - a).cross(&direction).magnitude() == Scalar::ZERO;
+                let is_known = edges.iter().any(|edge| {
+                    edge.global_form().id() == half_edge.global_form().id()
+                });
+
+                if is_parallel && !is_known {
+                    edges.push(half_edge.clone());
+                }
+            }
+        }
+
+        edges
+    }
+
+    fn assert_mesh_volume(solid: &Solid, expected: f64) -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        let error = (volume.into_f64() - expected).abs();
+        assert!(error < 0.01, "Unexpected volume: {volume}, not {expected}");
+
+        Ok(())
+    }
+}
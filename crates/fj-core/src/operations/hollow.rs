@@ -0,0 +1,480 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Line, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{GlobalPath, SurfaceGeometry, SurfacePath},
+    objects::{
+        Curve, Cycle, Face, GlobalEdge, HalfEdge, Handedness, Region, Shell,
+        Solid, Surface, Vertex,
+    },
+    services::Services,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+use super::{Insert, Reverse};
+
+/// Hollow out a [`Solid`], leaving walls of uniform thickness
+///
+/// This is the operation that is often called "shell" in CAD applications.
+/// It's called differently here, to prevent confusion with [`Shell`].
+pub trait Hollow {
+    /// Hollow out the object, leaving walls of the given thickness
+    ///
+    /// Each face is offset into the solid by `thickness`, forming the inner
+    /// boundary of the walls. The provided faces are removed, leaving openings
+    /// into the cavity. If no faces are provided, the cavity is closed off
+    /// completely.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only solids that consist of a single shell, bounded by planar faces
+    /// with straight edges, are supported. At each vertex, the offset faces
+    /// must meet in a single point, which is the case if no more than three
+    /// faces meet at any vertex.
+    ///
+    /// It is not checked, whether the walls intersect each other, which they
+    /// will, if the thickness is too large for the solid.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the solid is not supported (see above), or if the thickness
+    /// is not positive.
+    #[must_use]
+    fn hollow(
+        &self,
+        thickness: impl Into<Scalar>,
+        open_faces: impl IntoIterator<Item = Handle<Face>>,
+        services: &mut Services,
+    ) -> Self;
+}
+
+impl Hollow for Solid {
+    fn hollow(
+        &self,
+        thickness: impl Into<Scalar>,
+        open_faces: impl IntoIterator<Item = Handle<Face>>,
+        services: &mut Services,
+    ) -> Self {
+        let thickness = thickness.into();
+        assert!(thickness > Scalar::ZERO, "Wall thickness must be positive");
+
+        let shells = self.shells().collect::<Vec<_>>();
+        let [shell] = shells.as_slice() else {
+            todo!("Hollowing solids with multiple shells is not supported")
+        };
+
+        let open_faces = open_faces
+            .into_iter()
+            .map(|face| face.id())
+            .collect::<BTreeSet<_>>();
+
+        let planes = shell
+            .faces()
+            .into_iter()
+            .map(|face| {
+                let offset = if open_faces.contains(&face.id()) {
+                    // The inner boundary of the walls must meet the open face
+                    // right where the outer boundary does.
+                    Scalar::ZERO
+                } else {
+                    thickness
+                };
+
+                (face.id(), OffsetPlane::new(face, offset))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut inner = InnerShell::new(shell, &planes);
+
+        let mut faces = Vec::new();
+        let mut inner_faces = Vec::new();
+
+        for face in shell.faces() {
+            let inner_face = inner.face(face, &planes, services);
+
+            if open_faces.contains(&face.id()) {
+                // The open face turns into a rim, which connects the outer
+                // boundary of the walls to the inner one.
+                let interiors = inner_face
+                    .region()
+                    .all_cycles()
+                    .map(|cycle| cycle.reverse(services).insert(services))
+                    .collect::<Vec<_>>();
+                let region = Region::new(
+                    face.region().exterior().clone(),
+                    interiors,
+                    face.region().color(),
+                    face.region().material().cloned(),
+                )
+                .insert(services);
+
+                faces.push(
+                    Face::new(face.surface().clone(), region).insert(services),
+                );
+            } else {
+                faces.push(face.clone());
+
+                // The inner faces point into the cavity.
+                inner_faces.push(inner_face.reverse(services));
+            }
+        }
+
+        let inner_faces = inner_faces
+            .into_iter()
+            .map(|face| face.insert(services))
+            .collect::<Vec<_>>();
+
+        if open_faces.is_empty() {
+            let outer = (*shell).clone();
+            let inner = Shell::new(inner_faces).insert(services);
+
+            Solid::new([outer, inner])
+        } else {
+            let shell = Shell::new(faces.into_iter().chain(inner_faces))
+                .insert(services);
+
+            Solid::new([shell])
+        }
+    }
+}
+
+/// A face's plane, offset against the face's orientation
+struct OffsetPlane {
+    /// The normal of the face, pointing to its front side
+    normal: Vector<3>,
+
+    /// The distance of the offset plane from the origin, along `normal`
+    distance: Scalar,
+
+    /// The geometry of the offset plane
+    geometry: SurfaceGeometry,
+}
+
+impl OffsetPlane {
+    fn new(face: &Face, offset: Scalar) -> Self {
+        let surface = face.surface().geometry();
+        let GlobalPath::Line(line) = surface.u else {
+            todo!("Hollowing solids with curved faces is not supported")
+        };
+
+        let normal = line.direction().cross(&surface.v).normalize();
+        let normal = match face.coord_handedness() {
+            Handedness::RightHanded => normal,
+            Handedness::LeftHanded => -normal,
+        };
+
+        let origin = line.origin() - normal * offset;
+        let geometry = SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                origin,
+                line.direction(),
+            )),
+            v: surface.v,
+        };
+
+        Self {
+            normal,
+            distance: origin.coords.dot(&normal),
+            geometry,
+        }
+    }
+}
+
+/// The inner boundary of the walls of a hollowed solid
+///
+/// Mirrors the topology of the original shell, so the inner faces can share
+/// their edges and vertices the same way the original ones do.
+struct InnerShell {
+    positions: BTreeMap<ObjectId, Point<3>>,
+    vertices: BTreeMap<ObjectId, Handle<Vertex>>,
+    curves: BTreeMap<ObjectId, Handle<Curve>>,
+    global_edges: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+}
+
+impl InnerShell {
+    fn new(shell: &Shell, planes: &BTreeMap<ObjectId, OffsetPlane>) -> Self {
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        let mut planes_by_vertex = BTreeMap::<_, Vec<&OffsetPlane>>::new();
+        for face in shell.faces() {
+            let plane = &planes[&face.id()];
+
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let SurfacePath::Line(_) = half_edge.path() else {
+                        todo!(
+                            "Hollowing solids with curved edges is not \
+                            supported"
+                        )
+                    };
+
+                    planes_by_vertex
+                        .entry(half_edge.start_vertex().id())
+                        .or_default()
+                        .push(plane);
+                }
+            }
+        }
+
+        let positions = planes_by_vertex
+            .into_iter()
+            .map(|(vertex, planes)| {
+                let position = intersect_planes(&planes, epsilon)
+                    .unwrap_or_else(|| {
+                        todo!(
+                            "Hollowing solids where the offset faces don't \
+                            meet in a single point is not supported"
+                        )
+                    });
+
+                (vertex, position)
+            })
+            .collect();
+
+        Self {
+            positions,
+            vertices: BTreeMap::new(),
+            curves: BTreeMap::new(),
+            global_edges: BTreeMap::new(),
+        }
+    }
+
+    /// Create the inner counterpart of the provided face
+    fn face(
+        &mut self,
+        face: &Handle<Face>,
+        planes: &BTreeMap<ObjectId, OffsetPlane>,
+        services: &mut Services,
+    ) -> Face {
+        let geometry = planes[&face.id()].geometry.clone();
+
+        let cycles = face
+            .region()
+            .all_cycles()
+            .map(|cycle| {
+                let half_edges = cycle
+                    .half_edge_pairs()
+                    .map(|(half_edge, next)| {
+                        let [start, end] = [half_edge, next].map(|half_edge| {
+                            geometry.project_global_point(
+                                self.positions[&half_edge.start_vertex().id()],
+                            )
+                        });
+                        let [a, b] = half_edge.boundary().inner;
+
+                        let start_vertex = self
+                            .vertices
+                            .entry(half_edge.start_vertex().id())
+                            .or_insert_with(|| Vertex::new().insert(services))
+                            .clone();
+                        let curve = self
+                            .curves
+                            .entry(half_edge.curve().id())
+                            .or_insert_with(|| Curve::new().insert(services))
+                            .clone();
+                        let global_form = self
+                            .global_edges
+                            .entry(half_edge.global_form().id())
+                            .or_insert_with(|| {
+                                GlobalEdge::new().insert(services)
+                            })
+                            .clone();
+
+                        HalfEdge::new(
+                            SurfacePath::line_from_points_with_coords([
+                                (a, start),
+                                (b, end),
+                            ]),
+                            half_edge.boundary(),
+                            curve,
+                            start_vertex,
+                            global_form,
+                            half_edge.kind(),
+                        )
+                        .insert(services)
+                    })
+                    .collect::<Vec<_>>();
+
+                Cycle::new(half_edges).insert(services)
+            })
+            .collect::<Vec<_>>();
+
+        let surface = if &geometry == face.surface().geometry() {
+            face.surface().clone()
+        } else {
+            Surface::new(geometry).insert(services)
+        };
+
+        let mut cycles = cycles.into_iter();
+        let exterior = cycles.next().expect("Region has an exterior");
+        let region = Region::new(
+            exterior,
+            cycles,
+            face.region().color(),
+            face.region().material().cloned(),
+        )
+        .insert(services);
+
+        Face::new(surface, region)
+    }
+}
+
+/// Compute the single point in which the provided planes meet
+///
+/// Returns `None`, if there is no such point.
+fn intersect_planes(
+    planes: &[&OffsetPlane],
+    epsilon: Scalar,
+) -> Option<Point<3>> {
+    let [a, b, c] = independent_planes(planes, epsilon)?;
+
+    let point = (b.normal.cross(&c.normal) * a.distance
+        + c.normal.cross(&a.normal) * b.distance
+        + a.normal.cross(&b.normal) * c.distance)
+        / a.normal.dot(&b.normal.cross(&c.normal));
+    let point = Point { coords: point };
+
+    let is_on_all_planes = planes.iter().all(|plane| {
+        (point.coords.dot(&plane.normal) - plane.distance).abs() < epsilon
+    });
+
+    is_on_all_planes.then_some(point)
+}
+
+/// Find three planes among the provided ones, whose normals are independent
+fn independent_planes<'r>(
+    planes: &[&'r OffsetPlane],
+    epsilon: Scalar,
+) -> Option<[&'r OffsetPlane; 3]> {
+    for (i, a) in planes.iter().enumerate() {
+        for (j, b) in planes.iter().enumerate().skip(i + 1) {
+            if a.normal.cross(&b.normal).magnitude() < epsilon {
+                continue;
+            }
+
+            for c in planes.iter().skip(j + 1) {
+                let det = a.normal.dot(&b.normal.cross(&c.normal));
+                if det.abs() >= epsilon {
+                    return Some([a, b, c]);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, triangulate::Triangulate,
+        },
+        objects::{Face, Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::Hollow;
+
+    #[test]
+    fn hollow_cuboid_with_open_top() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = cuboid(&mut services);
+        let top = faces_at_height(&cuboid, 2.);
+        assert_eq!(top.len(), 1);
+
+        let thickness: f64 = 0.25;
+        let hollowed = cuboid.hollow(thickness, top, &mut services);
+
+        // Four outer walls, the outer floor, the rim, four inner walls, and
+        // the inner floor.
+        let num_faces = hollowed
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum::<usize>();
+        assert_eq!(num_faces, 5 + 1 + 5);
+
+        let cavity = (2. - thickness * 2.).powi(2) * (2. - thickness);
+        assert_mesh_volume(&hollowed, 8. - cavity)?;
+
+        let _ = hollowed.insert(&mut services);
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn hollow_closed_cuboid() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cuboid = cuboid(&mut services);
+
+        let thickness: f64 = 0.5;
+        let hollowed = cuboid.hollow(thickness, [], &mut services);
+        assert_eq!(hollowed.shells().count(), 2);
+
+        let cavity = (2. - thickness * 2.).powi(3);
+        assert_mesh_volume(&hollowed, 8. - cavity)?;
+
+        let _ = hollowed.insert(&mut services);
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn cuboid(services: &mut Services) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface).sweep([0., 0., 2.], services)
+    }
+
+    /// Find the faces whose exterior is located completely at the given height
+    fn faces_at_height(solid: &Solid, z: f64) -> Vec<Handle<Face>> {
+        solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .filter(|face| {
+                face.region().exterior().half_edges().all(|half_edge| {
+                    let point = face
+                        .surface()
+                        .geometry()
+                        .point_from_surface_coords(half_edge.start_position());
+                    point.z == Scalar::from(z)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn assert_mesh_volume(solid: &Solid, expected: f64) -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let mesh = (solid, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&(b.coords.cross(&c.coords))) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        let error = (volume.into_f64() - expected).abs();
+        assert!(error < 0.01, "Unexpected volume: {volume}, not {expected}");
+
+        Ok(())
+    }
+}
@@ -1,10 +1,15 @@
 //! Operations to update shapes
 
 mod build;
+mod fillet;
+mod hollow;
 mod insert;
 mod join;
 mod merge;
+mod offset;
+mod pattern;
 mod reverse;
+mod split_face;
 mod update;
 
 pub use self::{
@@ -18,10 +23,15 @@ pub use self::{
         solid::{BuildSolid, Tetrahedron},
         surface::BuildSurface,
     },
+    fillet::{Fillet, FilletError},
+    hollow::Hollow,
     insert::{Insert, IsInserted, IsInsertedNo, IsInsertedYes},
     join::cycle::JoinCycle,
     merge::Merge,
+    offset::Offset,
+    pattern::{CircularPattern, LinearPattern},
     reverse::Reverse,
+    split_face::SplitFace,
     update::{
         cycle::UpdateCycle, edge::UpdateHalfEdge, face::UpdateFace,
         region::UpdateRegion, shell::UpdateShell, sketch::UpdateSketch,
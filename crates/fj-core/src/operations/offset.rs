@@ -0,0 +1,423 @@
+use fj_math::{
+    Circle, Line, Point, Scalar, Segment, SegmentIntersection, Winding,
+};
+
+use crate::{
+    geometry::SurfacePath,
+    objects::{Cycle, HalfEdge},
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{BuildHalfEdge, Insert};
+
+/// Offset a [`Cycle`] within its surface
+pub trait Offset {
+    /// Offset the object by the given distance
+    ///
+    /// Each half-edge is moved along its normal by `distance`. A positive
+    /// distance moves the half-edges towards the inside of the cycle, as
+    /// defined by its winding, a negative one moves them outward.
+    ///
+    /// Where two straight half-edges meet, the offset half-edges are extended
+    /// or trimmed to the point where they intersect (a miter join). Circular
+    /// half-edges keep their center, and their radius is adjusted.
+    ///
+    /// Moving the half-edges can make some of them vanish. For example, if the
+    /// corner of a rectangle is chamfered, the chamfer vanishes, once the
+    /// rectangle is offset inward by more than the size of the chamfer. Those
+    /// half-edges are detected, by their direction being reversed, and are
+    /// removed.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only cycles made up of lines and circles are supported. Offsetting a
+    /// cycle such, that it would need to be split into multiple cycles, is not
+    /// supported either.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the cycle is not supported (see above), or if the distance is
+    /// so large that the cycle vanishes completely.
+    #[must_use]
+    fn offset(
+        &self,
+        distance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Self;
+}
+
+impl Offset for Cycle {
+    fn offset(
+        &self,
+        distance: impl Into<Scalar>,
+        services: &mut Services,
+    ) -> Self {
+        let distance = distance.into();
+
+        // The normal of a path points to its left. For a counter-clockwise
+        // cycle, that's the inside.
+        let distance_to_left = match self.winding() {
+            Winding::Ccw => distance,
+            Winding::Cw => -distance,
+        };
+
+        let mut edges = self
+            .half_edges()
+            .filter_map(|half_edge| {
+                OffsetEdge::new(half_edge, distance_to_left)
+            })
+            .collect::<Vec<_>>();
+
+        if let [edge] = edges.as_slice() {
+            // A single half-edge must be a full circle. It has no corners that
+            // need adjusting.
+            let [start, end] = edge.boundary;
+            return Cycle::new([edge.build(start, end, services)]);
+        }
+        assert!(
+            edges.len() == self.len(),
+            "Offset distance is too large for the cycle"
+        );
+
+        let corners = loop {
+            let corners = corners(&edges);
+
+            let collapsed = edges.iter().enumerate().position(|(i, edge)| {
+                let start = corners[i];
+                let end = corners[(i + 1) % edges.len()];
+
+                edge.is_collapsed(start, end)
+            });
+
+            let Some(collapsed) = collapsed else {
+                break corners;
+            };
+
+            edges.remove(collapsed);
+            assert!(
+                edges.len() >= 3
+                    || edges.iter().all(|edge| matches!(
+                        edge.path,
+                        SurfacePath::Circle(_)
+                    )),
+                "Offset distance is too large for the cycle"
+            );
+        };
+
+        // Removing collapsed half-edges takes care of degenerate loops at the
+        // corners. If the offset half-edges still intersect each other, the
+        // offset cycle would have to be split into multiple cycles.
+        let segments = (0..edges.len())
+            .map(|i| {
+                Segment::from_points([
+                    corners[i],
+                    corners[(i + 1) % edges.len()],
+                ])
+            })
+            .collect::<Vec<_>>();
+        for (i, a) in segments.iter().enumerate() {
+            for (j, b) in segments.iter().enumerate().skip(i + 2) {
+                if i == 0 && j == segments.len() - 1 {
+                    // These are neighbors, as the cycle wraps around.
+                    continue;
+                }
+
+                let is_intersecting = match a.intersect(b) {
+                    SegmentIntersection::Point { is_within_both, .. } => {
+                        is_within_both
+                    }
+                    SegmentIntersection::Coincident { overlap } => {
+                        overlap.is_some()
+                    }
+                    SegmentIntersection::Parallel => false,
+                };
+                if is_intersecting {
+                    todo!(
+                        "Offsetting a cycle into multiple cycles is not \
+                        supported"
+                    )
+                }
+            }
+        }
+
+        let half_edges = edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| {
+                let start = corners[i];
+                let end = corners[(i + 1) % edges.len()];
+
+                let [start, end] =
+                    [start, end].map(|point| edge.point_to_path_coords(point));
+                edge.build(start, end, services)
+            })
+            .collect::<Vec<_>>();
+
+        Cycle::new(half_edges)
+    }
+}
+
+/// A half-edge that has been moved by the offset distance
+///
+/// The path of the offset half-edge has the same coordinates as the path of the
+/// original one, only its boundary has yet to be adjusted to its neighbors.
+struct OffsetEdge {
+    path: SurfacePath,
+    boundary: [Point<1>; 2],
+}
+
+impl OffsetEdge {
+    /// Offset the half-edge
+    ///
+    /// Returns `None`, if the half-edge is a circle that vanishes.
+    fn new(half_edge: &HalfEdge, distance_to_left: Scalar) -> Option<Self> {
+        let boundary = half_edge.boundary().inner;
+        let [a, b] = boundary;
+
+        // The normal points to the left of the path. If the half-edge goes
+        // along the path in negative direction, that's its right.
+        let distance = if a < b {
+            distance_to_left
+        } else {
+            -distance_to_left
+        };
+
+        let path = match half_edge.path() {
+            SurfacePath::Circle(circle) => {
+                // For a circle, the normal points either towards its center or
+                // away from it, depending on the circle's orientation.
+                let normal = half_edge.path().normal_from_path_coords(a);
+                let outward = (circle.point_from_circle_coords(a)
+                    - circle.center())
+                .normalize();
+
+                let radius = circle.radius();
+                let radius_offset = radius + distance * normal.dot(&outward);
+                if radius_offset <= Scalar::ZERO {
+                    return None;
+                }
+
+                let scale = radius_offset / radius;
+                SurfacePath::Circle(Circle::new(
+                    circle.center(),
+                    circle.a() * scale,
+                    circle.b() * scale,
+                ))
+            }
+            SurfacePath::Bezier(_) | SurfacePath::BSpline(_) => {
+                todo!(
+                    "Offsetting cycles with free-form curves is not supported"
+                )
+            }
+            SurfacePath::Ellipse(_) => {
+                todo!("Offsetting cycles with ellipses is not supported")
+            }
+            SurfacePath::Line(line) => {
+                let normal = half_edge.path().normal_from_path_coords(a);
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    line.origin() + normal * distance,
+                    line.direction(),
+                ))
+            }
+        };
+
+        Some(Self { path, boundary })
+    }
+
+    fn start(&self) -> Point<2> {
+        self.path.point_from_path_coords(self.boundary[0])
+    }
+
+    fn end(&self) -> Point<2> {
+        self.path.point_from_path_coords(self.boundary[1])
+    }
+
+    fn point_to_path_coords(&self, point: Point<2>) -> Point<1> {
+        match self.path {
+            SurfacePath::Circle(circle) => {
+                // Circle coordinates are ambiguous. Pick those closest to the
+                // original boundary.
+                let t = circle.point_to_circle_coords(point).t;
+                let [a, b] = self.boundary.map(|point| point.t);
+                let reference =
+                    if (t - a).abs() < (t - b).abs() { a } else { b };
+
+                let turns = ((reference - t) / Scalar::TAU).round();
+                Point::from([t + Scalar::TAU * turns])
+            }
+            SurfacePath::Bezier(_)
+            | SurfacePath::BSpline(_)
+            | SurfacePath::Ellipse(_) => {
+                unreachable!(
+                    "Ellipses and free-form curves are rejected when offsetting"
+                )
+            }
+            SurfacePath::Line(line) => line.point_to_line_coords(point),
+        }
+    }
+
+    /// Determine whether the half-edge vanishes between the given corners
+    fn is_collapsed(&self, start: Point<2>, end: Point<2>) -> bool {
+        let [a, b] = self.boundary;
+
+        let [start, end] =
+            [start, end].map(|point| self.point_to_path_coords(point));
+
+        (end.t - start.t) * (b.t - a.t) <= Scalar::ZERO
+    }
+
+    fn build(
+        &self,
+        start: Point<1>,
+        end: Point<1>,
+        services: &mut Services,
+    ) -> Handle<HalfEdge> {
+        match &self.path {
+            SurfacePath::Line(_) => {
+                let points = [start, end]
+                    .map(|point| self.path.point_from_path_coords(point));
+                HalfEdge::line_segment(points, None, services).insert(services)
+            }
+            path => HalfEdge::unjoined(path.clone(), [start, end], services)
+                .insert(services),
+        }
+    }
+}
+
+/// Compute the corners of the offset cycle
+///
+/// The corner at index `i` is where the half-edge at index `i` starts.
+fn corners(edges: &[OffsetEdge]) -> Vec<Point<2>> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+    let n = edges.len();
+
+    (0..n)
+        .map(|i| {
+            let prev = &edges[(i + n - 1) % n];
+            let next = &edges[i];
+
+            // This is where the corner would be, if the offset half-edges met
+            // right where they end. That is the case for straight half-edges
+            // on the same line, and for half-edges that meet tangentially.
+            let [a, b] = [prev.end(), next.start()];
+            let natural = a + (b - a) / Scalar::TWO;
+
+            let candidates = prev.path.intersect(&next.path);
+            let closest = candidates
+                .into_iter()
+                .map(|(_, _, point)| point)
+                .min_by_key(|point| (*point - natural).magnitude());
+
+            match closest {
+                Some(point) => point,
+                None => {
+                    assert!(
+                        (b - a).magnitude() < epsilon,
+                        "Offsetting cycles with corners whose offset \
+                        half-edges don't meet is not supported"
+                    );
+                    natural
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::Cycle,
+        operations::{BuildCycle, Insert, Reverse},
+        services::Services,
+    };
+
+    use super::Offset;
+
+    #[test]
+    fn offset_rectangle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let rectangle = Cycle::polygon(
+            [[0., 0.], [4., 0.], [4., 2.], [0., 2.]],
+            &mut services,
+        );
+
+        let inward = rectangle.offset(0.5, &mut services);
+        assert_eq!(
+            corners(&inward),
+            [[0.5, 0.5], [3.5, 0.5], [3.5, 1.5], [0.5, 1.5]].map(Point::from)
+        );
+
+        let outward = rectangle.offset(-0.5, &mut services);
+        assert_eq!(
+            corners(&outward),
+            [[-0.5, -0.5], [4.5, -0.5], [4.5, 2.5], [-0.5, 2.5]]
+                .map(Point::from)
+        );
+
+        // Inward is inward, regardless of winding.
+        let reversed = rectangle.reverse(&mut services);
+        let inward_reversed = reversed.offset(0.5, &mut services);
+        assert_eq!(
+            corners(&inward_reversed),
+            [[0.5, 0.5], [0.5, 1.5], [3.5, 1.5], [3.5, 0.5]].map(Point::from)
+        );
+
+        for cycle in [inward, outward, inward_reversed] {
+            let _ = cycle.insert(&mut services);
+        }
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_removes_collapsed_half_edges() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A rectangle with one corner chamfered. The chamfer vanishes, when
+        // offsetting inward by more than its size.
+        let chamfered = Cycle::polygon(
+            [[0., 0.], [10., 0.], [10., 3.9], [9.9, 4.], [0., 4.]],
+            &mut services,
+        );
+
+        let inward = chamfered.offset(1., &mut services);
+        assert_eq!(
+            corners(&inward),
+            [[1., 1.], [9., 1.], [9., 3.], [1., 3.]].map(Point::from)
+        );
+
+        let _ = inward.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_circle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let circle = Cycle::circle([1., 1.], 2., &mut services);
+
+        let inward = circle.offset(0.5, &mut services);
+        let half_edge = inward.half_edges().next().unwrap();
+        assert_eq!(half_edge.start_position(), Point::from([2.5, 1.]));
+
+        let _ = inward.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    fn corners(cycle: &Cycle) -> Vec<Point<2>> {
+        cycle
+            .half_edges()
+            .map(|half_edge| half_edge.start_position())
+            .collect()
+    }
+}
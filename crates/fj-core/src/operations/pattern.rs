@@ -0,0 +1,179 @@
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::transform::TransformObject, objects::Solid, services::Services,
+};
+
+use super::Merge;
+
+/// Repeat a [`Solid`] along a straight line
+pub trait LinearPattern {
+    /// Create `count` instances of the solid, each offset from the last
+    ///
+    /// The first instance is the original solid, and each further instance is
+    /// translated by `offset` relative to the one before. The result contains
+    /// the shells of all instances. A `count` of 1 returns the original solid,
+    /// a `count` of 0 returns an empty solid.
+    #[must_use]
+    fn linear_pattern(
+        &self,
+        offset: impl Into<Vector<3>>,
+        count: usize,
+        services: &mut Services,
+    ) -> Self;
+}
+
+impl LinearPattern for Solid {
+    fn linear_pattern(
+        &self,
+        offset: impl Into<Vector<3>>,
+        count: usize,
+        services: &mut Services,
+    ) -> Self {
+        let offset = offset.into();
+
+        pattern(self, count, services, |i| {
+            Transform::translation(offset * i as f64)
+        })
+    }
+}
+
+/// Repeat a [`Solid`] around an axis
+pub trait CircularPattern {
+    /// Create `count` instances of the solid, evenly spaced around an axis
+    ///
+    /// The axis goes through `center` and points along `axis`. The first
+    /// instance is the original solid, and each further instance is rotated
+    /// about the axis by a full turn divided by `count`, relative to the one
+    /// before. The result contains the shells of all instances. A `count` of 1
+    /// returns the original solid, a `count` of 0 returns an empty solid.
+    #[must_use]
+    fn circular_pattern(
+        &self,
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        count: usize,
+        services: &mut Services,
+    ) -> Self;
+}
+
+impl CircularPattern for Solid {
+    fn circular_pattern(
+        &self,
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        count: usize,
+        services: &mut Services,
+    ) -> Self {
+        let center = center.into();
+        let axis = axis.into().normalize();
+        let angle = Scalar::TAU / count.max(1) as f64;
+
+        pattern(self, count, services, |i| {
+            Transform::translation(center.coords)
+                * Transform::rotation(axis * angle * i as f64)
+                * Transform::translation(-center.coords)
+        })
+    }
+}
+
+fn pattern(
+    solid: &Solid,
+    count: usize,
+    services: &mut Services,
+    transform: impl Fn(usize) -> Transform,
+) -> Solid {
+    (0..count).fold(Solid::new([]), |pattern, i| {
+        // The first instance is the original solid. Using it as-is, instead of
+        // transforming it by the identity, means it shares all of its objects
+        // with the original.
+        let instance = if i == 0 {
+            solid.clone()
+        } else {
+            solid.clone().transform(&transform(i), services)
+        };
+
+        pattern.merge(&instance)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, transform::TransformObject,
+            triangulate::Triangulate,
+        },
+        objects::{Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{CircularPattern, LinearPattern};
+
+    #[test]
+    fn linear_pattern() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cube = cube(&mut services);
+        let pattern = cube.linear_pattern([2., 0., 0.], 3, &mut services);
+        assert_eq!(pattern.shells().count(), 3);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let aabb = (&pattern, tolerance).triangulate().aabb().unwrap();
+        assert_eq!(aabb.min, Point::from([0., 0., 0.]));
+        assert_eq!(aabb.max, Point::from([5., 1., 1.]));
+
+        let single = cube.linear_pattern([2., 0., 0.], 1, &mut services);
+        assert!(single.shells().eq(cube.shells()));
+
+        let _ = pattern.insert(&mut services);
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn circular_pattern() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let cube = cube(&mut services).translate([2., 0., 0.], &mut services);
+        let pattern = cube.circular_pattern(
+            Point::origin(),
+            Vector::unit_z(),
+            4,
+            &mut services,
+        );
+        assert_eq!(pattern.shells().count(), 4);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let aabb = (&pattern, tolerance).triangulate().aabb().unwrap();
+        for (actual, expected) in [
+            (aabb.min, Point::from([-3., -3., 0.])),
+            (aabb.max, Point::from([3., 3., 1.])),
+        ] {
+            assert!((actual - expected).magnitude() < 1e-9.into());
+        }
+
+        let _ = pattern.insert(&mut services);
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    fn cube(services: &mut Services) -> Handle<Solid> {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+
+        let surface = services.objects.surfaces.xy_plane();
+        (sketch, surface).sweep([0., 0., 1.], services)
+    }
+}
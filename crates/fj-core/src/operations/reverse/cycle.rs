@@ -12,11 +12,12 @@ impl Reverse for Cycle {
             .half_edge_pairs()
             .map(|(current, next)| {
                 HalfEdge::new(
-                    current.path(),
+                    current.path().clone(),
                     current.boundary().reverse(),
                     current.curve().clone(),
                     next.start_vertex().clone(),
                     current.global_form().clone(),
+                    current.kind(),
                 )
                 .insert(services)
             })
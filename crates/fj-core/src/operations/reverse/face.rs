@@ -20,8 +20,13 @@ impl Reverse for Face {
             .map(|cycle| cycle.clone().reverse(services).insert(services))
             .collect::<Vec<_>>();
 
-        let region = Region::new(exterior, interiors, self.region().color())
-            .insert(services);
+        let region = Region::new(
+            exterior,
+            interiors,
+            self.region().color(),
+            self.region().material().cloned(),
+        )
+        .insert(services);
 
         Face::new(self.surface().clone(), region)
     }
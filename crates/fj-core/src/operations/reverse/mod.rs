@@ -4,8 +4,18 @@ use crate::services::Services;
 
 mod cycle;
 mod face;
+mod shell;
+mod solid;
 
 /// Reverse the direction/orientation of an object
+///
+/// Reversing a [`Shell`] or [`Solid`] turns it inside out, by reversing the
+/// orientation of all of its faces. This can be used to fix an object whose
+/// faces point inward. See [`Measure::is_inside_out`].
+///
+/// [`Shell`]: crate::objects::Shell
+/// [`Solid`]: crate::objects::Solid
+/// [`Measure::is_inside_out`]: crate::algorithms::measure::Measure::is_inside_out
 pub trait Reverse {
     /// Reverse the direction/orientation of the object
     #[must_use]
@@ -0,0 +1,17 @@
+use crate::{
+    objects::Shell,
+    operations::{Insert, Reverse},
+    services::Services,
+};
+
+impl Reverse for Shell {
+    fn reverse(&self, services: &mut Services) -> Self {
+        let faces = self
+            .faces()
+            .into_iter()
+            .map(|face| face.reverse(services).insert(services))
+            .collect::<Vec<_>>();
+
+        Shell::new(faces)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{
+    objects::Solid,
+    operations::{Insert, Reverse},
+    services::Services,
+};
+
+impl Reverse for Solid {
+    fn reverse(&self, services: &mut Services) -> Self {
+        let shells = self
+            .shells()
+            .map(|shell| shell.reverse(services).insert(services))
+            .collect::<Vec<_>>();
+
+        Solid::new(shells)
+    }
+}
@@ -0,0 +1,451 @@
+use fj_math::{LineIntersection, Point, Scalar};
+
+use crate::{
+    geometry::SurfacePath,
+    objects::{
+        Curve, Cycle, Face, GlobalEdge, HalfEdge, Region, Shell, Vertex,
+    },
+    services::Services,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{BuildHalfEdge, Insert, UpdateHalfEdge, UpdateShell};
+
+/// Split a [`Face`] into two
+pub trait SplitFace: Sized {
+    /// Split the provided face along the provided path
+    ///
+    /// The path must cross the boundary of the face exactly twice, either
+    /// through a vertex or through the middle of a half-edge. The two faces
+    /// that result from the split share a new edge, that lies on the path
+    /// between those two points.
+    ///
+    /// Half-edges that the path crosses are split in two. So are their
+    /// siblings in the adjacent faces of the shell, to keep the shell
+    /// watertight.
+    ///
+    /// Returns the updated shell, as well as the two faces that replace the
+    /// original face.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only straight paths are supported, and only faces that are bounded by
+    /// straight half-edges and have no holes.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the face is not part of the shell, if it is not supported
+    /// (see above), or if the path doesn't cross its boundary exactly twice.
+    #[must_use]
+    fn split_face(
+        &self,
+        face: &Handle<Face>,
+        path: SurfacePath,
+        services: &mut Services,
+    ) -> (Self, [Handle<Face>; 2]);
+}
+
+impl SplitFace for Shell {
+    fn split_face(
+        &self,
+        face: &Handle<Face>,
+        path: SurfacePath,
+        services: &mut Services,
+    ) -> (Self, [Handle<Face>; 2]) {
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        assert!(
+            self.faces().into_iter().any(|f| f.id() == face.id()),
+            "Face to split must be part of the shell"
+        );
+        if face.region().interiors().next().is_some() {
+            todo!("Splitting faces with holes is not supported")
+        }
+        let SurfacePath::Line(line) = path else {
+            todo!("Splitting faces along curved paths is not supported")
+        };
+
+        let exterior = face.region().exterior();
+
+        // Find out where the path crosses the boundary. A path that goes
+        // through a vertex, also goes through the end of the half-edge before
+        // it. Only the start of the half-edge after it is considered a hit, so
+        // the vertex isn't counted twice.
+        let mut hits = Vec::new();
+        for (index, half_edge) in exterior.half_edges().enumerate() {
+            let SurfacePath::Line(edge_line) = half_edge.path() else {
+                todo!("Splitting faces with curved boundaries is not supported")
+            };
+            let [a, b] = half_edge.boundary().inner;
+            let [start, end] =
+                [a, b].map(|point| edge_line.point_from_line_coords(point));
+
+            let t = match line.intersect(edge_line) {
+                LineIntersection::Point {
+                    line_coords: [_, t],
+                    ..
+                } => t,
+                LineIntersection::Coincident => {
+                    panic!(
+                        "Path to split face along must not run along its \
+                        boundary"
+                    )
+                }
+                LineIntersection::Parallel => continue,
+            };
+            let point = edge_line.point_from_line_coords(t);
+
+            if (point - start).magnitude() < epsilon {
+                hits.push(Hit::Vertex { index });
+                continue;
+            }
+            if (point - end).magnitude() < epsilon {
+                continue;
+            }
+
+            let fraction = (t.t - a.t) / (b.t - a.t);
+            if fraction > Scalar::ZERO && fraction < Scalar::ONE {
+                hits.push(Hit::HalfEdge { index, fraction });
+            }
+        }
+        assert_eq!(
+            hits.len(),
+            2,
+            "Path to split face along must cross its boundary exactly twice"
+        );
+
+        // Split the boundary at the hits. This creates new vertices, where the
+        // path crosses half-edges.
+        let mut half_edges = Vec::new();
+        let mut corners = Vec::new();
+        let mut split_half_edges = Vec::new();
+        for (index, half_edge) in exterior.half_edges().enumerate() {
+            let hit = hits.iter().find(|hit| match hit {
+                Hit::Vertex { index: i } | Hit::HalfEdge { index: i, .. } => {
+                    *i == index
+                }
+            });
+
+            match hit {
+                None => {
+                    half_edges.push(half_edge.clone());
+                }
+                Some(Hit::Vertex { .. }) => {
+                    corners.push(Corner {
+                        index: half_edges.len(),
+                        vertex: half_edge.start_vertex().clone(),
+                        position: half_edge.start_position(),
+                    });
+                    half_edges.push(half_edge.clone());
+                }
+                Some(Hit::HalfEdge { fraction, .. }) => {
+                    let vertex = Vertex::new().insert(services);
+                    let [before, after] = split_half_edge(
+                        half_edge,
+                        *fraction,
+                        vertex.clone(),
+                        [
+                            GlobalEdge::new().insert(services),
+                            GlobalEdge::new().insert(services),
+                        ],
+                        services,
+                    );
+
+                    half_edges.push(before.clone());
+                    corners.push(Corner {
+                        index: half_edges.len(),
+                        vertex: vertex.clone(),
+                        position: after.start_position(),
+                    });
+                    half_edges.push(after.clone());
+
+                    split_half_edges.push((
+                        half_edge.clone(),
+                        *fraction,
+                        vertex,
+                        [before, after],
+                    ));
+                }
+            }
+        }
+        let [a, b] = [&corners[0], &corners[1]];
+
+        // The new edge that the two faces share.
+        let curve = Curve::new().insert(services);
+        let global_form = GlobalEdge::new().insert(services);
+        let [chord_a, chord_b] = [(b, a), (a, b)].map(|(start, end)| {
+            HalfEdge::line_segment(
+                [start.position, end.position],
+                None,
+                services,
+            )
+            .replace_curve(curve.clone())
+            .replace_start_vertex(start.vertex.clone())
+            .replace_global_form(global_form.clone())
+            .insert(services)
+        });
+
+        let cycle_a = half_edges[a.index..b.index]
+            .iter()
+            .cloned()
+            .chain([chord_a]);
+        let cycle_b = half_edges[b.index..]
+            .iter()
+            .chain(&half_edges[..a.index])
+            .cloned()
+            .chain([chord_b]);
+
+        let [face_a, face_b] =
+            [Cycle::new(cycle_a), Cycle::new(cycle_b)].map(|cycle| {
+                let region = Region::new(
+                    cycle.insert(services),
+                    [],
+                    face.region().color(),
+                    face.region().material().cloned(),
+                )
+                .insert(services);
+                Face::new(face.surface().clone(), region).insert(services)
+            });
+
+        let mut shell = self
+            .replace_face(face, face_a.clone())
+            .add_faces([face_b.clone()]);
+
+        // The siblings of the split half-edges must be split too. A sibling
+        // goes the other way, so it's split at the opposite fraction, and its
+        // halves share their global forms with the opposite halves.
+        for (half_edge, fraction, vertex, [before, after]) in split_half_edges {
+            let sibling = shell.faces().into_iter().find_map(|face| {
+                face.region()
+                    .all_cycles()
+                    .flat_map(|cycle| cycle.half_edges())
+                    .find(|other| {
+                        other.id() != half_edge.id()
+                            && other.global_form().id()
+                                == half_edge.global_form().id()
+                    })
+                    .map(|sibling| (face.clone(), sibling.clone()))
+            });
+            let Some((adjacent, sibling)) = sibling else {
+                continue;
+            };
+
+            let halves = split_half_edge(
+                &sibling,
+                Scalar::ONE - fraction,
+                vertex,
+                [after.global_form().clone(), before.global_form().clone()],
+                services,
+            );
+
+            let cycles = adjacent.region().all_cycles().map(|cycle| {
+                if cycle.index_of(&sibling).is_none() {
+                    return cycle.clone();
+                }
+
+                Cycle::new(cycle.half_edges().flat_map(|half_edge| {
+                    if half_edge.id() == sibling.id() {
+                        halves.to_vec()
+                    } else {
+                        vec![half_edge.clone()]
+                    }
+                }))
+                .insert(services)
+            });
+            let mut cycles = cycles.collect::<Vec<_>>().into_iter();
+            let exterior =
+                cycles.next().expect("Region must have an exterior cycle");
+            let region = Region::new(
+                exterior,
+                cycles,
+                adjacent.region().color(),
+                adjacent.region().material().cloned(),
+            )
+            .insert(services);
+            let updated =
+                Face::new(adjacent.surface().clone(), region).insert(services);
+
+            shell = shell.replace_face(&adjacent, updated);
+        }
+
+        (shell, [face_a, face_b])
+    }
+}
+
+/// A point where the path to split along crosses the boundary of the face
+enum Hit {
+    /// The path goes through the start vertex of the half-edge at `index`
+    Vertex { index: usize },
+
+    /// The path crosses the half-edge at `index`, at the given fraction of it
+    HalfEdge { index: usize, fraction: Scalar },
+}
+
+/// A point of the split boundary, where the new edge starts or ends
+struct Corner {
+    /// The index of the half-edge that starts at the corner
+    index: usize,
+
+    vertex: Handle<Vertex>,
+    position: Point<2>,
+}
+
+/// Split a straight half-edge at the given fraction of its length
+fn split_half_edge(
+    half_edge: &HalfEdge,
+    fraction: Scalar,
+    vertex: Handle<Vertex>,
+    [global_before, global_after]: [Handle<GlobalEdge>; 2],
+    services: &mut Services,
+) -> [Handle<HalfEdge>; 2] {
+    let [a, b] = half_edge.boundary().inner;
+    let middle = a + (b - a) * fraction;
+
+    let before = HalfEdge::new(
+        half_edge.path().clone(),
+        [a, middle],
+        half_edge.curve().clone(),
+        half_edge.start_vertex().clone(),
+        global_before,
+        half_edge.kind(),
+    )
+    .insert(services);
+    let after = HalfEdge::new(
+        half_edge.path().clone(),
+        [middle, b],
+        half_edge.curve().clone(),
+        vertex,
+        global_after,
+        half_edge.kind(),
+    )
+    .insert(services);
+
+    [before, after]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        geometry::SurfacePath,
+        objects::{Face, Region, Shell, Sketch},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::SplitFace;
+
+    #[test]
+    fn split_face_through_half_edges() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = cuboid(&mut services);
+        let top = top_face(&shell);
+
+        // Split the top face down the middle, crossing two opposite half-edges.
+        let middle_of = |index| {
+            let cycle = top.region().exterior();
+            let half_edge = cycle.nth_half_edge(index).unwrap();
+            let next = cycle.half_edge_after(half_edge).unwrap();
+            let [a, b] =
+                [half_edge, next].map(|half_edge| half_edge.start_position());
+            a + (b - a) / 2.
+        };
+        let (path, _) = SurfacePath::line_from_points([0, 2].map(middle_of));
+
+        let (split, [a, b]) = shell.split_face(&top, path, &mut services);
+
+        assert_eq!(split.faces().into_iter().count(), 7);
+        assert_eq!(a.region().exterior().len(), 4);
+        assert_eq!(b.region().exterior().len(), 4);
+
+        // The side faces whose top edges were split, now have an additional
+        // half-edge.
+        let num_half_edges_of_sides = split
+            .faces()
+            .into_iter()
+            .filter(|face| face.id() != a.id() && face.id() != b.id())
+            .map(|face| face.region().exterior().len())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            num_half_edges_of_sides.iter().filter(|&&n| n == 5).count(),
+            2
+        );
+
+        let _ = split.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_face_through_vertices() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let shell = cuboid(&mut services);
+        let top = top_face(&shell);
+
+        // Split the top face along its diagonal.
+        let [a, b] = [0, 2].map(|index| {
+            top.region()
+                .exterior()
+                .nth_half_edge(index)
+                .unwrap()
+                .start_position()
+        });
+        let (path, _) = SurfacePath::line_from_points([a, b]);
+
+        let (split, [a, b]) = shell.split_face(&top, path, &mut services);
+
+        assert_eq!(split.faces().into_iter().count(), 7);
+        assert_eq!(a.region().exterior().len(), 3);
+        assert_eq!(b.region().exterior().len(), 3);
+
+        let _ = split.insert(&mut services);
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    fn cuboid(services: &mut Services) -> Shell {
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+                    services,
+                )
+                .insert(services),
+            )
+            .insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        let solid = (sketch, surface).sweep([0., 0., 2.], services);
+        let shell = solid
+            .shells()
+            .next()
+            .expect("Swept solid must have a shell")
+            .clone_object();
+        shell
+    }
+
+    fn top_face(shell: &Shell) -> Handle<Face> {
+        shell
+            .faces()
+            .into_iter()
+            .find(|face| {
+                face.region().exterior().half_edges().all(|half_edge| {
+                    let point = face
+                        .surface()
+                        .geometry()
+                        .point_from_surface_coords(half_edge.start_position());
+                    point.z == Scalar::from(2.)
+                })
+            })
+            .expect("Cuboid must have a top face")
+            .clone()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    objects::{Curve, GlobalEdge, HalfEdge, Vertex},
+    objects::{Curve, EdgeKind, GlobalEdge, HalfEdge, Vertex},
     storage::Handle,
 };
 
@@ -16,36 +16,56 @@ pub trait UpdateHalfEdge {
     /// Replace the global form of the half-edge
     #[must_use]
     fn replace_global_form(&self, global_form: Handle<GlobalEdge>) -> Self;
+
+    /// Set the kind of the half-edge
+    ///
+    /// See [`EdgeKind`].
+    #[must_use]
+    fn set_kind(&self, kind: EdgeKind) -> Self;
 }
 
 impl UpdateHalfEdge for HalfEdge {
     fn replace_curve(&self, curve: Handle<Curve>) -> Self {
         HalfEdge::new(
-            self.path(),
+            self.path().clone(),
             self.boundary(),
             curve,
             self.start_vertex().clone(),
             self.global_form().clone(),
+            self.kind(),
         )
     }
 
     fn replace_start_vertex(&self, start_vertex: Handle<Vertex>) -> Self {
         HalfEdge::new(
-            self.path(),
+            self.path().clone(),
             self.boundary(),
             self.curve().clone(),
             start_vertex,
             self.global_form().clone(),
+            self.kind(),
         )
     }
 
     fn replace_global_form(&self, global_form: Handle<GlobalEdge>) -> Self {
         HalfEdge::new(
-            self.path(),
+            self.path().clone(),
             self.boundary(),
             self.curve().clone(),
             self.start_vertex().clone(),
             global_form,
+            self.kind(),
+        )
+    }
+
+    fn set_kind(&self, kind: EdgeKind) -> Self {
+        HalfEdge::new(
+            self.path().clone(),
+            self.boundary(),
+            self.curve().clone(),
+            self.start_vertex().clone(),
+            self.global_form().clone(),
+            kind,
         )
     }
 }
@@ -1,8 +1,11 @@
 use std::array;
 
+use fj_interop::mesh::{Color, Material};
+
 use crate::{
     objects::{Face, Region},
-    operations::Polygon,
+    operations::{Insert, Polygon, UpdateRegion},
+    services::Services,
     storage::Handle,
 };
 
@@ -14,6 +17,31 @@ pub trait UpdateFace {
         &self,
         f: impl FnOnce(&Handle<Region>) -> Handle<Region>,
     ) -> Self;
+
+    /// Set the color of the face
+    ///
+    /// Replaces the region of the face with one that has the provided color.
+    #[must_use]
+    fn set_color(&self, color: Color, services: &mut Services) -> Self
+    where
+        Self: Sized,
+    {
+        self.update_region(|region| region.set_color(color).insert(services))
+    }
+
+    /// Set the material of the face
+    ///
+    /// Replaces the region of the face with one that has the provided
+    /// material.
+    #[must_use]
+    fn set_material(&self, material: Material, services: &mut Services) -> Self
+    where
+        Self: Sized,
+    {
+        self.update_region(|region| {
+            region.set_material(material).insert(services)
+        })
+    }
 }
 
 impl UpdateFace for Face {
@@ -1,3 +1,5 @@
+use fj_interop::mesh::{Color, Material};
+
 use crate::{
     objects::{Cycle, Region},
     storage::Handle,
@@ -18,6 +20,20 @@ pub trait UpdateRegion {
         &self,
         interiors: impl IntoIterator<Item = Handle<Cycle>>,
     ) -> Self;
+
+    /// Set the color of the region
+    ///
+    /// The color is used when the region is triangulated, and is inherited by
+    /// faces that are created from the region, for example by sweeping it.
+    #[must_use]
+    fn set_color(&self, color: Color) -> Self;
+
+    /// Set the material of the region
+    ///
+    /// Like the color, the material is used when the region is triangulated,
+    /// and is inherited by faces that are created from the region.
+    #[must_use]
+    fn set_material(&self, material: Material) -> Self;
 }
 
 impl UpdateRegion for Region {
@@ -26,7 +42,12 @@ impl UpdateRegion for Region {
         f: impl FnOnce(&Handle<Cycle>) -> Handle<Cycle>,
     ) -> Self {
         let exterior = f(self.exterior());
-        Region::new(exterior, self.interiors().cloned(), self.color())
+        Region::new(
+            exterior,
+            self.interiors().cloned(),
+            self.color(),
+            self.material().cloned(),
+        )
     }
 
     fn add_interiors(
@@ -34,6 +55,29 @@ impl UpdateRegion for Region {
         interiors: impl IntoIterator<Item = Handle<Cycle>>,
     ) -> Self {
         let interiors = self.interiors().cloned().chain(interiors);
-        Region::new(self.exterior().clone(), interiors, self.color())
+        Region::new(
+            self.exterior().clone(),
+            interiors,
+            self.color(),
+            self.material().cloned(),
+        )
+    }
+
+    fn set_color(&self, color: Color) -> Self {
+        Region::new(
+            self.exterior().clone(),
+            self.interiors().cloned(),
+            Some(color),
+            self.material().cloned(),
+        )
+    }
+
+    fn set_material(&self, material: Material) -> Self {
+        Region::new(
+            self.exterior().clone(),
+            self.interiors().cloned(),
+            self.color(),
+            Some(material),
+        )
     }
 }
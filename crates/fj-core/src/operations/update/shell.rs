@@ -1,5 +1,9 @@
+use fj_interop::mesh::Material;
+
 use crate::{
     objects::{Face, Shell},
+    operations::{Insert, UpdateFace},
+    services::Services,
     storage::Handle,
 };
 
@@ -20,6 +24,11 @@ pub trait UpdateShell {
     /// Remove a face from the shell
     #[must_use]
     fn remove_face(&self, handle: &Handle<Face>) -> Self;
+
+    /// Set the material of all faces of the shell
+    #[must_use]
+    fn set_material(&self, material: Material, services: &mut Services)
+        -> Self;
 }
 
 impl UpdateShell for Shell {
@@ -53,4 +62,17 @@ impl UpdateShell for Shell {
 
         Shell::new(faces)
     }
+
+    fn set_material(
+        &self,
+        material: Material,
+        services: &mut Services,
+    ) -> Self {
+        let faces = self.faces().into_iter().map(|face| {
+            face.set_material(material.clone(), services)
+                .insert(services)
+        });
+
+        Shell::new(faces)
+    }
 }
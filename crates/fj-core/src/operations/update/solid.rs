@@ -1,5 +1,9 @@
+use fj_interop::mesh::Material;
+
 use crate::{
     objects::{Shell, Solid},
+    operations::{Insert, UpdateShell},
+    services::Services,
     storage::Handle,
 };
 
@@ -11,6 +15,11 @@ pub trait UpdateSolid {
         &self,
         shells: impl IntoIterator<Item = Handle<Shell>>,
     ) -> Self;
+
+    /// Set the material of all faces of the solid
+    #[must_use]
+    fn set_material(&self, material: Material, services: &mut Services)
+        -> Self;
 }
 
 impl UpdateSolid for Solid {
@@ -21,4 +30,18 @@ impl UpdateSolid for Solid {
         let shells = self.shells().cloned().chain(shells);
         Solid::new(shells)
     }
+
+    fn set_material(
+        &self,
+        material: Material,
+        services: &mut Services,
+    ) -> Self {
+        let shells = self.shells().map(|shell| {
+            shell
+                .set_material(material.clone(), services)
+                .insert(services)
+        });
+
+        Solid::new(shells)
+    }
 }
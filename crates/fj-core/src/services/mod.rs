@@ -1,18 +1,21 @@
 //! Service API that promotes monitoring and interactivity
 //!
-//! See [`Service`].
+//! See [`Service`]. To monitor the kernel services, see
+//! [`Services::subscribe`].
 
 mod objects;
 mod service;
 mod validation;
 
+use std::sync::mpsc;
+
 use crate::{
     objects::{Object, ObjectSet, Objects, WithHandle},
-    validate::ValidationErrors,
+    validate::{ValidationConfig, ValidationErrors},
 };
 
 pub use self::{
-    objects::{InsertObject, Operation},
+    objects::{ObjectEvent, Operation},
     service::{Service, State},
     validation::{Validation, ValidationCommand, ValidationEvent},
 };
@@ -28,6 +31,12 @@ pub struct Services {
     ///
     /// Validates objects that are inserted using the objects service.
     pub validation: Service<Validation>,
+
+    /// The steps that can be undone or redone
+    history: History,
+
+    /// The senders of the channels that were handed out to subscribers
+    subscribers: Vec<mpsc::Sender<ServiceEvent>>,
 }
 
 impl Services {
@@ -39,30 +48,209 @@ impl Services {
         Self {
             objects,
             validation,
+            history: History::default(),
+            subscribers: Vec::new(),
         }
     }
 
+    /// Construct an instance of `Services`, using the provided configuration
+    ///
+    /// The configuration is used to validate all objects that are inserted.
+    pub fn new_with_validation_config(config: ValidationConfig) -> Self {
+        let objects = Service::<Objects>::default();
+        let validation =
+            Service::new(Validation::with_validation_config(config));
+
+        Self {
+            objects,
+            validation,
+            history: History::default(),
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// Subscribe to the events produced by the services
+    ///
+    /// All events that the services produce from then on, are sent to the
+    /// returned receiver, in the order in which they were executed.
+    ///
+    /// The receiver can be dropped at any time. The services then stop sending
+    /// events to it.
+    pub fn subscribe(&mut self) -> mpsc::Receiver<ServiceEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
     /// Insert an object into the stores
+    ///
+    /// The insertion can be undone using [`Services::undo`].
     pub fn insert_object(&mut self, object: Object<WithHandle>) {
+        let mut step = self.start_step();
+
         let mut object_events = Vec::new();
         self.objects
             .execute(Operation::InsertObject { object }, &mut object_events);
 
         for object_event in object_events {
+            self.notify(ServiceEvent::Object(object_event.clone()));
+
+            let ObjectEvent::InsertObject { object } = object_event else {
+                continue;
+            };
+
             let command = ValidationCommand::ValidateObject {
-                object: object_event.object.into(),
+                object: object.clone().into(),
             };
-            self.validation.execute(command, &mut Vec::new());
+            let mut validation_events = Vec::new();
+            self.validation.execute(command, &mut validation_events);
+
+            for validation_event in validation_events {
+                self.notify(ServiceEvent::Validation(validation_event));
+            }
+
+            step.objects.push(object);
         }
+
+        self.history.undo.push(step);
     }
 
     /// Validate the provided objects and forget all other validation errors
+    ///
+    /// This can be undone using [`Services::undo`], which restores the
+    /// forgotten validation errors.
     pub fn only_validate(&mut self, objects: impl Into<ObjectSet>) {
         let objects = objects.into();
+        self.execute_validation_step(ValidationCommand::OnlyValidate {
+            objects,
+        });
+    }
+
+    /// Validate all objects in the stores, keeping all other validation errors
+    ///
+    /// Objects are validated when they are inserted, but their validation
+    /// errors might have been forgotten since, by [`Services::only_validate`]
+    /// or [`Services::clear_validation_errors`]. This validates every object
+    /// again, resulting in a full report of what's wrong with them.
+    ///
+    /// This can be undone using [`Services::undo`].
+    pub fn validate_all(&mut self) {
+        let objects = self.objects.all().collect();
+        self.execute_validation_step(ValidationCommand::ValidateObjects {
+            objects,
+        });
+    }
+
+    /// Forget all validation errors
+    ///
+    /// This can be undone using [`Services::undo`], which restores the
+    /// forgotten validation errors.
+    pub fn clear_validation_errors(&mut self) {
+        self.execute_validation_step(ValidationCommand::ClearErrors);
+    }
+
+    /// Undo the last step: an insert, or a call to a validation method
+    ///
+    /// The validation methods are [`Services::only_validate`],
+    /// [`Services::validate_all`], and [`Services::clear_validation_errors`].
+    ///
+    /// Inserted objects are removed from the stores, using the inverse
+    /// operation, [`Operation::RemoveObject`]. The validation service is rolled
+    /// back in lockstep, by replaying its events up to the point before the
+    /// undone step.
+    ///
+    /// Returns `false`, if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(mut step) = self.history.undo.pop() else {
+            return false;
+        };
+
+        self.notify(ServiceEvent::Undo);
+
+        for object in step.objects.iter().rev() {
+            let command = Operation::RemoveObject {
+                object: object.clone().into(),
+            };
+            self.execute_object_operation(command);
+        }
+
+        step.validation_events =
+            self.validation
+                .rewind(step.num_validation_events, |validation| {
+                    validation.errors.clear();
+                });
+
+        self.history.redo.push(step);
+        true
+    }
+
+    /// Redo the last step that was undone using [`Services::undo`]
+    ///
+    /// The validation events that were rolled back are applied again, as they
+    /// were. Any step that is taken after an undo, discards the steps that can
+    /// be redone.
+    ///
+    /// Returns `false`, if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(mut step) = self.history.redo.pop() else {
+            return false;
+        };
+
+        self.notify(ServiceEvent::Redo);
+
+        for object in &step.objects {
+            let command = Operation::InsertObject {
+                object: object.clone(),
+            };
+            self.execute_object_operation(command);
+        }
+
+        for event in &step.validation_events {
+            self.notify(ServiceEvent::Validation(event.clone()));
+        }
+        self.validation.apply(step.validation_events.drain(..));
+
+        self.history.undo.push(step);
+        true
+    }
+
+    fn execute_validation_step(&mut self, command: ValidationCommand) {
+        let step = self.start_step();
 
         let mut events = Vec::new();
-        self.validation
-            .execute(ValidationCommand::OnlyValidate { objects }, &mut events);
+        self.validation.execute(command, &mut events);
+
+        for event in events {
+            self.notify(ServiceEvent::Validation(event));
+        }
+
+        self.history.undo.push(step);
+    }
+
+    fn execute_object_operation(&mut self, operation: Operation) {
+        let mut events = Vec::new();
+        self.objects.execute(operation, &mut events);
+
+        for event in events {
+            self.notify(ServiceEvent::Object(event));
+        }
+    }
+
+    fn notify(&mut self, event: ServiceEvent) {
+        // Sending fails, if the receiver has been dropped. Nobody is listening
+        // on that channel anymore, so we can forget about it.
+        self.subscribers
+            .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
+
+    fn start_step(&mut self) -> Step {
+        self.history.redo.clear();
+
+        Step {
+            objects: Vec::new(),
+            num_validation_events: self.validation.events().len(),
+            validation_events: Vec::new(),
+        }
     }
 
     /// Drop `Services`; return any unhandled validation error
@@ -84,3 +272,174 @@ impl Default for Services {
         Self::new()
     }
 }
+
+/// An event produced by the kernel services
+///
+/// See [`Services::subscribe`].
+#[derive(Clone, Debug)]
+pub enum ServiceEvent {
+    /// An event produced by the objects service
+    Object(ObjectEvent),
+
+    /// An event produced by the validation service
+    Validation(ValidationEvent),
+
+    /// A step is being undone
+    ///
+    /// Followed by the events that undo the step in the objects service. The
+    /// validation service is rolled back to its state before the step, without
+    /// producing any events.
+    Undo,
+
+    /// A step that was undone is being redone
+    ///
+    /// Followed by the events of the redone step.
+    Redo,
+}
+
+#[derive(Default)]
+struct History {
+    undo: Vec<Step>,
+    redo: Vec<Step>,
+}
+
+/// A step that can be undone, and redone after that
+struct Step {
+    /// The objects that were inserted during the step
+    objects: Vec<Object<WithHandle>>,
+
+    /// The number of validation events that were logged before the step
+    num_validation_events: usize,
+
+    /// The validation events that were rolled back, when undoing the step
+    validation_events: Vec<ValidationEvent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        objects::{BehindHandle, HalfEdge, Object},
+        operations::{BuildHalfEdge, Insert},
+        storage::Handle,
+    };
+
+    use super::{ObjectEvent, ServiceEvent, Services, ValidationEvent};
+
+    #[test]
+    fn undo_and_redo() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let half_edge = insert_invalid_half_edge(&mut services);
+        let is_stored = |services: &Services| {
+            services
+                .objects
+                .half_edges
+                .iter()
+                .any(|stored| stored.id() == half_edge.id())
+        };
+        assert!(is_stored(&services));
+        assert!(!services.validation.errors.is_empty());
+
+        assert!(services.undo());
+        assert!(!is_stored(&services));
+        assert!(services.validation.errors.is_empty());
+
+        assert!(services.redo());
+        assert!(is_stored(&services));
+        assert!(services.validation.errors.contains_key(&half_edge.id()));
+        assert!(!services.redo());
+
+        assert!(services.undo());
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn subscribe() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let receiver = services.subscribe();
+
+        let half_edge = insert_invalid_half_edge(&mut services);
+
+        // `insert_invalid_half_edge` inserts some valid objects too. The events
+        // that are relevant here are the last ones.
+        let events = receiver.try_iter().collect::<Vec<_>>();
+        let [.., inserted, validated] = events.as_slice() else {
+            panic!("Expected events to be sent");
+        };
+        let ServiceEvent::Object(ObjectEvent::InsertObject { object }) =
+            inserted
+        else {
+            panic!("Expected object to be inserted");
+        };
+        let ServiceEvent::Validation(ValidationEvent::ValidationFailed {
+            object: failed,
+            ..
+        }) = validated
+        else {
+            panic!("Expected validation to fail");
+        };
+        assert_eq!(
+            Object::<BehindHandle>::from(object.clone()).id(),
+            half_edge.id()
+        );
+        assert_eq!(failed.id(), half_edge.id());
+
+        // Dropping the receiver must not affect the services.
+        drop(receiver);
+        assert!(services.undo());
+        assert!(services.subscribers.is_empty());
+
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_all() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let a = insert_invalid_half_edge(&mut services);
+        services.clear_validation_errors();
+        assert!(services.validation.errors.is_empty());
+
+        let b = insert_invalid_half_edge(&mut services);
+        assert!(!services.validation.errors.contains_key(&a.id()));
+        assert!(services.validation.errors.contains_key(&b.id()));
+
+        // Validating all objects brings back the forgotten error, and keeps the
+        // other one.
+        services.validate_all();
+        assert_eq!(services.validation.errors.len(), 2);
+        assert!(services.validation.errors.contains_key(&a.id()));
+        assert!(services.validation.errors.contains_key(&b.id()));
+
+        assert!(services.undo());
+        assert_eq!(services.validation.errors.len(), 1);
+        assert!(services.redo());
+        assert_eq!(services.validation.errors.len(), 2);
+
+        services.clear_validation_errors();
+        services.drop_and_validate()?;
+
+        Ok(())
+    }
+
+    fn insert_invalid_half_edge(services: &mut Services) -> Handle<HalfEdge> {
+        let valid =
+            HalfEdge::line_segment([[0., 0.], [1., 0.]], None, services);
+        let invalid = HalfEdge::new(
+            valid.path().clone(),
+            [Point::from([0.]); 2],
+            valid.curve().clone(),
+            valid.start_vertex().clone(),
+            valid.global_form().clone(),
+            valid.kind(),
+        );
+
+        invalid.insert(services)
+    }
+}
@@ -1,18 +1,33 @@
-use crate::objects::{Object, Objects, WithHandle};
+use crate::objects::{BehindHandle, Object, Objects, WithHandle};
 
 use super::State;
 
 impl State for Objects {
     type Command = Operation;
-    type Event = InsertObject;
+    type Event = ObjectEvent;
 
     fn decide(&self, command: Self::Command, events: &mut Vec<Self::Event>) {
-        let Operation::InsertObject { object } = command;
-        events.push(InsertObject { object });
+        let event = match command {
+            Operation::InsertObject { object } => {
+                ObjectEvent::InsertObject { object }
+            }
+            Operation::RemoveObject { object } => {
+                ObjectEvent::RemoveObject { object }
+            }
+        };
+
+        events.push(event);
     }
 
     fn evolve(&mut self, event: &Self::Event) {
-        event.object.clone().insert(self);
+        match event {
+            ObjectEvent::InsertObject { object } => {
+                object.clone().insert(self);
+            }
+            ObjectEvent::RemoveObject { object } => {
+                object.clone().remove(self);
+            }
+        }
     }
 }
 
@@ -27,11 +42,29 @@ pub enum Operation {
         /// The object to insert
         object: Object<WithHandle>,
     },
+
+    /// Remove an object from the stores
+    ///
+    /// This is the inverse of [`Operation::InsertObject`]. Inserting the object
+    /// again restores it.
+    RemoveObject {
+        /// The object to remove
+        object: Object<BehindHandle>,
+    },
 }
 
 /// Event produced by `Service<Objects>`
 #[derive(Clone, Debug)]
-pub struct InsertObject {
-    /// The object to insert
-    pub object: Object<WithHandle>,
+pub enum ObjectEvent {
+    /// An object was inserted into the stores
+    InsertObject {
+        /// The object to insert
+        object: Object<WithHandle>,
+    },
+
+    /// An object was removed from the stores
+    RemoveObject {
+        /// The object to remove
+        object: Object<BehindHandle>,
+    },
 }
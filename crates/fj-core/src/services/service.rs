@@ -21,12 +21,16 @@ use std::ops::Deref;
 /// <https://thinkbeforecoding.com/post/2021/12/17/functional-event-sourcing-decider>
 pub struct Service<S: State> {
     state: S,
+    events: Vec<S::Event>,
 }
 
 impl<S: State> Service<S> {
     /// Create an instance of `Service`
     pub fn new(state: S) -> Self {
-        Self { state }
+        Self {
+            state,
+            events: Vec::new(),
+        }
     }
 
     /// Execute a command
@@ -38,9 +42,47 @@ impl<S: State> Service<S> {
 
         for event in events {
             self.state.evolve(event);
+            self.events.push(event.clone());
+        }
+    }
+
+    /// Access the log of all events that evolved the state so far
+    pub fn events(&self) -> &[S::Event] {
+        &self.events
+    }
+
+    /// Evolve the state according to events that were produced earlier
+    ///
+    /// Unlike [`Service::execute`], this doesn't decide anything anew. The
+    /// provided events are applied as-is, and are added to the log. This can be
+    /// used to re-apply events that were removed by [`Service::rewind`].
+    pub fn apply(&mut self, events: impl IntoIterator<Item = S::Event>) {
+        for event in events {
+            self.state.evolve(&event);
+            self.events.push(event);
         }
     }
 
+    /// Roll the state back to where it was after the first `num_events` events
+    ///
+    /// `reset` must return the state to where it was, before any events were
+    /// applied to it. The first `num_events` events from the log are then
+    /// replayed on that state.
+    ///
+    /// Returns the events that were removed from the log.
+    pub fn rewind(
+        &mut self,
+        num_events: usize,
+        reset: impl FnOnce(&mut S),
+    ) -> Vec<S::Event> {
+        let removed = self.events.split_off(num_events);
+
+        reset(&mut self.state);
+        Self::replay(&mut self.state, &self.events);
+
+        removed
+    }
+
     /// Replay the provided events on the given state
     pub fn replay<'event>(
         state: &mut S,
@@ -84,7 +126,7 @@ pub trait State {
     ///
     /// Events are produced by [`State::decide`] and processed by
     /// [`State::evolve`].
-    type Event;
+    type Event: Clone;
 
     /// Decide how to react to the provided command
     ///
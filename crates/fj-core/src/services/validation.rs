@@ -3,7 +3,7 @@ use std::{collections::BTreeMap, error::Error, thread};
 use crate::{
     objects::{BehindHandle, Object, ObjectSet},
     storage::ObjectId,
-    validate::ValidationError,
+    validate::{ValidationConfig, ValidationError},
 };
 
 use super::State;
@@ -13,6 +13,41 @@ use super::State;
 pub struct Validation {
     /// All unhandled validation errors
     pub errors: BTreeMap<ObjectId, ValidationError>,
+
+    /// Validation configuration for the validation service
+    config: ValidationConfig,
+}
+
+impl Validation {
+    /// Construct an instance of `Validation`, using the provided configuration
+    pub fn with_validation_config(config: ValidationConfig) -> Self {
+        let errors = BTreeMap::new();
+        Self { errors, config }
+    }
+
+    /// Access the configuration that is used for validation
+    pub fn config(&self) -> &ValidationConfig {
+        &self.config
+    }
+
+    fn validate_objects(
+        &self,
+        objects: impl IntoIterator<Item = Object<BehindHandle>>,
+        events: &mut Vec<ValidationEvent>,
+    ) {
+        let mut errors = Vec::new();
+
+        for object in objects {
+            object.validate_with_config(&self.config, &mut errors);
+
+            for err in errors.drain(..) {
+                events.push(ValidationEvent::ValidationFailed {
+                    object: object.clone(),
+                    err: Box::new(err),
+                });
+            }
+        }
+    }
 }
 
 impl Drop for Validation {
@@ -52,28 +87,24 @@ impl State for Validation {
 
         match command {
             ValidationCommand::ValidateObject { object } => {
-                object.validate(&mut errors);
+                object.validate_with_config(&self.config, &mut errors);
 
                 for err in errors {
                     events.push(ValidationEvent::ValidationFailed {
                         object: object.clone(),
-                        err,
+                        err: Box::new(err),
                     });
                 }
             }
             ValidationCommand::OnlyValidate { objects } => {
                 events.push(ValidationEvent::ClearErrors);
-
-                for object in objects {
-                    object.validate(&mut errors);
-
-                    for err in errors.drain(..) {
-                        events.push(ValidationEvent::ValidationFailed {
-                            object: object.clone(),
-                            err,
-                        });
-                    }
-                }
+                self.validate_objects(objects, events);
+            }
+            ValidationCommand::ValidateObjects { objects } => {
+                self.validate_objects(objects, events);
+            }
+            ValidationCommand::ClearErrors => {
+                events.push(ValidationEvent::ClearErrors);
             }
         }
     }
@@ -81,7 +112,7 @@ impl State for Validation {
     fn evolve(&mut self, event: &Self::Event) {
         match event {
             ValidationEvent::ValidationFailed { object, err } => {
-                self.errors.insert(object.id(), err.clone());
+                self.errors.insert(object.id(), err.as_ref().clone());
             }
             ValidationEvent::ClearErrors => self.errors.clear(),
         }
@@ -101,10 +132,19 @@ pub enum ValidationCommand {
         /// The objects to validate
         objects: ObjectSet,
     },
+
+    /// Validate the provided objects, keep all other validation errors
+    ValidateObjects {
+        /// The objects to validate
+        objects: Vec<Object<BehindHandle>>,
+    },
+
+    /// Discard all validation errors
+    ClearErrors,
 }
 
 /// The event produced by the validation service
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum ValidationEvent {
     /// Validation of an object failed
     ValidationFailed {
@@ -112,7 +152,7 @@ pub enum ValidationEvent {
         object: Object<BehindHandle>,
 
         /// The validation error
-        err: ValidationError,
+        err: Box<ValidationError>,
     },
 
     /// All stored validation errors are being cleared
@@ -50,6 +50,16 @@ impl<T> Blocks<T> {
         block.insert(index.object_index, object);
     }
 
+    pub fn remove(&mut self, index: Index) {
+        let block = &mut self.inner[index.block_index.0];
+        block.remove(index.object_index);
+    }
+
+    pub fn is_removed(&self, index: Index) -> bool {
+        let block = &self.inner[index.block_index.0];
+        block.is_removed(index.object_index)
+    }
+
     pub fn get_and_inc(&self, index: &mut Index) -> Option<&Option<T>> {
         let block = self.inner.get(index.block_index.0)?;
         let object = block.get(index.object_index);
@@ -63,6 +73,7 @@ impl<T> Blocks<T> {
 #[derive(Debug)]
 pub struct Block<T> {
     objects: Box<[Option<T>]>,
+    removed: Box<[bool]>,
     next: ObjectIndex,
 }
 
@@ -72,9 +83,11 @@ impl<T> Block<T> {
             .take(size)
             .collect::<Vec<Option<T>>>();
         let objects = vec.into_boxed_slice();
+        let removed = vec![false; size].into_boxed_slice();
 
         Self {
             objects,
+            removed,
             next: ObjectIndex(0),
         }
     }
@@ -92,6 +105,13 @@ impl<T> Block<T> {
     }
 
     pub fn insert(&mut self, index: ObjectIndex, object: T) {
+        if self.removed[index.0] {
+            // The object in the slot is being restored. References to it might
+            // still exist, so we keep it, instead of overwriting it.
+            self.removed[index.0] = false;
+            return;
+        }
+
         let slot = &mut self.objects[index.0];
 
         assert!(slot.is_none(), "Attempting to overwrite object in store");
@@ -99,6 +119,23 @@ impl<T> Block<T> {
         *slot = Some(object);
     }
 
+    pub fn remove(&mut self, index: ObjectIndex) {
+        assert!(
+            self.objects[index.0].is_some(),
+            "Attempting to remove object that was never inserted"
+        );
+        assert!(
+            !self.removed[index.0],
+            "Attempting to remove object that has already been removed"
+        );
+
+        self.removed[index.0] = true;
+    }
+
+    pub fn is_removed(&self, index: ObjectIndex) -> bool {
+        self.removed[index.0]
+    }
+
     pub fn get(&self, index: ObjectIndex) -> &Option<T> {
         &self.objects[index.0]
     }
@@ -20,6 +20,11 @@
 //! a custom development seemed justified.
 //!
 //! But in any case, this was fun to write, and not that much work.
+//!
+//! Objects can be removed from a store, to support undoing their insertion.
+//! Since handles to a removed object might still exist, it is never actually
+//! deallocated. Removing it only hides it from iteration, until it is inserted
+//! again.
 
 use std::{marker::PhantomData, sync::Arc};
 
@@ -78,16 +83,35 @@ impl<T> Store<T> {
 
     /// Insert an object into the store
     ///
+    /// If the passed `Handle` refers to an object that has been removed using
+    /// [`Store::remove`], that object is restored. The passed object is
+    /// expected to be equal to it, and is discarded.
+    ///
     /// # Panics
     ///
-    /// Panics, if the passed `Handle` does not refer to a reserved slot. This
-    /// can only be the case, if the handle has been used to insert an object
-    /// before.
+    /// Panics, if the passed `Handle` does not refer to a reserved slot or a
+    /// removed object. This can only be the case, if the handle has been used
+    /// to insert an object before.
     pub fn insert(&mut self, handle: Handle<T>, object: T) {
         let mut inner = self.inner.write();
         inner.blocks.insert(handle.index, object);
     }
 
+    /// Remove an object from the store
+    ///
+    /// The object is no longer returned when iterating over the store, but
+    /// `handle` and any of its clones can still be used to access it. Passing
+    /// `handle` to [`Store::insert`] restores the object.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if no object has been inserted using the passed `Handle`, or if
+    /// the object has already been removed.
+    pub fn remove(&mut self, handle: &Handle<T>) {
+        let mut inner = self.inner.write();
+        inner.blocks.remove(handle.index);
+    }
+
     /// Iterate over all objects in this store
     pub fn iter(&self) -> Iter<T> {
         Iter {
@@ -134,6 +158,9 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
                 // This is a reserved slot.
                 continue;
             }
+            if inner.blocks.is_removed(index) {
+                continue;
+            }
 
             return Some(Handle {
                 store: self.store.clone(),
@@ -181,4 +208,24 @@ mod tests {
         let objects = store.iter().collect::<Vec<_>>();
         assert_eq!(objects, [a, b]);
     }
+
+    #[test]
+    fn remove_and_restore() {
+        let mut store = Store::with_block_size(1);
+
+        let a: Handle<i32> = store.reserve();
+        let b = store.reserve();
+        store.insert(a.clone(), 0);
+        store.insert(b.clone(), 1);
+
+        store.remove(&a);
+        assert!(!store.iter().any(|handle| handle.id() == a.id()));
+
+        // The handle can still be used to access the object.
+        assert_eq!(*a, 0);
+
+        store.insert(a.clone(), 0);
+        let objects = store.iter().collect::<Vec<_>>();
+        assert_eq!(objects, [a, b]);
+    }
 }
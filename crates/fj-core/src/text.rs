@@ -0,0 +1,621 @@
+//! Build sketches from text
+//!
+//! See [`Font`].
+
+use std::{collections::BTreeMap, mem};
+
+use fj_math::{Point, Scalar, Segment, SegmentIntersection, Vector};
+use itertools::Itertools;
+use ttf_parser::{Face, FaceParsingError, GlyphId, OutlineBuilder};
+
+use crate::{
+    algorithms::approx::Tolerance,
+    objects::{Cycle, Region, Sketch},
+    operations::{
+        BuildCycle, BuildRegion, BuildSketch, Insert, UpdateRegion,
+        UpdateSketch,
+    },
+    services::Services,
+    validate::ValidationConfig,
+};
+
+/// A font, for turning text into sketches
+///
+/// Sweep the resulting sketch, to get embossed text.
+pub struct Font<'a> {
+    face: Face<'a>,
+}
+
+impl<'a> Font<'a> {
+    /// Parse a font from the contents of a TrueType or OpenType font file
+    ///
+    /// If the file contains a font collection, the first font is used.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, InvalidFont> {
+        let face = Face::parse(data, 0)?;
+        Ok(Self { face })
+    }
+
+    /// Build a sketch from the outlines of the glyphs that make up the text
+    ///
+    /// `size` is the size of the font, the distance between two lines of text,
+    /// roughly. The text starts at the origin, on its baseline, and runs along
+    /// the u-axis. Each line after the first moves down the v-axis.
+    ///
+    /// The curved segments of the glyph outlines are approximated by straight
+    /// lines, that deviate from them by no more than the tolerance. For a
+    /// relative tolerance, the size of the feature is the size of the font.
+    ///
+    /// Glyphs are combined using the non-zero winding rule, as is the
+    /// convention for fonts. Overlapping contours, whether they are from the
+    /// same glyph or from neighboring ones, are merged into a single region.
+    /// The counters of glyphs, like the hole in an "o", become the interiors of
+    /// their regions.
+    pub fn sketch(
+        &self,
+        text: &str,
+        size: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        services: &mut Services,
+    ) -> Sketch {
+        let size = size.into();
+        let tolerance = tolerance.into().for_feature_size(size);
+
+        let scale = size / f64::from(self.face.units_per_em());
+        let line_height = scale
+            * f64::from(
+                i32::from(self.face.ascender())
+                    - i32::from(self.face.descender())
+                    + i32::from(self.face.line_gap()),
+            );
+
+        let mut outlines = GlyphOutlines {
+            contours: Vec::new(),
+            current: Vec::new(),
+            origin: Point::origin(),
+            scale,
+            tolerance,
+        };
+
+        for (i, line) in text.lines().enumerate() {
+            let mut u = Scalar::ZERO;
+            let v = -line_height * i as f64;
+
+            for c in line.chars() {
+                // Characters that the font doesn't have a glyph for, are
+                // represented by the glyph the font provides for that case.
+                let glyph = self.face.glyph_index(c).unwrap_or(GlyphId(0));
+
+                outlines.origin = Point::from([u, v]);
+                self.face.outline_glyph(glyph, &mut outlines);
+                outlines.finish_contour();
+
+                let advance = self.face.glyph_hor_advance(glyph).unwrap_or(0);
+                u += scale * f64::from(advance);
+            }
+        }
+
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+        let shapes = resolve_contours(&outlines.contours, epsilon);
+
+        shapes.into_iter().fold(Sketch::empty(), |sketch, shape| {
+            let interiors = shape
+                .interiors
+                .into_iter()
+                .map(|interior| {
+                    Cycle::polygon(interior, services).insert(services)
+                })
+                .collect::<Vec<_>>();
+            let region = Region::polygon(shape.exterior, services)
+                .add_interiors(interiors)
+                .insert(services);
+
+            sketch.add_region(region)
+        })
+    }
+}
+
+/// Error parsing a font
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to parse font")]
+pub struct InvalidFont(#[from] FaceParsingError);
+
+/// Collects the contours of glyph outlines, approximated by polygons
+struct GlyphOutlines {
+    contours: Vec<Vec<Point<2>>>,
+    current: Vec<Point<2>>,
+
+    /// The position of the glyph that is currently being outlined
+    origin: Point<2>,
+
+    /// The factor that converts from font units to model units
+    scale: Scalar,
+
+    tolerance: Scalar,
+}
+
+impl GlyphOutlines {
+    fn point(&self, x: f32, y: f32) -> Point<2> {
+        self.origin + Vector::from([f64::from(x), f64::from(y)]) * self.scale
+    }
+
+    fn last(&self) -> Point<2> {
+        *self
+            .current
+            .last()
+            .expect("Font outline must start with a move before a curve")
+    }
+
+    /// Approximate a Bézier curve, starting at the last point, by lines
+    ///
+    /// The deviation of a line segment from the curve is limited by the
+    /// second derivative of the curve, which for a curve of degree `n` is
+    /// bounded by `n * (n - 1)` times the largest second difference of its
+    /// control points.
+    fn curve<const N: usize>(&mut self, control_points: [Point<2>; N]) {
+        let degree = N;
+        let points = [self.last()]
+            .into_iter()
+            .chain(control_points)
+            .collect::<Vec<_>>();
+
+        let max_second_difference = points
+            .iter()
+            .tuple_windows()
+            .map(|(a, b, c)| (a.coords - b.coords * 2. + c.coords).magnitude())
+            .max()
+            .unwrap_or(Scalar::ZERO);
+        let max_second_derivative =
+            max_second_difference * (degree * (degree - 1)) as f64;
+
+        // For a segment that spans `1 / n` of the curve, the deviation is at
+        // most an eighth of the second derivative, times `(1 / n)²`.
+        let num_segments = Scalar::from(
+            (max_second_derivative / (self.tolerance * 8.))
+                .into_f64()
+                .sqrt(),
+        )
+        .ceil()
+        .max(1.)
+        .into_u64();
+
+        for i in 1..=num_segments {
+            let t = Scalar::from(i as f64 / num_segments as f64);
+
+            // De Casteljau's algorithm
+            let mut points = points.clone();
+            while points.len() > 1 {
+                points = points
+                    .iter()
+                    .tuple_windows()
+                    .map(|(a, b)| *a + (*b - *a) * t)
+                    .collect();
+            }
+
+            self.current.push(points[0]);
+        }
+    }
+
+    fn finish_contour(&mut self) {
+        let contour = mem::take(&mut self.current);
+        if contour.len() >= 3 {
+            self.contours.push(contour);
+        }
+    }
+}
+
+impl OutlineBuilder for GlyphOutlines {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish_contour();
+        let point = self.point(x, y);
+        self.current.push(point);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.current.push(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let points = [self.point(x1, y1), self.point(x, y)];
+        self.curve(points);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let points = [self.point(x1, y1), self.point(x2, y2), self.point(x, y)];
+        self.curve(points);
+    }
+
+    fn close(&mut self) {
+        self.finish_contour();
+    }
+}
+
+/// A region, as defined by polygons
+#[derive(Debug)]
+struct Shape {
+    /// The exterior of the region, counter-clockwise
+    exterior: Vec<Point<2>>,
+
+    /// The interiors of the region, clockwise
+    interiors: Vec<Vec<Point<2>>>,
+}
+
+/// Turn contours that are filled according to the non-zero rule into shapes
+///
+/// The contours may intersect each other and themselves, and may be wound in
+/// any direction. The boundary of the area they fill is found in three steps:
+///
+/// 1. Split all contour segments wherever they touch another one.
+/// 2. Keep only those parts that have the filled area on exactly one side.
+///    Orient them, such that the filled area is on their left.
+/// 3. Connect those parts into polygons. Counter-clockwise polygons are
+///    exteriors, clockwise polygons are interiors.
+fn resolve_contours(contours: &[Vec<Point<2>>], epsilon: Scalar) -> Vec<Shape> {
+    let segments = contours
+        .iter()
+        .flat_map(|contour| {
+            contour
+                .iter()
+                .circular_tuple_windows()
+                .filter(|(&a, &b)| (b - a).magnitude() > epsilon)
+                .map(|(&a, &b)| Segment::from_points([a, b]))
+        })
+        .collect::<Vec<_>>();
+
+    // Step 1: Split the segments.
+    let mut splits = vec![Vec::new(); segments.len()];
+    for (i, a) in segments.iter().enumerate() {
+        for (j, b) in segments.iter().enumerate().skip(i + 1) {
+            for (k, segment, other) in [(i, a, b), (j, b, a)] {
+                for point in other.points() {
+                    if let Some(t) = split_at(segment, point, epsilon) {
+                        splits[k].push((t, point));
+                    }
+                }
+            }
+
+            if let SegmentIntersection::Point {
+                point,
+                segment_coords: [s, t],
+                is_within_both: true,
+            } = a.intersect(b)
+            {
+                let is_inside = |segment, t: Point<1>| {
+                    let length = length_of(segment);
+                    t.t * length > epsilon
+                        && (Scalar::ONE - t.t) * length > epsilon
+                };
+
+                if is_inside(a, s) && is_inside(b, t) {
+                    splits[i].push((s.t, point));
+                    splits[j].push((t.t, point));
+                }
+            }
+        }
+    }
+
+    let mut vertices = Vec::<Point<2>>::new();
+    let mut weld = |point: Point<2>| {
+        vertices
+            .iter()
+            .position(|vertex| (*vertex - point).magnitude() < epsilon)
+            .unwrap_or_else(|| {
+                vertices.push(point);
+                vertices.len() - 1
+            })
+    };
+
+    let mut edges = Vec::new();
+    for (segment, mut splits) in segments.iter().zip(splits) {
+        splits.sort_by_key(|&(t, _)| t);
+
+        let [start, end] = segment.points();
+        let points = [start]
+            .into_iter()
+            .chain(splits.into_iter().map(|(_, point)| point))
+            .chain([end]);
+
+        for (a, b) in points.tuple_windows() {
+            let [a, b] = [weld(a), weld(b)];
+            if a != b {
+                edges.push([a.min(b), a.max(b)]);
+            }
+        }
+    }
+    edges.sort();
+    edges.dedup();
+
+    // Step 2: Keep the parts of the boundary.
+    let boundary = edges
+        .into_iter()
+        .filter_map(|[a, b]| {
+            let [a_point, b_point] = [vertices[a], vertices[b]];
+            let direction = b_point - a_point;
+            let left = Vector::from([-direction.v, direction.u]).normalize();
+            let middle = a_point + direction / 2.;
+
+            let is_filled = |point| winding_number(&segments, point) != 0;
+            let is_filled_left = is_filled(middle + left * epsilon);
+            let is_filled_right = is_filled(middle - left * epsilon);
+
+            match (is_filled_left, is_filled_right) {
+                (true, false) => Some([a, b]),
+                (false, true) => Some([b, a]),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Step 3: Connect the parts.
+    let mut outgoing = BTreeMap::<_, Vec<_>>::new();
+    for (i, [a, _]) in boundary.iter().enumerate() {
+        outgoing.entry(*a).or_default().push(i);
+    }
+
+    let mut is_used = vec![false; boundary.len()];
+    let mut polygons = Vec::new();
+    for start in 0..boundary.len() {
+        if is_used[start] {
+            continue;
+        }
+
+        let mut polygon = Vec::new();
+        let mut current = start;
+        loop {
+            is_used[current] = true;
+
+            let [a, b] = boundary[current];
+            polygon.push(vertices[a]);
+
+            // Where polygons touch in a vertex, multiple edges go out from it.
+            // Taking the sharpest left turn keeps the polygons apart.
+            let reverse = vertices[a] - vertices[b];
+            let next = outgoing
+                .get(&b)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&edge| !is_used[edge] || edge == start)
+                .min_by_key(|&edge| {
+                    let [_, c] = boundary[edge];
+                    let direction = vertices[c] - vertices[b];
+
+                    let counter_clockwise = reverse
+                        .cross2d(&direction)
+                        .atan2(reverse.dot(&direction));
+                    let clockwise = (-counter_clockwise).into_f64();
+                    Scalar::from(if clockwise > 0. {
+                        clockwise
+                    } else {
+                        clockwise + Scalar::TAU.into_f64()
+                    })
+                })
+                .expect("Boundary of filled area must be closed");
+
+            if next == start {
+                break;
+            }
+            current = next;
+        }
+
+        polygons.push(polygon);
+    }
+
+    let (exteriors, interiors): (Vec<_>, Vec<_>) = polygons
+        .into_iter()
+        .partition(|polygon| signed_area(polygon) > Scalar::ZERO);
+
+    let mut shapes = exteriors
+        .into_iter()
+        .map(|exterior| Shape {
+            exterior,
+            interiors: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    for interior in interiors {
+        // The filled area is on the left of the interior, and within the
+        // exterior that it belongs to.
+        let [a, b] = [interior[0], interior[1]];
+        let direction = b - a;
+        let left = Vector::from([-direction.v, direction.u]).normalize();
+        let point = a + direction / 2. + left * epsilon;
+
+        let shape = shapes
+            .iter_mut()
+            .filter(|shape| {
+                let exterior = shape
+                    .exterior
+                    .iter()
+                    .circular_tuple_windows()
+                    .map(|(&a, &b)| Segment::from_points([a, b]))
+                    .collect::<Vec<_>>();
+                winding_number(&exterior, point) != 0
+            })
+            .min_by_key(|shape| signed_area(&shape.exterior))
+            .expect("Interior must be located within an exterior");
+
+        shape.interiors.push(interior);
+    }
+
+    shapes
+}
+
+fn length_of(segment: &Segment<2>) -> Scalar {
+    let [a, b] = segment.points();
+    (b - a).magnitude()
+}
+
+/// Determine where the point splits the segment, if it's on it
+///
+/// Returns the segment coordinate of the point, unless it is too far from the
+/// segment, or too close to one of its end points.
+fn split_at(
+    segment: &Segment<2>,
+    point: Point<2>,
+    epsilon: Scalar,
+) -> Option<Scalar> {
+    let [a, b] = segment.points();
+    let direction = b - a;
+    let length = direction.magnitude();
+
+    let t = (point - a).dot(&direction) / (length * length);
+    let distance = (point - (a + direction * t)).magnitude();
+
+    let is_split = t * length > epsilon
+        && (Scalar::ONE - t) * length > epsilon
+        && distance < epsilon;
+    is_split.then_some(t)
+}
+
+/// Compute how often the segments wind around the point
+///
+/// Counter-clockwise windings count as positive, clockwise ones as negative.
+fn winding_number(segments: &[Segment<2>], point: Point<2>) -> i32 {
+    let mut winding_number = 0;
+
+    for segment in segments {
+        let [a, b] = segment.points();
+        let is_left = (b - a).cross2d(&(point - a));
+
+        if a.v <= point.v {
+            if b.v > point.v && is_left > Scalar::ZERO {
+                winding_number += 1;
+            }
+        } else if b.v <= point.v && is_left < Scalar::ZERO {
+            winding_number -= 1;
+        }
+    }
+
+    winding_number
+}
+
+fn signed_area(polygon: &[Point<2>]) -> Scalar {
+    polygon
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| a.u * b.v - b.u * a.v)
+        .fold(Scalar::ZERO, |sum, area| sum + area)
+        / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::sweep::Sweep, operations::Insert, services::Services,
+    };
+
+    use super::{resolve_contours, signed_area, Font};
+
+    const FONT: &[u8] =
+        include_bytes!("../../fj-viewer/src/graphics/fonts/B612-Regular.ttf");
+
+    #[test]
+    fn sketch_from_text() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let font = Font::from_bytes(FONT)?;
+
+        let num_interiors = |text, services: &mut Services| {
+            font.sketch(text, 1., 0.01, services)
+                .regions()
+                .map(|region| region.interiors().count())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(num_interiors("l", &mut services), [0]);
+        assert_eq!(num_interiors("o", &mut services), [1]);
+        assert_eq!(num_interiors("B", &mut services), [2]);
+
+        let mut dot_and_stem = num_interiors("i", &mut services);
+        dot_and_stem.sort();
+        assert_eq!(dot_and_stem, [0, 0]);
+
+        assert!(num_interiors(" ", &mut services).is_empty());
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn emboss_text() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let font = Font::from_bytes(FONT)?;
+
+        let sketch = font.sketch("Ho", 1., 0.05, &mut services);
+        assert_eq!(sketch.regions().count(), 2);
+
+        let surface = services.objects.surfaces.xy_plane();
+        let _ = (sketch.insert(&mut services), surface)
+            .sweep([0., 0., 0.1], &mut services);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_overlapping_contours() {
+        let shapes = resolve_contours(
+            &[square([0., 0.], 2., false), square([1., 1.], 2., false)],
+            Scalar::from(1e-9),
+        );
+
+        let [shape] = shapes.as_slice() else {
+            panic!("Expected overlapping contours to be merged")
+        };
+        assert_eq!(shape.exterior.len(), 8);
+        assert_eq!(signed_area(&shape.exterior), Scalar::from(7.));
+        assert!(shape.interiors.is_empty());
+    }
+
+    #[test]
+    fn resolve_contours_by_non_zero_rule() {
+        let epsilon = Scalar::from(1e-9);
+
+        // Clockwise contours are filled just as well. The resulting exterior
+        // is always counter-clockwise.
+        let shapes = resolve_contours(&[square([0., 0.], 3., true)], epsilon);
+        let [shape] = shapes.as_slice() else {
+            panic!("Expected a single shape")
+        };
+        assert_eq!(signed_area(&shape.exterior), Scalar::from(9.));
+
+        // A nested contour of opposite winding is a hole.
+        let shapes = resolve_contours(
+            &[square([0., 0.], 3., false), square([1., 1.], 1., true)],
+            epsilon,
+        );
+        let [shape] = shapes.as_slice() else {
+            panic!("Expected a single shape")
+        };
+        let [interior] = shape.interiors.as_slice() else {
+            panic!("Expected a single interior")
+        };
+        assert_eq!(signed_area(interior), Scalar::from(-1.));
+
+        // A nested contour of the same winding is filled twice, which is not
+        // a hole.
+        let shapes = resolve_contours(
+            &[square([0., 0.], 3., false), square([1., 1.], 1., false)],
+            epsilon,
+        );
+        let [shape] = shapes.as_slice() else {
+            panic!("Expected a single shape")
+        };
+        assert!(shape.interiors.is_empty());
+    }
+
+    fn square([u, v]: [f64; 2], size: f64, clockwise: bool) -> Vec<Point<2>> {
+        let mut points =
+            [[u, v], [u + size, v], [u + size, v + size], [u, v + size]]
+                .map(Point::from)
+                .to_vec();
+
+        if clockwise {
+            points.reverse();
+        }
+
+        points
+    }
+}
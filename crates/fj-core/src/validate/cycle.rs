@@ -2,7 +2,10 @@ use fj_math::{Point, Scalar};
 
 use crate::objects::{Cycle, HalfEdge};
 
-use super::{Validate, ValidationConfig, ValidationError};
+use super::{
+    ErrorPosition, Validate, ValidationConfig, ValidationError,
+    ValidationErrorDetails, ValidationErrorKind,
+};
 
 impl Validate for Cycle {
     fn validate_with_config(
@@ -46,6 +49,39 @@ pub enum CycleValidationError {
     NotEnoughHalfEdges,
 }
 
+impl CycleValidationError {
+    /// Access structured information about the error
+    pub fn details(&self) -> ValidationErrorDetails {
+        match self {
+            Self::HalfEdgesDisconnected {
+                end_of_first,
+                start_of_second,
+                half_edges,
+                ..
+            } => {
+                let (first, second) = half_edges.as_ref();
+
+                ValidationErrorDetails {
+                    kind: ValidationErrorKind::HalfEdgesDisconnected,
+                    objects: vec![
+                        first.global_form().clone().into(),
+                        second.global_form().clone().into(),
+                    ],
+                    positions: vec![
+                        ErrorPosition::Surface(*end_of_first),
+                        ErrorPosition::Surface(*start_of_second),
+                    ],
+                }
+            }
+            Self::NotEnoughHalfEdges => ValidationErrorDetails {
+                kind: ValidationErrorKind::NotEnoughHalfEdges,
+                objects: Vec::new(),
+                positions: Vec::new(),
+            },
+        }
+    }
+}
+
 impl CycleValidationError {
     fn check_enough_half_edges(
         cycle: &Cycle,
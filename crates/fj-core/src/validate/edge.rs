@@ -2,7 +2,10 @@ use fj_math::{Point, Scalar};
 
 use crate::objects::{GlobalEdge, HalfEdge};
 
-use super::{Validate, ValidationConfig, ValidationError};
+use super::{
+    ErrorPosition, Validate, ValidationConfig, ValidationError,
+    ValidationErrorDetails, ValidationErrorKind,
+};
 
 impl Validate for HalfEdge {
     fn validate_with_config(
@@ -48,6 +51,30 @@ pub enum HalfEdgeValidationError {
     },
 }
 
+impl HalfEdgeValidationError {
+    /// Access structured information about the error
+    pub fn details(&self) -> ValidationErrorDetails {
+        match self {
+            Self::VerticesAreCoincident {
+                back_position,
+                front_position,
+                half_edge,
+                ..
+            } => ValidationErrorDetails {
+                kind: ValidationErrorKind::VerticesAreCoincident,
+                objects: vec![
+                    half_edge.global_form().clone().into(),
+                    half_edge.curve().clone().into(),
+                ],
+                positions: vec![
+                    ErrorPosition::Curve(*back_position),
+                    ErrorPosition::Curve(*front_position),
+                ],
+            },
+        }
+    }
+}
+
 impl HalfEdgeValidationError {
     fn check_vertex_coincidence(
         half_edge: &HalfEdge,
@@ -73,14 +100,17 @@ impl HalfEdgeValidationError {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Point;
+    use fj_math::{Point, Scalar};
 
     use crate::{
         assert_contains_err,
         objects::HalfEdge,
-        operations::BuildHalfEdge,
+        operations::{BuildHalfEdge, Insert},
         services::Services,
-        validate::{HalfEdgeValidationError, Validate, ValidationError},
+        validate::{
+            ErrorPosition, HalfEdgeValidationError, Validate, ValidationConfig,
+            ValidationError, ValidationErrorKind,
+        },
     };
 
     #[test]
@@ -93,11 +123,12 @@ mod tests {
             let boundary = [Point::from([0.]); 2];
 
             HalfEdge::new(
-                valid.path(),
+                valid.path().clone(),
                 boundary,
                 valid.curve().clone(),
                 valid.start_vertex().clone(),
                 valid.global_form().clone(),
+                valid.kind(),
             )
         };
 
@@ -109,6 +140,63 @@ mod tests {
             )
         );
 
+        let err = invalid
+            .validate_and_return_first_error()
+            .expect_err("Half-edge is invalid");
+        let details = err.details();
+        assert_eq!(details.kind, ValidationErrorKind::VerticesAreCoincident);
+        assert!(details
+            .objects
+            .iter()
+            .any(|object| object.id() == valid.global_form().id()));
+        assert_eq!(
+            details.positions,
+            vec![ErrorPosition::Curve(Point::from([0.])); 2]
+        );
+
+        // The structured form doesn't affect the human-readable one.
+        assert!(err.to_string().contains("validation error"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn half_edge_vertices_are_coincident_with_config() -> anyhow::Result<()> {
+        let mut services =
+            Services::new_with_validation_config(ValidationConfig {
+                distinct_min_distance: Scalar::from(1e-9),
+                ..ValidationConfig::default()
+            });
+
+        let half_edge = {
+            let valid = HalfEdge::line_segment(
+                [[0., 0.], [1., 0.]],
+                None,
+                &mut services,
+            );
+            let boundary = [Point::from([0.]), Point::from([1e-8])];
+
+            HalfEdge::new(
+                valid.path().clone(),
+                boundary,
+                valid.curve().clone(),
+                valid.start_vertex().clone(),
+                valid.global_form().clone(),
+                valid.kind(),
+            )
+        };
+
+        // With the default configuration, the vertices are too close. With the
+        // one passed to `Services`, they are far enough apart.
+        assert_contains_err!(
+            half_edge,
+            ValidationError::HalfEdge(
+                HalfEdgeValidationError::VerticesAreCoincident { .. }
+            )
+        );
+        let _ = half_edge.insert(&mut services);
+
+        services.drop_and_validate()?;
         Ok(())
     }
 }
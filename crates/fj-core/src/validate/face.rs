@@ -1,8 +1,16 @@
-use fj_math::Winding;
+use fj_math::{Scalar, Winding};
 
-use crate::objects::Face;
+use crate::{
+    algorithms::intersect::CurveEdgeIntersection,
+    geometry::SurfacePath,
+    objects::{Cycle, Face, HalfEdge},
+    storage::Handle,
+};
 
-use super::{Validate, ValidationConfig, ValidationError};
+use super::{
+    Validate, ValidationConfig, ValidationError, ValidationErrorDetails,
+    ValidationErrorKind,
+};
 
 impl Validate for Face {
     fn validate_with_config(
@@ -11,6 +19,7 @@ impl Validate for Face {
         errors: &mut Vec<ValidationError>,
     ) {
         FaceValidationError::check_interior_winding(self, errors);
+        FaceValidationError::check_self_intersection(self, errors);
     }
 }
 
@@ -34,6 +43,55 @@ pub enum FaceValidationError {
         /// The face
         face: Face,
     },
+
+    /// Cycle of [`Face`] intersects itself
+    #[error(
+        "Cycle of `Face` intersects itself\n\
+        - First intersecting `HalfEdge`: {half_edge_a:#?}\n\
+        - Second intersecting `HalfEdge`: {half_edge_b:#?}\n\
+        - `Cycle`: {cycle:#?}"
+    )]
+    SelfIntersectingCycle {
+        /// The first of the intersecting half-edges
+        half_edge_a: Handle<HalfEdge>,
+
+        /// The second of the intersecting half-edges
+        half_edge_b: Handle<HalfEdge>,
+
+        /// The cycle that intersects itself
+        cycle: Handle<Cycle>,
+    },
+}
+
+impl FaceValidationError {
+    /// Access structured information about the error
+    pub fn details(&self) -> ValidationErrorDetails {
+        match self {
+            Self::InvalidInteriorWinding { face, .. } => {
+                ValidationErrorDetails {
+                    kind: ValidationErrorKind::InvalidInteriorWinding,
+                    objects: vec![
+                        face.surface().clone().into(),
+                        face.region().clone().into(),
+                    ],
+                    positions: Vec::new(),
+                }
+            }
+            Self::SelfIntersectingCycle {
+                half_edge_a,
+                half_edge_b,
+                cycle,
+            } => ValidationErrorDetails {
+                kind: ValidationErrorKind::SelfIntersectingCycle,
+                objects: vec![
+                    half_edge_a.clone().into(),
+                    half_edge_b.clone().into(),
+                    cycle.clone().into(),
+                ],
+                positions: Vec::new(),
+            },
+        }
+    }
 }
 
 impl FaceValidationError {
@@ -43,6 +101,11 @@ impl FaceValidationError {
             // like a job for a different validation check.
             return;
         }
+        if face.region().interiors().count() == 0 {
+            // Nothing to compare the exterior's winding to. And if the
+            // exterior intersects itself, it might not even have a winding.
+            return;
+        }
 
         let exterior_winding = face.region().exterior().winding();
 
@@ -66,6 +129,74 @@ impl FaceValidationError {
             }
         }
     }
+
+    fn check_self_intersection(face: &Face, errors: &mut Vec<ValidationError>) {
+        for cycle in face.region().all_cycles() {
+            let half_edges = cycle.half_edges().collect::<Vec<_>>();
+            let n = half_edges.len();
+
+            for (i, half_edge_a) in half_edges.iter().enumerate() {
+                for (j, half_edge_b) in
+                    half_edges.iter().enumerate().skip(i + 1)
+                {
+                    let are_neighbors = j == i + 1 || (i == 0 && j == n - 1);
+
+                    if edges_intersect(half_edge_a, half_edge_b, are_neighbors)
+                    {
+                        errors.push(
+                            Self::SelfIntersectingCycle {
+                                half_edge_a: (*half_edge_a).clone(),
+                                half_edge_b: (*half_edge_b).clone(),
+                                cycle: cycle.clone(),
+                            }
+                            .into(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Determine whether two half-edges of the same cycle intersect
+///
+/// Neighboring half-edges always share a vertex, which doesn't count as an
+/// intersection. They only intersect, if they overlap.
+///
+/// Only half-edges that are line segments are supported. Other half-edges are
+/// assumed to not intersect anything.
+fn edges_intersect(a: &HalfEdge, b: &HalfEdge, are_neighbors: bool) -> bool {
+    let (SurfacePath::Line(_), SurfacePath::Line(_)) = (a.path(), b.path())
+    else {
+        return false;
+    };
+
+    let [start, end] = a.boundary().inner;
+    let (min, max) = if start < end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+
+    match CurveEdgeIntersection::compute(a.path(), b) {
+        None => false,
+        Some(CurveEdgeIntersection::Point { point_on_curve }) => {
+            // Two line segments that aren't parallel can only intersect in one
+            // point. For neighbors, that's the vertex they share.
+            !are_neighbors && point_on_curve >= min && point_on_curve <= max
+        }
+        Some(CurveEdgeIntersection::Coincident { points_on_curve }) => {
+            let [start, end] = points_on_curve;
+            let overlap =
+                max.t.min(start.t.max(end.t)) - min.t.max(start.t.min(end.t));
+
+            if are_neighbors {
+                overlap > Scalar::ZERO
+            } else {
+                overlap >= Scalar::ZERO
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -114,6 +245,7 @@ mod tests {
                 valid.region().exterior().clone(),
                 interiors,
                 valid.region().color(),
+                valid.region().material().cloned(),
             )
             .insert(&mut services);
 
@@ -132,4 +264,55 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn face_self_intersecting_cycle() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let valid =
+            face([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &mut services);
+        let bowtie =
+            face([[0., 0.], [1., 1.], [1., 0.], [0., 1.]], &mut services);
+        let overlapping = face(
+            [
+                [0., 0.],
+                [3., 0.],
+                [3., 1.],
+                [2., 1.],
+                [2., 0.],
+                [1., 0.],
+                [1., -1.],
+                [0., -1.],
+            ],
+            &mut services,
+        );
+
+        valid.validate_and_return_first_error()?;
+        for invalid in [bowtie, overlapping] {
+            assert_contains_err!(
+                invalid,
+                ValidationError::Face(
+                    FaceValidationError::SelfIntersectingCycle { .. }
+                )
+            );
+        }
+
+        services.only_validate(valid);
+
+        Ok(())
+    }
+
+    fn face<const N: usize>(
+        points: [[f64; 2]; N],
+        services: &mut Services,
+    ) -> Face {
+        Face::unbound(services.objects.surfaces.xy_plane(), services)
+            .update_region(|region| {
+                region
+                    .update_exterior(|_| {
+                        Cycle::polygon(points, services).insert(services)
+                    })
+                    .insert(services)
+            })
+    }
 }
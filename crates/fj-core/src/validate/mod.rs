@@ -19,7 +19,9 @@ pub use self::{
 
 use std::{convert::Infallible, fmt};
 
-use fj_math::Scalar;
+use fj_math::{Point, Scalar};
+
+use crate::objects::{BehindHandle, Object};
 
 /// Assert that some object has a validation error which matches a specific
 /// pattern. This is preferred to matching on [`Validate::validate_and_return_first_error`], since usually we don't care about the order.
@@ -80,6 +82,24 @@ pub struct ValidationConfig {
     /// that distance is less than the one defined in this field, can not be
     /// considered identical.
     pub identical_max_distance: Scalar,
+
+    /// The minimum thickness of the walls of a solid
+    ///
+    /// If this is set, solids are checked for walls that are thinner than the
+    /// value defined in this field, as they might not be manufacturable. This
+    /// check is geometric and requires the faces of the solid to be
+    /// triangulated, which is why it is disabled by default.
+    ///
+    /// The check runs every time a solid is validated, which includes every
+    /// time one is inserted. The faces are triangulated with a tolerance of
+    /// 0.1% of the size of the solid, which is also how far off the measured
+    /// thickness of curved walls can be. Rays are then cast from multiple
+    /// points on each triangle, against all triangles that are within the
+    /// minimum thickness of it. This is quadratic in the number of triangles,
+    /// for solids where most of them are that close, so the check gets
+    /// expensive for solids with many curved faces, and for large values of
+    /// this field.
+    pub min_thickness: Option<Scalar>,
 }
 
 impl Default for ValidationConfig {
@@ -92,6 +112,8 @@ impl Default for ValidationConfig {
             // false positives due to floating-point accuracy issues), we can
             // adjust it.
             identical_max_distance: Scalar::from_f64(5e-14),
+
+            min_thickness: None,
         }
     }
 }
@@ -120,12 +142,109 @@ pub enum ValidationError {
     Solid(#[from] SolidValidationError),
 }
 
+impl ValidationError {
+    /// Access structured information about the error
+    ///
+    /// This is meant for tools that present validation errors, for example by
+    /// highlighting the objects involved. The [`Display`] implementation of the
+    /// error provides a description that is meant for humans instead.
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn details(&self) -> ValidationErrorDetails {
+        match self {
+            Self::Cycle(err) => err.details(),
+            Self::Face(err) => err.details(),
+            Self::HalfEdge(err) => err.details(),
+            Self::Shell(err) => err.details(),
+            Self::Solid(err) => err.details(),
+        }
+    }
+}
+
 impl From<Infallible> for ValidationError {
     fn from(infallible: Infallible) -> Self {
         match infallible {}
     }
 }
 
+/// Structured information about a [`ValidationError`]
+///
+/// See [`ValidationError::details`].
+#[derive(Clone, Debug)]
+pub struct ValidationErrorDetails {
+    /// The kind of error
+    pub kind: ValidationErrorKind,
+
+    /// The objects involved in the error
+    ///
+    /// Some errors refer to objects that are not behind a handle, because they
+    /// were detected before the object was inserted. For those, this contains
+    /// the objects that those objects refer to, like the global form of a
+    /// half-edge.
+    pub objects: Vec<Object<BehindHandle>>,
+
+    /// The positions where the error occurs, if the error provides them
+    pub positions: Vec<ErrorPosition>,
+}
+
+/// The kind of a [`ValidationError`]
+///
+/// Each kind corresponds to a variant of one of the object-specific validation
+/// errors, like [`CycleValidationError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ValidationErrorKind {
+    /// See [`CycleValidationError::HalfEdgesDisconnected`]
+    HalfEdgesDisconnected,
+
+    /// See [`CycleValidationError::NotEnoughHalfEdges`]
+    NotEnoughHalfEdges,
+
+    /// See [`HalfEdgeValidationError::VerticesAreCoincident`]
+    VerticesAreCoincident,
+
+    /// See [`FaceValidationError::InvalidInteriorWinding`]
+    InvalidInteriorWinding,
+
+    /// See [`FaceValidationError::SelfIntersectingCycle`]
+    SelfIntersectingCycle,
+
+    /// See [`ShellValidationError::NotWatertight`]
+    NotWatertight,
+
+    /// See [`ShellValidationError::NotManifold`]
+    NotManifold,
+
+    /// See [`ShellValidationError::CoincidentEdgesNotIdentical`]
+    CoincidentEdgesNotIdentical,
+
+    /// See [`ShellValidationError::IdenticalEdgesNotCoincident`]
+    IdenticalEdgesNotCoincident,
+
+    /// See [`SolidValidationError::DistinctVerticesCoincide`]
+    DistinctVerticesCoincide,
+
+    /// See [`SolidValidationError::IdenticalVerticesNotCoincident`]
+    IdenticalVerticesNotCoincident,
+
+    /// See [`SolidValidationError::WallTooThin`]
+    WallTooThin,
+}
+
+/// A position where a validation error occurs
+///
+/// Which object the coordinates refer to depends on the kind of error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ErrorPosition {
+    /// A position on a curve, in curve coordinates
+    Curve(Point<1>),
+
+    /// A position on a surface, in surface coordinates
+    Surface(Point<2>),
+
+    /// A position in global coordinates
+    Global(Point<3>),
+}
+
 /// A collection of validation errors
 #[derive(Debug, thiserror::Error)]
 pub struct ValidationErrors(pub Vec<ValidationError>);
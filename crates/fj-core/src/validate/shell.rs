@@ -12,7 +12,10 @@ use crate::{
     storage::{Handle, HandleWrapper, ObjectId},
 };
 
-use super::{Validate, ValidationConfig, ValidationError};
+use super::{
+    Validate, ValidationConfig, ValidationError, ValidationErrorDetails,
+    ValidationErrorKind,
+};
 
 impl Validate for Shell {
     fn validate_with_config(
@@ -22,6 +25,7 @@ impl Validate for Shell {
     ) {
         ShellValidationError::validate_edges_coincident(self, config, errors);
         ShellValidationError::validate_watertight(self, config, errors);
+        ShellValidationError::validate_manifold(self, config, errors);
     }
 }
 
@@ -32,6 +36,25 @@ pub enum ShellValidationError {
     #[error("Shell is not watertight")]
     NotWatertight,
 
+    /// [`Shell`] contains a half-edge that isn't paired with exactly one
+    /// sibling
+    ///
+    /// In a manifold shell, every half-edge has exactly one sibling: another
+    /// half-edge that refers to the same curve, but bounds it in the opposite
+    /// direction.
+    #[error(
+        "Shell is not manifold; `HalfEdge` has {num_siblings} siblings \
+        instead of 1\n\
+        Edge: {half_edge:#?}"
+    )]
+    NotManifold {
+        /// The half-edge that isn't paired with exactly one sibling
+        half_edge: Handle<HalfEdge>,
+
+        /// The number of siblings that were found for the half-edge
+        num_siblings: usize,
+    },
+
     /// [`Shell`] contains half-edges that are coincident, but refer to
     /// different global_edges
     #[error(
@@ -65,6 +88,46 @@ pub enum ShellValidationError {
     },
 }
 
+impl ShellValidationError {
+    /// Access structured information about the error
+    pub fn details(&self) -> ValidationErrorDetails {
+        match self {
+            Self::NotWatertight => ValidationErrorDetails {
+                kind: ValidationErrorKind::NotWatertight,
+                objects: Vec::new(),
+                positions: Vec::new(),
+            },
+            Self::NotManifold { half_edge, .. } => ValidationErrorDetails {
+                kind: ValidationErrorKind::NotManifold,
+                objects: vec![half_edge.clone().into()],
+                positions: Vec::new(),
+            },
+            Self::CoincidentEdgesNotIdentical(edge_a, edge_b) => {
+                ValidationErrorDetails {
+                    kind: ValidationErrorKind::CoincidentEdgesNotIdentical,
+                    objects: vec![edge_a.clone().into(), edge_b.clone().into()],
+                    positions: Vec::new(),
+                }
+            }
+            Self::IdenticalEdgesNotCoincident {
+                edge_a,
+                surface_a,
+                edge_b,
+                surface_b,
+            } => ValidationErrorDetails {
+                kind: ValidationErrorKind::IdenticalEdgesNotCoincident,
+                objects: vec![
+                    edge_a.clone().into(),
+                    surface_a.clone().into(),
+                    edge_b.clone().into(),
+                    surface_b.clone().into(),
+                ],
+                positions: Vec::new(),
+            },
+        }
+    }
+}
+
 /// Sample two edges at various (currently 3) points in 3D along them.
 ///
 /// Returns an [`Iterator`] of the distance at each sample.
@@ -77,7 +140,7 @@ fn distances(
 ) -> impl Iterator<Item = Scalar> {
     fn sample(
         percent: f64,
-        (edge, surface): (&Handle<HalfEdge>, SurfaceGeometry),
+        (edge, surface): (&Handle<HalfEdge>, &SurfaceGeometry),
     ) -> Point<3> {
         let [start, end] = edge.boundary().inner;
         let path_coords = start + (end - start) * percent;
@@ -258,16 +321,73 @@ impl ShellValidationError {
             errors.push(Self::NotWatertight.into())
         }
     }
+
+    fn validate_manifold(
+        shell: &Shell,
+        _: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut half_edges = Vec::new();
+
+        for face in shell.faces() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let [start, end] = cycle
+                        .bounding_vertices_of_edge(half_edge)
+                        .expect(
+                            "Cycle should provide bounds of its own half-edge",
+                        )
+                        .inner
+                        .map(|vertex| vertex.id());
+
+                    half_edges.push((
+                        half_edge,
+                        half_edge.curve().id(),
+                        [start, end],
+                    ));
+                }
+            }
+        }
+
+        let mut num_edges = HashMap::new();
+        for &(_, curve, vertices) in &half_edges {
+            *num_edges.entry((curve, vertices)).or_insert(0) += 1;
+        }
+
+        // A sibling refers to the same curve, but goes the other way.
+        for &(half_edge, curve, [start, end]) in &half_edges {
+            let mut num_siblings =
+                num_edges.get(&(curve, [end, start])).copied().unwrap_or(0);
+
+            // If the edge is closed, like a circle, going the other way looks
+            // just the same, and the half-edge counted itself.
+            if start == end {
+                num_siblings -= 1;
+            }
+
+            if num_siblings != 1 {
+                errors.push(
+                    Self::NotManifold {
+                        half_edge: half_edge.clone(),
+                        num_siblings,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
+        algorithms::sweep::Sweep,
         assert_contains_err,
-        objects::{Curve, GlobalEdge, Shell},
+        objects::{Curve, GlobalEdge, Region, Shell, Sketch},
         operations::{
-            BuildShell, Insert, UpdateCycle, UpdateFace, UpdateHalfEdge,
-            UpdateRegion, UpdateShell,
+            BuildRegion, BuildShell, BuildSketch, Insert, UpdateCycle,
+            UpdateFace, UpdateHalfEdge, UpdateRegion, UpdateShell,
+            UpdateSketch,
         },
         services::Services,
         validate::{shell::ShellValidationError, Validate, ValidationError},
@@ -337,4 +457,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn shell_not_manifold() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    &mut services,
+                )
+                .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cuboid = (sketch, surface).sweep([0., 0., 1.], &mut services);
+
+        let valid = cuboid.shells().next().expect("Cuboid has a shell");
+        let face = valid
+            .faces()
+            .into_iter()
+            .next()
+            .expect("Cuboid has faces")
+            .clone();
+        let invalid = valid.remove_face(&face);
+
+        valid.validate_and_return_first_error()?;
+
+        // The four edges that bordered the removed face have lost their
+        // siblings.
+        let mut errors = Vec::new();
+        invalid.validate(&mut errors);
+        let num_unpaired = errors
+            .iter()
+            .filter(|err| {
+                matches!(
+                    err,
+                    ValidationError::Shell(ShellValidationError::NotManifold {
+                        num_siblings: 0,
+                        ..
+                    })
+                )
+            })
+            .count();
+        assert_eq!(num_unpaired, 4);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
 }
@@ -1,12 +1,20 @@
-use std::iter::repeat;
+use std::{collections::BTreeMap, iter::repeat, ops::Deref};
 
 use crate::{
-    objects::{Solid, Vertex},
+    algorithms::{
+        approx::{Approx, Tolerance},
+        bounding_volume::BoundingVolume,
+        triangulate::Triangulate,
+    },
+    objects::{Face, Solid, Vertex},
     storage::Handle,
 };
-use fj_math::Point;
+use fj_math::{Aabb, Point, Ray, Scalar, Triangle, Vector};
 
-use super::{Validate, ValidationConfig, ValidationError};
+use super::{
+    ErrorPosition, Validate, ValidationConfig, ValidationError,
+    ValidationErrorDetails, ValidationErrorKind,
+};
 
 impl Validate for Solid {
     fn validate_with_config(
@@ -14,7 +22,8 @@ impl Validate for Solid {
         config: &ValidationConfig,
         errors: &mut Vec<ValidationError>,
     ) {
-        SolidValidationError::check_vertices(self, config, errors)
+        SolidValidationError::check_vertices(self, config, errors);
+        SolidValidationError::check_thickness(self, config, errors);
     }
 }
 
@@ -60,6 +69,68 @@ pub enum SolidValidationError {
         /// Position of second vertex
         position_b: Point<3>,
     },
+
+    /// [`Solid`] has a wall that is thinner than the minimum thickness
+    ///
+    /// See [`ValidationConfig::min_thickness`]. Both faces can be the same, if
+    /// the thin wall is formed by a single curved face, like a thin pin.
+    #[error(
+        "Solid has a wall that is thinner than the minimum thickness\n
+        Face 1: {face_a:#?}
+        Face 2: {face_b:#?}
+        Gap: {gap}"
+    )]
+    WallTooThin {
+        /// The face on one side of the wall
+        face_a: Handle<Face>,
+
+        /// The face on the other side of the wall
+        face_b: Handle<Face>,
+
+        /// The smallest distance between the faces that was measured
+        gap: Scalar,
+    },
+}
+
+impl SolidValidationError {
+    /// Access structured information about the error
+    pub fn details(&self) -> ValidationErrorDetails {
+        match self {
+            Self::DistinctVerticesCoincide {
+                vertex_a,
+                vertex_b,
+                position_a,
+                position_b,
+            } => ValidationErrorDetails {
+                kind: ValidationErrorKind::DistinctVerticesCoincide,
+                objects: vec![vertex_a.clone().into(), vertex_b.clone().into()],
+                positions: vec![
+                    ErrorPosition::Global(*position_a),
+                    ErrorPosition::Global(*position_b),
+                ],
+            },
+            Self::IdenticalVerticesNotCoincident {
+                vertex_a,
+                vertex_b,
+                position_a,
+                position_b,
+            } => ValidationErrorDetails {
+                kind: ValidationErrorKind::IdenticalVerticesNotCoincident,
+                objects: vec![vertex_a.clone().into(), vertex_b.clone().into()],
+                positions: vec![
+                    ErrorPosition::Global(*position_a),
+                    ErrorPosition::Global(*position_b),
+                ],
+            },
+            Self::WallTooThin { face_a, face_b, .. } => {
+                ValidationErrorDetails {
+                    kind: ValidationErrorKind::WallTooThin,
+                    objects: vec![face_a.clone().into(), face_b.clone().into()],
+                    positions: Vec::new(),
+                }
+            }
+        }
+    }
 }
 
 impl SolidValidationError {
@@ -126,4 +197,311 @@ impl SolidValidationError {
             }
         }
     }
+
+    fn check_thickness(
+        solid: &Solid,
+        config: &ValidationConfig,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        // The approximation of curved faces shifts them by up to this fraction
+        // of the size of the model, which shows up in the measured gap.
+        const RELATIVE_TOLERANCE: f64 = 0.001;
+
+        let Some(min_thickness) = config.min_thickness else {
+            return;
+        };
+        if min_thickness <= Scalar::ZERO {
+            // No wall can be thinner than that.
+            return;
+        }
+
+        // Deriving the tolerance from the model, instead of the minimum
+        // thickness, keeps a small minimum thickness from resulting in a huge
+        // number of triangles.
+        let Some(tolerance) = solid.aabb().and_then(|aabb| {
+            Tolerance::for_model(aabb, RELATIVE_TOLERANCE).ok()
+        }) else {
+            // The solid is empty, or has no size. Either way, it has no walls.
+            return;
+        };
+
+        let mut triangles: Vec<(Handle<Face>, Triangle<3>, Aabb<3>)> = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| {
+                face.deref()
+                    .approx(tolerance)
+                    .triangulate()
+                    .triangles()
+                    .map(|triangle| {
+                        let aabb =
+                            Aabb::<3>::from_points(triangle.inner.points());
+                        (face.clone(), triangle.inner, aabb)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Sorting the triangles along the x-axis makes it cheap to skip those
+        // that are too far away, to be within the minimum thickness.
+        triangles.sort_by_key(|(_, _, aabb)| aabb.min.x);
+
+        // The wall behind each triangle is measured by casting rays from its
+        // inside, against the direction of its normal, which points outward.
+        // The first triangle hit by a ray is where it leaves the solid again.
+        //
+        // A gap that is thinner than the minimum thickness can only be formed
+        // by triangles whose AABBs are within the minimum thickness of each
+        // other. Only those are tested against the rays. In the worst case,
+        // where all triangles are that close, this is still O(N^2).
+        let margin =
+            Vector::from([min_thickness, min_thickness, min_thickness]);
+        let mut gaps = BTreeMap::new();
+        for (i, (face_a, triangle_a, aabb_a)) in triangles.iter().enumerate() {
+            let direction = -triangle_a.normal();
+
+            let search = Aabb {
+                min: aabb_a.min - margin,
+                max: aabb_a.max + margin,
+            };
+            let end = triangles
+                .partition_point(|(_, _, aabb)| aabb.min.x <= search.max.x);
+            let candidates = triangles[..end]
+                .iter()
+                .enumerate()
+                .filter(|&(j, (_, _, aabb_b))| {
+                    j != i && overlap(aabb_b, &search)
+                })
+                .map(|(_, (face_b, triangle_b, _))| (face_b, triangle_b))
+                .collect::<Vec<_>>();
+            if candidates.is_empty() {
+                continue;
+            }
+
+            for origin in thickness_samples(triangle_a, min_thickness) {
+                let ray = Ray::from_origin_and_direction(origin, direction);
+
+                let hit = candidates
+                    .iter()
+                    .filter_map(|&(face_b, triangle_b)| {
+                        let intersection =
+                            ray.intersect_triangle(triangle_b, true)?;
+                        Some((intersection.t, face_b, triangle_b))
+                    })
+                    .filter(|(t, _, _)| *t > config.distinct_min_distance)
+                    .min_by_key(|(t, _, _)| *t);
+                let Some((gap, face_b, triangle_b)) = hit else {
+                    continue;
+                };
+
+                // If the ray enters the solid through the triangle it hit,
+                // instead of leaving it, the faces are not oriented
+                // consistently, and the gap is not a wall. It's up to other
+                // checks to report that.
+                let ray_leaves_solid =
+                    direction.dot(&triangle_b.normal()) > Scalar::ZERO;
+                if !ray_leaves_solid || gap >= min_thickness {
+                    continue;
+                }
+
+                let key = if face_a.id() <= face_b.id() {
+                    (face_a.id(), face_b.id())
+                } else {
+                    (face_b.id(), face_a.id())
+                };
+                gaps.entry(key)
+                    .and_modify(|(_, _, min_gap)| {
+                        if gap < *min_gap {
+                            *min_gap = gap;
+                        }
+                    })
+                    .or_insert((face_a.clone(), face_b.clone(), gap));
+            }
+        }
+
+        for (face_a, face_b, gap) in gaps.into_values() {
+            errors.push(
+                Self::WallTooThin {
+                    face_a,
+                    face_b,
+                    gap,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+/// Determine whether two AABBs overlap
+fn overlap(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    (0..3).all(|i| {
+        a.min.coords.components[i] <= b.max.coords.components[i]
+            && b.min.coords.components[i] <= a.max.coords.components[i]
+    })
+}
+
+/// Compute the points of a triangle, from which to measure its wall thickness
+///
+/// The triangle is subdivided into smaller triangles, whose size is about the
+/// minimum thickness, and the centers of those are returned. To keep the number
+/// of samples in check for large triangles, the number of subdivisions along
+/// each edge is limited.
+fn thickness_samples(
+    triangle: &Triangle<3>,
+    min_thickness: Scalar,
+) -> Vec<Point<3>> {
+    const MAX_SUBDIVISIONS: usize = 4;
+
+    let [a, b, c] = triangle.points();
+    let longest_edge = [b - a, c - b, a - c]
+        .into_iter()
+        .map(|edge| edge.magnitude())
+        .max()
+        .unwrap_or(Scalar::ZERO);
+    let n = (longest_edge / min_thickness)
+        .ceil()
+        .into_f64()
+        .clamp(1., MAX_SUBDIVISIONS as f64) as usize;
+
+    let point_at = |u: f64, v: f64| {
+        let n = n as f64;
+        a + (b - a) * (u / n) + (c - a) * (v / n)
+    };
+
+    let mut samples = Vec::new();
+    for i in 0..n {
+        for j in 0..n - i {
+            let [i, j] = [i, j].map(|x| x as f64);
+
+            samples.push(point_at(i + 1. / 3., j + 1. / 3.));
+            if i + j + 2. <= n as f64 {
+                samples.push(point_at(i + 2. / 3., j + 2. / 3.));
+            }
+        }
+    }
+
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+        validate::{
+            solid::SolidValidationError, Validate, ValidationConfig,
+            ValidationError, ValidationErrorKind,
+        },
+    };
+
+    #[test]
+    fn wall_too_thin() {
+        let mut services = Services::new();
+
+        let slab = slab(0.1, &mut services);
+
+        let mut errors = Vec::new();
+        slab.validate_with_config(
+            &ValidationConfig {
+                min_thickness: Some(Scalar::from(0.05)),
+                ..ValidationConfig::default()
+            },
+            &mut errors,
+        );
+        assert!(errors.is_empty());
+
+        let mut errors = Vec::new();
+        slab.validate_with_config(
+            &ValidationConfig {
+                min_thickness: Some(Scalar::from(0.2)),
+                ..ValidationConfig::default()
+            },
+            &mut errors,
+        );
+        assert_eq!(errors.len(), 1);
+        let ValidationError::Solid(SolidValidationError::WallTooThin {
+            face_a,
+            face_b,
+            gap,
+        }) = &errors[0]
+        else {
+            panic!("Expected wall to be too thin: {errors:#?}");
+        };
+        assert_ne!(face_a.id(), face_b.id());
+        assert!((*gap - Scalar::from(0.1)).abs() < Scalar::from(1e-9));
+
+        let details = errors[0].details();
+        assert_eq!(details.kind, ValidationErrorKind::WallTooThin);
+        assert_eq!(
+            details
+                .objects
+                .iter()
+                .map(|object| object.id())
+                .collect::<Vec<_>>(),
+            vec![face_a.id(), face_b.id()]
+        );
+    }
+
+    #[test]
+    fn wall_too_thin_curved() {
+        let mut services = Services::new();
+
+        let disc = {
+            let region = Region::circle([0., 0.], 1., &mut services)
+                .insert(&mut services);
+            sweep_region(region, 0.1, &mut services)
+        };
+
+        let mut errors = Vec::new();
+        disc.validate_with_config(
+            &ValidationConfig {
+                min_thickness: Some(Scalar::from(0.05)),
+                ..ValidationConfig::default()
+            },
+            &mut errors,
+        );
+        assert!(errors.is_empty());
+
+        let mut errors = Vec::new();
+        disc.validate_with_config(
+            &ValidationConfig {
+                min_thickness: Some(Scalar::from(0.2)),
+                ..ValidationConfig::default()
+            },
+            &mut errors,
+        );
+        assert_eq!(errors.len(), 1);
+        let ValidationError::Solid(SolidValidationError::WallTooThin {
+            gap,
+            ..
+        }) = &errors[0]
+        else {
+            panic!("Expected wall to be too thin: {errors:#?}");
+        };
+        assert!((*gap - Scalar::from(0.1)).abs() < Scalar::from(1e-9));
+    }
+
+    fn slab(height: f64, services: &mut Services) -> Handle<Solid> {
+        let region =
+            Region::polygon([[0., 0.], [1., 0.], [1., 1.], [0., 1.]], services)
+                .insert(services);
+
+        sweep_region(region, height, services)
+    }
+
+    fn sweep_region(
+        region: Handle<Region>,
+        height: f64,
+        services: &mut Services,
+    ) -> Handle<Solid> {
+        let sketch = Sketch::empty().add_region(region).insert(services);
+        let surface = services.objects.surfaces.xy_plane();
+
+        (sketch, surface).sweep([0., 0., height], services)
+    }
 }
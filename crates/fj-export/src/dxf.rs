@@ -0,0 +1,459 @@
+//! Export sketches to DXF
+//!
+//! See [`write_dxf`].
+
+use std::{fmt, io};
+
+use fj_math::{ApproxEq, Circle, Point, Scalar, Vector};
+
+use fj_core::{
+    geometry::{BoundaryOnCurve, SurfacePath},
+    objects::{Cycle, EdgeKind, HalfEdge, Sketch},
+};
+
+/// Write the sketch to a DXF file
+///
+/// Every edge of the sketch becomes an entity in the DXF file: lines become
+/// `LINE` entities, circular arcs become `ARC` entities, and elliptical
+/// arcs become `ELLIPSE` entities. A cycle that goes once around a circle
+/// becomes a single `CIRCLE` entity, regardless of how many edges it
+/// consists of. Construction edges are not written.
+///
+/// The entities are placed in the XY plane of the DXF file, using the 2D
+/// coordinates of the sketch. Those are the coordinates of any surface the
+/// sketch is applied to, for example the plane of a section.
+///
+/// DXF has no direct representation for Bézier curves or B-splines. Edges
+/// on those result in an error.
+pub fn write_dxf(
+    sketch: &Sketch,
+    writer: impl io::Write,
+) -> Result<(), DxfError> {
+    let mut dxf = Dxf { writer };
+
+    dxf.group(0, "SECTION")?;
+    dxf.group(2, "ENTITIES")?;
+
+    for cycle in sketch.regions().flat_map(|region| region.all_cycles()) {
+        if let Some(circle) = full_circle(cycle) {
+            dxf.entity("CIRCLE")?;
+            dxf.point(10, circle.center())?;
+            dxf.group(40, circle.radius())?;
+            continue;
+        }
+
+        for half_edge in cycle.half_edges() {
+            if half_edge.kind() == EdgeKind::Construction {
+                continue;
+            }
+
+            dxf.half_edge(half_edge)?;
+        }
+    }
+
+    dxf.group(0, "ENDSEC")?;
+    dxf.group(0, "EOF")?;
+
+    Ok(())
+}
+
+/// Error writing a sketch to a DXF file
+///
+/// See [`write_dxf`].
+#[derive(Debug, thiserror::Error)]
+pub enum DxfError {
+    /// The sketch has an edge that can't be represented in DXF
+    #[error("Can't write edge on path to DXF: {0:?}")]
+    UnsupportedPath(SurfacePath),
+
+    /// Error writing to the writer
+    #[error("I/O error while writing DXF")]
+    Io(#[from] io::Error),
+}
+
+struct Dxf<W> {
+    writer: W,
+}
+
+impl<W: io::Write> Dxf<W> {
+    fn half_edge(&mut self, half_edge: &HalfEdge) -> Result<(), DxfError> {
+        let boundary = half_edge.boundary();
+
+        match half_edge.path() {
+            SurfacePath::Circle(circle) => {
+                let [start, end] = ccw_angles(circle.a(), circle.b(), boundary)
+                    .map(|angle| angle + angle_of(circle.a()));
+
+                self.entity("ARC")?;
+                self.point(10, circle.center())?;
+                self.group(40, circle.radius())?;
+                self.group(50, normalized(start).to_degrees())?;
+                self.group(51, normalized(end).to_degrees())?;
+            }
+            SurfacePath::Ellipse(ellipse) => {
+                // The parameters of an ellipse in DXF are angles relative to
+                // its major axis, just like our path coordinates.
+                let [start, end] =
+                    ccw_angles(ellipse.major(), ellipse.minor(), boundary);
+                let end = if end - start >= Scalar::TAU {
+                    start + Scalar::TAU
+                } else {
+                    normalized(end)
+                };
+
+                self.entity("ELLIPSE")?;
+                self.point(10, ellipse.center())?;
+                self.point(11, Point::origin() + ellipse.major())?;
+                self.group(
+                    40,
+                    ellipse.minor().magnitude() / ellipse.major().magnitude(),
+                )?;
+                self.group(41, start)?;
+                self.group(42, end)?;
+            }
+            SurfacePath::Line(line) => {
+                let [start, end] = boundary
+                    .inner
+                    .map(|point| line.point_from_line_coords(point));
+
+                self.entity("LINE")?;
+                self.point(10, start)?;
+                self.point(11, end)?;
+            }
+            path => return Err(DxfError::UnsupportedPath(path.clone())),
+        }
+
+        Ok(())
+    }
+
+    fn entity(&mut self, kind: &str) -> io::Result<()> {
+        self.group(0, kind)?;
+        self.group(8, 0)
+    }
+
+    fn point(&mut self, code: u16, point: Point<2>) -> io::Result<()> {
+        self.group(code, point.u)?;
+        self.group(code + 10, point.v)?;
+        self.group(code + 20, Scalar::ZERO)
+    }
+
+    fn group(&mut self, code: u16, value: impl fmt::Display) -> io::Result<()> {
+        writeln!(self.writer, "{code:>3}\n{value}")
+    }
+}
+
+/// Return the circle that a cycle goes around exactly once, if any
+fn full_circle(cycle: &Cycle) -> Option<Circle<2>> {
+    // Half-circles that are constructed from their end points don't end up
+    // with exactly the same circle, due to numerical inaccuracy.
+    const EPSILON: f64 = 1e-9;
+
+    let mut circle = None;
+    let mut angle = Scalar::ZERO;
+
+    for half_edge in cycle.half_edges() {
+        let SurfacePath::Circle(path) = half_edge.path() else {
+            return None;
+        };
+        if half_edge.kind() == EdgeKind::Construction {
+            return None;
+        }
+
+        let circle = circle.get_or_insert(*path);
+        let epsilon = circle.radius() * EPSILON;
+        if !path.center().approx_eq(&circle.center(), epsilon)
+            || !path.radius().approx_eq(&circle.radius(), epsilon)
+        {
+            return None;
+        }
+
+        let [start, end] = half_edge.boundary().inner;
+        angle += (end.t - start.t).abs();
+    }
+
+    if angle.approx_eq(&Scalar::TAU, EPSILON) {
+        circle
+    } else {
+        None
+    }
+}
+
+/// Compute the angles of a boundary on a circle or ellipse
+///
+/// The circle or ellipse is defined by the vectors `a` and `b`, as in
+/// [`Circle`]. The returned angles are relative to `a`, the first one in the
+/// range `[0, 2pi)`. The arc goes counter-clockwise from the first to the
+/// second.
+fn ccw_angles(
+    a: Vector<2>,
+    b: Vector<2>,
+    boundary: BoundaryOnCurve,
+) -> [Scalar; 2] {
+    let direction = a.cross2d(&b).sign().to_scalar();
+    let [start, end] = boundary.inner.map(|point| point.t * direction);
+
+    let [start, end] = if start <= end {
+        [start, end]
+    } else {
+        [end, start]
+    };
+
+    let normalized_start = normalized(start);
+    [normalized_start, normalized_start + (end - start)]
+}
+
+fn angle_of(vector: Vector<2>) -> Scalar {
+    vector.v.atan2(vector.u)
+}
+
+/// Normalize an angle to the range `[0, 2pi)`
+fn normalized(angle: Scalar) -> Scalar {
+    let angle = angle % Scalar::TAU;
+    if angle < Scalar::ZERO {
+        angle + Scalar::TAU
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use fj_core::{
+        algorithms::{
+            section::Section, sweep::Sweep, transform::TransformObject,
+        },
+        geometry::SurfacePath,
+        objects::{Cycle, HalfEdge, Region, Sketch},
+        operations::{
+            BuildCycle, BuildHalfEdge, BuildRegion, BuildSketch, Insert,
+            Reverse, UpdateCycle, UpdateRegion, UpdateSketch,
+        },
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::{write_dxf, DxfError};
+
+    #[test]
+    fn dxf_entities() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let hole = Cycle::circle([0.5, 0.5], 0.25, &mut services)
+            .reverse(&mut services)
+            .insert(&mut services);
+        let square = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut services,
+        )
+        .add_interiors([hole]);
+
+        // A circle made up of two half-circles
+        let circle = Cycle::empty().add_half_edges([
+            HalfEdge::arc([3., 0.], [1., 0.], Scalar::PI, &mut services)
+                .insert(&mut services),
+            HalfEdge::arc([1., 0.], [3., 0.], Scalar::PI, &mut services)
+                .insert(&mut services),
+        ]);
+
+        // A rounded corner, which is not a full circle
+        let corner = Cycle::empty().add_half_edges([
+            HalfEdge::line_segment([[5., 0.], [6., 0.]], None, &mut services)
+                .insert(&mut services),
+            HalfEdge::arc([6., 0.], [5., 1.], Scalar::PI / 2., &mut services)
+                .insert(&mut services),
+            HalfEdge::line_segment([[5., 1.], [5., 0.]], None, &mut services)
+                .insert(&mut services),
+        ]);
+
+        let sketch = Sketch::empty()
+            .add_region(square.insert(&mut services))
+            .add_region(region(circle, &mut services))
+            .add_region(region(corner, &mut services));
+
+        let mut dxf = Vec::new();
+        write_dxf(&sketch, &mut dxf)?;
+        let dxf = String::from_utf8(dxf)?;
+
+        let entities =
+            |kind: &str| dxf.lines().filter(|line| *line == kind).count();
+        assert_eq!(entities("LINE"), 6);
+        assert_eq!(entities("CIRCLE"), 2);
+        assert_eq!(entities("ARC"), 1);
+        assert!(dxf.ends_with("  0\nEOF\n"));
+
+        // The arc of the rounded corner goes counter-clockwise.
+        let value = entity_values(&dxf, "ARC");
+        assert!((value(" 10") - 5.).abs() < 1e-9);
+        assert!(value(" 20").abs() < 1e-9);
+        assert!(value(" 50").abs() < 1e-9);
+        assert!((value(" 51") - 90.).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dxf_clockwise_arc() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        // A quarter of a circle that goes clockwise, from the positive x-axis
+        // to the negative y-axis.
+        let path =
+            SurfacePath::circle_from_center_and_radius([0., 0.], 1.).reverse();
+        let corner = Cycle::empty().add_half_edges([
+            HalfEdge::unjoined(
+                path,
+                [Scalar::ZERO, Scalar::PI / 2.].map(|t| Point::from([t])),
+                &mut services,
+            )
+            .insert(&mut services),
+            HalfEdge::line_segment([[0., -1.], [0., 0.]], None, &mut services)
+                .insert(&mut services),
+            HalfEdge::line_segment([[0., 0.], [1., 0.]], None, &mut services)
+                .insert(&mut services),
+        ]);
+        let sketch = Sketch::empty().add_region(region(corner, &mut services));
+
+        let mut dxf = Vec::new();
+        write_dxf(&sketch, &mut dxf)?;
+        let dxf = String::from_utf8(dxf)?;
+
+        // DXF arcs always go counter-clockwise, so the arc goes from the
+        // negative y-axis to the positive x-axis.
+        let value = entity_values(&dxf, "ARC");
+        assert!((value(" 50") - 270.).abs() < 1e-9);
+        assert!(value(" 51").abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dxf_ellipse() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let path = SurfacePath::ellipse_from_center_and_axes(
+            [0., 0.],
+            [2., 0.],
+            [0., 1.],
+        );
+        let half = Cycle::empty().add_half_edges([
+            HalfEdge::unjoined(
+                path.clone(),
+                [Scalar::ZERO, Scalar::PI].map(|t| Point::from([t])),
+                &mut services,
+            )
+            .insert(&mut services),
+            HalfEdge::line_segment([[-2., 0.], [2., 0.]], None, &mut services)
+                .insert(&mut services),
+        ]);
+        let full = Cycle::empty().add_half_edges([HalfEdge::unjoined(
+            path,
+            [Scalar::ZERO, Scalar::TAU].map(|t| Point::from([t])),
+            &mut services,
+        )
+        .insert(&mut services)]);
+
+        for (cycle, end) in [(half, Scalar::PI), (full, Scalar::TAU)] {
+            let sketch =
+                Sketch::empty().add_region(region(cycle, &mut services));
+
+            let mut dxf = Vec::new();
+            write_dxf(&sketch, &mut dxf)?;
+            let dxf = String::from_utf8(dxf)?;
+
+            let value = entity_values(&dxf, "ELLIPSE");
+            assert!((value(" 11") - 2.).abs() < 1e-9);
+            assert!(value(" 21").abs() < 1e-9);
+            assert!((value(" 40") - 0.5).abs() < 1e-9);
+            assert!(value(" 41").abs() < 1e-9);
+
+            // A full ellipse ends at 2pi, not at 0, which would make it empty.
+            assert!((value(" 42") - end.into_f64()).abs() < 1e-9);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn dxf_with_unsupported_path() {
+        let mut services = Services::new();
+
+        let (path, boundary) = SurfacePath::bezier_from_control_points([
+            [0., 0.],
+            [1., 1.],
+            [2., 1.],
+            [3., 0.],
+        ]);
+        let bezier = Cycle::empty().add_half_edges([
+            HalfEdge::unjoined(path, boundary, &mut services)
+                .insert(&mut services),
+            HalfEdge::line_segment([[3., 0.], [0., 0.]], None, &mut services)
+                .insert(&mut services),
+        ]);
+        let sketch = Sketch::empty().add_region(region(bezier, &mut services));
+
+        assert!(matches!(
+            write_dxf(&sketch, Vec::new()),
+            Err(DxfError::UnsupportedPath(_))
+        ));
+    }
+
+    #[test]
+    fn dxf_of_section() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::circle([1., 2.], 0.5, &mut services)
+                    .insert(&mut services),
+            )
+            .insert(&mut services);
+        let surface = services.objects.surfaces.xy_plane();
+        let cylinder =
+            (sketch, surface.clone()).sweep([0., 0., 1.], &mut services);
+
+        let plane = surface.translate([0., 0., 0.5], &mut services);
+        let section = cylinder.section(&plane, &mut services)?;
+
+        let mut dxf = Vec::new();
+        write_dxf(&section, &mut dxf)?;
+        let dxf = String::from_utf8(dxf)?;
+
+        // The cut through the cylinder is a circle, not a polygon.
+        let entities =
+            |kind: &str| dxf.lines().filter(|line| *line == kind).count();
+        assert_eq!(entities("CIRCLE"), 1);
+        assert_eq!(entities("LINE"), 0);
+
+        let value = entity_values(&dxf, "CIRCLE");
+        assert!((value(" 10") - 1.).abs() < 1e-9);
+        assert!((value(" 20") - 2.).abs() < 1e-9);
+        assert!((value(" 40") - 0.5).abs() < 1e-9);
+
+        services.drop_and_validate()?;
+        Ok(())
+    }
+
+    /// Access the group values of the first entity of the given kind
+    fn entity_values<'a>(
+        dxf: &'a str,
+        kind: &str,
+    ) -> impl Fn(&str) -> f64 + 'a {
+        let entity = dxf
+            .split(&format!("{kind}\n"))
+            .nth(1)
+            .unwrap()
+            .lines()
+            .collect::<Vec<_>>();
+
+        move |code| {
+            let i = entity.iter().position(|line| *line == code).unwrap();
+            entity[i + 1].parse::<f64>().unwrap()
+        }
+    }
+
+    fn region(exterior: Cycle, services: &mut Services) -> Handle<Region> {
+        Region::new(exterior.insert(services), [], None, None).insert(services)
+    }
+}
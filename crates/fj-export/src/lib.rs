@@ -4,25 +4,38 @@
 //! split into multiple libraries that can be used semi-independently, and this
 //! is one of those.
 //!
-//! This library exports Fornjot models to external file formats.
+//! This library exports Fornjot models to external file formats. Meshes are
+//! exported using [`export`], 2D sketches using the [`dxf`] and [`svg`]
+//! modules.
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
 #![warn(missing_docs)]
 
-use std::{fs::File, path::Path};
+pub mod dxf;
+pub mod svg;
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Write as _},
+    fs::File,
+    io::{Seek, Write},
+    path::Path,
+};
 
 use thiserror::Error;
+use zip::{write::FileOptions, ZipWriter};
 
-use fj_interop::mesh::Mesh;
-use fj_math::{Point, Triangle};
+use fj_interop::mesh::{Color, Material, Mesh};
+use fj_math::Point;
 
 /// Export the provided mesh to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
-/// the provided path is used to switch between supported types.
+/// Currently 3MF, STL, OBJ & binary glTF (GLB) file types are supported. The
+/// case insensitive file extension of the provided path is used to switch
+/// between supported types.
 pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
@@ -34,6 +47,9 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
             export_obj(mesh, path)
         }
+        Some(extension) if extension.to_ascii_uppercase() == "GLB" => {
+            export_glb(mesh, path)
+        }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
         )),
@@ -42,37 +58,414 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
 }
 
 fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
-    let vertices = mesh
-        .vertices()
-        .map(|point| threemf::model::Vertex {
-            x: point.x.into_f64(),
-            y: point.y.into_f64(),
-            z: point.z.into_f64(),
-        })
-        .collect();
-
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| threemf::model::Triangle {
-            v1: triangle[0] as usize,
-            v2: triangle[1] as usize,
-            v3: triangle[2] as usize,
-        })
-        .collect();
+    let file = File::create(path)?;
+    write_3mf(mesh, file)
+}
 
-    let mesh = threemf::Mesh {
-        vertices: threemf::model::Vertices { vertex: vertices },
-        triangles: threemf::model::Triangles {
-            triangle: triangles,
-        },
+/// Write the mesh as a 3MF package
+///
+/// The colors of the triangles are preserved, using a color group from the 3MF
+/// materials extension. It contains each distinct color once, in the order in
+/// which the colors first appear in the mesh.
+///
+/// Triangles that have a material refer to a base materials group instead.
+/// It contains each distinct combination of material and color once, named
+/// after the material, and each entry is accompanied by the physically based
+/// display properties of its material.
+fn write_3mf(
+    mesh: &Mesh<Point<3>>,
+    writer: impl Write + Seek,
+) -> Result<(), Error> {
+    const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+    const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+    // The IDs of the resources within the model.
+    const COLOR_GROUP_ID: u32 = 1;
+    const OBJECT_ID: u32 = 2;
+    const DISPLAY_PROPERTIES_ID: u32 = 3;
+    const BASE_MATERIALS_ID: u32 = 4;
+
+    let mut colors = Vec::new();
+    let mut color_indices = HashMap::new();
+    let mut bases = Vec::new();
+    let mut base_indices = HashMap::new();
+    for triangle in mesh.triangles() {
+        match triangle.material {
+            Some(material) => {
+                let key = (material, triangle.color);
+                base_indices.entry(key).or_insert_with(|| {
+                    bases.push(key);
+                    bases.len() - 1
+                });
+            }
+            None => {
+                color_indices.entry(triangle.color).or_insert_with(|| {
+                    colors.push(triangle.color);
+                    colors.len() - 1
+                });
+            }
+        }
+    }
+
+    let mut model = String::new();
+
+    model.push_str(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="millimeter" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02" xmlns:m="http://schemas.microsoft.com/3dmanufacturing/material/2015/02">
+  <resources>
+"#,
+    );
+
+    // Neither a color group nor a base materials group must be empty. We leave
+    // out those that aren't used by any triangle.
+    if !colors.is_empty() {
+        writeln!(model, r#"    <m:colorgroup id="{COLOR_GROUP_ID}">"#)?;
+        for Color([r, g, b, a]) in &colors {
+            writeln!(
+                model,
+                r##"      <m:color color="#{r:02X}{g:02X}{b:02X}{a:02X}"/>"##
+            )?;
+        }
+        writeln!(model, "    </m:colorgroup>")?;
+    }
+    if !bases.is_empty() {
+        let materials = mesh.materials();
+
+        writeln!(
+            model,
+            r#"    <m:pbmetallicdisplayproperties id="{DISPLAY_PROPERTIES_ID}">"#
+        )?;
+        for &(material, _) in &bases {
+            let Material {
+                name,
+                roughness,
+                metalness,
+            } = &materials[material];
+
+            writeln!(
+                model,
+                r#"      <m:pbmetallic name="{}" metallicness="{metalness}" roughness="{roughness}"/>"#,
+                escape_xml(name),
+            )?;
+        }
+        writeln!(model, "    </m:pbmetallicdisplayproperties>")?;
+
+        writeln!(
+            model,
+            r#"    <basematerials id="{BASE_MATERIALS_ID}" m:displaypropertiesid="{DISPLAY_PROPERTIES_ID}">"#
+        )?;
+        for &(material, Color([r, g, b, a])) in &bases {
+            writeln!(
+                model,
+                r##"      <base name="{}" displaycolor="#{r:02X}{g:02X}{b:02X}{a:02X}"/>"##,
+                escape_xml(&materials[material].name),
+            )?;
+        }
+        writeln!(model, "    </basematerials>")?;
+    }
+
+    // If there are no triangles, there are no properties to refer to.
+    let object_properties = if !colors.is_empty() {
+        format!(r#" pid="{COLOR_GROUP_ID}" pindex="0""#)
+    } else if !bases.is_empty() {
+        format!(r#" pid="{BASE_MATERIALS_ID}" pindex="0""#)
+    } else {
+        String::new()
     };
 
-    threemf::write(path, mesh)?;
+    writeln!(
+        model,
+        r#"    <object id="{OBJECT_ID}" type="model"{object_properties}>"#
+    )?;
+    writeln!(model, "      <mesh>")?;
+
+    writeln!(model, "        <vertices>")?;
+    for vertex in mesh.vertices() {
+        let [x, y, z] = vertex.coords.components.map(|s| s.into_f64());
+        writeln!(model, r#"          <vertex x="{x}" y="{y}" z="{z}"/>"#)?;
+    }
+    writeln!(model, "        </vertices>")?;
+
+    writeln!(model, "        <triangles>")?;
+    let indices = mesh.indices().collect::<Vec<_>>();
+    for (vertices, triangle) in indices.chunks(3).zip(mesh.triangles()) {
+        let [v1, v2, v3] = [vertices[0], vertices[1], vertices[2]];
+        let (pid, p1) = match triangle.material {
+            Some(material) => {
+                (BASE_MATERIALS_ID, base_indices[&(material, triangle.color)])
+            }
+            None => (COLOR_GROUP_ID, color_indices[&triangle.color]),
+        };
+
+        writeln!(
+            model,
+            r#"          <triangle v1="{v1}" v2="{v2}" v3="{v3}" pid="{pid}" p1="{p1}"/>"#
+        )?;
+    }
+    writeln!(model, "        </triangles>")?;
+
+    writeln!(model, "      </mesh>")?;
+    writeln!(model, "    </object>")?;
+    writeln!(model, "  </resources>")?;
+    writeln!(model, "  <build>")?;
+    writeln!(model, r#"    <item objectid="{OBJECT_ID}"/>"#)?;
+    writeln!(model, "  </build>")?;
+    writeln!(model, "</model>")?;
+
+    let mut zip = ZipWriter::new(writer);
+    let files = [
+        ("[Content_Types].xml", CONTENT_TYPES),
+        ("_rels/.rels", RELATIONSHIPS),
+        ("3D/3dmodel.model", model.as_str()),
+    ];
+    for (name, content) in files {
+        zip.start_file(name, FileOptions::default())?;
+        zip.write_all(content.as_bytes())?;
+    }
+    zip.finish()?;
 
     Ok(())
 }
 
+/// Escape the characters that have special meaning in XML attribute values
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn export_glb(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+    let file = File::create(path)?;
+    write_glb(mesh, file)
+}
+
+/// Write the mesh as a binary glTF file
+///
+/// The triangles are grouped into one primitive per material, in the order in
+/// which the materials first appear in the mesh. Each material of the mesh
+/// becomes a glTF material with the same metallic-roughness properties, while
+/// triangles without a material use the default material of glTF.
+///
+/// The colors of the triangles are preserved as vertex colors. Vertices are not
+/// shared between triangles, so each triangle keeps its own color and normal.
+fn write_glb(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+) -> Result<(), Error> {
+    const ARRAY_BUFFER: u32 = 34962;
+    const FLOAT: u32 = 5126;
+    const TRIANGLES: u32 = 4;
+
+    let mut groups: Vec<(Option<usize>, Vec<_>)> = Vec::new();
+    for triangle in mesh.triangles() {
+        match groups
+            .iter_mut()
+            .find(|(material, _)| *material == triangle.material)
+        {
+            Some((_, triangles)) => triangles.push(triangle),
+            None => groups.push((triangle.material, vec![triangle])),
+        }
+    }
+
+    let mut buffer = Vec::new();
+    let mut buffer_views = Vec::new();
+    let mut accessors = Vec::new();
+    let mut primitives = Vec::new();
+
+    for (material, triangles) in &groups {
+        let count = triangles.len() * 3;
+
+        let mut positions = Vec::new();
+        let mut normals = Vec::new();
+        let mut colors = Vec::new();
+        for triangle in triangles {
+            let normal =
+                triangle.inner.normal().components.map(|s| s.into_f32());
+            let color = triangle.color.to_rgba_f64().map(|c| c as f32);
+
+            for point in triangle.inner.points() {
+                positions.push(point.coords.components.map(|s| s.into_f32()));
+                normals.push(normal);
+                colors.extend(color);
+            }
+        }
+
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for position in &positions {
+            for i in 0..3 {
+                min[i] = min[i].min(position[i]);
+                max[i] = max[i].max(position[i]);
+            }
+        }
+
+        let attributes = [
+            positions.into_iter().flatten().collect::<Vec<_>>(),
+            normals.into_iter().flatten().collect(),
+            colors,
+        ];
+        let mut accessor_indices = [0; 3];
+        for (i, attribute) in attributes.iter().enumerate() {
+            let byte_offset = buffer.len();
+            for value in attribute {
+                buffer.extend(value.to_le_bytes());
+            }
+            let byte_length = buffer.len() - byte_offset;
+
+            buffer_views.push(format!(
+                r#"{{"buffer":0,"byteOffset":{byte_offset},"byteLength":{byte_length},"target":{ARRAY_BUFFER}}}"#
+            ));
+
+            let (kind, bounds) = match i {
+                0 => (
+                    "VEC3",
+                    format!(
+                        r#","min":[{}],"max":[{}]"#,
+                        format_floats(&min),
+                        format_floats(&max),
+                    ),
+                ),
+                1 => ("VEC3", String::new()),
+                _ => ("VEC4", String::new()),
+            };
+            accessor_indices[i] = accessors.len();
+            accessors.push(format!(
+                r#"{{"bufferView":{},"componentType":{FLOAT},"count":{count},"type":"{kind}"{bounds}}}"#,
+                buffer_views.len() - 1,
+            ));
+        }
+
+        let [position, normal, color] = accessor_indices;
+        let material = match material {
+            Some(material) => format!(r#","material":{material}"#),
+            None => String::new(),
+        };
+        primitives.push(format!(
+            r#"{{"attributes":{{"POSITION":{position},"NORMAL":{normal},"COLOR_0":{color}}},"mode":{TRIANGLES}{material}}}"#
+        ));
+    }
+
+    let materials = mesh
+        .materials()
+        .iter()
+        .map(|material| {
+            format!(
+                r#"{{"name":"{}","pbrMetallicRoughness":{{"metallicFactor":{},"roughnessFactor":{}}}}}"#,
+                escape_json(&material.name),
+                material.metalness,
+                material.roughness,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut json = String::new();
+    write!(
+        json,
+        r#"{{"asset":{{"version":"2.0","generator":"Fornjot"}}"#
+    )?;
+    // A mesh must have at least one primitive, and a buffer must not be empty.
+    // If there are no triangles, we leave out the scene and its data.
+    if !primitives.is_empty() {
+        write!(
+            json,
+            r#","scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0}}]"#
+        )?;
+        write!(
+            json,
+            r#","meshes":[{{"primitives":[{}]}}]"#,
+            primitives.join(",")
+        )?;
+        write!(json, r#","accessors":[{}]"#, accessors.join(","))?;
+        write!(json, r#","bufferViews":[{}]"#, buffer_views.join(","))?;
+        write!(json, r#","buffers":[{{"byteLength":{}}}]"#, buffer.len())?;
+    }
+    if !materials.is_empty() {
+        write!(json, r#","materials":[{}]"#, materials.join(","))?;
+    }
+    write!(json, "}}")?;
+
+    // Chunks must be aligned to 4 bytes. The JSON chunk is padded with spaces,
+    // the binary chunk with zeros.
+    let mut json = json.into_bytes();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+    while buffer.len() % 4 != 0 {
+        buffer.push(0);
+    }
+
+    let mut chunks = vec![(0x4E4F534A_u32, json)];
+    if !buffer.is_empty() {
+        chunks.push((0x004E4942, buffer));
+    }
+
+    let length =
+        12 + chunks.iter().map(|(_, data)| 8 + data.len()).sum::<usize>();
+    let length =
+        u32::try_from(length).map_err(|_| Error::InvalidTriangleCount)?;
+
+    writer.write_all(b"glTF")?;
+    writer.write_all(&2_u32.to_le_bytes())?;
+    writer.write_all(&length.to_le_bytes())?;
+    for (kind, data) in chunks {
+        // The length of each chunk is bounded by the total length, which has
+        // been checked to fit into `u32` already.
+        writer.write_all(&(data.len() as u32).to_le_bytes())?;
+        writer.write_all(&kind.to_le_bytes())?;
+        writer.write_all(&data)?;
+    }
+
+    Ok(())
+}
+
+/// Format floats as a comma-separated list, as used in JSON arrays
+fn format_floats(values: &[f32]) -> String {
+    values
+        .iter()
+        .map(|value| format!("{value:?}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Escape the characters that have special meaning in JSON strings
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", u32::from(c))
+                    .expect("Writing to `String` can't fail");
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
 fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     let points = mesh
         .triangles()
@@ -83,10 +476,8 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         points.map(|point| point.coords.components.map(|s| s.into_f32()))
     });
 
-    let normals = points
-        .iter()
-        .map(|&points| points.into())
-        .map(|triangle: Triangle<3>| triangle.normal())
+    let normals = mesh
+        .triangle_normals()
         .map(|vector| vector.components.map(|s| s.into_f32()));
 
     let triangles = vertices
@@ -186,9 +577,13 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// Error whilst formatting the model of a 3MF file
+    #[error("error whilst formatting the model of a 3MF file")]
+    Format(#[from] fmt::Error),
+
+    /// Zip error whilst packaging a 3MF file
+    #[error("zip error whilst packaging a 3MF file")]
+    Zip(#[from] zip::result::ZipError),
 
     /// OBJ exporter error whilst exporting to OBJ file
     #[error("obj error whilst exporting to OBJ file")]
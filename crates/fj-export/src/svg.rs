@@ -0,0 +1,265 @@
+//! Export sketches to SVG
+//!
+//! See [`write_svg`].
+
+use std::{fmt::Write as _, io};
+
+use fj_math::{Point, Scalar, Vector};
+
+use fj_core::{
+    algorithms::bounding_volume::BoundingVolume,
+    geometry::SurfacePath,
+    objects::{Cycle, HalfEdge, Sketch},
+};
+
+/// Write the sketch to an SVG file
+///
+/// Each region of the sketch becomes a `<path>` element, with one subpath
+/// per cycle. The path uses the even-odd fill rule, so the interiors of the
+/// region are rendered as holes. Lines become line commands, circular and
+/// elliptical arcs become arc commands.
+///
+/// The sketch's 2D coordinates are used as SVG user units, with the y-axis
+/// flipped, so the sketch isn't rendered upside down. The `viewBox` covers
+/// the AABB of the sketch, plus [`SvgOptions::margin`]. An empty sketch has no
+/// AABB, and results in an SVG file without a `viewBox`.
+///
+/// Bézier curves and B-splines are not supported. Edges on those result in
+/// an error.
+pub fn write_svg(
+    sketch: &Sketch,
+    mut writer: impl io::Write,
+    options: &SvgOptions,
+) -> Result<(), SvgError> {
+    let fill = options.fill.as_deref().unwrap_or("none");
+    let stroke = options.stroke.as_deref().unwrap_or("none");
+
+    write!(writer, "<svg xmlns=\"http://www.w3.org/2000/svg\"")?;
+    if let Some(aabb) = sketch.aabb() {
+        let margin = Vector::from([options.margin, options.margin]);
+        let min = aabb.min - margin;
+        let max = aabb.max + margin;
+
+        write!(
+            writer,
+            " viewBox=\"{} {} {} {}\"",
+            min.u,
+            Scalar::ZERO - max.v,
+            max.u - min.u,
+            max.v - min.v,
+        )?;
+    }
+    writeln!(writer, ">")?;
+
+    for region in sketch.regions() {
+        let mut data = String::new();
+        for cycle in region.all_cycles() {
+            write_cycle(&mut data, cycle)?;
+        }
+
+        writeln!(
+            writer,
+            "  <path d=\"{}\" fill=\"{}\" fill-rule=\"evenodd\" \
+            stroke=\"{}\" stroke-width=\"{}\"/>",
+            data.trim_end(),
+            escape_attribute(fill),
+            escape_attribute(stroke),
+            options.stroke_width,
+        )?;
+    }
+
+    writeln!(writer, "</svg>")?;
+
+    Ok(())
+}
+
+/// Options for [`write_svg`]
+#[derive(Clone, Debug)]
+pub struct SvgOptions {
+    /// The color that regions are filled with, if any
+    ///
+    /// Can be any color that SVG understands, like `black` or `#ff8000`.
+    pub fill: Option<String>,
+
+    /// The color of the outlines of regions, if any
+    pub stroke: Option<String>,
+
+    /// The width of the outlines of regions, in sketch units
+    pub stroke_width: Scalar,
+
+    /// The space around the sketch, in sketch units
+    pub margin: Scalar,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            fill: Some("black".to_string()),
+            stroke: None,
+            stroke_width: Scalar::ONE,
+            margin: Scalar::ZERO,
+        }
+    }
+}
+
+/// Error writing a sketch to an SVG file
+///
+/// See [`write_svg`].
+#[derive(Debug, thiserror::Error)]
+pub enum SvgError {
+    /// The sketch has an edge that can't be represented in SVG
+    #[error("Can't write edge on path to SVG: {0:?}")]
+    UnsupportedPath(SurfacePath),
+
+    /// Error writing to the writer
+    #[error("I/O error while writing SVG")]
+    Io(#[from] io::Error),
+}
+
+fn write_cycle(data: &mut String, cycle: &Cycle) -> Result<(), SvgError> {
+    let Some(first) = cycle.half_edges().next() else {
+        return Ok(());
+    };
+
+    let start = point(first.start_position());
+    write!(data, "M {start} ").expect("Writing to `String` can't fail");
+
+    for half_edge in cycle.half_edges() {
+        write_half_edge(data, half_edge)?;
+    }
+
+    data.push_str("Z ");
+
+    Ok(())
+}
+
+fn write_half_edge(
+    data: &mut String,
+    half_edge: &HalfEdge,
+) -> Result<(), SvgError> {
+    let path = half_edge.path();
+    let [start, end] = half_edge.boundary().inner.map(|point| point.t);
+
+    let (a, b) = match path {
+        SurfacePath::Circle(circle) => (circle.a(), circle.b()),
+        SurfacePath::Ellipse(ellipse) => (ellipse.major(), ellipse.minor()),
+        SurfacePath::Line(_) => {
+            let end = point(path.point_from_path_coords([end]));
+            write!(data, "L {end} ").expect("Writing to `String` can't fail");
+            return Ok(());
+        }
+        path => return Err(SvgError::UnsupportedPath(path.clone())),
+    };
+
+    // The y-axis is flipped, which flips the rotation of the axes and the
+    // direction of the arc too.
+    let rotation = Scalar::ZERO - a.v.atan2(a.u).to_degrees();
+    let counter_clockwise = (a.cross2d(&b) * (end - start)).is_positive();
+    let sweep = if counter_clockwise { 0 } else { 1 };
+
+    // An arc command can't describe a full circle, and arcs of more than half
+    // a circle need the large-arc flag. Splitting them avoids both.
+    let ends = if (end - start).abs() > Scalar::PI {
+        vec![start + (end - start) / 2., end]
+    } else {
+        vec![end]
+    };
+    for end in ends {
+        let end = point(path.point_from_path_coords([end]));
+
+        write!(
+            data,
+            "A {} {} {rotation} 0 {sweep} {end} ",
+            a.magnitude(),
+            b.magnitude(),
+        )
+        .expect("Writing to `String` can't fail");
+    }
+
+    Ok(())
+}
+
+fn point(point: Point<2>) -> String {
+    // Subtracting from zero, instead of negating, avoids writing `-0`.
+    format!("{} {}", point.u, Scalar::ZERO - point.v)
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use fj_core::{
+        objects::{Cycle, Region, Sketch},
+        operations::{
+            BuildCycle, BuildRegion, BuildSketch, Insert, Reverse,
+            UpdateRegion, UpdateSketch,
+        },
+        services::Services,
+    };
+
+    use super::{write_svg, SvgOptions};
+
+    #[test]
+    fn svg_of_sketch() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let hole = Cycle::circle([1., 1.], 0.5, &mut services)
+            .reverse(&mut services)
+            .insert(&mut services);
+        let square = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut services,
+        )
+        .add_interiors([hole])
+        .insert(&mut services);
+        let sketch = Sketch::empty().add_region(square);
+
+        let mut svg = Vec::new();
+        write_svg(
+            &sketch,
+            &mut svg,
+            &SvgOptions {
+                margin: Scalar::ONE,
+                ..SvgOptions::default()
+            },
+        )?;
+        let svg = String::from_utf8(svg)?;
+
+        assert!(svg.contains("viewBox=\"-1 -3 4 4\""));
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert!(svg.contains("fill-rule=\"evenodd\""));
+
+        // The square is drawn with lines, the circle with two arcs. Both are
+        // closed subpaths.
+        let data = svg.split("d=\"").nth(1).unwrap().split('"').next().unwrap();
+        assert_eq!(data.matches('M').count(), 2);
+        assert_eq!(data.matches('L').count(), 4);
+        assert_eq!(data.matches('A').count(), 2);
+        assert_eq!(data.matches('Z').count(), 2);
+        assert!(data.starts_with("M 0 0 L 2 0 L 2 -2 L 0 -2 L 0 0 Z M 1.5 "));
+
+        // The hole runs clockwise. That is still the case after flipping the
+        // y-axis, which makes it the positive direction in SVG.
+        assert!(data.contains("A 0.5 0.5 0 0 1 "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn svg_of_empty_sketch() -> anyhow::Result<()> {
+        let mut svg = Vec::new();
+        write_svg(&Sketch::empty(), &mut svg, &SvgOptions::default())?;
+        let svg = String::from_utf8(svg)?;
+
+        assert_eq!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\">\n</svg>\n");
+
+        Ok(())
+    }
+}
@@ -1,22 +1,53 @@
 //! A triangle mesh
 
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    io::{self, Read, Write},
+    iter,
+    ops::Range,
+    str,
+};
 
-use fj_math::Point;
+use fj_math::{Aabb, Point, Ray, Scalar, Transform, Vector};
+
+use crate::ext::ArrayExt;
 
 /// A triangle mesh
+///
+/// # Ordering guarantee
+///
+/// Vertices are stored in the order in which they are first added, and
+/// triangles in the order in which they are added. The index of a vertex
+/// therefore only depends on the sequence of operations performed on the mesh.
+/// No part of the mesh depends on hashing or other sources of randomness, so
+/// the same sequence of operations always results in the same mesh, and the
+/// same output of its export methods.
 #[derive(Clone, Debug)]
 pub struct Mesh<V> {
     vertices: Vec<V>,
     indices: Vec<Index>,
 
-    indices_by_vertex: HashMap<V, Index>,
+    indices_by_vertex: BTreeMap<V, Index>,
     triangles: Vec<Triangle>,
+
+    /// The distinct materials of the triangles, in order of first use
+    materials: Vec<Material>,
+
+    /// The normals at the corners of each triangle, if computed
+    normals: Option<Vec<[Vector<3>; 3]>>,
+
+    /// The index of the first triangle of each object, except the first one
+    ///
+    /// See [`Mesh::start_object`].
+    object_starts: Vec<usize>,
+
+    /// Used to weld vertices that are close to each other, if configured
+    weld: Option<Weld>,
 }
 
 impl<V> Mesh<V>
 where
-    V: Copy + Eq + Hash,
+    V: Copy + Ord,
 {
     /// Construct a new instance of `Mesh`
     pub fn new() -> Self {
@@ -56,6 +87,9 @@ where
     }
 
     /// Access the vertices of the mesh
+    ///
+    /// Returns the distinct vertices, in the order in which they were first
+    /// added to the mesh.
     pub fn vertices(&self) -> impl Iterator<Item = V> + '_ {
         self.vertices.iter().copied()
     }
@@ -69,25 +103,995 @@ where
     pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
         self.triangles.iter().copied()
     }
+
+    /// Access the materials of the mesh
+    ///
+    /// Returns each distinct material once, in the order in which they were
+    /// first used by a triangle. [`Triangle::material`] refers to this list.
+    pub fn materials(&self) -> &[Material] {
+        &self.materials
+    }
+
+    /// Start a new object
+    ///
+    /// Triangles that are added from now on belong to the new object, until
+    /// the next one is started. Triangles that were added before the first
+    /// call belong to an implicit first object.
+    ///
+    /// This keeps the boundaries between parts of the mesh that come from
+    /// separate objects, like the solids of an assembly, so those parts can
+    /// be handled separately later on. See [`Mesh::objects`].
+    pub fn start_object(&mut self) {
+        let start = self.triangles.len();
+        let current = self.object_starts.last().copied().unwrap_or(0);
+
+        // The current object is still empty. Starting another one wouldn't
+        // make a difference.
+        if start > current {
+            self.object_starts.push(start);
+        }
+    }
+
+    /// Access the objects of the mesh
+    ///
+    /// Returns the range of triangles that belongs to each object, in the
+    /// order of [`Mesh::triangles`]. Objects without any triangles are left
+    /// out. See [`Mesh::start_object`].
+    pub fn objects(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let starts = iter::once(0).chain(self.object_starts.iter().copied());
+        let ends = self
+            .object_starts
+            .iter()
+            .copied()
+            .chain(iter::once(self.triangles.len()));
+
+        starts
+            .zip(ends)
+            .map(|(start, end)| start..end)
+            .filter(|triangles| !triangles.is_empty())
+    }
+
+    /// Return the number of triangles in the mesh
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Return the number of distinct vertices in the mesh
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
 }
 
 impl Mesh<Point<3>> {
+    /// Construct a new instance of `Mesh` that welds close vertices
+    ///
+    /// Any vertex that is added to the mesh and is within the provided
+    /// distance of a vertex that is already part of the mesh, is replaced with
+    /// that existing vertex. This prevents cracks between triangles, whose
+    /// vertices should be identical, but are not, due to floating-point
+    /// inaccuracies.
+    pub fn with_weld_tolerance(tolerance: impl Into<Scalar>) -> Self {
+        let tolerance = tolerance.into();
+        assert!(tolerance > Scalar::ZERO, "Weld tolerance must be positive");
+
+        Self {
+            weld: Some(Weld {
+                tolerance,
+                grid: BTreeMap::new(),
+            }),
+            ..Self::default()
+        }
+    }
+
     /// Add a triangle to the mesh
+    ///
+    /// Discards any vertex normals that have been computed, as those would no
+    /// longer match the triangles.
+    ///
+    /// If the mesh welds vertices (see [`Mesh::with_weld_tolerance`]), the
+    /// triangle's vertices are welded first. Triangles that collapse as a
+    /// result are not added.
+    ///
+    /// # Panics
+    ///
+    /// Converting points that don't form a triangle into
+    /// [`fj_math::Triangle`] panics. Use [`Mesh::try_push_triangle`] for
+    /// points that might do that.
     pub fn push_triangle(
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
     ) {
-        let triangle = triangle.into();
+        self.push_triangle_with_material(triangle, color, None);
+    }
+
+    /// Add a triangle with a material to the mesh
+    ///
+    /// Like [`Mesh::push_triangle`], but also assigns the provided material to
+    /// the triangle. A triangle without a material is presented the same way
+    /// as one that was added by [`Mesh::push_triangle`].
+    pub fn push_triangle_with_material(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        color: Color,
+        material: Option<&Material>,
+    ) {
+        let mut triangle = triangle.into();
+
+        if let Some(weld) = &self.weld {
+            let mut points: Vec<Point<3>> = Vec::new();
+            for point in triangle.points() {
+                let welded = weld
+                    .find_close_vertex(point, &self.vertices)
+                    .or_else(|| {
+                        points.iter().copied().find(|other| {
+                            other.distance_to(&point) <= weld.tolerance
+                        })
+                    })
+                    .unwrap_or(point);
+                points.push(welded);
+            }
+
+            let points = [points[0], points[1], points[2]];
+            let Ok(welded) = fj_math::Triangle::from_points(points) else {
+                // The triangle collapsed.
+                return;
+            };
+
+            triangle = welded;
+        }
 
         for point in triangle.points() {
+            if let Some(weld) = &mut self.weld {
+                if !self.indices_by_vertex.contains_key(&point) {
+                    weld.insert(point, self.vertices.len() as Index);
+                }
+            }
+
             self.push_vertex(point);
         }
 
+        let material = material.map(|material| {
+            self.materials
+                .iter()
+                .position(|m| m == material)
+                .unwrap_or_else(|| {
+                    self.materials.push(material.clone());
+                    self.materials.len() - 1
+                })
+        });
+
         self.triangles.push(Triangle {
             inner: triangle,
             color,
+            material,
+        });
+        self.normals = None;
+    }
+
+    /// Add a triangle to the mesh, if the provided points form one
+    ///
+    /// Like [`Mesh::push_triangle`], but returns an error instead of
+    /// panicking, if the points are collinear, for example because two of them
+    /// are equal. The mesh is left unchanged in that case.
+    pub fn try_push_triangle(
+        &mut self,
+        points: [impl Into<Point<3>>; 3],
+        color: Color,
+    ) -> Result<(), DegenerateTriangle> {
+        let points = points.map(Into::into);
+        let triangle = fj_math::Triangle::from_points(points)
+            .map_err(|_| DegenerateTriangle { points })?;
+
+        self.push_triangle(triangle, color);
+        Ok(())
+    }
+
+    /// Merge another mesh into this one
+    ///
+    /// Adds all triangles of `other` to this mesh, preserving their colors and
+    /// materials.
+    /// The boundaries between the objects of `other` are preserved too. Its
+    /// first object becomes part of the current object of this mesh. Call
+    /// [`Mesh::start_object`] before merging, to keep it separate.
+    /// Vertices of `other` that are already part of this mesh are reused, and
+    /// the triangles are indexed accordingly.
+    ///
+    /// Vertices are deduplicated according to this mesh's policy: They must be
+    /// identical, unless this mesh welds vertices (see
+    /// [`Mesh::with_weld_tolerance`]), in which case vertices within the weld
+    /// tolerance are merged, and triangles that collapse are dropped.
+    ///
+    /// Without welding, merging is associative: Merging a number of meshes
+    /// results in the same vertex set, regardless of the order. With welding,
+    /// that is only the case, as long as the vertices that are welded together
+    /// are much closer to each other than the weld tolerance, while all others
+    /// are much further apart.
+    pub fn merge(&mut self, other: &Mesh<Point<3>>) {
+        for (i, object) in other.objects().enumerate() {
+            if i > 0 {
+                self.start_object();
+            }
+
+            for triangle in &other.triangles[object] {
+                let material =
+                    triangle.material.map(|index| &other.materials[index]);
+                self.push_triangle_with_material(
+                    triangle.inner,
+                    triangle.color,
+                    material,
+                );
+            }
+        }
+    }
+
+    /// Remove all vertices that are not part of any triangle
+    ///
+    /// Rewrites the indices of the triangles to refer to the remaining
+    /// vertices, which keep their relative order. Returns the number of
+    /// vertices that have been removed.
+    pub fn compact(&mut self) -> usize {
+        let referenced = self
+            .triangles
+            .iter()
+            .flat_map(|triangle| triangle.inner.points())
+            .collect::<BTreeSet<_>>();
+
+        let num_vertices = self.vertices.len();
+        self.vertices.retain(|vertex| referenced.contains(vertex));
+
+        self.indices_by_vertex = self
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(index, &vertex)| (vertex, index as Index))
+            .collect();
+        self.indices = self
+            .triangles
+            .iter()
+            .flat_map(|triangle| triangle.inner.points())
+            .map(|point| self.indices_by_vertex[&point])
+            .collect();
+
+        if let Some(weld) = &mut self.weld {
+            weld.grid.clear();
+            for (index, &vertex) in self.vertices.iter().enumerate() {
+                weld.insert(vertex, index as Index);
+            }
+        }
+
+        num_vertices - self.vertices.len()
+    }
+
+    /// Compute the normal of each triangle
+    ///
+    /// Returns the normals in the order of [`Mesh::triangles`]. Each normal is
+    /// normalized, and points towards the side from which the triangle's points
+    /// appear counter-clockwise.
+    ///
+    /// Returns the zero vector for a triangle whose points are so close to a
+    /// line, that its normal can't be computed.
+    pub fn triangle_normals(&self) -> impl Iterator<Item = Vector<3>> + '_ {
+        self.triangles.iter().map(|triangle| {
+            let [a, b, c] = triangle.inner.points();
+
+            let normal = (b - a).cross(&(c - a));
+            if normal.magnitude() == Scalar::ZERO {
+                normal
+            } else {
+                normal.normalize()
+            }
+        })
+    }
+
+    /// Compute smooth normals for the vertices of the mesh
+    ///
+    /// The normal at each corner of a triangle is the average of the normals
+    /// of all triangles that share the vertex, weighted by the angle those
+    /// triangles have at the vertex.
+    ///
+    /// Triangles whose normal deviates from the triangle in question by more
+    /// than `crease_angle` (in radians) are left out of the average. This keeps
+    /// sharp edges, like those of a cube, from being smoothed over.
+    #[must_use]
+    pub fn with_vertex_normals(
+        mut self,
+        crease_angle: impl Into<Scalar>,
+    ) -> Self {
+        let crease_angle = crease_angle.into();
+
+        // The normal of each triangle, and the angle at each of its corners.
+        //
+        // Degenerate triangles don't have a normal. They won't contribute to
+        // the average.
+        let triangles = self
+            .triangles
+            .iter()
+            .zip(self.triangle_normals())
+            .map(|(triangle, normal)| {
+                let [a, b, c] = triangle.inner.points();
+
+                let angle = |vertex: Point<3>, p: Point<3>, q: Point<3>| {
+                    let [u, v] = [p - vertex, q - vertex];
+                    if u.magnitude() == Scalar::ZERO
+                        || v.magnitude() == Scalar::ZERO
+                    {
+                        return Scalar::ZERO;
+                    }
+
+                    u.normalize()
+                        .dot(&v.normalize())
+                        .clamp(-Scalar::ONE, Scalar::ONE)
+                        .acos()
+                };
+                let angles = [angle(a, b, c), angle(b, c, a), angle(c, a, b)];
+
+                (normal, angles)
+            })
+            .collect::<Vec<_>>();
+
+        // The triangles adjacent to each vertex, and at which of their corners
+        // the vertex is located.
+        let mut adjacent = vec![Vec::new(); self.vertices.len()];
+        for (i, triangle) in self.triangles.iter().enumerate() {
+            for (corner, point) in triangle.inner.points().iter().enumerate() {
+                let index = self.indices_by_vertex[point];
+                adjacent[index as usize].push((i, corner));
+            }
+        }
+
+        let normals = self
+            .triangles
+            .iter()
+            .zip(&triangles)
+            .map(|(triangle, (normal, _))| {
+                triangle.inner.points().map(|point| {
+                    let index = self.indices_by_vertex[&point];
+
+                    let sum = adjacent[index as usize]
+                        .iter()
+                        .map(|&(other, corner)| {
+                            let (other_normal, angles) = triangles[other];
+
+                            let deviation = normal
+                                .dot(&other_normal)
+                                .clamp(-Scalar::ONE, Scalar::ONE)
+                                .acos();
+                            if deviation > crease_angle {
+                                return Vector::from([0., 0., 0.]);
+                            }
+
+                            other_normal * angles[corner]
+                        })
+                        .fold(Vector::from([0., 0., 0.]), |sum, normal| {
+                            sum + normal
+                        });
+
+                    if sum.magnitude() == Scalar::ZERO {
+                        *normal
+                    } else {
+                        sum.normalize()
+                    }
+                })
+            })
+            .collect();
+
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Transform the mesh
+    ///
+    /// Applies the transform to every vertex. Vertex normals, if they have been
+    /// computed (see [`Mesh::with_vertex_normals`]), are transformed using the
+    /// inverse transpose of the transform. This keeps them perpendicular to
+    /// the triangles, even if the transform scales non-uniformly.
+    ///
+    /// If the transform reverses orientation, like a reflection does, the
+    /// winding of every triangle is reversed too. Otherwise, the triangles
+    /// would end up facing the other way.
+    #[must_use]
+    pub fn transform(&self, transform: &Transform) -> Self {
+        let reverse = transform.is_orientation_reversing();
+
+        let vertices = self
+            .vertices
+            .iter()
+            .map(|vertex| transform.transform_point(vertex))
+            .collect::<Vec<_>>();
+        let indices_by_vertex = vertices
+            .iter()
+            .enumerate()
+            .map(|(index, &vertex)| (vertex, index as Index))
+            .collect::<BTreeMap<_, _>>();
+
+        let triangles = self
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let points = transform.transform_triangle(&triangle.inner);
+                Triangle {
+                    inner: fj_math::Triangle::from(reorder_corners(
+                        points.points(),
+                        reverse,
+                    )),
+                    color: triangle.color,
+                    material: triangle.material,
+                }
+            })
+            .collect::<Vec<_>>();
+        let indices = triangles
+            .iter()
+            .flat_map(|triangle| triangle.inner.points())
+            .map(|point| indices_by_vertex[&point])
+            .collect();
+
+        let normal_transform =
+            transform.extract_rotation().inverse().transpose();
+        let normals = self.normals.as_ref().map(|normals| {
+            normals
+                .iter()
+                .map(|&normals| {
+                    let normals = normals.map(|normal| {
+                        let normal = normal_transform.transform_vector(&normal);
+                        if normal.magnitude() == Scalar::ZERO {
+                            normal
+                        } else {
+                            normal.normalize()
+                        }
+                    });
+                    reorder_corners(normals, reverse)
+                })
+                .collect()
+        });
+
+        let weld = self.weld.as_ref().map(|weld| {
+            let mut weld = Weld {
+                tolerance: weld.tolerance,
+                grid: BTreeMap::new(),
+            };
+            for (index, &vertex) in vertices.iter().enumerate() {
+                weld.insert(vertex, index as Index);
+            }
+            weld
+        });
+
+        Self {
+            vertices,
+            indices,
+            indices_by_vertex,
+            triangles,
+            materials: self.materials.clone(),
+            normals,
+            object_starts: self.object_starts.clone(),
+            weld,
+        }
+    }
+
+    /// Access the vertex normals of the mesh, if they have been computed
+    ///
+    /// Returns the normals at the three corners of each triangle, in the same
+    /// order as [`Mesh::triangles`]. See [`Mesh::with_vertex_normals`].
+    pub fn vertex_normals(
+        &self,
+    ) -> Option<impl Iterator<Item = [Vector<3>; 3]> + '_> {
+        self.normals.as_ref().map(|normals| normals.iter().copied())
+    }
+
+    /// Find the triangle that a ray hits first
+    ///
+    /// Returns `None`, if the ray doesn't hit any triangle. If
+    /// `include_back_faces` is `false`, triangles whose back side faces the
+    /// ray are ignored. See [`Ray::intersect_triangle`].
+    pub fn intersect_ray(
+        &self,
+        ray: &Ray<3>,
+        include_back_faces: bool,
+    ) -> Option<RayHit> {
+        self.triangles
+            .iter()
+            .enumerate()
+            .filter_map(|(index, triangle)| {
+                let intersection = ray
+                    .intersect_triangle(&triangle.inner, include_back_faces)?;
+                Some((index, intersection))
+            })
+            .min_by_key(|(_, intersection)| intersection.t)
+            .map(|(triangle, intersection)| RayHit {
+                triangle,
+                point: ray.point_at(intersection.t),
+                t: intersection.t,
+                barycentric: intersection.barycentric,
+            })
+    }
+
+    /// Compute the axis-aligned bounding box of the mesh's vertices
+    ///
+    /// Returns `None`, if the mesh is empty.
+    pub fn aabb(&self) -> Option<Aabb<3>> {
+        let (first, rest) = self.vertices.split_first()?;
+
+        let aabb = Aabb {
+            min: *first,
+            max: *first,
+        };
+        Some(
+            rest.iter()
+                .fold(aabb, |aabb, vertex| aabb.include_point(vertex)),
+        )
+    }
+
+    /// Compute the total area of the triangles in the mesh
+    ///
+    /// Degenerate triangles don't have any area, and don't contribute to the
+    /// result.
+    pub fn surface_area(&self) -> f32 {
+        self.triangles
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                (b - a).cross(&(c - a)).magnitude() / 2.
+            })
+            .fold(Scalar::ZERO, |sum, area| sum + area)
+            .into_f32()
+    }
+
+    /// Compute statistics about the size of the mesh and its triangles
+    ///
+    /// Degenerate triangles are left out of the statistics about triangle
+    /// areas, edge lengths, and angles, so a single bad triangle doesn't
+    /// distort them.
+    /// They are still included in the triangle count.
+    pub fn stats(&self) -> MeshStats {
+        let mut areas = Vec::new();
+        let mut edge_lengths = Vec::new();
+        let mut min_angles = Vec::new();
+
+        for triangle in &self.triangles {
+            let [a, b, c] = triangle.inner.points();
+
+            let area = (b - a).cross(&(c - a)).magnitude() / 2.;
+            if area == Scalar::ZERO {
+                continue;
+            }
+
+            areas.push(area);
+            min_angles.push(triangle.inner.min_angle());
+            edge_lengths.extend(
+                [[a, b], [b, c], [c, a]].map(|[a, b]| a.distance_to(&b)),
+            );
+        }
+
+        MeshStats {
+            triangle_count: self.triangle_count(),
+            vertex_count: self.vertex_count(),
+            triangle_area: ValueStats::from_values(areas),
+            edge_length: ValueStats::from_values(edge_lengths),
+            min_angle: ValueStats::from_values(min_angles),
+        }
+    }
+
+    /// Read a mesh from STL
+    ///
+    /// Both binary and ASCII STL are supported. STL stores the vertices of each
+    /// triangle separately. Here, vertices that are exactly equal are merged.
+    /// Triangles whose points don't actually form a triangle are skipped.
+    ///
+    /// The normals stored in the STL are ignored. The orientation of each
+    /// triangle is defined by its winding. Use [`Mesh::from_stl_with_normals`],
+    /// to keep the stored normals.
+    pub fn from_stl(reader: impl Read) -> io::Result<Self> {
+        let mut mesh = Self::new();
+
+        for (_, points) in read_stl(reader)? {
+            let Ok(triangle) = fj_math::Triangle::from_points(points) else {
+                continue;
+            };
+
+            mesh.push_triangle(triangle, Color::default());
+        }
+
+        Ok(mesh)
+    }
+
+    /// Read a mesh from STL, keeping the stored normals as vertex normals
+    ///
+    /// Works like [`Mesh::from_stl`], but each corner of a triangle gets the
+    /// normal that is stored for the triangle (see [`Mesh::vertex_normals`]).
+    ///
+    /// The winding of a triangle is trusted over the stored normal. If the
+    /// stored normal is missing (which is allowed in STL), or points to the
+    /// other side of the triangle, the normal is computed from the winding.
+    pub fn from_stl_with_normals(reader: impl Read) -> io::Result<Self> {
+        let mut mesh = Self::new();
+        let mut normals = Vec::new();
+
+        for (normal, points) in read_stl(reader)? {
+            let Ok(triangle) = fj_math::Triangle::from_points(points) else {
+                continue;
+            };
+
+            let winding_normal = triangle.normal();
+            let normal = if normal.dot(&winding_normal) > Scalar::ZERO {
+                normal.normalize()
+            } else {
+                winding_normal
+            };
+
+            mesh.push_triangle(triangle, Color::default());
+            normals.push([normal; 3]);
+        }
+
+        mesh.normals = Some(normals);
+
+        Ok(mesh)
+    }
+
+    /// Write the mesh as binary STL
+    ///
+    /// The header is filled with zeros, so the output only depends on the
+    /// mesh. Degenerate triangles don't have a normal and are skipped.
+    pub fn to_stl(&self, mut writer: impl Write) -> io::Result<()> {
+        let triangles = self
+            .triangles
+            .iter()
+            .filter_map(|triangle| {
+                let points = triangle.inner.points();
+                let [a, b, c] = points;
+
+                let normal = (b - a).cross(&(c - a));
+                if normal.magnitude() == Scalar::ZERO {
+                    return None;
+                }
+
+                Some((normal.normalize(), points))
+            })
+            .collect::<Vec<_>>();
+
+        let num_triangles = u32::try_from(triangles.len()).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Too many triangles for STL format",
+            )
+        })?;
+
+        writer.write_all(&[0; 80])?;
+        writer.write_all(&num_triangles.to_le_bytes())?;
+
+        for (normal, points) in triangles {
+            let vectors = [normal]
+                .into_iter()
+                .chain(points.into_iter().map(|point| point.coords));
+
+            for vector in vectors {
+                for component in vector.components {
+                    writer.write_all(&component.into_f32().to_le_bytes())?;
+                }
+            }
+
+            // Attribute byte count. Not used.
+            writer.write_all(&0u16.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the mesh as Wavefront OBJ
+    ///
+    /// Vertices are written in the order they were added to the mesh, and are
+    /// shared between the faces that use them. If vertex normals have been
+    /// computed, those are written too, and shared in the same way.
+    pub fn to_obj(&self, mut writer: impl Write) -> io::Result<()> {
+        for vertex in &self.vertices {
+            let [x, y, z] = vertex.coords.components.map(Scalar::into_f64);
+            writeln!(writer, "v {x} {y} {z}")?;
+        }
+
+        // OBJ indices start at 1.
+        let vertex_indices = self.triangles.iter().map(|triangle| {
+            triangle
+                .inner
+                .points()
+                .map(|point| self.indices_by_vertex[&point] + 1)
         });
+
+        let Some(normals) = &self.normals else {
+            for [a, b, c] in vertex_indices {
+                writeln!(writer, "f {a} {b} {c}")?;
+            }
+
+            return Ok(());
+        };
+
+        let mut normal_indices = BTreeMap::new();
+        for &normal in normals.iter().flatten() {
+            let index = normal_indices.len() + 1;
+            if let Entry::Vacant(entry) = normal_indices.entry(normal) {
+                let [x, y, z] = normal.components.map(Scalar::into_f64);
+                writeln!(writer, "vn {x} {y} {z}")?;
+
+                entry.insert(index);
+            }
+        }
+
+        for (vertices, corners) in vertex_indices.zip(normals) {
+            let [a, b, c] = vertices
+                .zip_ext(corners.map(|normal| normal_indices[&normal]))
+                .map(|(vertex, normal)| format!("{vertex}//{normal}"));
+            writeln!(writer, "f {a} {b} {c}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the mesh as binary little-endian PLY
+    ///
+    /// Each vertex is written with a color, averaged from the colors of the
+    /// triangles that share it. If vertex normals have been computed, those are
+    /// written too. A vertex that has different normals in different triangles
+    /// is written once for each of those normals.
+    ///
+    /// Vertices that are not part of any triangle are not written.
+    pub fn to_ply(&self, mut writer: impl Write) -> io::Result<()> {
+        struct PlyVertex {
+            point: Point<3>,
+            normal: Option<Vector<3>>,
+            color_sum: [u32; 4],
+            num_triangles: u32,
+        }
+
+        let mut vertices: Vec<PlyVertex> = Vec::new();
+        let mut ply_indices = BTreeMap::new();
+        let mut faces = Vec::new();
+
+        for (i, triangle) in self.triangles.iter().enumerate() {
+            let normals = match &self.normals {
+                Some(normals) => normals[i].map(Some),
+                None => [None; 3],
+            };
+
+            let mut face = [0; 3];
+            for ((point, normal), index) in triangle
+                .inner
+                .points()
+                .zip_ext(normals)
+                .into_iter()
+                .zip(&mut face)
+            {
+                let key = (self.indices_by_vertex[&point], normal);
+                let ply_index = match ply_indices.get(&key) {
+                    Some(&ply_index) => ply_index,
+                    None => {
+                        let ply_index =
+                            u32::try_from(vertices.len()).map_err(|_| {
+                                io::Error::new(
+                                    io::ErrorKind::InvalidInput,
+                                    "Too many vertices for PLY format",
+                                )
+                            })?;
+
+                        vertices.push(PlyVertex {
+                            point,
+                            normal,
+                            color_sum: [0; 4],
+                            num_triangles: 0,
+                        });
+                        ply_indices.insert(key, ply_index);
+
+                        ply_index
+                    }
+                };
+
+                let vertex = &mut vertices[ply_index as usize];
+                for (sum, channel) in
+                    vertex.color_sum.iter_mut().zip(triangle.color.0)
+                {
+                    *sum += u32::from(channel);
+                }
+                vertex.num_triangles += 1;
+
+                *index = ply_index;
+            }
+
+            faces.push(face);
+        }
+
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format binary_little_endian 1.0")?;
+        writeln!(writer, "element vertex {}", vertices.len())?;
+        for property in ["x", "y", "z"] {
+            writeln!(writer, "property float {property}")?;
+        }
+        if self.normals.is_some() {
+            for property in ["nx", "ny", "nz"] {
+                writeln!(writer, "property float {property}")?;
+            }
+        }
+        for property in ["red", "green", "blue", "alpha"] {
+            writeln!(writer, "property uchar {property}")?;
+        }
+        writeln!(writer, "element face {}", faces.len())?;
+        writeln!(writer, "property list uchar uint vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        for vertex in vertices {
+            let vectors =
+                [vertex.point.coords].into_iter().chain(vertex.normal);
+            for vector in vectors {
+                for component in vector.components {
+                    writer.write_all(&component.into_f32().to_le_bytes())?;
+                }
+            }
+
+            // Every vertex is part of at least one triangle, so we're not
+            // dividing by zero here.
+            let color = vertex.color_sum.map(|sum| {
+                let average =
+                    (sum + vertex.num_triangles / 2) / vertex.num_triangles;
+                average as u8
+            });
+            writer.write_all(&color)?;
+        }
+
+        for face in faces {
+            writer.write_all(&[3])?;
+            for index in face {
+                writer.write_all(&index.to_le_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A triangle read from STL, with its stored normal
+type StlTriangle = (Vector<3>, [Point<3>; 3]);
+
+fn read_stl(mut reader: impl Read) -> io::Result<Vec<StlTriangle>> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    // Binary STL starts with an 80-byte header, which may well start with
+    // "solid", like ASCII STL does. But the size of binary STL is determined
+    // by the number of triangles that follows the header, so we check that
+    // first.
+    if let Some(num_triangles) = bytes.get(80..84) {
+        let num_triangles = u32::from_le_bytes([
+            num_triangles[0],
+            num_triangles[1],
+            num_triangles[2],
+            num_triangles[3],
+        ]);
+
+        if bytes.len() as u64 == 84 + 50 * u64::from(num_triangles) {
+            return read_binary_stl(&bytes[84..]);
+        }
+    }
+
+    if bytes.starts_with(b"solid") {
+        let text = str::from_utf8(&bytes).map_err(invalid_stl)?;
+        return read_ascii_stl(text);
+    }
+
+    Err(invalid_stl("Neither valid binary nor ASCII STL"))
+}
+
+fn read_binary_stl(bytes: &[u8]) -> io::Result<Vec<StlTriangle>> {
+    bytes
+        .chunks(50)
+        .map(|record| {
+            // Each record has 12 floats: the normal, then the three points. The
+            // attribute byte count at the end is ignored.
+            let vectors = record[..48]
+                .chunks(12)
+                .map(|vector| {
+                    let mut components = [Scalar::ZERO; 3];
+                    for (component, float) in
+                        components.iter_mut().zip(vector.chunks(4))
+                    {
+                        let float = f32::from_le_bytes([
+                            float[0], float[1], float[2], float[3],
+                        ]);
+                        *component = stl_scalar(float.into())?;
+                    }
+
+                    Ok(Vector::from(components))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            let normal = vectors[0];
+            let points = [1, 2, 3].map(|i| Point { coords: vectors[i] });
+
+            Ok((normal, points))
+        })
+        .collect()
+}
+
+fn read_ascii_stl(text: &str) -> io::Result<Vec<StlTriangle>> {
+    let mut triangles = Vec::new();
+
+    // The normal and points of the facet that is currently being read
+    let mut facet: Option<(Vector<3>, Vec<Point<3>>)> = None;
+
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("facet") => {
+                if tokens.next() != Some("normal") {
+                    return Err(invalid_stl("Expected `normal` after `facet`"));
+                }
+
+                facet = Some((read_stl_vector(tokens)?, Vec::new()));
+            }
+            Some("vertex") => {
+                let (_, points) = facet
+                    .as_mut()
+                    .ok_or_else(|| invalid_stl("`vertex` outside of facet"))?;
+
+                points.push(Point {
+                    coords: read_stl_vector(tokens)?,
+                });
+            }
+            Some("endfacet") => {
+                let (normal, points) = facet
+                    .take()
+                    .ok_or_else(|| invalid_stl("`endfacet` without `facet`"))?;
+                let points = points.try_into().map_err(|_| {
+                    invalid_stl("Facet doesn't have exactly 3 vertices")
+                })?;
+
+                triangles.push((normal, points));
+            }
+            Some("solid" | "endsolid" | "outer" | "endloop") | None => {}
+            Some(keyword) => {
+                return Err(invalid_stl(format!(
+                    "Unexpected keyword `{keyword}`"
+                )));
+            }
+        }
+    }
+
+    Ok(triangles)
+}
+
+fn read_stl_vector<'a>(
+    mut tokens: impl Iterator<Item = &'a str>,
+) -> io::Result<Vector<3>> {
+    let mut components = [Scalar::ZERO; 3];
+
+    for component in &mut components {
+        let token = tokens
+            .next()
+            .ok_or_else(|| invalid_stl("Missing coordinate"))?;
+        let value = token.parse::<f64>().map_err(invalid_stl)?;
+
+        *component = stl_scalar(value)?;
+    }
+
+    Ok(Vector::from(components))
+}
+
+fn stl_scalar(value: f64) -> io::Result<Scalar> {
+    if !value.is_finite() {
+        return Err(invalid_stl("Coordinate is not a finite number"));
+    }
+
+    Ok(Scalar::from_f64(value))
+}
+
+fn invalid_stl(
+    err: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Reverse the order of a triangle's corners, if requested
+fn reorder_corners<T>([a, b, c]: [T; 3], reverse: bool) -> [T; 3] {
+    if reverse {
+        [a, c, b]
+    } else {
+        [a, b, c]
     }
 }
 
@@ -98,15 +1102,163 @@ impl<V> Default for Mesh<V> {
         Self {
             vertices: Vec::default(),
             indices: Vec::default(),
-            indices_by_vertex: HashMap::default(),
+            indices_by_vertex: BTreeMap::default(),
             triangles: Vec::default(),
+            materials: Vec::default(),
+            normals: None,
+            object_starts: Vec::default(),
+            weld: None,
+        }
+    }
+}
+
+/// The state required to weld the vertices of a mesh
+#[derive(Clone, Debug)]
+struct Weld {
+    /// The distance within which vertices are welded
+    tolerance: Scalar,
+
+    /// The indices of the vertices, sorted into cells of the size of the
+    /// tolerance
+    grid: BTreeMap<[i64; 3], Vec<Index>>,
+}
+
+impl Weld {
+    /// Find the vertex closest to the point, if any is within the tolerance
+    fn find_close_vertex(
+        &self,
+        point: Point<3>,
+        vertices: &[Point<3>],
+    ) -> Option<Point<3>> {
+        let [x, y, z] = self.cell(point);
+
+        let mut neighbors = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    neighbors.push([x + dx, y + dy, z + dz]);
+                }
+            }
         }
+
+        neighbors
+            .into_iter()
+            .filter_map(|cell| self.grid.get(&cell))
+            .flatten()
+            .map(|&index| vertices[index as usize])
+            .map(|vertex| (vertex.distance_to(&point), vertex))
+            .filter(|&(distance, _)| distance <= self.tolerance)
+            .min()
+            .map(|(_, vertex)| vertex)
+    }
+
+    /// Register a vertex, so it can be found later
+    fn insert(&mut self, vertex: Point<3>, index: Index) {
+        self.grid.entry(self.cell(vertex)).or_default().push(index);
+    }
+
+    fn cell(&self, point: Point<3>) -> [i64; 3] {
+        point
+            .coords
+            .components
+            .map(|component| (component / self.tolerance).floor().into_f64())
+            .map(|component| component as i64)
     }
 }
 
+/// A hit of a ray on a mesh
+///
+/// Returned by [`Mesh::intersect_ray`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RayHit {
+    /// The index of the triangle that was hit
+    ///
+    /// Refers to the triangle in the order of [`Mesh::triangles`].
+    pub triangle: usize,
+
+    /// The point where the ray hit the triangle
+    pub point: Point<3>,
+
+    /// The parameter of the hit point on the ray
+    ///
+    /// See [`Ray::point_at`].
+    pub t: Scalar,
+
+    /// The barycentric coordinates of the hit point within the triangle
+    pub barycentric: [Scalar; 3],
+}
+
+/// Points that don't form a triangle
+///
+/// Returned by [`Mesh::try_push_triangle`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct DegenerateTriangle {
+    /// The points that were passed
+    pub points: [Point<3>; 3],
+}
+
 /// An index that refers to a vertex in a mesh
 pub type Index = u32;
 
+/// Statistics about a mesh
+///
+/// Returned by [`Mesh::stats`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshStats {
+    /// The number of triangles in the mesh
+    pub triangle_count: usize,
+
+    /// The number of distinct vertices in the mesh
+    pub vertex_count: usize,
+
+    /// Statistics about the areas of the non-degenerate triangles
+    ///
+    /// `None`, if there are no non-degenerate triangles.
+    pub triangle_area: Option<ValueStats>,
+
+    /// Statistics about the lengths of the edges of non-degenerate triangles
+    ///
+    /// Edges that are shared by two triangles are counted once per triangle.
+    /// `None`, if there are no non-degenerate triangles.
+    pub edge_length: Option<ValueStats>,
+
+    /// Statistics about the smallest angle of each non-degenerate triangle
+    ///
+    /// The angles are given in radians. `None`, if there are no non-degenerate
+    /// triangles.
+    pub min_angle: Option<ValueStats>,
+}
+
+/// The minimum, maximum, and mean of a set of values
+///
+/// See [`MeshStats`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ValueStats {
+    /// The smallest value
+    pub min: f32,
+
+    /// The largest value
+    pub max: f32,
+
+    /// The mean of all values
+    pub mean: f32,
+}
+
+impl ValueStats {
+    fn from_values(values: Vec<Scalar>) -> Option<Self> {
+        let min = values.iter().copied().min()?;
+        let max = values.iter().copied().max()?;
+        let sum = values.iter().fold(Scalar::ZERO, |sum, &value| sum + value);
+        let mean = sum / values.len() as f64;
+
+        Some(Self {
+            min: min.into_f32(),
+            max: max.into_f32(),
+            mean: mean.into_f32(),
+        })
+    }
+}
+
 /// A triangle
 ///
 /// Extension of [`fj_math::Triangle`] that also includes a color.
@@ -117,15 +1269,832 @@ pub struct Triangle {
 
     /// The color of the triangle
     pub color: Color,
+
+    /// The material of the triangle, if any
+    ///
+    /// Refers to a material in [`Mesh::materials`], of the mesh that contains
+    /// the triangle.
+    pub material: Option<usize>,
 }
 
 /// RGBA color
+///
+/// Each channel is stored as a byte, where `0` is none and `255` is full
+/// intensity. For the alpha channel, `255` means fully opaque.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Color(pub [u8; 4]);
 
+impl Color {
+    /// Black
+    pub const BLACK: Self = Self::from_rgb(0, 0, 0);
+
+    /// White
+    pub const WHITE: Self = Self::from_rgb(255, 255, 255);
+
+    /// Gray, halfway between black and white
+    pub const GRAY: Self = Self::from_rgb(128, 128, 128);
+
+    /// Red
+    pub const RED: Self = Self::from_rgb(255, 0, 0);
+
+    /// Green
+    pub const GREEN: Self = Self::from_rgb(0, 255, 0);
+
+    /// Blue
+    pub const BLUE: Self = Self::from_rgb(0, 0, 255);
+
+    /// Yellow
+    pub const YELLOW: Self = Self::from_rgb(255, 255, 0);
+
+    /// Cyan
+    pub const CYAN: Self = Self::from_rgb(0, 255, 255);
+
+    /// Magenta
+    pub const MAGENTA: Self = Self::from_rgb(255, 0, 255);
+
+    /// Fully transparent black
+    pub const TRANSPARENT: Self = Self::from_rgba(0, 0, 0, 0);
+
+    /// Construct an opaque color from red, green, and blue bytes
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::from_rgba(r, g, b, 255)
+    }
+
+    /// Construct a color from red, green, blue, and alpha bytes
+    pub const fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self([r, g, b, a])
+    }
+
+    /// Construct an opaque color from red, green, and blue floats
+    ///
+    /// See [`Color::from_rgba_f64`].
+    pub fn from_rgb_f64([r, g, b]: [f64; 3]) -> Self {
+        Self::from_rgba_f64([r, g, b, 1.])
+    }
+
+    /// Construct a color from red, green, blue, and alpha floats
+    ///
+    /// Each channel ranges from `0.` to `1.`. Values outside of that range are
+    /// clamped, and NaN is treated as `0.`.
+    pub fn from_rgba_f64(rgba: [f64; 4]) -> Self {
+        Self(rgba.map(|channel| {
+            // Casting a float to an integer saturates, and turns NaN into zero.
+            (channel * 255.).round() as u8
+        }))
+    }
+
+    /// Convert the color into red, green, blue, and alpha floats
+    ///
+    /// Each channel ranges from `0.` to `1.`.
+    pub fn to_rgba_f64(self) -> [f64; 4] {
+        self.0.map(|channel| f64::from(channel) / 255.)
+    }
+
+    /// Linearly interpolate between this color and another one
+    ///
+    /// Returns this color, if `t` is `0.`, and `other`, if `t` is `1.`. All
+    /// channels, including alpha, are interpolated. `t` is clamped to the range
+    /// from `0.` to `1.`.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0., 1.);
+
+        let [a, b] = [self, other].map(Self::to_rgba_f64);
+        let rgba = a.zip_ext(b).map(|(a, b)| a + (b - a) * t);
+
+        Self::from_rgba_f64(rgba)
+    }
+}
+
 impl Default for Color {
     fn default() -> Self {
         // The default color is red. This is an arbitrary choice.
-        Self([255, 0, 0, 255])
+        Self::RED
+    }
+}
+
+/// A material, describing the physical appearance of a surface
+///
+/// The properties follow the metallic-roughness model of physically based
+/// rendering, as used by glTF. Materials are identified by their name, which
+/// is what 3D printers use to map them to filaments, for example.
+///
+/// A material doesn't define a color. The color of a triangle is defined
+/// separately, and combined with its material.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Material {
+    /// The name of the material
+    pub name: String,
+
+    /// The roughness of the material
+    ///
+    /// Ranges from `0`, for a perfectly smooth surface, to `1`, for a fully
+    /// rough one.
+    pub roughness: Scalar,
+
+    /// The metalness of the material
+    ///
+    /// Ranges from `0`, for a dielectric material, like plastic, to `1`, for a
+    /// metal.
+    pub metalness: Scalar,
+}
+
+impl Material {
+    /// Construct an instance of `Material`
+    pub fn new(
+        name: impl Into<String>,
+        roughness: impl Into<Scalar>,
+        metalness: impl Into<Scalar>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            roughness: roughness.into(),
+            metalness: metalness.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Plane, Point, Ray, Scalar, Transform, Vector};
+
+    use super::{Color, DegenerateTriangle, Material, Mesh};
+
+    #[test]
+    fn aabb() {
+        let mut mesh = Mesh::new();
+        assert_eq!(mesh.aabb(), None);
+
+        mesh.push_triangle(
+            [[0., 0., 0.], [2., 0., 1.], [0., -3., 0.]],
+            Color::default(),
+        );
+
+        let aabb = mesh.aabb().expect("Mesh is not empty");
+        assert_eq!(aabb.min, Point::from([0., -3., 0.]));
+        assert_eq!(aabb.max, Point::from([2., 0., 1.]));
+    }
+
+    #[test]
+    fn color() {
+        assert_eq!(Color::default(), Color([255, 0, 0, 255]));
+
+        assert_eq!(Color::from_rgb(1, 2, 3), Color([1, 2, 3, 255]));
+        assert_eq!(
+            Color::from_rgb_f64([0., 0.5, 1.]),
+            Color::from_rgb(0, 128, 255)
+        );
+        assert_eq!(
+            Color::from_rgba_f64([-1., 2., f64::NAN, 1.]),
+            Color::from_rgb(0, 255, 0)
+        );
+        assert_eq!(
+            Color::from_rgba_f64(Color([12, 34, 56, 78]).to_rgba_f64()),
+            Color([12, 34, 56, 78])
+        );
+
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.), Color::BLACK);
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 1.), Color::WHITE);
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color::GRAY);
+        assert_eq!(
+            Color::RED.lerp(Color::TRANSPARENT, 0.25),
+            Color([191, 0, 0, 191])
+        );
+        assert_eq!(Color::RED.lerp(Color::BLUE, 2.), Color::BLUE);
+    }
+
+    #[test]
+    fn materials() {
+        let brass = Material::new("brass", 0.3, 1.);
+        let steel = Material::new("steel", 0.5, 1.);
+
+        let a = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+        let b = [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+        let c = [[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]];
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle_with_material(a, Color::default(), Some(&steel));
+        mesh.push_triangle(b, Color::default());
+        mesh.push_triangle_with_material(c, Color::default(), Some(&steel));
+
+        let mut other = Mesh::new();
+        other.push_triangle_with_material(a, Color::default(), Some(&brass));
+        mesh.merge(&other);
+
+        assert_eq!(mesh.materials(), [steel, brass]);
+        assert_eq!(
+            mesh.triangles()
+                .map(|triangle| triangle.material)
+                .collect::<Vec<_>>(),
+            [Some(0), None, Some(0), Some(1)]
+        );
+
+        let transformed = mesh.transform(&Transform::translation([1., 2., 3.]));
+        assert_eq!(transformed.materials(), mesh.materials());
+        assert!(transformed
+            .triangles()
+            .zip(mesh.triangles())
+            .all(|(a, b)| a.material == b.material));
+    }
+
+    #[test]
+    fn vertex_order() {
+        let a = [0., 0., 0.];
+        let b = [1., 0., 0.];
+        let c = [0., 1., 0.];
+        let d = [1., 1., 0.];
+
+        let mut mesh = Mesh::with_weld_tolerance(1e-6);
+        mesh.push_triangle([d, b, c], Color::default());
+        mesh.push_triangle([[1., 1e-9, 0.], a, c], Color::default());
+
+        // Vertices are in the order they were first added, and welded
+        // vertices don't change that.
+        assert_eq!(
+            mesh.vertices().collect::<Vec<_>>(),
+            [d, b, c, a].map(Point::from)
+        );
+        assert_eq!(mesh.indices().collect::<Vec<_>>(), [0, 1, 2, 1, 3, 2]);
+    }
+
+    #[test]
+    fn try_push_triangle() {
+        let mut mesh = Mesh::new();
+
+        let valid = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+        assert!(mesh.try_push_triangle(valid, Color::default()).is_ok());
+
+        for points in [
+            [[0., 0., 0.], [1., 0., 0.], [1., 0., 0.]],
+            [[0., 0., 0.], [1., 0., 0.], [2., 0., 0.]],
+        ] {
+            assert_eq!(
+                mesh.try_push_triangle(points, Color::default()),
+                Err(DegenerateTriangle {
+                    points: points.map(Point::from)
+                })
+            );
+        }
+
+        assert_eq!(mesh.triangle_count(), 1);
+        assert_eq!(mesh.vertex_count(), 3);
+    }
+
+    #[test]
+    fn weld_tolerance() {
+        let mut mesh = Mesh::with_weld_tolerance(1e-6);
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[1. + 1e-9, 0., 0.], [1., 1., 0.], [0., 1. - 1e-9, 0.]],
+            Color::default(),
+        );
+        assert_eq!(mesh.vertices().count(), 4);
+        assert!(mesh.contains_triangle([
+            [1., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.]
+        ]));
+
+        // This triangle collapses into a line, once it's welded.
+        mesh.push_triangle(
+            [[0., 0., 0.], [1e-7, 0., 0.], [0., 0., 1.]],
+            Color::default(),
+        );
+        assert_eq!(mesh.triangles().count(), 2);
+        assert_eq!(mesh.vertices().count(), 4);
+    }
+
+    #[test]
+    fn merge() {
+        let [a, b, c, d, e] = [
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0., 1., 0.],
+            [1., 1., 0.],
+            [0., 0., 1.],
+        ];
+        let color = Color([0, 0, 255, 255]);
+
+        let mesh_with = |triangle: [[f64; 3]; 3], color| {
+            let mut mesh = Mesh::new();
+            mesh.push_triangle(triangle, color);
+            mesh
+        };
+        let x = mesh_with([a, b, c], Color::default());
+        let y = mesh_with([b, d, c], color);
+        let z = mesh_with([a, c, e], Color::default());
+
+        let mut xy = x.clone();
+        xy.merge(&y);
+        assert_eq!(xy.triangle_count(), 2);
+        assert_eq!(xy.vertex_count(), 4);
+        assert!(xy.contains_triangle([b, d, c]));
+        assert_eq!(xy.triangles().nth(1).map(|t| t.color), Some(color));
+
+        let mut xy_z = xy;
+        xy_z.merge(&z);
+
+        let mut yz = y.clone();
+        yz.merge(&z);
+        let mut x_yz = x;
+        x_yz.merge(&yz);
+
+        let vertices_xy_z = xy_z.vertices().collect::<BTreeSet<_>>();
+        let vertices_x_yz = x_yz.vertices().collect::<BTreeSet<_>>();
+        assert_eq!(vertices_xy_z, vertices_x_yz);
+        assert_eq!(x_yz.vertex_count(), 5);
+    }
+
+    #[test]
+    fn objects() {
+        let a = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+        let b = [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+        let c = [[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+
+        let mut mesh = Mesh::new();
+        assert_eq!(mesh.objects().count(), 0);
+
+        // Starting objects without adding triangles makes no difference.
+        mesh.start_object();
+        mesh.push_triangle(a, Color::default());
+        mesh.push_triangle(b, Color::default());
+        mesh.start_object();
+        mesh.start_object();
+        mesh.push_triangle(c, Color::default());
+        mesh.start_object();
+        assert_eq!(mesh.objects().collect::<Vec<_>>(), [0..2, 2..3]);
+
+        // The first object of a merged mesh continues the current object.
+        let mut merged = Mesh::new();
+        merged.push_triangle(c, Color::default());
+        merged.merge(&mesh);
+        assert_eq!(merged.objects().collect::<Vec<_>>(), [0..3, 3..4]);
+
+        let mut merged = Mesh::new();
+        merged.push_triangle(c, Color::default());
+        merged.start_object();
+        merged.merge(&mesh);
+        assert_eq!(merged.objects().collect::<Vec<_>>(), [0..1, 1..3, 3..4]);
+
+        let transformed = mesh.transform(&Transform::translation([1., 0., 0.]));
+        assert_eq!(transformed.objects().collect::<Vec<_>>(), [0..2, 2..3]);
+    }
+
+    #[test]
+    fn merge_with_weld_tolerance() {
+        let mut other = Mesh::new();
+        other.push_triangle(
+            [[1. + 1e-9, 0., 0.], [1., 1., 0.], [0., 1. - 1e-9, 0.]],
+            Color::default(),
+        );
+
+        let mut mesh = Mesh::with_weld_tolerance(1e-6);
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.merge(&other);
+
+        assert_eq!(mesh.vertex_count(), 4);
+        assert!(mesh.contains_triangle([
+            [1., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.]
+        ]));
+    }
+
+    #[test]
+    fn compact() {
+        let mut mesh = Mesh::new();
+        mesh.push_vertex(Point::from([5., 5., 5.]));
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_vertex(Point::from([6., 6., 6.]));
+        assert_eq!(mesh.vertex_count(), 5);
+
+        assert_eq!(mesh.compact(), 2);
+        assert_eq!(mesh.compact(), 0);
+
+        assert_eq!(mesh.vertex_count(), 3);
+        assert_eq!(mesh.indices().collect::<Vec<_>>(), [0, 1, 2]);
+        assert!(mesh.contains_triangle([
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0., 1., 0.]
+        ]));
+
+        // The mesh is still consistent, when adding more triangles.
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.indices().collect::<Vec<_>>(), [0, 1, 2, 1, 3, 2]);
+    }
+
+    #[test]
+    fn transform() {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[1., 0., 0.], [0., 1., 0.], [0., 1., 1.]],
+            Color::default(),
+        );
+        let mesh = mesh.with_vertex_normals(0.);
+
+        let translated = mesh.transform(&Transform::translation([0., 0., 1.]));
+        assert!(translated.contains_triangle([
+            [1., 0., 1.],
+            [0., 1., 1.],
+            [0., 1., 2.]
+        ]));
+
+        // Under non-uniform scaling, normals can't be transformed like the
+        // vertices. They need to remain perpendicular to the triangle.
+        for transform in [
+            Transform::scale([2., 1., 1.]),
+            Transform::mirror(&Plane::from_parametric(
+                Point::origin(),
+                Vector::unit_y(),
+                Vector::unit_z(),
+            )),
+        ] {
+            let transformed = mesh.transform(&transform);
+
+            let triangle = transformed.triangles().next().unwrap().inner;
+            let [a, b, c] = triangle.points();
+            let expected = (b - a).cross(&(c - a)).normalize();
+
+            let normals = transformed.vertex_normals().unwrap().next().unwrap();
+            for normal in normals {
+                assert!((normal - expected).magnitude() < Scalar::from(1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn intersect_ray() {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[0., 0., 1.], [0., 1., 1.], [1., 0., 1.]],
+            Color::default(),
+        );
+
+        // The ray hits the back of the upper triangle, then the front of the
+        // lower one.
+        let ray =
+            Ray::from_origin_and_direction([0.25, 0.25, 2.], [0., 0., -1.]);
+
+        let hit = mesh.intersect_ray(&ray, true).unwrap();
+        assert_eq!(hit.triangle, 1);
+        assert_eq!(hit.point, Point::from([0.25, 0.25, 1.]));
+        assert_eq!(hit.t, Scalar::ONE);
+
+        let hit = mesh.intersect_ray(&ray, false).unwrap();
+        assert_eq!(hit.triangle, 0);
+        assert_eq!(hit.t, Scalar::TWO);
+        assert_eq!(hit.barycentric, [0.5, 0.25, 0.25].map(Scalar::from));
+
+        let parallel =
+            Ray::from_origin_and_direction([0., 0., 0.5], [1., 0., 0.]);
+        assert_eq!(mesh.intersect_ray(&parallel, true), None);
+    }
+
+    #[test]
+    fn surface_area() {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [2., 0., 0.], [0., 2., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[2., 0., 0.], [2., 2., 0.], [0., 2., 0.]],
+            Color::default(),
+        );
+
+        assert_eq!(mesh.surface_area(), 4.);
+    }
+
+    #[test]
+    fn triangle_normals() {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [2., 0., 0.], [0., 2., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[0., 0., 1.], [0., 2., 1.], [2., 0., 1.]],
+            Color::default(),
+        );
+
+        let normals = mesh.triangle_normals().collect::<Vec<_>>();
+        assert_eq!(
+            normals,
+            [Vector::from([0., 0., 1.]), Vector::from([0., 0., -1.])]
+        );
+    }
+
+    #[test]
+    fn stats() {
+        let mut mesh = Mesh::new();
+        assert_eq!(mesh.stats().triangle_area, None);
+
+        mesh.push_triangle(
+            [[0., 0., 0.], [4., 0., 0.], [0., 3., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[4., 0., 0.], [4., 3., 0.], [0., 3., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[0., 0., 1.], [2., 0., 1.], [0., 2., 1.]],
+            Color::default(),
+        );
+
+        let stats = mesh.stats();
+        assert_eq!(mesh.triangle_count(), 3);
+        assert_eq!(mesh.vertex_count(), 7);
+        assert_eq!(stats.triangle_count, 3);
+        assert_eq!(stats.vertex_count, 7);
+
+        let area = stats.triangle_area.expect("Mesh has triangles");
+        assert_eq!([area.min, area.max, area.mean], [2., 6., 14. / 3.]);
+
+        let edge_length = stats.edge_length.expect("Mesh has triangles");
+        assert_eq!(edge_length.min, 2.);
+        assert_eq!(edge_length.max, 5.);
+
+        let min_angle = stats.min_angle.expect("Mesh has triangles");
+        assert_eq!(min_angle.max, std::f32::consts::FRAC_PI_4);
+    }
+
+    #[test]
+    fn to_stl() -> std::io::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+
+        let mut stl = Vec::new();
+        mesh.to_stl(&mut stl)?;
+
+        assert_eq!(stl.len(), 80 + 4 + 50);
+        assert_eq!(stl[..80], [0; 80]);
+        assert_eq!(stl[80..84], 1u32.to_le_bytes());
+        assert_eq!(stl[84..88], 0f32.to_le_bytes());
+        assert_eq!(stl[92..96], 1f32.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_stl() -> std::io::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+
+        let mut stl = Vec::new();
+        mesh.to_stl(&mut stl)?;
+
+        // Binary STL, with the header starting like ASCII STL does.
+        stl[..5].copy_from_slice(b"solid");
+        let binary = Mesh::from_stl(stl.as_slice())?;
+
+        let ascii = Mesh::from_stl(
+            "solid square
+              facet normal 0 0 1
+                outer loop
+                  vertex 0 0 0
+                  vertex 1 0 0
+                  vertex 0 1 0
+                endloop
+              endfacet
+              facet normal 0 0 1
+                outer loop
+                  vertex 1 0 0
+                  vertex 1 1 0
+                  vertex 0 1 0
+                endloop
+              endfacet
+            endsolid square"
+                .as_bytes(),
+        )?;
+
+        for read in [binary, ascii] {
+            // The vertices that the triangles share are merged.
+            assert_eq!(read.vertices().count(), 4);
+            assert_eq!(
+                read.triangles().collect::<Vec<_>>(),
+                mesh.triangles().collect::<Vec<_>>()
+            );
+        }
+
+        assert!(Mesh::from_stl("solid\nfacet normal 0 0".as_bytes()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_stl_with_normals() -> std::io::Result<()> {
+        // The first normal agrees with the winding, the second one is missing,
+        // the third one disagrees.
+        let stl = [[0., 0., 2.], [0., 0., 0.], [0., 0., -1.]]
+            .map(|normal| {
+                format!(
+                    "facet normal {} {} {}
+                      outer loop
+                        vertex 0 0 0
+                        vertex 1 0 0
+                        vertex 0 1 0
+                      endloop
+                    endfacet\n",
+                    normal[0], normal[1], normal[2],
+                )
+            })
+            .concat();
+        let stl = format!("solid\n{stl}endsolid\n");
+
+        let mesh = Mesh::from_stl_with_normals(stl.as_bytes())?;
+        let normals = mesh.vertex_normals().unwrap().collect::<Vec<_>>();
+        assert_eq!(normals, [[Vector::from([0., 0., 1.]); 3]; 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_obj() -> std::io::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+
+        let mut obj = Vec::new();
+        mesh.to_obj(&mut obj)?;
+
+        assert_eq!(
+            String::from_utf8(obj).unwrap(),
+            "v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            v 1 1 0\n\
+            f 1 2 3\n\
+            f 2 4 3\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_ply() -> std::io::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color([255, 0, 0, 255]),
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color([0, 0, 255, 255]),
+        );
+
+        let mut ply = Vec::new();
+        mesh.to_ply(&mut ply)?;
+
+        let header = "ply\n\
+            format binary_little_endian 1.0\n\
+            element vertex 4\n\
+            property float x\n\
+            property float y\n\
+            property float z\n\
+            property uchar red\n\
+            property uchar green\n\
+            property uchar blue\n\
+            property uchar alpha\n\
+            element face 2\n\
+            property list uchar uint vertex_indices\n\
+            end_header\n";
+        assert_eq!(ply[..header.len()], *header.as_bytes());
+
+        let (vertices, faces) = ply[header.len()..].split_at(4 * (12 + 4));
+        assert_eq!(faces.len(), 2 * (1 + 12));
+
+        // The second vertex is shared by both triangles, and its color is
+        // averaged from theirs.
+        assert_eq!(
+            vertices[16..28],
+            [1f32, 0., 0.].map(f32::to_le_bytes).concat()
+        );
+        assert_eq!(vertices[28..32], [128, 0, 128, 255]);
+
+        assert_eq!(faces[0], 3);
+        assert_eq!(faces[1..13], [0u32, 1, 2].map(u32::to_le_bytes).concat());
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_ply_with_vertex_normals() -> std::io::Result<()> {
+        // Two triangles that meet at a right angle, so their shared vertices
+        // have different normals in each of them.
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 0., 1.]],
+            Color::default(),
+        );
+        let mesh = mesh.with_vertex_normals(Scalar::PI / 4.);
+
+        let mut ply = Vec::new();
+        mesh.to_ply(&mut ply)?;
+
+        let end_header = b"end_header\n";
+        let header_len = ply
+            .windows(end_header.len())
+            .position(|window| window == end_header)
+            .unwrap()
+            + end_header.len();
+        let header = String::from_utf8(ply[..header_len].to_vec()).unwrap();
+
+        assert!(header.contains("element vertex 6\n"));
+        assert!(header.contains("property float nz\n"));
+        assert_eq!(ply.len() - header_len, 6 * (24 + 4) + 2 * (1 + 12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn vertex_normals() {
+        // Two triangles that meet at a right angle.
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 0., 1.]],
+            Color::default(),
+        );
+        assert!(mesh.vertex_normals().is_none());
+
+        let faceted = mesh.clone().with_vertex_normals(Scalar::PI / 4.);
+        let normals = faceted.vertex_normals().unwrap().collect::<Vec<_>>();
+        assert_eq!(normals[0], [Vector::from([0., 0., 1.]); 3]);
+        assert_eq!(normals[1], [Vector::from([0., -1., 0.]); 3]);
+
+        let smooth = mesh.with_vertex_normals(Scalar::PI);
+        let normals = smooth.vertex_normals().unwrap().collect::<Vec<_>>();
+        let shared = Vector::from([0., -1., 1.]).normalize();
+        assert!((normals[0][0] - shared).magnitude() < Scalar::from(1e-12));
+        assert!((normals[0][1] - shared).magnitude() < Scalar::from(1e-12));
+        assert_eq!(normals[0][2], Vector::from([0., 0., 1.]));
+    }
+
+    #[test]
+    fn to_obj_with_vertex_normals() -> std::io::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        let mesh = mesh.with_vertex_normals(Scalar::PI / 4.);
+
+        let mut obj = Vec::new();
+        mesh.to_obj(&mut obj)?;
+
+        assert_eq!(
+            String::from_utf8(obj).unwrap(),
+            "v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            vn 0 0 1\n\
+            f 1//1 2//1 3//1\n"
+        );
+
+        Ok(())
     }
 }
@@ -1,6 +1,6 @@
 //! An approximated model
 
-use fj_math::{Aabb, Point};
+use fj_math::{Aabb, Point, Segment};
 
 use crate::mesh::Mesh;
 
@@ -12,4 +12,13 @@ pub struct Model {
 
     /// The axis-aligned bounding box of the model
     pub aabb: Aabb<3>,
+
+    /// The line segments that approximate the edges of the model
+    pub edges: Vec<Segment<3>>,
+
+    /// The line segments that approximate the construction edges of the model
+    ///
+    /// Construction edges only serve as a reference, like a centerline. They
+    /// are presented differently from [`Model::edges`].
+    pub construction_edges: Vec<Segment<3>>,
 }
@@ -0,0 +1,186 @@
+use crate::{Point, Scalar, Vector};
+
+/// Compare values for equality, allowing for a small difference
+///
+/// Results of floating-point computations are rarely exact. After a transform,
+/// for example, a point that should end up at `[1., 2., 3.]` might be off by a
+/// tiny amount. Comparing it using `==` would fail, while this comparison
+/// succeeds, as long as the difference is within the given [`Epsilon`].
+///
+/// See [`assert_approx_eq!`] for a convenient way to use this in tests.
+///
+/// [`assert_approx_eq!`]: crate::assert_approx_eq
+pub trait ApproxEq {
+    /// Determine whether `self` and `other` are equal within `epsilon`
+    fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool;
+}
+
+impl ApproxEq for Scalar {
+    fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool {
+        epsilon
+            .into()
+            .allows((*self - *other).abs(), self.abs().max(other.abs()))
+    }
+}
+
+impl<const D: usize> ApproxEq for Point<D> {
+    fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool {
+        Point::approx_eq(self, other, epsilon)
+    }
+}
+
+impl<const D: usize> ApproxEq for Vector<D> {
+    fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool {
+        Vector::approx_eq(self, other, epsilon)
+    }
+}
+
+impl<T, const N: usize> ApproxEq for [T; N]
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool {
+        let epsilon = epsilon.into();
+        self.iter().zip(other).all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+/// The difference that [`ApproxEq`] allows between values
+///
+/// Converting a number into `Epsilon` results in [`Epsilon::Absolute`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Epsilon {
+    /// Values are equal, if they differ by no more than this amount
+    Absolute(Scalar),
+
+    /// Values are equal, if they differ by no more than this fraction of the
+    /// larger of their magnitudes
+    ///
+    /// Use this to compare values whose magnitude isn't known in advance, as
+    /// any absolute epsilon is too strict for large values, or too lenient for
+    /// small ones. For points, the magnitude is the distance from the origin.
+    ///
+    /// Values very close to zero are only equal, if they are very close to
+    /// each other. Consider an absolute epsilon for those.
+    Relative(Scalar),
+}
+
+impl Epsilon {
+    /// Construct an absolute epsilon
+    ///
+    /// See [`Epsilon::Absolute`].
+    pub fn absolute(epsilon: impl Into<Scalar>) -> Self {
+        Self::Absolute(epsilon.into())
+    }
+
+    /// Construct a relative epsilon
+    ///
+    /// See [`Epsilon::Relative`].
+    pub fn relative(epsilon: impl Into<Scalar>) -> Self {
+        Self::Relative(epsilon.into())
+    }
+
+    /// Determine whether a difference between values of a magnitude is allowed
+    pub(crate) fn allows(&self, difference: Scalar, magnitude: Scalar) -> bool {
+        match *self {
+            Self::Absolute(epsilon) => difference <= epsilon,
+            Self::Relative(epsilon) => difference <= epsilon * magnitude,
+        }
+    }
+}
+
+impl From<f64> for Epsilon {
+    fn from(epsilon: f64) -> Self {
+        Self::absolute(epsilon)
+    }
+}
+
+impl From<Scalar> for Epsilon {
+    fn from(epsilon: Scalar) -> Self {
+        Self::absolute(epsilon)
+    }
+}
+
+/// Assert that two values are equal within an [`Epsilon`]
+///
+/// Works with any type that implements [`ApproxEq`] and [`Debug`]. Panics with
+/// a message showing both values, if they are not equal.
+///
+/// ```
+/// use fj_math::{assert_approx_eq, Epsilon, Point};
+///
+/// let a = Point::from([0.1 + 0.2, 1e9]);
+/// let b = Point::from([0.3, 1e9 + 1.]);
+///
+/// assert_approx_eq!(a, b, Epsilon::relative(1e-6));
+/// ```
+///
+/// [`ApproxEq`]: crate::ApproxEq
+/// [`Debug`]: std::fmt::Debug
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        let epsilon = $crate::Epsilon::from($epsilon);
+
+        assert!(
+            $crate::ApproxEq::approx_eq(left, right, epsilon),
+            "assertion failed: `left ≈ right`\n\
+            \x20   left: `{left:?}`\n\
+            \x20  right: `{right:?}`\n\
+            \x20epsilon: `{epsilon:?}`",
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ApproxEq, Epsilon, Point, Scalar, Transform, Vector};
+
+    #[test]
+    fn absolute() {
+        let a = Point::from([1., 2., 3.]);
+        let b = Point::from([1., 2., 3. + 1e-10]);
+
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-11));
+        assert!(Vector::from([1e-10]).approx_eq(&Vector::from([0.]), 1e-9));
+
+        // The epsilon applies to the distance, not to each component.
+        let c = Point::from([1. + 1e-9, 2. + 1e-9, 3.]);
+        assert!(!a.approx_eq(&c, 1e-9));
+    }
+
+    #[test]
+    fn relative() {
+        let a = Vector::from([1e9, 0.]);
+        let b = Vector::from([1e9 + 1., 0.]);
+
+        assert!(a.approx_eq(&b, Epsilon::relative(1e-6)));
+        assert!(!a.approx_eq(&b, Epsilon::relative(1e-12)));
+        assert!(!a.approx_eq(&b, 1e-6));
+
+        let [c, d] = [1e-9, 2e-9].map(Scalar::from);
+        assert!(c.approx_eq(&d, 1e-6));
+        assert!(!c.approx_eq(&d, Epsilon::relative(1e-6)));
+        assert!(Scalar::ZERO.approx_eq(&Scalar::ZERO, Epsilon::relative(0.)));
+    }
+
+    #[test]
+    fn after_transform() {
+        let transform = Transform::rotation(Vector::from([0., 0., 1.]) * 0.1);
+
+        let point = Point::from([1., 2., 3.]);
+        let transformed = transform
+            .transform_point(&transform.inverse().transform_point(&point));
+
+        assert_approx_eq!(transformed, point, 1e-12);
+        assert_approx_eq!([transformed, point], [point, transformed], 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "left ≈ right")]
+    fn assert_approx_eq_fails() {
+        assert_approx_eq!(Scalar::ONE, Scalar::TWO, 0.5);
+    }
+}
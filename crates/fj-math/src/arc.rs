@@ -81,13 +81,79 @@ impl Arc {
             end_angle,
         }
     }
+
+    /// Constructs an [`Arc`] that starts at `p0`, passes `pm`, and ends at `p1`
+    ///
+    /// Returns an error, if the points are collinear, as no circle passes
+    /// through them then.
+    pub fn from_three_points(
+        p0: impl Into<Point<2>>,
+        pm: impl Into<Point<2>>,
+        p1: impl Into<Point<2>>,
+    ) -> Result<Self, CollinearPoints> {
+        let points = [p0.into(), pm.into(), p1.into()];
+        let [p0, pm, p1] = points;
+
+        // The center is where the perpendicular bisectors of the chords meet.
+        // This is an adaptation of this:
+        // https://en.wikipedia.org/wiki/Circumscribed_circle#Cartesian_coordinates_2
+        let [b, c] = [pm - p0, p1 - p0];
+        let d = b.cross2d(&c) * 2.;
+        if d == Scalar::ZERO {
+            return Err(CollinearPoints { points });
+        }
+
+        let [b_sq, c_sq] = [b.dot(&b), c.dot(&c)];
+        let center = p0
+            + Vector::from([
+                (c.v * b_sq - b.v * c_sq) / d,
+                (b.u * c_sq - c.u * b_sq) / d,
+            ]);
+        let radius = (p0 - center).magnitude();
+
+        let [start_angle, mid_angle, end_angle] = points.map(|point| {
+            let from_center = point - center;
+            from_center.v.atan2(from_center.u)
+        });
+
+        // Going counter-clockwise from the start, if the middle point comes
+        // before the end point, that's the direction of the arc. Otherwise,
+        // it's the other way around.
+        let ccw_offset = |angle: Scalar| {
+            let offset = angle - start_angle;
+            if offset < Scalar::ZERO {
+                offset + Scalar::TAU
+            } else {
+                offset
+            }
+        };
+        let end_angle = if ccw_offset(mid_angle) < ccw_offset(end_angle) {
+            start_angle + ccw_offset(end_angle)
+        } else {
+            start_angle - (Scalar::TAU - ccw_offset(end_angle))
+        };
+
+        Ok(Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        })
+    }
+}
+
+/// Returned by [`Arc::from_three_points`], if the points are collinear
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CollinearPoints {
+    /// The points that were passed
+    pub points: [Point<2>; 3],
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{Point, Scalar, Vector};
 
-    use super::Arc;
+    use super::{Arc, CollinearPoints};
 
     use approx::{assert_abs_diff_eq, AbsDiffEq};
 
@@ -125,6 +191,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arc_from_three_points() {
+        let epsilon = Scalar::default_epsilon() * 10.;
+
+        // Counter-clockwise, through the top of the circle.
+        let arc = Arc::from_three_points([3., 2.], [1., 4.], [-1., 2.])
+            .expect("Points are not collinear");
+        assert_abs_diff_eq!(
+            arc.center,
+            Point::from([1., 2.]),
+            epsilon = epsilon
+        );
+        assert_abs_diff_eq!(arc.radius, Scalar::from(2.), epsilon = epsilon);
+        assert_abs_diff_eq!(arc.start_angle, Scalar::ZERO, epsilon = epsilon);
+        assert_abs_diff_eq!(arc.end_angle, Scalar::PI, epsilon = epsilon);
+
+        // Clockwise, the long way around.
+        let arc = Arc::from_three_points([3., 2.], [1., 0.], [1., 4.])
+            .expect("Points are not collinear");
+        assert_abs_diff_eq!(arc.start_angle, Scalar::ZERO, epsilon = epsilon);
+        assert_abs_diff_eq!(
+            arc.end_angle,
+            -Scalar::PI * 1.5,
+            epsilon = epsilon
+        );
+
+        let points = [[0., 0.], [1., 1.], [2., 2.]].map(Point::from);
+        assert_eq!(
+            Arc::from_three_points(points[0], points[1], points[2]).err(),
+            Some(CollinearPoints { points })
+        );
+    }
+
     fn check_arc_calculation(
         center: impl Into<Point<2>>,
         radius: f64,
@@ -0,0 +1,240 @@
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional cubic Bézier curve
+///
+/// The dimensionality of the curve is defined by the const generic `D`
+/// parameter.
+///
+/// The curve starts at the first control point, at curve coordinate `0`, and
+/// ends at the last one, at curve coordinate `1`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Bezier<const D: usize> {
+    control_points: [Point<D>; 4],
+}
+
+impl<const D: usize> Bezier<D> {
+    /// Construct a cubic Bézier curve from its four control points
+    pub fn from_control_points(
+        control_points: [impl Into<Point<D>>; 4],
+    ) -> Self {
+        let control_points = control_points.map(Into::into);
+        Self { control_points }
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> [Point<D>; 4] {
+        self.control_points
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed curve covers the same points, but coordinate `0` of the
+    /// reversed curve is coordinate `1` of the original one, and vice versa.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.control_points.reverse();
+        self
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_bezier_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [p0, p1, p2, p3] = self.control_points.map(|point| point.coords);
+        let coords = p0 * (s * s * s)
+            + p1 * (s * s * t * 3.)
+            + p2 * (s * t * t * 3.)
+            + p3 * (t * t * t);
+
+        Point { coords }
+    }
+
+    /// Compute the derivative of the curve at the given curve coordinate
+    pub fn tangent_from_bezier_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [p0, p1, p2, p3] = self.control_points;
+        (p1 - p0) * (s * s * 3.)
+            + (p2 - p1) * (s * t * 6.)
+            + (p3 - p2) * (t * t * 3.)
+    }
+
+    /// Split the curve at the given curve coordinate
+    ///
+    /// Returns two curves that together cover the same points as this one.
+    /// Each of them has its own curve coordinates, from `0` to `1`.
+    pub fn split(&self, point: impl Into<Point<1>>) -> [Self; 2] {
+        let t = point.into().t;
+        let lerp = |a: Point<D>, b: Point<D>| a + (b - a) * t;
+
+        let [p0, p1, p2, p3] = self.control_points;
+
+        let p01 = lerp(p0, p1);
+        let p12 = lerp(p1, p2);
+        let p23 = lerp(p2, p3);
+
+        let p012 = lerp(p01, p12);
+        let p123 = lerp(p12, p23);
+
+        let p0123 = lerp(p012, p123);
+
+        [
+            Self::from_control_points([p0, p01, p012, p0123]),
+            Self::from_control_points([p0123, p123, p23, p3]),
+        ]
+    }
+
+    /// Determine whether the curve is flat, within the given tolerance
+    ///
+    /// A curve is flat, if it deviates from the straight segment between its
+    /// end points by no more than `tolerance`. Since a Bézier curve is always
+    /// within the convex hull of its control points, this is the case if the
+    /// inner control points are within `tolerance` of that segment.
+    ///
+    /// This means a curve whose control points are all on the segment between
+    /// its end points is always considered flat.
+    pub fn is_flat(&self, tolerance: impl Into<Scalar>) -> bool {
+        let tolerance = tolerance.into();
+        let [start, a, b, end] = self.control_points;
+
+        [a, b]
+            .into_iter()
+            .all(|point| distance_to_segment(point, [start, end]) <= tolerance)
+    }
+}
+
+pub(crate) fn distance_to_segment<const D: usize>(
+    point: Point<D>,
+    [a, b]: [Point<D>; 2],
+) -> Scalar {
+    let segment = b - a;
+    let length_squared = segment.dot(&segment);
+
+    if length_squared == Scalar::ZERO {
+        return point.distance_to(&a);
+    }
+
+    let t = ((point - a).dot(&segment) / length_squared)
+        .max(Scalar::ZERO)
+        .min(Scalar::ONE);
+
+    point.distance_to(&(a + segment * t))
+}
+
+impl<const D: usize> approx::AbsDiffEq for Bezier<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.control_points
+            .iter()
+            .zip(&other.control_points)
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::Bezier;
+
+    #[test]
+    fn point_from_bezier_coords() {
+        let bezier = Bezier::from_control_points([
+            [0., 0.],
+            [0., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+
+        assert_eq!(
+            bezier.point_from_bezier_coords([0.]),
+            Point::from([0., 0.])
+        );
+        assert_eq!(
+            bezier.point_from_bezier_coords([0.5]),
+            Point::from([0.5, 0.75])
+        );
+        assert_eq!(
+            bezier.point_from_bezier_coords([1.]),
+            Point::from([1., 0.])
+        );
+
+        assert_eq!(
+            bezier.tangent_from_bezier_coords([0.]),
+            Vector::from([0., 3.])
+        );
+        assert_eq!(
+            bezier.tangent_from_bezier_coords([0.5]),
+            Vector::from([1.5, 0.])
+        );
+    }
+
+    #[test]
+    fn split() {
+        let bezier = Bezier::from_control_points([
+            [0., 0., 0.],
+            [0., 1., 0.],
+            [1., 1., 1.],
+            [1., 0., 2.],
+        ]);
+
+        let [a, b] = bezier.split([0.25]);
+
+        for t in [0., 0.3, 0.7, 1.] {
+            assert_abs_diff_eq!(
+                a.point_from_bezier_coords([t]),
+                bezier.point_from_bezier_coords([t * 0.25]),
+                epsilon = Scalar::from(1e-12),
+            );
+            assert_abs_diff_eq!(
+                b.point_from_bezier_coords([t]),
+                bezier.point_from_bezier_coords([0.25 + t * 0.75]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+
+    #[test]
+    fn is_flat() {
+        let straight = Bezier::from_control_points([
+            [0., 0.],
+            [0.1, 0.],
+            [1.5, 0.],
+            [2., 0.],
+        ]);
+        assert!(straight.is_flat(1e-12));
+
+        // Collinear, but overshooting the end point.
+        let overshooting = Bezier::from_control_points([
+            [0., 0.],
+            [3., 0.],
+            [3., 0.],
+            [2., 0.],
+        ]);
+        assert!(!overshooting.is_flat(0.1));
+
+        let curved = Bezier::from_control_points([
+            [0., 0.],
+            [0., 1.],
+            [1., 1.],
+            [1., 0.],
+        ]);
+        assert!(!curved.is_flat(0.5));
+        assert!(curved.is_flat(1.));
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{bezier::distance_to_segment, Point, Scalar, Vector};
+
+/// An n-dimensional, non-rational B-spline curve
+///
+/// The dimensionality of the curve is defined by the const generic `D`
+/// parameter.
+///
+/// The knot vector is clamped and uniform. It is not stored, but derived from
+/// the number of control points and the degree. The curve starts at the first
+/// control point, at curve coordinate `0`, and ends at the last one, at curve
+/// coordinate `1`. The internal knots are spaced evenly in between.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BSpline<const D: usize> {
+    control_points: Vec<Point<D>>,
+    degree: usize,
+}
+
+impl<const D: usize> BSpline<D> {
+    /// Construct a B-spline from its control points and degree
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - `degree` must be at least `1`.
+    /// - There must be more control points than `degree`.
+    pub fn from_control_points(
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+        degree: usize,
+    ) -> Self {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        assert!(degree >= 1, "B-spline degree must be at least 1");
+        assert!(
+            control_points.len() > degree,
+            "B-spline of degree {degree} needs at least {} control points",
+            degree + 1
+        );
+
+        Self {
+            control_points,
+            degree,
+        }
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed curve covers the same points, but coordinate `0` of the
+    /// reversed curve is coordinate `1` of the original one, and vice versa.
+    /// This works, because the knot vector is symmetric.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.control_points.reverse();
+        self
+    }
+
+    /// Access the knots that separate the polynomial pieces of the curve
+    ///
+    /// Returns the distinct knot values, from `0` to `1`. The curve is smooth
+    /// between two consecutive knots, but might have a kink at each of them.
+    pub fn knots(&self) -> impl Iterator<Item = Scalar> {
+        let num_spans = self.control_points.len() - self.degree;
+        (0..=num_spans).map(move |i| {
+            Scalar::from(i as f64) / Scalar::from(num_spans as f64)
+        })
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    ///
+    /// Outside of the range from `0` to `1`, the polynomial piece at the
+    /// respective end of the curve is extrapolated.
+    pub fn point_from_bspline_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let coefficients =
+            self.control_points().iter().map(|point| point.coords);
+        let knots = (0..self.num_knots()).map(|i| self.knot(i));
+
+        let coords = de_boor(coefficients, knots, self.degree, point.into().t);
+        Point { coords }
+    }
+
+    /// Compute the derivative of the curve at the given curve coordinate
+    pub fn tangent_from_bspline_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        // The derivative is a B-spline of one degree lower, with one fewer
+        // coefficient, defined on the same knots, minus the first and last one.
+        let p = self.degree;
+        let coefficients =
+            self.control_points()
+                .windows(2)
+                .enumerate()
+                .map(|(i, points)| {
+                    let scale = Scalar::from(p as f64)
+                        / (self.knot(i + p + 1) - self.knot(i + 1));
+                    (points[1] - points[0]) * scale
+                });
+        let knots = (1..self.num_knots() - 1).map(|i| self.knot(i));
+
+        de_boor(coefficients, knots, p - 1, point.into().t)
+    }
+
+    /// Determine whether the curve is flat within the given range
+    ///
+    /// The curve is considered flat, if it deviates from the straight segment
+    /// between the points at the boundaries of the range by no more than
+    /// `tolerance`.
+    ///
+    /// Unlike [`Bezier::is_flat`], this doesn't use the control points, but
+    /// checks the deviation at a number of samples within the range. This is
+    /// not guaranteed to find the largest deviation, but the range is meant to
+    /// be within a single knot span, where the curve is a low-degree
+    /// polynomial. Within a knot span, a B-spline of degree `1` is always
+    /// considered flat.
+    ///
+    /// [`Bezier::is_flat`]: crate::Bezier::is_flat
+    pub fn is_flat_between(
+        &self,
+        range: [impl Into<Point<1>>; 2],
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        const NUM_SAMPLES: u32 = 8;
+
+        let tolerance = tolerance.into();
+        let [start, end] = range.map(|point| point.into().t);
+        let chord = [start, end].map(|t| self.point_from_bspline_coords([t]));
+
+        (1..NUM_SAMPLES).all(|i| {
+            let t =
+                start + (end - start) * (f64::from(i) / f64::from(NUM_SAMPLES));
+            let point = self.point_from_bspline_coords([t]);
+
+            distance_to_segment(point, chord) <= tolerance
+        })
+    }
+
+    fn num_knots(&self) -> usize {
+        self.control_points.len() + self.degree + 1
+    }
+
+    fn knot(&self, i: usize) -> Scalar {
+        let n = self.control_points.len();
+        let p = self.degree;
+
+        if i <= p {
+            Scalar::ZERO
+        } else if i >= n {
+            Scalar::ONE
+        } else {
+            Scalar::from((i - p) as f64) / Scalar::from((n - p) as f64)
+        }
+    }
+}
+
+/// Evaluate a B-spline, using De Boor's algorithm
+///
+/// There must be `coefficients.len() + degree + 1` knots. Outside of the range
+/// covered by the knots, the first or last polynomial piece is extrapolated.
+fn de_boor<const D: usize>(
+    coefficients: impl IntoIterator<Item = Vector<D>>,
+    knots: impl IntoIterator<Item = Scalar>,
+    degree: usize,
+    t: Scalar,
+) -> Vector<D> {
+    let coefficients = coefficients.into_iter().collect::<Vec<_>>();
+    let knots = knots.into_iter().collect::<Vec<_>>();
+    let p = degree;
+
+    // Find the index of the knot span that `t` is in, such that
+    // `knots[k] <= t < knots[k + 1]`. Spans of zero length, as well as the
+    // spans before the first and after the last coefficient, are skipped.
+    let k = (p..coefficients.len())
+        .rev()
+        .find(|&k| knots[k] <= t && knots[k] < knots[k + 1])
+        .unwrap_or(p);
+
+    let mut d = coefficients[k - p..=k].to_vec();
+
+    for r in 1..=p {
+        for j in (r..=p).rev() {
+            let left = knots[j + k - p];
+            let right = knots[j + 1 + k - r];
+
+            let alpha = (t - left) / (right - left);
+            d[j] = d[j - 1] + (d[j] - d[j - 1]) * alpha;
+        }
+    }
+
+    d[p]
+}
+
+impl<const D: usize> approx::AbsDiffEq for BSpline<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.degree == other.degree
+            && self.control_points.len() == other.control_points.len()
+            && self
+                .control_points()
+                .iter()
+                .zip(other.control_points())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Bezier, Line, Point, Scalar, Vector};
+
+    use super::BSpline;
+
+    #[test]
+    fn degree_1_is_line() {
+        let points = [[1., 2., 3.], [-2., 5., 0.5]];
+
+        let bspline = BSpline::from_control_points(points, 1);
+        let (line, _) = Line::from_points(points);
+
+        for t in [-1., 0., 0.1, 0.5, 0.7, 1., 2.5] {
+            assert_eq!(
+                bspline.point_from_bspline_coords([t]),
+                line.point_from_line_coords([t])
+            );
+            assert_eq!(
+                bspline.tangent_from_bspline_coords([t]),
+                line.direction()
+            );
+        }
+    }
+
+    #[test]
+    fn degree_1_is_polyline() {
+        let bspline =
+            BSpline::from_control_points([[0., 0.], [1., 0.], [1., 2.]], 1);
+
+        assert_eq!(
+            bspline.knots().collect::<Vec<_>>(),
+            [0., 0.5, 1.].map(Scalar::from)
+        );
+        assert_eq!(
+            bspline.point_from_bspline_coords([0.5]),
+            Point::from([1., 0.])
+        );
+        assert_eq!(
+            bspline.point_from_bspline_coords([0.75]),
+            Point::from([1., 1.])
+        );
+        assert_eq!(
+            bspline.tangent_from_bspline_coords([0.25]),
+            Vector::from([2., 0.])
+        );
+        assert_eq!(
+            bspline.tangent_from_bspline_coords([0.75]),
+            Vector::from([0., 4.])
+        );
+    }
+
+    #[test]
+    fn degree_3_with_4_control_points_is_bezier() {
+        let points = [[0., 0.], [0., 1.], [1., 1.], [1., 0.]];
+
+        let bspline = BSpline::from_control_points(points, 3);
+        let bezier = Bezier::from_control_points(points);
+
+        for t in [0., 0.25, 0.5, 0.9, 1.] {
+            assert_abs_diff_eq!(
+                bspline.point_from_bspline_coords([t]),
+                bezier.point_from_bezier_coords([t]),
+                epsilon = Scalar::from(1e-12),
+            );
+            assert_abs_diff_eq!(
+                bspline.tangent_from_bspline_coords([t]),
+                bezier.tangent_from_bezier_coords([t]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+
+    #[test]
+    fn clamped_end_points() {
+        let bspline = BSpline::from_control_points(
+            [[0., 0.], [1., 2.], [2., -1.], [3., 3.], [4., 0.]],
+            2,
+        );
+
+        assert_eq!(
+            bspline.point_from_bspline_coords([0.]),
+            Point::from([0., 0.])
+        );
+        assert_eq!(
+            bspline.point_from_bspline_coords([1.]),
+            Point::from([4., 0.])
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        let bspline = BSpline::from_control_points(
+            [[0., 0.], [1., 2.], [2., -1.], [3., 3.], [4., 0.]],
+            3,
+        );
+        let reversed = bspline.clone().reverse();
+
+        for t in [0., 0.2, 0.5, 0.9, 1.] {
+            assert_abs_diff_eq!(
+                reversed.point_from_bspline_coords([t]),
+                bspline.point_from_bspline_coords([1. - t]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use approx::AbsDiffEq;
-
 use crate::{Aabb, Point, Scalar, Vector};
 
+/// The tolerance for the requirements of [`Circle::new`], relative to radius
+const RELATIVE_EPSILON: f64 = 1e-12;
+
 /// An n-dimensional circle
 ///
 /// The dimensionality of the circle is defined by the const generic `D`
@@ -24,6 +25,9 @@ impl<const D: usize> Circle<D> {
     ///   zero.
     /// - `a` and `b` must be of equal length.
     /// - `a` and `b` must be perpendicular to each other.
+    ///
+    /// Both of the latter requirements are checked with a tolerance relative to
+    /// the radius, to allow for numerical inaccuracy.
     pub fn new(
         center: impl Into<Point<D>>,
         a: impl Into<Vector<D>>,
@@ -33,21 +37,22 @@ impl<const D: usize> Circle<D> {
         let a = a.into();
         let b = b.into();
 
-        assert_eq!(
-            a.magnitude(),
-            b.magnitude(),
-            "`a` and `b` must be of equal length"
-        );
         assert_ne!(
             a.magnitude(),
             Scalar::ZERO,
             "circle radius must not be zero"
         );
-        // Requiring the vector to be *precisely* perpendicular is not
-        // practical, because of numerical inaccuracy. This epsilon value seems
-        // seems to work for now, but maybe it needs to become configurable.
+        // Requiring the vectors to be *precisely* of equal length and
+        // perpendicular is not practical, because of numerical inaccuracy. A
+        // rotated circle, for example, can be off by a rounding error, which
+        // grows with its radius.
+        assert!(
+            (a.magnitude() - b.magnitude()).abs()
+                <= a.magnitude() * RELATIVE_EPSILON,
+            "`a` and `b` must be of equal length"
+        );
         assert!(
-            a.dot(&b) < Scalar::default_epsilon(),
+            a.dot(&b).abs() <= a.magnitude() * b.magnitude() * RELATIVE_EPSILON,
             "`a` and `b` must be perpendicular to each other"
         );
 
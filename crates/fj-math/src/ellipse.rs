@@ -0,0 +1,219 @@
+use num_traits::Float;
+
+use crate::{Aabb, Point, Scalar, Vector};
+
+/// The tolerance for the requirements of [`Ellipse::new`], relative to size
+const RELATIVE_EPSILON: f64 = 1e-12;
+
+/// An n-dimensional ellipse
+///
+/// The dimensionality of the ellipse is defined by the const generic `D`
+/// parameter.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    major: Vector<D>,
+    minor: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// Construct an ellipse
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - Neither `major` nor `minor` must be of zero length.
+    /// - `major` must not be shorter than `minor`.
+    /// - `major` and `minor` must be perpendicular to each other. This is
+    ///   checked with a tolerance relative to the size of the ellipse, to allow
+    ///   for numerical inaccuracy.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        major: impl Into<Vector<D>>,
+        minor: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let major = major.into();
+        let minor = minor.into();
+
+        assert_ne!(
+            minor.magnitude(),
+            Scalar::ZERO,
+            "ellipse axes must not be zero"
+        );
+        assert!(
+            major.magnitude() >= minor.magnitude(),
+            "`major` must not be shorter than `minor`"
+        );
+        // Same as for `Circle`, requiring the axes to be *precisely*
+        // perpendicular is not practical, due to numerical inaccuracy.
+        assert!(
+            major.dot(&minor).abs()
+                <= major.magnitude() * minor.magnitude() * RELATIVE_EPSILON,
+            "`major` and `minor` must be perpendicular to each other"
+        );
+
+        Self {
+            center,
+            major,
+            minor,
+        }
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that defines the major axis of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is the zero
+    /// coordinate of the ellipse's coordinate system. The length of the vector
+    /// defines the ellipse's semi-major axis.
+    ///
+    /// Please also refer to [`Self::minor`].
+    pub fn major(&self) -> Vector<D> {
+        self.major
+    }
+
+    /// Access the vector that defines the minor axis of the ellipse
+    ///
+    /// Also defines the direction of the ellipse's coordinate system. The
+    /// length is equal to the ellipse's semi-minor axis, and this vector is
+    /// perpendicular to [`Self::major`].
+    pub fn minor(&self) -> Vector<D> {
+        self.minor
+    }
+
+    /// Access the length of the semi-major axis
+    pub fn major_radius(&self) -> Scalar {
+        self.major.magnitude()
+    }
+
+    /// Access the length of the semi-minor axis
+    pub fn minor_radius(&self) -> Scalar {
+        self.minor.magnitude()
+    }
+
+    /// Compute the radius of curvature at the given ellipse coordinate
+    ///
+    /// This is the radius of the circle that best approximates the ellipse at
+    /// this point. It is smallest at the ends of the major axis, and largest at
+    /// the ends of the minor axis.
+    pub fn radius_of_curvature(&self, point: impl Into<Point<1>>) -> Scalar {
+        let a = self.major_radius();
+        let b = self.minor_radius();
+
+        let speed = self.speed(point);
+        speed * speed * speed / (a * b)
+    }
+
+    /// Compute the length of the ellipse's derivative at the given coordinate
+    ///
+    /// This is the rate at which distance is covered along the ellipse, per
+    /// unit of ellipse coordinate.
+    pub fn speed(&self, point: impl Into<Point<1>>) -> Scalar {
+        let (sin, cos) = point.into().t.sin_cos();
+
+        let a = self.major_radius();
+        let b = self.minor_radius();
+
+        (a * a * sin * sin + b * b * cos * cos).sqrt()
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.minor = -self.minor;
+        self
+    }
+
+    /// Convert a point in ellipse coordinates into a `D`-dimensional point
+    pub fn point_from_ellipse_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_ellipse_coords(point.into().coords)
+    }
+
+    /// Convert a vector in ellipse coordinates into a `D`-dimensional vector
+    pub fn vector_from_ellipse_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.major * cos + self.minor * sin
+    }
+
+    /// Calculate an AABB for the ellipse
+    pub fn aabb(&self) -> Aabb<D> {
+        let mut center_to_min_max = [Scalar::ZERO; D];
+        for (i, extent) in center_to_min_max.iter_mut().enumerate() {
+            let major = self.major.components[i];
+            let minor = self.minor.components[i];
+
+            *extent = (major * major + minor * minor).sqrt();
+        }
+        let center_to_min_max = Vector::from(center_to_min_max);
+
+        Aabb {
+            min: self.center() - center_to_min_max,
+            max: self.center() + center_to_min_max,
+        }
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Ellipse<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.major.abs_diff_eq(&other.major, epsilon)
+            && self.minor.abs_diff_eq(&other.minor, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar};
+
+    use super::Ellipse;
+
+    #[test]
+    fn point_from_ellipse_coords() {
+        let ellipse = Ellipse::new([1., 2.], [2., 0.], [0., 1.]);
+
+        assert_abs_diff_eq!(
+            ellipse.point_from_ellipse_coords([0.]),
+            Point::from([3., 2.]),
+        );
+        assert_abs_diff_eq!(
+            ellipse.point_from_ellipse_coords([FRAC_PI_2]),
+            Point::from([1., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+
+    #[test]
+    fn radius_of_curvature() {
+        let ellipse = Ellipse::new([0., 0.], [2., 0.], [0., 1.]);
+
+        assert_eq!(ellipse.radius_of_curvature([0.]), Scalar::from(0.5));
+        assert_abs_diff_eq!(
+            ellipse.radius_of_curvature([FRAC_PI_2]),
+            Scalar::from(4.),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use approx::AbsDiffEq;
+use num_traits::Float;
+
+use crate::{Point, Scalar, Vector};
+
+/// A helix in 3D space
+///
+/// A helix winds around an axis at a constant distance (its radius), while
+/// advancing along that axis at a constant rate. The distance it advances
+/// during one full turn is its pitch.
+///
+/// Like for a [`Circle`], the coordinate of a point on the helix is an angle in
+/// radians. Unlike for a circle, increasing the coordinate by a full turn does
+/// not lead back to the same point, but to the one that is one pitch further
+/// along the axis.
+///
+/// [`Circle`]: crate::Circle
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Helix {
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    advance: Vector<3>,
+}
+
+impl Helix {
+    /// Construct a helix
+    ///
+    /// `center`, `a`, and `b` define the circle that the helix projects onto,
+    /// as they would for a [`Circle`]. `advance` is the distance the helix
+    /// advances during each full turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The helix radius (defined by the length of `a` and `b`) must not be
+    ///   zero.
+    /// - `a` and `b` must be of equal length.
+    /// - `a`, `b`, and `advance` must be perpendicular to each other.
+    ///
+    /// [`Circle`]: crate::Circle
+    pub fn new(
+        center: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        advance: impl Into<Vector<3>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+        let advance = advance.into();
+
+        let radius = a.magnitude();
+        assert_ne!(radius, Scalar::ZERO, "helix radius must not be zero");
+
+        // Same as for `Circle`, requiring the vectors to be *precisely* equal
+        // in length, or perpendicular, is not practical, due to numerical
+        // inaccuracy.
+        let epsilon = Scalar::default_epsilon() * radius * 16.;
+        assert!(
+            (radius - b.magnitude()).abs() <= epsilon,
+            "`a` and `b` must be of equal length"
+        );
+        assert!(
+            a.dot(&b).abs() <= epsilon * radius
+                && a.dot(&advance).abs() <= epsilon * advance.magnitude()
+                && b.dot(&advance).abs() <= epsilon * advance.magnitude(),
+            "`a`, `b`, and `advance` must be perpendicular to each other"
+        );
+
+        Self {
+            center,
+            a,
+            b,
+            advance,
+        }
+    }
+
+    /// Construct a helix from an axis, a radius, and a pitch
+    ///
+    /// The helix winds counter-clockwise around `axis`, when looking against
+    /// the direction of the axis, and advances in the direction of the axis. A
+    /// negative pitch results in a helix of opposite handedness.
+    ///
+    /// The point on the helix at coordinate zero is chosen arbitrarily. Use
+    /// [`Helix::new`], for full control over the helix' coordinate system.
+    pub fn from_axis_radius_and_pitch(
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        radius: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+    ) -> Self {
+        let axis = axis.into().normalize();
+        let radius = radius.into();
+
+        // Any vector that is perpendicular to the axis will do for `a`. Start
+        // from the unit vector that is least aligned with the axis, to avoid
+        // numerical trouble.
+        let unit = [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()]
+            .into_iter()
+            .min_by_key(|unit| unit.dot(&axis).abs())
+            .expect("Array is not empty");
+        let a = axis.cross(&unit).normalize();
+        let b = axis.cross(&a);
+
+        Self::new(center, a * radius, b * radius, axis * pitch.into())
+    }
+
+    /// Access the center point of the circle that the helix starts on
+    pub fn center(&self) -> Point<3> {
+        self.center
+    }
+
+    /// Access the vector that points to the zero coordinate of the helix
+    ///
+    /// Please refer to [`Circle::a`] for details.
+    ///
+    /// [`Circle::a`]: crate::Circle::a
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the winding direction of the helix
+    ///
+    /// Please refer to [`Circle::b`] for details.
+    ///
+    /// [`Circle::b`]: crate::Circle::b
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the vector that the helix advances during one full turn
+    pub fn advance(&self) -> Vector<3> {
+        self.advance
+    }
+
+    /// Access the radius of the helix
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the pitch of the helix
+    ///
+    /// This is the distance that the helix advances along its axis during one
+    /// full turn. It is negative, if the helix is left-handed.
+    pub fn pitch(&self) -> Scalar {
+        let axis = self.a.cross(&self.b).normalize();
+        self.advance.dot(&axis)
+    }
+
+    /// Compute the radius of curvature of the helix
+    ///
+    /// Unlike that of an ellipse, the curvature of a helix is constant.
+    pub fn radius_of_curvature(&self) -> Scalar {
+        let radius = self.radius();
+        let rise = self.advance.magnitude() / Scalar::TAU;
+
+        (radius * radius + rise * rise) / radius
+    }
+
+    /// Compute the length of the helix' derivative
+    ///
+    /// This is the rate at which distance is covered along the helix, per unit
+    /// of helix coordinate. Like the curvature, it is constant.
+    pub fn speed(&self) -> Scalar {
+        let radius = self.radius();
+        let rise = self.advance.magnitude() / Scalar::TAU;
+
+        (radius * radius + rise * rise).sqrt()
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self.advance = -self.advance;
+        self
+    }
+
+    /// Convert a point in helix coordinates into a 3D point
+    pub fn point_from_helix_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<3> {
+        self.center + self.vector_from_helix_coords(point.into().coords)
+    }
+
+    /// Convert a vector in helix coordinates into a 3D vector
+    pub fn vector_from_helix_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<3> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin + self.advance * (angle / Scalar::TAU)
+    }
+}
+
+impl approx::AbsDiffEq for Helix {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+            && self.advance.abs_diff_eq(&other.advance, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::Helix;
+
+    #[test]
+    fn point_from_helix_coords() {
+        let helix =
+            Helix::new([0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 4.]);
+
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([FRAC_PI_2]),
+            Point::from([0., 1., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([TAU * 2.]),
+            Point::from([1., 0., 8.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn from_axis_radius_and_pitch() {
+        for pitch in [2., -2.] {
+            let helix = Helix::from_axis_radius_and_pitch(
+                [1., 2., 3.],
+                [0., 0., 2.],
+                3.,
+                pitch,
+            );
+
+            assert_abs_diff_eq!(
+                helix.radius(),
+                Scalar::from(3.),
+                epsilon = Scalar::from(1e-12),
+            );
+            assert_abs_diff_eq!(
+                helix.pitch(),
+                Scalar::from(pitch),
+                epsilon = Scalar::from(1e-12),
+            );
+            assert_abs_diff_eq!(
+                helix.point_from_helix_coords([TAU])
+                    - helix.point_from_helix_coords([0.]),
+                Vector::from([0., 0., pitch]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+}
@@ -34,13 +34,19 @@
 #![warn(missing_docs)]
 
 mod aabb;
+mod approx_eq;
 mod arc;
+mod bezier;
+mod bspline;
 mod circle;
 mod coordinates;
+mod ellipse;
+mod helix;
 mod line;
 mod plane;
 mod point;
 mod poly_chain;
+mod ray;
 mod scalar;
 mod segment;
 mod transform;
@@ -49,15 +55,21 @@ mod vector;
 
 pub use self::{
     aabb::Aabb,
-    arc::Arc,
+    approx_eq::{ApproxEq, Epsilon},
+    arc::{Arc, CollinearPoints},
+    bezier::Bezier,
+    bspline::BSpline,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
-    line::Line,
+    ellipse::Ellipse,
+    helix::Helix,
+    line::{Line, LineIntersection},
     plane::Plane,
     point::Point,
     poly_chain::PolyChain,
+    ray::{Ray, RayTriangleIntersection},
     scalar::{Scalar, Sign},
-    segment::Segment,
+    segment::{Segment, SegmentIntersection},
     transform::Transform,
     triangle::{Triangle, Winding},
     vector::Vector,
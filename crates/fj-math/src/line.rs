@@ -1,5 +1,12 @@
 use crate::{Point, Scalar, Triangle, Vector};
 
+/// The relative tolerance used when intersecting lines and segments
+///
+/// Directions are considered parallel, if the sine of the angle between them is
+/// no larger than this. Distances are compared relative to the size of the
+/// geometry involved.
+pub(crate) const INTERSECTION_EPSILON: f64 = 1e-12;
+
 /// An n-dimensional line, defined by an origin and a direction
 ///
 /// The dimensionality of the line is defined by the const generic `D`
@@ -161,6 +168,52 @@ impl<const D: usize> Line<D> {
     }
 }
 
+impl Line<2> {
+    /// Compute the intersection with another line
+    ///
+    /// Lines that are parallel within a tolerance, are treated as parallel. The
+    /// intersection of nearly parallel lines would be far away and very
+    /// sensitive to numerical inaccuracy, so that is the more robust answer.
+    /// Parallel lines are reported as coincident, if the distance between them
+    /// is within a tolerance, relative to their size and position.
+    pub fn intersect(&self, other: &Self) -> LineIntersection {
+        let [d1, d2] = [self.direction, other.direction];
+        let offset = other.origin - self.origin;
+
+        let denominator = d1.cross2d(&d2);
+
+        // Comparing to the lengths of the directions makes this a check of the
+        // angle between them, independent of their lengths.
+        if denominator.abs()
+            <= d1.magnitude() * d2.magnitude() * INTERSECTION_EPSILON
+        {
+            let distance = offset.cross2d(&d1).abs() / d1.magnitude();
+            let scale = [
+                self.origin.coords.magnitude(),
+                other.origin.coords.magnitude(),
+                d1.magnitude(),
+                d2.magnitude(),
+            ]
+            .into_iter()
+            .fold(Scalar::ONE, Scalar::max);
+
+            return if distance <= scale * INTERSECTION_EPSILON {
+                LineIntersection::Coincident
+            } else {
+                LineIntersection::Parallel
+            };
+        }
+
+        let t = offset.cross2d(&d2) / denominator;
+        let s = offset.cross2d(&d1) / denominator;
+
+        LineIntersection::Point {
+            point: self.point_from_line_coords([t]),
+            line_coords: [Point::from([t]), Point::from([s])],
+        }
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Line<D> {
     type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
 
@@ -174,13 +227,37 @@ impl<const D: usize> approx::AbsDiffEq for Line<D> {
     }
 }
 
+/// The intersection of two lines
+///
+/// Returned by [`Line::intersect`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum LineIntersection {
+    /// The lines intersect in a single point
+    Point {
+        /// The intersection point
+        point: Point<2>,
+
+        /// The intersection point, in the line coordinates of both lines
+        ///
+        /// The first coordinate is on the line that `intersect` was called on,
+        /// the second on the other one.
+        line_coords: [Point<1>; 2],
+    },
+
+    /// The lines are coincident
+    Coincident,
+
+    /// The lines are parallel, but not coincident
+    Parallel,
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
 
     use crate::{Point, Scalar, Vector};
 
-    use super::Line;
+    use super::{Line, LineIntersection};
 
     #[test]
     fn from_points_with_line_coords() {
@@ -219,6 +296,43 @@ mod tests {
         assert!(!line.is_coincident_with(&c));
     }
 
+    #[test]
+    fn intersect() {
+        let (line, _) = Line::from_points([[0., 0.], [2., 0.]]);
+
+        let (crossing, _) = Line::from_points([[1., -1.], [1., 1.]]);
+        assert_eq!(
+            line.intersect(&crossing),
+            LineIntersection::Point {
+                point: Point::from([1., 0.]),
+                line_coords: [Point::from([0.5]), Point::from([0.5])],
+            }
+        );
+
+        let (coincident, _) = Line::from_points([[3., 0.], [-1., 0.]]);
+        assert_eq!(line.intersect(&coincident), LineIntersection::Coincident);
+
+        let (parallel, _) = Line::from_points([[0., 1.], [1., 1.]]);
+        assert_eq!(line.intersect(&parallel), LineIntersection::Parallel);
+
+        // Not exactly parallel, but close enough that the intersection would
+        // be meaningless.
+        let (nearly_parallel, _) =
+            Line::from_points([[0., 1.], [1., 1. + 1e-14]]);
+        assert_eq!(
+            line.intersect(&nearly_parallel),
+            LineIntersection::Parallel
+        );
+
+        // Not exactly coincident either.
+        let (nearly_coincident, _) =
+            Line::from_points([[0., 1e-14], [1., 1e-14]]);
+        assert_eq!(
+            line.intersect(&nearly_coincident),
+            LineIntersection::Coincident
+        );
+    }
+
     #[test]
     fn convert_point_to_line_coords() {
         let line = Line {
@@ -2,7 +2,7 @@ use std::{fmt, ops};
 
 use super::{
     coordinates::{Uv, Xyz, T},
-    Scalar, Vector,
+    Epsilon, Scalar, Vector,
 };
 
 /// An n-dimensional point
@@ -63,6 +63,24 @@ impl<const D: usize> Point<D> {
     pub fn distance_to(&self, other: &Self) -> Scalar {
         (self.coords - other.coords).magnitude()
     }
+
+    /// Compute the squared distance to another point
+    ///
+    /// Cheaper than [`Point::distance_to`], as it avoids a square root. Useful
+    /// for comparing distances.
+    pub fn distance_squared_to(&self, other: &Self) -> Scalar {
+        let difference = self.coords - other.coords;
+        difference.dot(&difference)
+    }
+
+    /// Determine whether the point is equal to another, within `epsilon`
+    ///
+    /// The points are equal, if the distance between them is within
+    /// `epsilon`. For a relative epsilon, the distance is compared to that of
+    /// the points from the origin. See [`Epsilon`].
+    pub fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool {
+        self.coords.approx_eq(&other.coords, epsilon)
+    }
 }
 
 impl ops::Deref for Point<1> {
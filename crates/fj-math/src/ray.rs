@@ -0,0 +1,165 @@
+use crate::{line::INTERSECTION_EPSILON, Point, Scalar, Triangle, Vector};
+
+/// An n-dimensional ray, defined by an origin and a direction
+///
+/// The dimensionality of the ray is defined by the const generic `D`
+/// parameter.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[repr(C)]
+pub struct Ray<const D: usize> {
+    origin: Point<D>,
+    direction: Vector<D>,
+}
+
+impl<const D: usize> Ray<D> {
+    /// Create a ray from a point and a vector
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `direction` has a length of zero.
+    pub fn from_origin_and_direction(
+        origin: impl Into<Point<D>>,
+        direction: impl Into<Vector<D>>,
+    ) -> Self {
+        let origin = origin.into();
+        let direction = direction.into();
+
+        assert!(
+            direction.magnitude() != Scalar::ZERO,
+            "Can't construct `Ray`. Direction is zero: {direction:?}"
+        );
+
+        Self { origin, direction }
+    }
+
+    /// Access the origin of the ray
+    pub fn origin(&self) -> Point<D> {
+        self.origin
+    }
+
+    /// Access the direction of the ray
+    pub fn direction(&self) -> Vector<D> {
+        self.direction
+    }
+
+    /// Compute the point on the ray at the given parameter
+    ///
+    /// The parameter is measured in multiples of the direction vector.
+    pub fn point_at(&self, t: impl Into<Scalar>) -> Point<D> {
+        self.origin + self.direction * t.into()
+    }
+}
+
+impl Ray<3> {
+    /// Intersect the ray with a triangle
+    ///
+    /// Uses the Möller–Trumbore algorithm. Returns `None`, if the ray misses
+    /// the triangle, runs parallel to it, or if the triangle is behind the
+    /// ray's origin.
+    ///
+    /// The front side of a triangle is the side from which its points appear
+    /// counter-clockwise. If `include_back_faces` is `false`, a ray that hits
+    /// the back side of the triangle does not count as a hit.
+    pub fn intersect_triangle(
+        &self,
+        triangle: &Triangle<3>,
+        include_back_faces: bool,
+    ) -> Option<RayTriangleIntersection> {
+        let [a, b, c] = triangle.points();
+        let (e1, e2) = (b - a, c - a);
+
+        let p = self.direction.cross(&e2);
+        let det = e1.dot(&p);
+
+        // `det` is positive, if the ray hits the front side of the triangle,
+        // negative, if it hits the back side, and zero, if it runs parallel.
+        let scale =
+            self.direction.magnitude() * e1.magnitude() * e2.magnitude();
+        if det.abs() <= scale * INTERSECTION_EPSILON {
+            return None;
+        }
+        if det < Scalar::ZERO && !include_back_faces {
+            return None;
+        }
+
+        let s = self.origin - a;
+        let u = s.dot(&p) / det;
+        if u < Scalar::ZERO || u > Scalar::ONE {
+            return None;
+        }
+
+        let q = s.cross(&e1);
+        let v = self.direction.dot(&q) / det;
+        if v < Scalar::ZERO || u + v > Scalar::ONE {
+            return None;
+        }
+
+        let t = e2.dot(&q) / det;
+        if t < Scalar::ZERO {
+            return None;
+        }
+
+        Some(RayTriangleIntersection {
+            t,
+            barycentric: [Scalar::ONE - u - v, u, v],
+        })
+    }
+}
+
+/// An intersection between a [`Ray`] and a [`Triangle`]
+///
+/// Returned by [`Ray::intersect_triangle`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct RayTriangleIntersection {
+    /// The parameter of the intersection point on the ray
+    ///
+    /// See [`Ray::point_at`].
+    pub t: Scalar,
+
+    /// The barycentric coordinates of the intersection point
+    ///
+    /// These are the weights of the triangle's points, in the same order as
+    /// [`Triangle::points`] returns them.
+    pub barycentric: [Scalar; 3],
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Scalar, Triangle};
+
+    use super::Ray;
+
+    #[test]
+    fn intersect_triangle() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [2., 0., 0.], [0., 2., 0.]]);
+
+        let from_front =
+            Ray::from_origin_and_direction([0.5, 0.5, 2.], [0., 0., -1.]);
+        let intersection = from_front.intersect_triangle(&triangle, false);
+        let intersection = intersection.expect("Ray should hit triangle");
+        assert_eq!(intersection.t, Scalar::TWO);
+        assert_eq!(
+            intersection.barycentric,
+            [0.5, 0.25, 0.25].map(Scalar::from)
+        );
+        assert_eq!(from_front.point_at(intersection.t), [0.5, 0.5, 0.].into());
+
+        let from_back =
+            Ray::from_origin_and_direction([0.5, 0.5, -2.], [0., 0., 1.]);
+        assert!(from_back.intersect_triangle(&triangle, false).is_none());
+        assert!(from_back.intersect_triangle(&triangle, true).is_some());
+
+        let missing =
+            Ray::from_origin_and_direction([3., 3., 2.], [0., 0., -1.]);
+        assert!(missing.intersect_triangle(&triangle, true).is_none());
+
+        let pointing_away =
+            Ray::from_origin_and_direction([0.5, 0.5, 2.], [0., 0., 1.]);
+        assert!(pointing_away.intersect_triangle(&triangle, true).is_none());
+
+        let parallel =
+            Ray::from_origin_and_direction([0.5, 0.5, 0.], [1., 0., 0.]);
+        assert!(parallel.intersect_triangle(&triangle, true).is_none());
+    }
+}
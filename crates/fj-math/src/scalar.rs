@@ -55,6 +55,18 @@ impl Scalar {
         Self::from_f64(scalar as f64)
     }
 
+    /// Construct an angle in radians from an angle in degrees
+    pub fn from_degrees(degrees: impl Into<Self>) -> Self {
+        degrees.into().0.to_radians().into()
+    }
+
+    /// Construct an angle in radians from a fraction of a full turn
+    ///
+    /// `Scalar::turns(0.25)`, for example, is a right angle.
+    pub fn turns(fraction: impl Into<Self>) -> Self {
+        Self::TAU * fraction.into()
+    }
+
     /// Convert the scalar into an `f32`
     pub fn into_f32(self) -> f32 {
         self.0 as f32
@@ -70,6 +82,11 @@ impl Scalar {
         self.0 as u64
     }
 
+    /// Convert an angle in radians into degrees
+    pub fn to_degrees(self) -> Self {
+        self.0.to_degrees().into()
+    }
+
     /// Indicate whether the scalar is negative
     pub fn is_negative(self) -> bool {
         self < Self::ZERO
@@ -614,3 +631,27 @@ impl Sign {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::Scalar;
+
+    #[test]
+    fn angle_units() {
+        assert_abs_diff_eq!(Scalar::from_degrees(180.), Scalar::PI);
+        assert_abs_diff_eq!(Scalar::from_degrees(30.), Scalar::PI / 6.);
+        assert_abs_diff_eq!(Scalar::turns(0.25), Scalar::PI / 2.);
+        assert_abs_diff_eq!(Scalar::turns(1.), Scalar::TAU);
+
+        for degrees in [-720., -90., 0., 1., 45., 180., 359.] {
+            let degrees = Scalar::from(degrees);
+            assert_abs_diff_eq!(
+                Scalar::from_degrees(degrees).to_degrees(),
+                degrees,
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use crate::Scalar;
+use crate::{
+    line::{LineIntersection, INTERSECTION_EPSILON},
+    Line, Scalar,
+};
 
 use super::Point;
 
@@ -48,6 +51,56 @@ impl<const D: usize> Segment<D> {
 }
 
 impl Segment<2> {
+    /// Compute the intersection with another segment
+    ///
+    /// This works like [`Line::intersect`], for the lines through both
+    /// segments, and uses the same tolerances. In addition, the result reports
+    /// whether the intersection lies within both segments.
+    ///
+    /// The segment coordinates in the result are line coordinates, for lines
+    /// where the first point of a segment is at `0`, and the second one at `1`.
+    pub fn intersect(&self, other: &Self) -> SegmentIntersection {
+        let (a, _) = Line::from_points(self.points);
+        let (b, _) = Line::from_points(other.points);
+
+        // An intersection right at the end of a segment might end up slightly
+        // outside of it, due to numerical inaccuracy.
+        let is_within = |t: Scalar| {
+            t >= Scalar::from(-INTERSECTION_EPSILON)
+                && t <= Scalar::from(1. + INTERSECTION_EPSILON)
+        };
+
+        match a.intersect(&b) {
+            LineIntersection::Point { point, line_coords } => {
+                let is_within_both =
+                    line_coords.iter().all(|point| is_within(point.t));
+
+                SegmentIntersection::Point {
+                    point,
+                    segment_coords: line_coords,
+                    is_within_both,
+                }
+            }
+            LineIntersection::Coincident => {
+                let [t0, t1] =
+                    other.points.map(|point| a.point_to_line_coords(point).t);
+
+                let min = t0.min(t1).max(Scalar::ZERO);
+                let max = t0.max(t1).min(Scalar::ONE);
+
+                let overlap = if is_within(min) && is_within(max) && min <= max
+                {
+                    Some([min, max].map(|t| Point::from([t])))
+                } else {
+                    None
+                };
+
+                SegmentIntersection::Coincident { overlap }
+            }
+            LineIntersection::Parallel => SegmentIntersection::Parallel,
+        }
+    }
+
     /// Convert the 2-dimensional segment to a Parry segment
     pub fn to_parry(self) -> parry2d_f64::shape::Segment {
         self.points.map(|point| point.to_na()).into()
@@ -75,3 +128,136 @@ impl<const D: usize> fmt::Debug for Segment<D> {
         write!(f, "[{:?} -> {:?}]", self.points[0], self.points[1])
     }
 }
+
+/// The intersection of two segments
+///
+/// Returned by [`Segment::intersect`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SegmentIntersection {
+    /// The lines through the segments intersect in a single point
+    Point {
+        /// The intersection point
+        point: Point<2>,
+
+        /// The intersection point, in the segment coordinates of both segments
+        ///
+        /// The first coordinate is on the segment that `intersect` was called
+        /// on, the second on the other one.
+        segment_coords: [Point<1>; 2],
+
+        /// Indicates whether the point lies within both segments
+        ///
+        /// This includes the end points of the segments.
+        is_within_both: bool,
+    },
+
+    /// The segments are on the same line
+    Coincident {
+        /// The range in which the segments overlap, if they do
+        ///
+        /// Given in the segment coordinates of the segment that `intersect` was
+        /// called on. If the segments only touch at their end points, the range
+        /// is a single point.
+        overlap: Option<[Point<1>; 2]>,
+    },
+
+    /// The segments are parallel, but not on the same line
+    Parallel,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::{Segment, SegmentIntersection};
+
+    #[test]
+    fn intersect_crossing() {
+        let a = Segment::from_points([[0., 0.], [2., 2.]]);
+
+        let b = Segment::from_points([[0., 2.], [2., 0.]]);
+        assert_eq!(
+            a.intersect(&b),
+            SegmentIntersection::Point {
+                point: Point::from([1., 1.]),
+                segment_coords: [Point::from([0.5]), Point::from([0.5])],
+                is_within_both: true,
+            }
+        );
+
+        // The lines cross, but not within the second segment.
+        let b = Segment::from_points([[0., 2.], [0.5, 1.5]]);
+        let SegmentIntersection::Point { is_within_both, .. } = a.intersect(&b)
+        else {
+            panic!("Expected lines to cross");
+        };
+        assert!(!is_within_both);
+    }
+
+    #[test]
+    fn intersect_t_junction() {
+        let a = Segment::from_points([[0., 0.], [2., 0.]]);
+        let b = Segment::from_points([[1., 0.], [1., 1.]]);
+
+        assert_eq!(
+            a.intersect(&b),
+            SegmentIntersection::Point {
+                point: Point::from([1., 0.]),
+                segment_coords: [Point::from([0.5]), Point::from([0.])],
+                is_within_both: true,
+            }
+        );
+    }
+
+    #[test]
+    fn intersect_shared_end_point() {
+        let a = Segment::from_points([[0., 0.], [0.3, 0.1]]);
+        let b = Segment::from_points([[0.3, 0.1], [0.7, 0.9]]);
+
+        let SegmentIntersection::Point {
+            segment_coords: [t, s],
+            is_within_both,
+            ..
+        } = a.intersect(&b)
+        else {
+            panic!("Expected lines to cross");
+        };
+
+        assert!((t.t - 1.).abs() < Scalar::from(1e-12));
+        assert!(s.t.abs() < Scalar::from(1e-12));
+        assert!(is_within_both);
+    }
+
+    #[test]
+    fn intersect_collinear() {
+        let a = Segment::from_points([[0., 0.], [2., 0.]]);
+
+        // Overlapping, in opposite direction.
+        let b = Segment::from_points([[3., 0.], [1., 0.]]);
+        assert_eq!(
+            a.intersect(&b),
+            SegmentIntersection::Coincident {
+                overlap: Some([Point::from([0.5]), Point::from([1.])]),
+            }
+        );
+
+        // Touching at an end point.
+        let b = Segment::from_points([[2., 0.], [3., 0.]]);
+        assert_eq!(
+            a.intersect(&b),
+            SegmentIntersection::Coincident {
+                overlap: Some([Point::from([1.]), Point::from([1.])]),
+            }
+        );
+
+        // On the same line, but apart.
+        let b = Segment::from_points([[3., 0.], [4., 0.]]);
+        assert_eq!(
+            a.intersect(&b),
+            SegmentIntersection::Coincident { overlap: None }
+        );
+
+        let b = Segment::from_points([[0., 1.], [2., 1.]]);
+        assert_eq!(a.intersect(&b), SegmentIntersection::Parallel);
+    }
+}
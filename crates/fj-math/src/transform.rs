@@ -2,7 +2,7 @@ use std::ops;
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{BSpline, Bezier, Circle, Ellipse, Helix, Line, Plane, Scalar};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -29,7 +29,8 @@ impl Transform {
     /// Construct a rotation
     ///
     /// The direction of the vector defines the rotation axis. Its length
-    /// defines the angle of the rotation.
+    /// defines the angle of the rotation. To provide axis and angle separately,
+    /// use [`Transform::from_axis_angle`].
     pub fn rotation(axis_angle: impl Into<Vector<3>>) -> Self {
         let axis_angle = axis_angle.into();
 
@@ -40,10 +41,114 @@ impl Transform {
         ))
     }
 
+    /// Construct a rotation around an axis
+    ///
+    /// The angle is in radians (see [`Scalar::from_degrees`]). Looking along
+    /// the axis, the rotation is clockwise, which is counter-clockwise when
+    /// looking at the axis from its tip (right-hand rule).
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the axis has zero length.
+    pub fn from_axis_angle(
+        axis: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+    ) -> Self {
+        let axis = axis.into();
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "rotation axis must not have zero length"
+        );
+
+        Self::rotation(axis.normalize() * angle.into())
+    }
+
+    /// Construct a rotation from Euler angles
+    ///
+    /// The angles are in radians (see [`Scalar::from_degrees`]). The rotation
+    /// first rotates by `roll` around the x-axis, then by `pitch` around the
+    /// y-axis, then by `yaw` around the z-axis. Those axes are the fixed axes
+    /// of the coordinate system, not the axes rotated by the previous step
+    /// (extrinsic XYZ).
+    ///
+    /// This is the same as rotating by `yaw`, `pitch`, and `roll` around the
+    /// z-, y-, and x-axes of the object, as it's being rotated (intrinsic ZYX,
+    /// the usual convention in aerospace).
+    pub fn from_euler(
+        roll: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+        yaw: impl Into<Scalar>,
+    ) -> Self {
+        let rotation = nalgebra::Rotation3::from_euler_angles(
+            roll.into().into_f64(),
+            pitch.into().into_f64(),
+            yaw.into().into_f64(),
+        );
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            rotation.to_homogeneous(),
+        ))
+    }
+
+    /// Construct a rotation from a quaternion
+    ///
+    /// The quaternion is provided as its real part `w`, and its imaginary part
+    /// `[x, y, z]`. It is normalized, so it doesn't need to be a unit
+    /// quaternion.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the quaternion has zero length.
+    pub fn from_quaternion(
+        w: impl Into<Scalar>,
+        xyz: impl Into<Vector<3>>,
+    ) -> Self {
+        let quaternion = nalgebra::Quaternion::from_parts(
+            w.into().into_f64(),
+            xyz.into().to_na(),
+        );
+        assert_ne!(
+            quaternion.norm(),
+            0.,
+            "quaternion must not have zero length"
+        );
+
+        let rotation = nalgebra::UnitQuaternion::from_quaternion(quaternion);
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            rotation.to_homogeneous(),
+        ))
+    }
+
+    /// Construct a reflection across the given plane
+    ///
+    /// A reflection reverses the orientation of the objects it is applied to.
+    /// See [`Transform::is_orientation_reversing`].
+    pub fn mirror(plane: &Plane) -> Self {
+        let normal = plane.normal().to_na();
+        let distance = normal.dot(&plane.origin().coords.to_na());
+
+        let mut matrix = nalgebra::Matrix4::identity();
+        matrix.fixed_view_mut::<3, 3>(0, 0).copy_from(
+            &(nalgebra::Matrix3::identity() - normal * normal.transpose() * 2.),
+        );
+        matrix
+            .fixed_view_mut::<3, 1>(0, 3)
+            .copy_from(&(normal * distance * 2.));
+
+        Self(nalgebra::Transform::from_matrix_unchecked(matrix))
+    }
+
     /// Construct a scaling
-    pub fn scale(scaling_factor: f64) -> Self {
+    ///
+    /// Each component of `scaling_factors` is the factor along the respective
+    /// axis. Pass the same factor for all axes, to scale uniformly.
+    pub fn scale(scaling_factors: impl Into<Vector<3>>) -> Self {
         Self(nalgebra::Transform::from_matrix_unchecked(
-            nalgebra::OMatrix::new_scaling(scaling_factor),
+            nalgebra::OMatrix::new_nonuniform_scaling(
+                &scaling_factors.into().to_na(),
+            ),
         ))
     }
 
@@ -86,6 +191,26 @@ impl Transform {
         ])
     }
 
+    /// Transform the given Bézier curve
+    pub fn transform_bezier(&self, bezier: &Bezier<3>) -> Bezier<3> {
+        Bezier::from_control_points(
+            bezier
+                .control_points()
+                .map(|point| self.transform_point(&point)),
+        )
+    }
+
+    /// Transform the given B-spline curve
+    pub fn transform_bspline(&self, bspline: &BSpline<3>) -> BSpline<3> {
+        BSpline::from_control_points(
+            bspline
+                .control_points()
+                .iter()
+                .map(|point| self.transform_point(point)),
+            bspline.degree(),
+        )
+    }
+
     /// Transform the given circle
     pub fn transform_circle(&self, circle: &Circle<3>) -> Circle<3> {
         Circle::new(
@@ -95,6 +220,42 @@ impl Transform {
         )
     }
 
+    /// Transform the given ellipse
+    pub fn transform_ellipse(&self, ellipse: &Ellipse<3>) -> Ellipse<3> {
+        Ellipse::new(
+            self.transform_point(&ellipse.center()),
+            self.transform_vector(&ellipse.major()),
+            self.transform_vector(&ellipse.minor()),
+        )
+    }
+
+    /// Transform the given helix
+    pub fn transform_helix(&self, helix: &Helix) -> Helix {
+        Helix::new(
+            self.transform_point(&helix.center()),
+            self.transform_vector(&helix.a()),
+            self.transform_vector(&helix.b()),
+            self.transform_vector(&helix.advance()),
+        )
+    }
+
+    /// Indicate whether the transform reverses orientation
+    ///
+    /// This is the case for reflections, like [`Transform::mirror`], which
+    /// turn a right-handed coordinate system into a left-handed one.
+    pub fn is_orientation_reversing(&self) -> bool {
+        self.0.matrix().fixed_view::<3, 3>(0, 0).determinant() < 0.
+    }
+
+    /// Compose this transform with another one
+    ///
+    /// The resulting transform applies this transform first, then `other`.
+    /// This is the same as `other * self`, but reads in the order in which the
+    /// transforms are applied.
+    pub fn then(&self, other: &Self) -> Self {
+        *other * *self
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
@@ -151,6 +312,23 @@ impl Transform {
         ))
     }
 
+    /// Compute the rotation that this transform applies
+    ///
+    /// Returns the rotation in the same form that [`Transform::rotation`]
+    /// expects: the direction of the vector is the rotation axis, its length
+    /// the angle. Translation is ignored. If the transform also scales, the
+    /// rotation that is closest to the scaled one is returned.
+    ///
+    /// The result is not meaningful for transforms that reverse orientation
+    /// (see [`Transform::is_orientation_reversing`]), as those aren't just
+    /// rotations.
+    pub fn rotation_part(&self) -> Vector<3> {
+        let matrix = self.0.matrix().fixed_view::<3, 3>(0, 0).into_owned();
+        let rotation = nalgebra::Rotation3::from_matrix(&matrix);
+
+        Vector::from(rotation.scaled_axis())
+    }
+
     /// Extract the translation component of this transform
     pub fn extract_translation(&self) -> Self {
         *self * self.extract_rotation().inverse()
@@ -169,7 +347,7 @@ impl ops::Mul<Self> for Transform {
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Line, Point, Scalar, Vector};
+    use crate::{Line, Plane, Point, Scalar, Vector};
 
     use super::Transform;
 
@@ -194,6 +372,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mirror() {
+        let plane = Plane::from_parametric(
+            [1., 0., 0.],
+            Vector::unit_y(),
+            Vector::unit_z(),
+        );
+        let transform = Transform::mirror(&plane);
+
+        assert_abs_diff_eq!(
+            transform.transform_point(&Point::from([3., 2., 1.])),
+            Point::from([-1., 2., 1.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            transform.transform_vector(&Vector::from([1., 2., 3.])),
+            Vector::from([-1., 2., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+
+        assert!(transform.is_orientation_reversing());
+        assert!(
+            !Transform::rotation(Vector::unit_z()).is_orientation_reversing()
+        );
+    }
+
+    #[test]
+    fn scale() {
+        let transform = Transform::scale([1., 2., 3.]);
+
+        assert_abs_diff_eq!(
+            transform.transform_point(&Point::from([1., 1., 1.])),
+            Point::from([1., 2., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert!(!transform.is_orientation_reversing());
+        assert!(Transform::scale([1., 1., -1.]).is_orientation_reversing());
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
@@ -224,4 +441,120 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn from_axis_angle() {
+        let angle = Scalar::PI / 2.;
+
+        assert_abs_diff_eq!(
+            Transform::from_axis_angle([0., 0., 2.], angle).data(),
+            Transform::rotation(Vector::unit_z() * angle).data(),
+            epsilon = 1e-8,
+        );
+    }
+
+    #[test]
+    fn from_euler() {
+        let angle = Scalar::from_degrees(90.);
+        let transform = Transform::from_euler(angle, Scalar::ZERO, angle);
+
+        // Rolling around x first turns y into z, which yawing around z leaves
+        // alone. x is not affected by rolling, and turned into y by yawing.
+        assert_abs_diff_eq!(
+            transform.transform_vector(&Vector::unit_y()),
+            Vector::unit_z(),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            transform.transform_vector(&Vector::unit_x()),
+            Vector::unit_y(),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+
+    #[test]
+    fn from_quaternion() {
+        // This quaternion is not normalized, on purpose.
+        let (sin, cos) = (Scalar::PI / 4.).sin_cos();
+        let transform =
+            Transform::from_quaternion(cos * 2., Vector::unit_z() * sin * 2.);
+
+        assert_abs_diff_eq!(
+            transform.data(),
+            Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.)).data(),
+            epsilon = 1e-8,
+        );
+    }
+
+    #[test]
+    fn rotation_part() {
+        let axis_angle = Vector::from([1., 2., 3.]).normalize() * 0.5;
+        let transform = Transform::translation([1., 2., 3.])
+            * Transform::rotation(axis_angle)
+            * Transform::scale([2.; 3]);
+
+        assert_abs_diff_eq!(
+            transform.rotation_part(),
+            axis_angle,
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+
+    #[test]
+    fn then() {
+        let translation = Transform::translation([1., 0., 0.]);
+        let rotation = Transform::from_axis_angle(Vector::unit_z(), Scalar::PI);
+
+        // The point is first moved away from the origin, then rotated around
+        // it.
+        assert_abs_diff_eq!(
+            translation
+                .then(&rotation)
+                .transform_point(&Point::from([1., 0., 0.])),
+            Point::from([-2., 0., 0.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            translation.then(&rotation).data(),
+            (rotation * translation).data(),
+            epsilon = 1e-8,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let mirror = Transform::mirror(&Plane::from_parametric(
+            [1., 2., 3.],
+            Vector::unit_y(),
+            Vector::unit_z(),
+        ));
+        let transform = Transform::translation([1., 2., 3.])
+            .then(&Transform::from_euler(0.1, 0.2, 0.3))
+            .then(&Transform::scale([1., 2., 3.]))
+            .then(&mirror);
+
+        for t in [transform, transform.inverse()] {
+            assert_abs_diff_eq!(
+                t.then(&t.inverse()).data(),
+                Transform::identity().data(),
+                epsilon = 1e-8,
+            );
+        }
+
+        let point = Point::from([3., 2., 1.]);
+        assert_abs_diff_eq!(
+            transform
+                .inverse()
+                .transform_point(&transform.transform_point(&point)),
+            point,
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            transform
+                .inverse_transform_point(&transform.transform_point(&point)),
+            point,
+            epsilon = Scalar::from(1e-8),
+        );
+        assert!(transform.inverse().is_orientation_reversing());
+    }
 }
@@ -1,3 +1,4 @@
+use nalgebra::Isometry3;
 use parry3d_f64::query::{Ray, RayCast as _};
 
 use crate::Vector;
@@ -53,6 +54,20 @@ impl<const D: usize> Triangle<D> {
         self.points.sort();
         self
     }
+
+    /// Compute the smallest of the triangle's interior angles, in radians
+    ///
+    /// This is a measure for the quality of a triangle. Thin triangles, also
+    /// called slivers, have a small minimum angle.
+    pub fn min_angle(&self) -> Scalar {
+        let [a, b, c] = self.points;
+
+        [[a, b, c], [b, c, a], [c, a, b]]
+            .map(|[vertex, p, q]| (p - vertex).angle_to(&(q - vertex)))
+            .into_iter()
+            .min()
+            .expect("Triangle has three angles")
+    }
 }
 
 impl Triangle<2> {
@@ -102,6 +117,22 @@ impl Triangle<3> {
             .map(Into::into)
     }
 
+    /// Compute the minimum distance to another triangle
+    ///
+    /// Returns zero, if the triangles touch or intersect.
+    pub fn distance_to(&self, other: &Self) -> Scalar {
+        let identity = Isometry3::identity();
+
+        parry3d_f64::query::distance(
+            &identity,
+            &self.to_parry(),
+            &identity,
+            &other.to_parry(),
+        )
+        .expect("Distance between triangles should be supported")
+        .into()
+    }
+
     /// Compute the triangle's normal
     pub fn normal(&self) -> Vector<3> {
         self.to_parry()
@@ -138,10 +169,39 @@ pub enum Winding {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
 
     use super::Triangle;
 
+    #[test]
+    fn distance_to() {
+        let a = Triangle::from([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+
+        // Closest features are a vertex and the interior of a triangle.
+        let b = Triangle::from([[0.2, 0.2, 1.], [0.2, 0.2, 2.], [1., 1., 3.]]);
+        assert_abs_diff_eq!(
+            a.distance_to(&b),
+            Scalar::ONE,
+            epsilon = Scalar::from(1e-12)
+        );
+
+        // Closest features are two edges. The distances from the vertices of
+        // either triangle to the other triangle are all larger.
+        let c =
+            Triangle::from([[0.5, -1., -1.], [0.5, -1., 1.], [0.5, -2., 0.]]);
+        assert_abs_diff_eq!(
+            a.distance_to(&c),
+            Scalar::ONE,
+            epsilon = Scalar::from(1e-12)
+        );
+
+        // Intersecting triangles have no distance.
+        let d = Triangle::from([[0.2, 0.2, -1.], [0.2, 0.2, 1.], [1., 1., 1.]]);
+        assert_eq!(a.distance_to(&d), Scalar::ZERO);
+    }
+
     #[test]
     fn valid_triangle_2d() {
         let a = Point::from([0.0, 0.0]);
@@ -158,6 +218,23 @@ mod tests {
         let _triangle = Triangle::from([a, b, c]);
     }
 
+    #[test]
+    fn min_angle() {
+        let triangle = Triangle::from([[0., 0.], [1., 0.], [0., 1.]]);
+        assert_abs_diff_eq!(triangle.min_angle(), Scalar::PI / 4.);
+
+        let triangle = Triangle::from([
+            [0., 0., 0.],
+            [2., 0., 0.],
+            [1., 3_f64.sqrt(), 0.],
+        ]);
+        assert_abs_diff_eq!(
+            triangle.min_angle(),
+            Scalar::PI / 3.,
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
     #[test]
     #[should_panic]
     fn invalid_triangle_2d() {
@@ -2,7 +2,7 @@ use std::{fmt, ops};
 
 use super::{
     coordinates::{Uv, Xyz, T},
-    Scalar,
+    Epsilon, Scalar,
 };
 
 /// An n-dimensional vector
@@ -98,6 +98,53 @@ impl<const D: usize> Vector<D> {
 
         self.dot(&other.normalize())
     }
+
+    /// Compute the angle between this vector and another, in radians
+    ///
+    /// The result is between zero and pi. If either vector has zero length,
+    /// the angle is zero.
+    pub fn angle_to(&self, other: &Self) -> Scalar {
+        if self.magnitude() == Scalar::ZERO || other.magnitude() == Scalar::ZERO
+        {
+            return Scalar::ZERO;
+        }
+
+        // Due to rounding, the dot product of normalized vectors can end up
+        // slightly outside of the domain of `acos`, which would result in NaN.
+        let cos = self.normalize().dot(&other.normalize()).into_f64();
+        Scalar::from(cos.clamp(-1., 1.)).acos()
+    }
+
+    /// Compute the projection of this vector onto an axis
+    ///
+    /// Returns the zero vector, if `axis` has zero length.
+    pub fn project_onto(&self, axis: &Self) -> Self {
+        if axis.magnitude() == Scalar::ZERO {
+            return Self::default();
+        }
+
+        axis.normalize() * self.scalar_projection_onto(axis)
+    }
+
+    /// Compute the rejection of this vector from an axis
+    ///
+    /// This is the part of the vector that is perpendicular to `axis`, so the
+    /// projection and the rejection add up to the original vector.
+    pub fn reject_from(&self, axis: &Self) -> Self {
+        *self - self.project_onto(axis)
+    }
+
+    /// Determine whether the vector is equal to another, within `epsilon`
+    ///
+    /// The vectors are equal, if the magnitude of their difference is within
+    /// `epsilon`. For a relative epsilon, that is compared to the larger of
+    /// their magnitudes. See [`Epsilon`].
+    pub fn approx_eq(&self, other: &Self, epsilon: impl Into<Epsilon>) -> bool {
+        let difference = (*self - *other).magnitude();
+        let magnitude = self.magnitude().max(other.magnitude());
+
+        epsilon.into().allows(difference, magnitude)
+    }
 }
 
 impl Vector<1> {
@@ -393,6 +440,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn angle_to() {
+        let x = Vector::from([2., 0., 0.]);
+        let y = Vector::from([0., 3., 0.]);
+
+        assert_eq!(x.angle_to(&y), Scalar::PI / 2.);
+        assert_eq!(x.angle_to(&-x), Scalar::PI);
+        assert_eq!(x.angle_to(&Vector::from([0., 0., 0.])), Scalar::ZERO);
+
+        // The dot product of this vector with itself, once normalized, rounds
+        // to a value slightly larger than one.
+        let v = Vector::from([1.5, 0., 0.3]);
+        assert!(v.normalize().dot(&v.normalize()) > Scalar::ONE);
+        assert_eq!(v.angle_to(&v), Scalar::ZERO);
+    }
+
+    #[test]
+    fn project_onto_and_reject_from() {
+        let v = Vector::from([1., 2., 3.]);
+        let axis = Vector::unit_z() * 2.;
+
+        assert_eq!(v.project_onto(&axis), Vector::from([0., 0., 3.]));
+        assert_eq!(v.reject_from(&axis), Vector::from([1., 2., 0.]));
+
+        let zero = Vector::from([0., 0., 0.]);
+        assert_eq!(v.project_onto(&zero), zero);
+        assert_eq!(v.reject_from(&zero), v);
+    }
+
     #[test]
     fn is_between() {
         let v = Vector::from([1., 1.]);
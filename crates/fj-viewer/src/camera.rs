@@ -2,7 +2,7 @@
 use std::f64::consts::FRAC_PI_2;
 
 use fj_interop::{mesh::Mesh, model::Model};
-use fj_math::{Aabb, Point, Scalar, Transform, Vector};
+use fj_math::{Aabb, Point, Ray, Scalar, Transform, Vector};
 
 use crate::screen::NormalizedScreenPosition;
 
@@ -12,7 +12,7 @@ use crate::screen::NormalizedScreenPosition;
 /// is handled, for example) is not that of a camera freely flying through a
 /// static scene. Instead, the camera is static, and the model is freely
 /// translated and rotated.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Camera {
     /// The distance to the near plane
     near_plane: f64,
@@ -33,6 +33,9 @@ impl Camera {
 
     const INITIAL_FIELD_OF_VIEW_IN_X: f64 = FRAC_PI_2; // 90 degrees
 
+    /// The factor by which a framed model is smaller than the field of view
+    const FRAME_MARGIN: f64 = 1.2;
+
     /// Returns a new camera aligned for viewing a bounding box
     pub fn new() -> Self {
         Self {
@@ -93,27 +96,19 @@ impl Camera {
         cursor: Option<NormalizedScreenPosition>,
         mesh: &Mesh<Point<3>>,
     ) -> Option<FocusPoint> {
-        // Transform camera and cursor positions to model space.
-        let origin = self.position();
-        let cursor = self.cursor_to_model_space(cursor?);
-        let dir = (cursor - origin).normalize();
-
-        let mut min_t = None;
+        let ray = self.cursor_ray(cursor?);
+        let hit = mesh.intersect_ray(&ray, true)?;
 
-        for triangle in mesh.triangles() {
-            let t =
-                triangle
-                    .inner
-                    .cast_local_ray(origin, dir, f64::INFINITY, true);
+        Some(FocusPoint(hit.point))
+    }
 
-            if let Some(t) = t {
-                if t <= min_t.unwrap_or(t) {
-                    min_t = Some(t);
-                }
-            }
-        }
+    /// Compute the ray from the camera through the cursor, in model space
+    pub fn cursor_ray(&self, cursor: NormalizedScreenPosition) -> Ray<3> {
+        // Transform camera and cursor positions to model space.
+        let origin = self.position();
+        let cursor = self.cursor_to_model_space(cursor);
 
-        Some(FocusPoint(origin + dir * min_t?))
+        Ray::from_origin_and_direction(origin, (cursor - origin).normalize())
     }
 
     /// Access the transform from camera to model space.
@@ -176,6 +171,70 @@ impl Camera {
         self.translation = translation;
     }
 
+    /// Move the camera, so the whole bounding box is in view
+    ///
+    /// Keeps the current rotation, and places the camera in front of the
+    /// center of the bounding box, far enough away to fit the whole box into
+    /// the field of view, with some margin.
+    pub fn frame(&mut self, aabb: &Aabb<3>, aspect_ratio: f64) {
+        // Fitting the bounding sphere of the box, instead of the box itself,
+        // means the result doesn't depend on the rotation. It also takes care
+        // of flat models, as the sphere's radius is only zero, if the box is a
+        // single point. We need some distance in that case too.
+        let radius = (aabb.size().magnitude() / 2.).into_f64();
+        let radius = if radius > 0. { radius } else { 1. };
+
+        // The field of view is given in x. On a screen that is wider than it
+        // is high, the field of view in y is narrower, and limits the fit.
+        let half_field_of_view_in_x = self.field_of_view_in_x() / 2.;
+        let half_field_of_view_in_y =
+            (half_field_of_view_in_x.tan() / aspect_ratio).atan();
+        let half_field_of_view =
+            half_field_of_view_in_x.min(half_field_of_view_in_y);
+
+        let distance = radius * Self::FRAME_MARGIN / half_field_of_view.sin();
+
+        let center = self.rotation.transform_point(&aabb.center());
+        self.translation = Transform::translation(
+            Vector::from([0., 0., -distance]) - center.coords,
+        );
+    }
+
+    /// Determine whether any part of the bounding box might be in view
+    ///
+    /// Returns `false` only if the bounding box is completely outside of the
+    /// view frustum, which is defined by the field of view, the near and far
+    /// planes, and the aspect ratio of the screen.
+    pub fn is_in_view(&self, aabb: &Aabb<3>, aspect_ratio: f64) -> bool {
+        let transform = self.camera_to_model();
+
+        let tan_x = (self.field_of_view_in_x() / 2.).tan();
+        let tan_y = tan_x / aspect_ratio;
+
+        let points = aabb.vertices().map(|vertex| {
+            let point = transform.transform_point(&vertex);
+            let [x, y, z] = point.coords.components.map(Scalar::into_f64);
+
+            // The camera looks along the negative z-axis.
+            [x, y, -z]
+        });
+
+        let planes: [&dyn Fn([f64; 3]) -> bool; 6] = [
+            &|[_, _, depth]| depth < self.near_plane,
+            &|[_, _, depth]| depth > self.far_plane,
+            &|[x, _, depth]| x > depth * tan_x,
+            &|[x, _, depth]| -x > depth * tan_x,
+            &|[_, y, depth]| y > depth * tan_y,
+            &|[_, y, depth]| -y > depth * tan_y,
+        ];
+
+        // The bounding box is convex. If all of its corners are outside of
+        // the same plane, all of it is.
+        !planes
+            .iter()
+            .any(|is_outside| points.iter().all(|&point| is_outside(point)))
+    }
+
     /// Update the max and minimum rendering distance for this camera.
     pub fn update_planes(&mut self, aabb: &Aabb<3>) {
         let view_transform = self.camera_to_model();
@@ -6,6 +6,17 @@ pub struct DrawConfig {
 
     /// Toggle for displaying the wireframe model
     pub draw_mesh: bool,
+
+    /// Toggle for displaying the edges of the model
+    pub draw_edges: bool,
+
+    /// Toggle for displaying the construction edges of the model
+    pub draw_construction_edges: bool,
+
+    /// Toggle for skipping objects that are outside of the camera's view
+    ///
+    /// This only affects performance. The same pixels are drawn either way.
+    pub cull_objects: bool,
 }
 
 impl Default for DrawConfig {
@@ -13,6 +24,9 @@ impl Default for DrawConfig {
         Self {
             draw_model: true,
             draw_mesh: false,
+            draw_edges: false,
+            draw_construction_edges: true,
+            cull_objects: true,
         }
     }
 }
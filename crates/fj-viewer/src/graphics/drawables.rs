@@ -1,3 +1,5 @@
+use fj_math::Aabb;
+
 use super::{
     geometries::{Geometries, Geometry},
     pipelines::{Pipeline, Pipelines},
@@ -6,14 +8,24 @@ use super::{
 pub struct Drawables<'r> {
     pub model: Drawable<'r>,
     pub mesh: Drawable<'r>,
+    pub edges: Drawable<'r>,
+    pub construction_edges: Drawable<'r>,
 }
 
 impl<'r> Drawables<'r> {
     pub fn new(geometries: &'r Geometries, pipelines: &'r Pipelines) -> Self {
         let model = Drawable::new(&geometries.mesh, &pipelines.model);
         let mesh = Drawable::new(&geometries.mesh, &pipelines.mesh);
+        let edges = Drawable::new(&geometries.edges, &pipelines.lines);
+        let construction_edges =
+            Drawable::new(&geometries.construction_edges, &pipelines.lines);
 
-        Self { model, mesh }
+        Self {
+            model,
+            mesh,
+            edges,
+            construction_edges,
+        }
     }
 }
 
@@ -40,4 +52,33 @@ impl<'a> Drawable<'a> {
 
         render_pass.draw_indexed(0..self.geometry.num_indices, 0, 0..1);
     }
+
+    /// Draw only the objects whose bounding box passes `is_visible`
+    ///
+    /// If the geometry isn't partitioned into objects, all of it is drawn.
+    pub fn draw_visible<'b>(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'b>,
+        is_visible: impl Fn(&Aabb<3>) -> bool,
+    ) where
+        'a: 'b,
+    {
+        if self.geometry.objects.is_empty() {
+            self.draw(render_pass);
+            return;
+        }
+
+        render_pass.set_pipeline(&self.pipeline.0);
+        render_pass.set_vertex_buffer(0, self.geometry.vertex_buffer.slice(..));
+        render_pass.set_index_buffer(
+            self.geometry.index_buffer.slice(..),
+            wgpu::IndexFormat::Uint32,
+        );
+
+        for object in &self.geometry.objects {
+            if is_visible(&object.aabb) {
+                render_pass.draw_indexed(object.indices.clone(), 0, 0..1);
+            }
+        }
+    }
 }
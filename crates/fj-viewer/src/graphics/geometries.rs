@@ -2,18 +2,31 @@ use std::convert::TryInto;
 
 use wgpu::util::DeviceExt;
 
-use super::vertices::{Vertex, Vertices};
+use super::vertices::{Object, Vertices};
 
 #[derive(Debug)]
 pub struct Geometries {
     pub mesh: Geometry,
+    pub edges: Geometry,
+    pub construction_edges: Geometry,
 }
 
 impl Geometries {
-    pub fn new(device: &wgpu::Device, mesh: &Vertices) -> Self {
-        let mesh = Geometry::new(device, mesh.vertices(), mesh.indices());
+    pub fn new(
+        device: &wgpu::Device,
+        mesh: &Vertices,
+        edges: &Vertices,
+        construction_edges: &Vertices,
+    ) -> Self {
+        let mesh = Geometry::new(device, mesh);
+        let edges = Geometry::new(device, edges);
+        let construction_edges = Geometry::new(device, construction_edges);
 
-        Self { mesh }
+        Self {
+            mesh,
+            edges,
+            construction_edges,
+        }
     }
 }
 
@@ -22,19 +35,20 @@ pub struct Geometry {
     pub vertex_buffer: wgpu::Buffer,
     pub index_buffer: wgpu::Buffer,
     pub num_indices: u32,
+
+    /// The objects that the geometry is partitioned into, if any
+    pub objects: Vec<Object>,
 }
 
 impl Geometry {
-    fn new(
-        device: &wgpu::Device,
-        vertices: &[Vertex],
-        indices: &[u32],
-    ) -> Self {
+    fn new(device: &wgpu::Device, vertices: &Vertices) -> Self {
+        let indices = vertices.indices();
+
         Self {
             vertex_buffer: device.create_buffer_init(
                 &wgpu::util::BufferInitDescriptor {
                     label: None,
-                    contents: bytemuck::cast_slice(vertices),
+                    contents: bytemuck::cast_slice(vertices.vertices()),
                     usage: wgpu::BufferUsages::VERTEX,
                 },
             ),
@@ -49,6 +63,7 @@ impl Geometry {
                 .len()
                 .try_into()
                 .expect("`usize` couldn't be cast to `u32`"),
+            objects: vertices.objects().to_vec(),
         }
     }
 }
@@ -5,6 +5,7 @@ mod drawables;
 mod geometries;
 mod model;
 mod navigation_cube;
+mod offscreen;
 mod pipelines;
 mod renderer;
 mod shaders;
@@ -15,7 +16,9 @@ mod vertices;
 
 pub use self::{
     draw_config::DrawConfig,
-    renderer::{DrawError, Renderer, RendererInitError},
+    offscreen::{render_to_image, RenderToImageError},
+    renderer::{DrawError, Renderer, RendererInitError, ScreenshotError},
+    vertices::Vertices,
 };
 
 pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
@@ -0,0 +1,224 @@
+use std::sync::mpsc;
+
+use image::RgbaImage;
+use thiserror::Error;
+
+use crate::{camera::Camera, screen::ScreenSize};
+
+use super::{
+    drawables::Drawables,
+    geometries::Geometries,
+    pipelines::Pipelines,
+    renderer::{
+        create_depth_buffer, create_uniforms, features, request_device,
+        RendererInitError,
+    },
+    transform::Transform,
+    uniforms::Uniforms,
+    vertices::Vertices,
+};
+
+/// The color format of the offscreen texture
+///
+/// This matches the memory layout of [`RgbaImage`], so the texture can be
+/// copied into the image without conversion.
+const COLOR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+/// Render the provided vertices to an image, without requiring a window
+///
+/// The image is rendered without multisampling, so rendering the same vertices
+/// with the same camera produces the same image every time, as long as the
+/// same graphics driver is used.
+pub async fn render_to_image(
+    vertices: &Vertices,
+    camera: &Camera,
+    size: ScreenSize,
+) -> Result<RgbaImage, RenderToImageError> {
+    let ScreenSize { width, height } = size;
+
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::PRIMARY,
+        ..Default::default()
+    });
+
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            force_fallback_adapter: false,
+            compatible_surface: None,
+        })
+        .await
+        .ok_or(RendererInitError::RequestAdapter)?;
+
+    let (device, queue) = request_device(&adapter, features(&adapter))
+        .await
+        .map_err(RendererInitError::from)?;
+
+    let (uniform_buffer, bind_group, bind_group_layout) =
+        create_uniforms(&device);
+
+    let aspect_ratio = f64::from(width) / f64::from(height);
+    let uniforms = Uniforms {
+        transform: Transform::for_vertices(camera, aspect_ratio),
+        transform_normals: Transform::for_normals(camera),
+    };
+    queue.write_buffer(&uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+
+    let geometries = Geometries::new(
+        &device,
+        vertices,
+        &Vertices::empty(),
+        &Vertices::empty(),
+    );
+    let pipelines =
+        Pipelines::new(&device, &bind_group_layout, COLOR_FORMAT, 1);
+
+    let extent = wgpu::Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    let color_texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: extent,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: COLOR_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let color_view =
+        color_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let depth_view = create_depth_buffer(&device, width, height, 1);
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: None,
+        });
+
+    // Need this block here, as a render pass only takes effect once it's
+    // dropped.
+    {
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &color_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: Some(
+                    wgpu::RenderPassDepthStencilAttachment {
+                        view: &depth_view,
+                        depth_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(1.0),
+                            store: true,
+                        }),
+                        stencil_ops: None,
+                    },
+                ),
+            });
+        render_pass.set_bind_group(0, &bind_group, &[]);
+
+        let drawables = Drawables::new(&geometries, &pipelines);
+        drawables.model.draw(&mut render_pass);
+    }
+
+    let pixels = read_texture(&device, &queue, encoder, &color_texture)?;
+
+    let image = RgbaImage::from_raw(width, height, pixels)
+        .expect("Pixels should make up exactly one image of the given size");
+
+    Ok(image)
+}
+
+/// Copy a texture with 4 bytes per pixel back from the graphics device
+///
+/// Submits the provided encoder, after appending the copy to it. Returns the
+/// rows of pixels of the texture, without any padding between them.
+pub fn read_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    mut encoder: wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+) -> Result<Vec<u8>, wgpu::BufferAsyncError> {
+    let (width, height) = (texture.width(), texture.height());
+
+    // Rows in the buffer that we copy the texture into must be aligned. We need
+    // to strip that padding again, when reading the image back.
+    let unpadded_bytes_per_row = width * 4;
+    let padded_bytes_per_row = {
+        let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        (unpadded_bytes_per_row + alignment - 1) / alignment * alignment
+    };
+    let output_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: u64::from(padded_bytes_per_row) * u64::from(height),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &output_buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        texture.size(),
+    );
+
+    queue.submit(Some(encoder.finish()));
+
+    let output = output_buffer.slice(..);
+
+    let (sender, receiver) = mpsc::channel();
+    output.map_async(wgpu::MapMode::Read, move |result| {
+        // The receiver is only dropped after the callback has been called, so
+        // this can't fail.
+        let _ = sender.send(result);
+    });
+    device.poll(wgpu::Maintain::Wait);
+    receiver
+        .recv()
+        .expect("Waiting for device should have called mapping callback")?;
+
+    let pixels = output
+        .get_mapped_range()
+        .chunks_exact(padded_bytes_per_row as usize)
+        .flat_map(|row| &row[..unpadded_bytes_per_row as usize])
+        .copied()
+        .collect();
+    output_buffer.unmap();
+
+    Ok(pixels)
+}
+
+/// Error rendering to an image
+///
+/// Returned by [`Viewer::render_to_image`].
+///
+/// [`Viewer::render_to_image`]: crate::Viewer::render_to_image
+#[derive(Error, Debug)]
+pub enum RenderToImageError {
+    /// Error initializing the graphics device
+    #[error("Error initializing renderer: {0}")]
+    Init(#[from] RendererInitError),
+
+    /// Error reading the rendered image back from the graphics device
+    #[error("Error reading back rendered image: {0}")]
+    ReadBack(#[from] wgpu::BufferAsyncError),
+}
@@ -3,7 +3,7 @@ use std::mem::size_of;
 use super::{
     shaders::{Shader, Shaders},
     vertices::Vertex,
-    DEPTH_FORMAT, SAMPLE_COUNT,
+    DEPTH_FORMAT,
 };
 
 #[derive(Debug)]
@@ -18,6 +18,7 @@ impl Pipelines {
         device: &wgpu::Device,
         bind_group_layout: &wgpu::BindGroupLayout,
         color_format: wgpu::TextureFormat,
+        sample_count: u32,
     ) -> Self {
         let pipeline_layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -36,6 +37,7 @@ impl Pipelines {
                 wgpu::PrimitiveTopology::TriangleList,
                 wgpu::PolygonMode::Fill,
                 color_format,
+                sample_count,
             ),
             mesh: Pipeline::new(
                 device,
@@ -44,14 +46,18 @@ impl Pipelines {
                 wgpu::PrimitiveTopology::TriangleList,
                 wgpu::PolygonMode::Line,
                 color_format,
+                sample_count,
             ),
             lines: Pipeline::new(
                 device,
                 &pipeline_layout,
                 shaders.lines(),
                 wgpu::PrimitiveTopology::LineList,
-                wgpu::PolygonMode::Line,
+                // The polygon mode doesn't affect lines. Using `Fill` here
+                // means this pipeline doesn't require `POLYGON_MODE_LINE`.
+                wgpu::PolygonMode::Fill,
                 color_format,
+                sample_count,
             ),
         }
     }
@@ -68,6 +74,7 @@ impl Pipeline {
         topology: wgpu::PrimitiveTopology,
         polygon_mode: wgpu::PolygonMode,
         color_format: wgpu::TextureFormat,
+        sample_count: u32,
     ) -> Self {
         let pipeline =
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
@@ -75,7 +82,7 @@ impl Pipeline {
                 layout: Some(pipeline_layout),
                 vertex: wgpu::VertexState {
                     module: shader.module,
-                    entry_point: "vertex",
+                    entry_point: shader.vert_entry,
                     buffers: &[wgpu::VertexBufferLayout {
                         array_stride: size_of::<Vertex>() as u64,
                         step_mode: wgpu::VertexStepMode::Vertex,
@@ -108,9 +115,10 @@ impl Pipeline {
                     bias: wgpu::DepthBiasState::default(),
                 }),
                 multisample: wgpu::MultisampleState {
-                    count: SAMPLE_COUNT,
+                    count: sample_count,
                     mask: !0,
-                    alpha_to_coverage_enabled: true,
+                    // Alpha to coverage only makes sense with multisampling.
+                    alpha_to_coverage_enabled: sample_count > 1,
                 },
                 fragment: Some(wgpu::FragmentState {
                     module: shader.module,
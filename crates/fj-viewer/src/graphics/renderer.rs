@@ -1,5 +1,7 @@
 use std::{io, mem::size_of, vec};
 
+use fj_math::Aabb;
+use image::RgbaImage;
 use thiserror::Error;
 use tracing::debug;
 use wgpu::util::DeviceExt as _;
@@ -11,9 +13,9 @@ use crate::{
 
 use super::{
     draw_config::DrawConfig, drawables::Drawables, geometries::Geometries,
-    navigation_cube::NavigationCubeRenderer, pipelines::Pipelines,
-    transform::Transform, uniforms::Uniforms, vertices::Vertices, DEPTH_FORMAT,
-    SAMPLE_COUNT,
+    navigation_cube::NavigationCubeRenderer, offscreen::read_texture,
+    pipelines::Pipelines, transform::Transform, uniforms::Uniforms,
+    vertices::Vertices, DEPTH_FORMAT, SAMPLE_COUNT,
 };
 
 /// Graphics rendering state and target abstraction
@@ -57,43 +59,8 @@ impl Renderer {
             .await
             .ok_or(RendererInitError::RequestAdapter)?;
 
-        let features = {
-            let desired_features = wgpu::Features::POLYGON_MODE_LINE;
-            let available_features = adapter.features();
-
-            // By requesting the intersection of desired and available features,
-            // we prevent two things:
-            //
-            // 1. That requesting the device panics, which would happen if we
-            //    requested unavailable features.
-            // 2. That a developer ends up accidentally using features that
-            //    happen to be available on their machine, but that aren't
-            //    necessarily available for all the users.
-            desired_features.intersection(available_features)
-        };
-
-        let limits = {
-            // This is the lowest of the available defaults. It should guarantee
-            // that we can run pretty much everywhere.
-            let lowest_limits = wgpu::Limits::downlevel_webgl2_defaults();
-
-            // However, these lowest limits aren't necessarily capable of
-            // supporting the screen resolution of our current platform, so
-            // let's amend them.
-            let supported_limits = adapter.limits();
-            lowest_limits.using_resolution(supported_limits)
-        };
-
-        let (device, queue) = adapter
-            .request_device(
-                &wgpu::DeviceDescriptor {
-                    label: None,
-                    features,
-                    limits,
-                },
-                None,
-            )
-            .await?;
+        let features = features(&adapter);
+        let (device, queue) = request_device(&adapter, features).await?;
 
         let color_format = 'color_format: {
             let capabilities = surface.get_capabilities(&adapter);
@@ -144,49 +111,24 @@ impl Renderer {
         surface.configure(&device, &surface_config);
 
         let frame_buffer = Self::create_frame_buffer(&device, &surface_config);
-        let depth_view = Self::create_depth_buffer(&device, &surface_config);
+        let depth_view =
+            create_depth_buffer(&device, width, height, SAMPLE_COUNT);
 
-        let uniform_buffer =
-            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: None,
-                contents: bytemuck::cast_slice(&[Uniforms::default()]),
-                usage: wgpu::BufferUsages::UNIFORM
-                    | wgpu::BufferUsages::COPY_DST,
-            });
-        let bind_group_layout =
-            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::all(),
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: wgpu::BufferSize::new(size_of::<
-                            Uniforms,
-                        >(
-                        )
-                            as u64),
-                    },
-                    count: None,
-                }],
-                label: None,
-            });
-        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            layout: &bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
-                    buffer: &uniform_buffer,
-                    offset: 0,
-                    size: None,
-                }),
-            }],
-            label: None,
-        });
+        let (uniform_buffer, bind_group, bind_group_layout) =
+            create_uniforms(&device);
 
-        let geometries = Geometries::new(&device, &Vertices::empty());
-        let pipelines =
-            Pipelines::new(&device, &bind_group_layout, color_format);
+        let geometries = Geometries::new(
+            &device,
+            &Vertices::empty(),
+            &Vertices::empty(),
+            &Vertices::empty(),
+        );
+        let pipelines = Pipelines::new(
+            &device,
+            &bind_group_layout,
+            color_format,
+            SAMPLE_COUNT,
+        );
 
         let navigation_cube_renderer =
             NavigationCubeRenderer::new(&device, &queue, &surface_config);
@@ -212,8 +154,14 @@ impl Renderer {
     }
 
     /// Updates the geometry of the model being rendered.
-    pub fn update_geometry(&mut self, mesh: Vertices) {
-        self.geometries = Geometries::new(&self.device, &mesh);
+    pub fn update_geometry(
+        &mut self,
+        mesh: Vertices,
+        edges: Vertices,
+        construction_edges: Vertices,
+    ) {
+        self.geometries =
+            Geometries::new(&self.device, &mesh, &edges, &construction_edges);
     }
 
     /// Resizes the render surface.
@@ -228,8 +176,12 @@ impl Renderer {
 
         self.frame_buffer =
             Self::create_frame_buffer(&self.device, &self.surface_config);
-        self.depth_view =
-            Self::create_depth_buffer(&self.device, &self.surface_config);
+        self.depth_view = create_depth_buffer(
+            &self.device,
+            size.width,
+            size.height,
+            SAMPLE_COUNT,
+        );
     }
 
     /// Draws the renderer, camera, and config state to the window.
@@ -238,19 +190,6 @@ impl Renderer {
         camera: &Camera,
         config: &DrawConfig,
     ) -> Result<(), DrawError> {
-        let aspect_ratio = f64::from(self.surface_config.width)
-            / f64::from(self.surface_config.height);
-        let uniforms = Uniforms {
-            transform: Transform::for_vertices(camera, aspect_ratio),
-            transform_normals: Transform::for_normals(camera),
-        };
-
-        self.queue.write_buffer(
-            &self.uniform_buffer,
-            0,
-            bytemuck::cast_slice(&[uniforms]),
-        );
-
         let surface_texture = match self.surface.get_current_texture() {
             Ok(surface_texture) => surface_texture,
             Err(wgpu::SurfaceError::Timeout) => {
@@ -272,6 +211,93 @@ impl Renderer {
         let mut encoder = self.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor { label: None },
         );
+        self.render(&color_view, &mut encoder, camera, config);
+
+        let command_buffer = encoder.finish();
+        self.queue.submit(Some(command_buffer));
+
+        debug!("Presenting...");
+        surface_texture.present();
+
+        debug!("Finished drawing.");
+        Ok(())
+    }
+
+    /// Captures the current frame, as it is drawn to the window
+    ///
+    /// The frame is rendered once more, exactly like [`Renderer::draw`] would,
+    /// but into a texture that can be read back, instead of the window.
+    pub fn capture(
+        &mut self,
+        camera: &Camera,
+        config: &DrawConfig,
+    ) -> Result<RgbaImage, ScreenshotError> {
+        let format = self.surface_config.format;
+        let swap_red_and_blue = match format {
+            wgpu::TextureFormat::Rgba8Unorm
+            | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            wgpu::TextureFormat::Bgra8Unorm
+            | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            format => return Err(ScreenshotError::UnsupportedFormat(format)),
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: self.surface_config.width,
+                height: self.surface_config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let color_view =
+            texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: None },
+        );
+        self.render(&color_view, &mut encoder, camera, config);
+
+        let mut pixels =
+            read_texture(&self.device, &self.queue, encoder, &texture)?;
+
+        if swap_red_and_blue {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        let image =
+            RgbaImage::from_raw(texture.width(), texture.height(), pixels)
+                .expect("Pixels should make up one image of texture size");
+
+        Ok(image)
+    }
+
+    fn render(
+        &mut self,
+        color_view: &wgpu::TextureView,
+        encoder: &mut wgpu::CommandEncoder,
+        camera: &Camera,
+        config: &DrawConfig,
+    ) {
+        let aspect_ratio = self.aspect_ratio();
+        let uniforms = Uniforms {
+            transform: Transform::for_vertices(camera, aspect_ratio),
+            transform_normals: Transform::for_normals(camera),
+        };
+
+        self.queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::cast_slice(&[uniforms]),
+        );
 
         // Need this block here, as a render pass only takes effect once it's
         // dropped.
@@ -282,7 +308,7 @@ impl Renderer {
                     color_attachments: &[Some(
                         wgpu::RenderPassColorAttachment {
                             view: &self.frame_buffer,
-                            resolve_target: Some(&color_view),
+                            resolve_target: Some(color_view),
                             ops: wgpu::Operations {
                                 load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
                                 // Not necessary, due to MSAA being enabled.
@@ -305,31 +331,36 @@ impl Renderer {
 
             let drawables = Drawables::new(&self.geometries, &self.pipelines);
 
+            // Objects that are outside of the view frustum would be clipped
+            // entirely. Skipping them doesn't change the result.
+            let is_visible = |aabb: &Aabb<3>| {
+                !config.cull_objects || camera.is_in_view(aabb, aspect_ratio)
+            };
+
             if config.draw_model {
-                drawables.model.draw(&mut render_pass);
+                drawables.model.draw_visible(&mut render_pass, is_visible);
             }
 
             if self.is_line_drawing_available() && config.draw_mesh {
-                drawables.mesh.draw(&mut render_pass);
+                drawables.mesh.draw_visible(&mut render_pass, is_visible);
+            }
+
+            if config.draw_edges {
+                drawables.edges.draw(&mut render_pass);
+            }
+
+            if config.draw_construction_edges {
+                drawables.construction_edges.draw(&mut render_pass);
             }
         }
 
         self.navigation_cube_renderer.draw(
-            &color_view,
-            &mut encoder,
+            color_view,
+            encoder,
             &self.queue,
             aspect_ratio,
             camera.rotation,
         );
-
-        let command_buffer = encoder.finish();
-        self.queue.submit(Some(command_buffer));
-
-        debug!("Presenting...");
-        surface_texture.present();
-
-        debug!("Finished drawing.");
-        Ok(())
     }
 
     fn create_frame_buffer(
@@ -353,26 +384,10 @@ impl Renderer {
         texture.create_view(&wgpu::TextureViewDescriptor::default())
     }
 
-    fn create_depth_buffer(
-        device: &wgpu::Device,
-        surface_config: &wgpu::SurfaceConfiguration,
-    ) -> wgpu::TextureView {
-        let texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: None,
-            size: wgpu::Extent3d {
-                width: surface_config.width,
-                height: surface_config.height,
-                depth_or_array_layers: 1,
-            },
-            mip_level_count: 1,
-            sample_count: SAMPLE_COUNT,
-            dimension: wgpu::TextureDimension::D2,
-            format: DEPTH_FORMAT,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            view_formats: &[],
-        });
-
-        texture.create_view(&wgpu::TextureViewDescriptor::default())
+    /// Returns the aspect ratio of the render surface
+    pub fn aspect_ratio(&self) -> f64 {
+        f64::from(self.surface_config.width)
+            / f64::from(self.surface_config.height)
     }
 
     /// Returns true if the renderer's adapter can draw lines
@@ -381,6 +396,118 @@ impl Renderer {
     }
 }
 
+/// Determine the features to request from the adapter
+pub fn features(adapter: &wgpu::Adapter) -> wgpu::Features {
+    let desired_features = wgpu::Features::POLYGON_MODE_LINE;
+    let available_features = adapter.features();
+
+    // By requesting the intersection of desired and available features, we
+    // prevent two things:
+    //
+    // 1. That requesting the device panics, which would happen if we requested
+    //    unavailable features.
+    // 2. That a developer ends up accidentally using features that happen to
+    //    be available on their machine, but that aren't necessarily available
+    //    for all the users.
+    desired_features.intersection(available_features)
+}
+
+/// Request a device with the provided features from the adapter
+pub async fn request_device(
+    adapter: &wgpu::Adapter,
+    features: wgpu::Features,
+) -> Result<(wgpu::Device, wgpu::Queue), wgpu::RequestDeviceError> {
+    let limits = {
+        // This is the lowest of the available defaults. It should guarantee
+        // that we can run pretty much everywhere.
+        let lowest_limits = wgpu::Limits::downlevel_webgl2_defaults();
+
+        // However, these lowest limits aren't necessarily capable of
+        // supporting the screen resolution of our current platform, so
+        // let's amend them.
+        let supported_limits = adapter.limits();
+        lowest_limits.using_resolution(supported_limits)
+    };
+
+    adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                features,
+                limits,
+            },
+            None,
+        )
+        .await
+}
+
+/// Create the uniform buffer, and the bind group that makes it available
+pub fn create_uniforms(
+    device: &wgpu::Device,
+) -> (wgpu::Buffer, wgpu::BindGroup, wgpu::BindGroupLayout) {
+    let uniform_buffer =
+        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&[Uniforms::default()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+    let bind_group_layout =
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::all(),
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: wgpu::BufferSize::new(
+                        size_of::<Uniforms>() as u64,
+                    ),
+                },
+                count: None,
+            }],
+            label: None,
+        });
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout: &bind_group_layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                buffer: &uniform_buffer,
+                offset: 0,
+                size: None,
+            }),
+        }],
+        label: None,
+    });
+
+    (uniform_buffer, bind_group, bind_group_layout)
+}
+
+/// Create the depth buffer that goes with a color target of the given size
+pub fn create_depth_buffer(
+    device: &wgpu::Device,
+    width: u32,
+    height: u32,
+    sample_count: u32,
+) -> wgpu::TextureView {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format: DEPTH_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+
+    texture.create_view(&wgpu::TextureViewDescriptor::default())
+}
+
 /// Error describing the set of render surface initialization errors
 #[derive(Error, Debug)]
 pub enum RendererInitError {
@@ -409,3 +536,21 @@ pub enum RendererInitError {
 #[derive(Error, Debug)]
 #[error("Error acquiring output surface: {0}")]
 pub struct DrawError(#[from] wgpu::SurfaceError);
+
+/// Error capturing a screenshot
+///
+/// Returned by [`Renderer::capture`].
+#[derive(Error, Debug)]
+pub enum ScreenshotError {
+    /// The format of the window surface can't be converted into an image
+    #[error("Unsupported surface format: {0:?}")]
+    UnsupportedFormat(wgpu::TextureFormat),
+
+    /// Error reading the captured frame back from the graphics device
+    #[error("Error reading back captured frame: {0}")]
+    ReadBack(#[from] wgpu::BufferAsyncError),
+
+    /// Error writing the screenshot to disk
+    #[error("Error saving screenshot: {0}")]
+    Save(#[from] image::ImageError),
+}
@@ -24,6 +24,21 @@ struct FragmentOutput {
 
 @vertex
 fn vertex(in: VertexInput) -> VertexOutput {
+    return transform_vertex(in);
+}
+
+@vertex
+fn vert_lines(in: VertexInput) -> VertexOutput {
+    var out = transform_vertex(in);
+
+    // Lines are drawn on top of the triangles they border. Move them towards
+    // the camera a bit, so they aren't hidden by those triangles.
+    out.position.z -= 0.0001 * out.position.w;
+
+    return out;
+}
+
+fn transform_vertex(in: VertexInput) -> VertexOutput {
     var out: VertexOutput;
     out.normal = (uniforms.transform_normals * vec4<f32>(in.normal, 0.0)).xyz;
     out.position = uniforms.transform * vec4<f32>(in.position, 1.0);
@@ -18,6 +18,7 @@ impl Shaders {
     pub fn model(&self) -> Shader {
         Shader {
             module: &self.0,
+            vert_entry: "vertex",
             frag_entry: "frag_model",
         }
     }
@@ -25,6 +26,7 @@ impl Shaders {
     pub fn mesh(&self) -> Shader {
         Shader {
             module: &self.0,
+            vert_entry: "vertex",
             frag_entry: "frag_mesh",
         }
     }
@@ -32,6 +34,7 @@ impl Shaders {
     pub fn lines(&self) -> Shader {
         Shader {
             module: &self.0,
+            vert_entry: "vert_lines",
             frag_entry: "frag_lines",
         }
     }
@@ -40,5 +43,6 @@ impl Shaders {
 #[derive(Clone, Copy)]
 pub struct Shader<'r> {
     pub module: &'r wgpu::ShaderModule,
+    pub vert_entry: &'static str,
     pub frag_entry: &'static str,
 }
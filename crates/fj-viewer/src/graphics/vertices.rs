@@ -1,10 +1,19 @@
+use std::ops::Range;
+
 use bytemuck::{Pod, Zeroable};
 use fj_interop::mesh::{Index, Mesh};
+use fj_math::{Aabb, Scalar, Segment};
 
 #[derive(Debug)]
 pub struct Vertices {
     vertices: Vec<Vertex>,
     indices: Vec<Index>,
+
+    /// The objects that the indices are partitioned into
+    ///
+    /// Empty, if the vertices don't come from a mesh with objects. See
+    /// [`Mesh::objects`].
+    objects: Vec<Object>,
 }
 
 impl Vertices {
@@ -12,7 +21,69 @@ impl Vertices {
         Self {
             vertices: Vec::new(),
             indices: Vec::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    /// Construct vertices that draw the segments as dashed lines
+    ///
+    /// Dashes and the gaps between them are `dash_length` long. If a segment
+    /// starts where the previous one ended, the pattern continues across them.
+    /// That way, curved edges, which consist of many short segments, are
+    /// dashed too.
+    ///
+    /// The segments are drawn as solid lines, if `dash_length` isn't positive.
+    pub fn dashed(segments: &[Segment<3>], dash_length: Scalar) -> Self {
+        let dash_length = dash_length.into_f64();
+        if dash_length <= 0. {
+            return Self::from(segments);
+        }
+
+        let mut dashes = Vec::new();
+
+        // How far into the current dash (if smaller than `dash_length`) or
+        // the gap after it (otherwise) we are.
+        let mut phase = 0.;
+        let mut previous_end = None;
+
+        for segment in segments {
+            let [start, end] = segment.points();
+            if previous_end != Some(start) {
+                phase = 0.;
+            }
+            previous_end = Some(end);
+
+            let direction = end - start;
+            let length = direction.magnitude().into_f64();
+            let point_at = |s: f64| start + direction * (s / length);
+
+            let mut s = 0.;
+            while s < length {
+                let is_dash = phase < dash_length;
+                let phase_end = if is_dash {
+                    dash_length
+                } else {
+                    dash_length * 2.
+                };
+                let s_end = f64::min(s + phase_end - phase, length);
+
+                let [a, b] = [s, s_end].map(point_at);
+                if is_dash && a != b {
+                    dashes.push(Segment::from([a, b]));
+                }
+
+                // Setting the phase exactly, where possible, keeps rounding
+                // errors from accumulating.
+                phase = if s_end < length {
+                    phase_end % (dash_length * 2.)
+                } else {
+                    (phase + (s_end - s)) % (dash_length * 2.)
+                };
+                s = s_end;
+            }
         }
+
+        Self::from(dashes.as_slice())
     }
 
     pub fn vertices(&self) -> &[Vertex] {
@@ -22,6 +93,10 @@ impl Vertices {
     pub fn indices(&self) -> &[Index] {
         self.indices.as_slice()
     }
+
+    pub fn objects(&self) -> &[Object] {
+        self.objects.as_slice()
+    }
 }
 
 impl From<&Mesh<fj_math::Point<3>>> for Vertices {
@@ -50,10 +125,65 @@ impl From<&Mesh<fj_math::Point<3>>> for Vertices {
 
         let indices = m.indices().collect();
 
-        Self { vertices, indices }
+        // Each triangle got three indices above, in the same order as the
+        // triangles of the mesh.
+        let objects = mesh
+            .objects()
+            .map(|triangles| {
+                let aabb = Aabb::<3>::from_points(
+                    mesh.triangles()
+                        .skip(triangles.start)
+                        .take(triangles.len())
+                        .flat_map(|triangle| triangle.inner.points()),
+                );
+                let indices =
+                    index(triangles.start * 3)..index(triangles.end * 3);
+
+                Object { indices, aabb }
+            })
+            .collect();
+
+        Self {
+            vertices,
+            indices,
+            objects,
+        }
     }
 }
 
+impl From<&[Segment<3>]> for Vertices {
+    fn from(segments: &[Segment<3>]) -> Self {
+        let vertices = segments
+            .iter()
+            .flat_map(|segment| segment.points())
+            .map(|point| Vertex {
+                position: point.into(),
+                // Lines are not lit, so they don't need normals.
+                normal: [0.; 3],
+                color: [0., 0., 0., 1.],
+            })
+            .collect::<Vec<_>>();
+
+        let indices = (0..vertices.len()).map(index).collect();
+
+        Self {
+            vertices,
+            indices,
+            objects: Vec::new(),
+        }
+    }
+}
+
+/// The part of the vertices that comes from one object of a mesh
+#[derive(Clone, Debug)]
+pub struct Object {
+    /// The range of indices that make up the object's triangles
+    pub indices: Range<Index>,
+
+    /// The axis-aligned bounding box of the object
+    pub aabb: Aabb<3>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 #[repr(C)]
 pub struct Vertex {
@@ -61,3 +191,7 @@ pub struct Vertex {
     pub normal: [f32; 3],
     pub color: [f32; 4],
 }
+
+fn index(index: usize) -> Index {
+    index.try_into().expect("`usize` couldn't be cast to `u32`")
+}
@@ -18,7 +18,8 @@ mod screen;
 mod viewer;
 
 pub use self::{
-    graphics::RendererInitError,
+    camera::Camera,
+    graphics::{RenderToImageError, RendererInitError, ScreenshotError},
     input::InputEvent,
     screen::{NormalizedScreenPosition, Screen, ScreenSize},
     viewer::Viewer,
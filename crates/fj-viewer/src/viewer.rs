@@ -1,13 +1,16 @@
+use std::path::Path;
+
 use fj_interop::model::Model;
 use fj_math::Aabb;
+use image::{ImageFormat, RgbaImage};
 use tracing::warn;
 
 use crate::{
     camera::{Camera, FocusPoint},
-    graphics::{DrawConfig, Renderer},
+    graphics::{self, DrawConfig, Renderer, Vertices},
     input::InputHandler,
-    InputEvent, NormalizedScreenPosition, RendererInitError, Screen,
-    ScreenSize,
+    InputEvent, NormalizedScreenPosition, RenderToImageError,
+    RendererInitError, Screen, ScreenSize, ScreenshotError,
 };
 
 /// The Fornjot model viewer
@@ -21,6 +24,9 @@ pub struct Viewer {
 }
 
 impl Viewer {
+    /// The number of dashes that fit along the diagonal of the model
+    const DASHES_PER_MODEL: f64 = 200.;
+
     /// Construct a new instance of `Viewer`
     pub async fn new(screen: &impl Screen) -> Result<Self, RendererInitError> {
         let renderer = Renderer::new(screen).await?;
@@ -52,9 +58,49 @@ impl Viewer {
         }
     }
 
+    /// Toggle the "draw edges" setting
+    pub fn toggle_draw_edges(&mut self) {
+        self.draw_config.draw_edges = !self.draw_config.draw_edges;
+    }
+
+    /// Toggle the "draw construction edges" setting
+    ///
+    /// Construction edges are drawn as dashed lines.
+    pub fn toggle_draw_construction_edges(&mut self) {
+        self.draw_config.draw_construction_edges =
+            !self.draw_config.draw_construction_edges;
+    }
+
+    /// Toggle the "cull objects" setting
+    ///
+    /// If enabled, objects of the model that are outside of the camera's view
+    /// are not drawn. This makes drawing models that consist of many objects
+    /// faster, without changing what's visible.
+    pub fn toggle_cull_objects(&mut self) {
+        self.draw_config.cull_objects = !self.draw_config.cull_objects;
+    }
+
+    /// Enable or disable drawing the edges of the model
+    ///
+    /// The edges are drawn as lines on top of the model. They are based on the
+    /// approximations of the model's edges, so curved edges appear curved,
+    /// instead of showing the triangulation of the faces.
+    pub fn set_draw_edges(&mut self, draw_edges: bool) {
+        self.draw_config.draw_edges = draw_edges;
+    }
+
     /// Handle the model being updated
     pub fn handle_model_update(&mut self, model: Model) {
-        self.renderer.update_geometry((&model.mesh).into());
+        // The dashes of the construction edges scale with the model, so they
+        // stay distinguishable from solid lines at any size.
+        let dash_length =
+            model.aabb.size().magnitude() / Self::DASHES_PER_MODEL;
+
+        self.renderer.update_geometry(
+            (&model.mesh).into(),
+            model.edges.as_slice().into(),
+            Vertices::dashed(&model.construction_edges, dash_length),
+        );
 
         let aabb = model.aabb;
         if self.model.replace(model).is_none() {
@@ -62,6 +108,20 @@ impl Viewer {
         }
     }
 
+    /// Move the camera, so the whole model is in view
+    ///
+    /// The camera keeps looking at the model from the same direction. The
+    /// center of the model's bounding box becomes the point around which the
+    /// next camera movement happens.
+    pub fn frame_model(&mut self) {
+        if let Some(model) = &self.model {
+            let aabb = model.aabb;
+
+            self.camera.frame(&aabb, self.renderer.aspect_ratio());
+            self.focus_point = Some(FocusPoint(aabb.center()));
+        }
+    }
+
     /// Handle an input event
     pub fn handle_input_event(&mut self, event: InputEvent) {
         if let Some(focus_point) = self.focus_point {
@@ -91,6 +151,51 @@ impl Viewer {
 
     /// Draw the graphics
     pub fn draw(&mut self) {
+        self.update_planes();
+
+        if let Err(err) = self.renderer.draw(&self.camera, &self.draw_config) {
+            warn!("Draw error: {}", err);
+        }
+    }
+
+    /// Save the frame that is currently displayed to a PNG file
+    ///
+    /// The frame is captured using the current camera pose and draw settings,
+    /// so the image contains exactly the pixels that are shown on screen.
+    pub fn save_screenshot(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), ScreenshotError> {
+        self.update_planes();
+
+        let image = self.renderer.capture(&self.camera, &self.draw_config)?;
+        image.save_with_format(path, ImageFormat::Png)?;
+
+        Ok(())
+    }
+
+    /// Render a model to an image, without requiring a window
+    ///
+    /// Creates a graphics device that isn't attached to any window, renders
+    /// the model as seen from `camera` into an offscreen texture of the given
+    /// size, and reads the result back. The near and far planes of the camera
+    /// are fitted to the model, as they are when drawing to the screen.
+    ///
+    /// No multisampling is used, so the output only depends on the model, the
+    /// camera, and the graphics driver. This makes it suitable for comparing
+    /// against reference images.
+    pub async fn render_to_image(
+        model: &Model,
+        camera: &Camera,
+        size: ScreenSize,
+    ) -> Result<RgbaImage, RenderToImageError> {
+        let mut camera = camera.clone();
+        camera.update_planes(&model.aabb);
+
+        graphics::render_to_image(&(&model.mesh).into(), &camera, size).await
+    }
+
+    fn update_planes(&mut self) {
         let aabb = self
             .model
             .as_ref()
@@ -98,9 +203,5 @@ impl Viewer {
             .unwrap_or_else(Aabb::default);
 
         self.camera.update_planes(&aabb);
-
-        if let Err(err) = self.renderer.draw(&self.camera, &self.draw_config) {
-            warn!("Draw error: {}", err);
-        }
     }
 }
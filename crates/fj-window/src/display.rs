@@ -1,9 +1,12 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use fj_interop::model::Model;
 use fj_viewer::{
     InputEvent, NormalizedScreenPosition, RendererInitError, Screen,
     ScreenSize, Viewer,
 };
 use futures::executor::block_on;
+use tracing::{info, warn};
 use winit::{
     dpi::PhysicalPosition,
     event::{
@@ -13,12 +16,16 @@ use winit::{
     event_loop::{ControlFlow, EventLoop},
 };
 
-use crate::window::{self, Window};
+use crate::window::{self, Window, WindowConfig};
 
 /// Display the provided mesh in a window that processes input
-pub fn display(model: Model, invert_zoom: bool) -> Result<(), Error> {
+pub fn display(
+    model: Model,
+    invert_zoom: bool,
+    window_config: WindowConfig,
+) -> Result<(), Error> {
     let event_loop = EventLoop::new();
-    let window = Window::new(&event_loop)?;
+    let window = Window::new(&event_loop, window_config)?;
     let mut viewer = block_on(Viewer::new(&window))?;
 
     viewer.handle_model_update(model);
@@ -66,6 +73,25 @@ pub fn display(model: Model, invert_zoom: bool) -> Result<(), Error> {
                 VirtualKeyCode::Key2 => {
                     viewer.toggle_draw_mesh();
                 }
+                VirtualKeyCode::Key3 => {
+                    viewer.toggle_draw_edges();
+                }
+                VirtualKeyCode::Key4 => {
+                    viewer.toggle_cull_objects();
+                }
+                VirtualKeyCode::Key5 => {
+                    viewer.toggle_draw_construction_edges();
+                }
+                VirtualKeyCode::F => {
+                    viewer.frame_model();
+                }
+                VirtualKeyCode::S => {
+                    let path = screenshot_path();
+                    match viewer.save_screenshot(&path) {
+                        Ok(()) => info!("Saved screenshot to `{path}`"),
+                        Err(err) => warn!("Error saving screenshot: {err}"),
+                    }
+                }
                 _ => {}
             },
             Event::WindowEvent {
@@ -128,6 +154,16 @@ pub enum Error {
     GraphicsInit(#[from] RendererInitError),
 }
 
+/// Build a file name for a screenshot that doesn't clash with earlier ones
+fn screenshot_path() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+
+    format!("fornjot-screenshot-{timestamp}.png")
+}
+
 fn input_event<T>(
     event: &Event<T>,
     window: &Window,
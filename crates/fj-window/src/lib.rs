@@ -15,5 +15,5 @@ mod window;
 
 pub use self::{
     display::{display, Error},
-    window::WindowError,
+    window::{Window, WindowConfig, WindowError},
 };
@@ -1,15 +1,59 @@
 use fj_viewer::{Screen, ScreenSize};
-use winit::{event_loop::EventLoop, window::WindowBuilder};
+use winit::{dpi::PhysicalSize, event_loop::EventLoop, window::WindowBuilder};
 
 /// A window that can be used with `fj-viewer`
 pub struct Window(winit::window::Window);
 
 impl Window {
     /// Create an instance of `Window` from the given `EventLoop`
-    pub fn new<T>(event_loop: &EventLoop<T>) -> Result<Self, WindowError> {
-        let window = WindowBuilder::new()
-            .with_title("Fornjot")
-            .with_maximized(true)
+    pub fn new<T>(
+        event_loop: &EventLoop<T>,
+        config: WindowConfig,
+    ) -> Result<Self, WindowError> {
+        let mut builder = WindowBuilder::new()
+            .with_title(config.title)
+            .with_maximized(config.maximized)
+            .with_decorations(config.decorations)
+            .with_transparent(false);
+
+        if let Some(ScreenSize { width, height }) = config.size {
+            builder = builder.with_inner_size(PhysicalSize::new(width, height));
+        }
+
+        let window = builder.build(event_loop)?;
+
+        Ok(Self(window))
+    }
+}
+
+/// Configuration for a [`Window`]
+#[derive(Clone, Debug)]
+pub struct WindowConfig {
+    /// The title of the window
+    pub title: String,
+
+    /// The initial size of the window, in physical pixels
+    ///
+    /// If this is `None`, the platform picks a size.
+    pub size: Option<ScreenSize>,
+
+    /// Whether the window opens maximized
+    pub maximized: bool,
+
+    /// Whether the window has decorations, like a title bar and borders
+    ///
+    /// This is disabled by default, to work around a problem on Gnome/Wayland.
+    /// See the implementation of [`Default`] for more information. Users who
+    /// aren't affected can enable decorations again.
+    pub decorations: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: String::from("Fornjot"),
+            size: None,
+            maximized: true,
             // When the window decorations are enabled, I'm seeing the following
             // error on Gnome/Wayland, in response to a `ScaleFactorChange`
             // event:
@@ -29,11 +73,8 @@ impl Window {
             // Window decorations should be re-enabled once possible. This is
             // being tracked in this issue:
             // https://github.com/hannobraun/fornjot/issues/1848
-            .with_decorations(false)
-            .with_transparent(false)
-            .build(event_loop)?;
-
-        Ok(Self(window))
+            decorations: false,
+        }
     }
 }
 
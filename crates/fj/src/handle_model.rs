@@ -1,18 +1,23 @@
-use std::{mem, ops::Deref};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem,
+    ops::Deref,
+};
 
 use fj_core::{
     algorithms::{
-        approx::{InvalidTolerance, Tolerance},
+        approx::{face::FaceApprox, Approx, InvalidTolerance, Tolerance},
         bounding_volume::BoundingVolume,
         triangulate::Triangulate,
     },
+    objects::EdgeKind,
     services::Services,
     validate::ValidationErrors,
 };
-use fj_interop::model::Model;
+use fj_interop::{mesh::Mesh, model::Model};
 use fj_math::{Aabb, Point, Scalar};
 
-use crate::Args;
+use crate::{window::WindowConfig, Args};
 
 /// Export or display a model, according to CLI arguments
 ///
@@ -27,7 +32,7 @@ pub fn handle_model<M>(
     services: Services,
 ) -> Result
 where
-    for<'r> (&'r M, Tolerance): Triangulate,
+    for<'r> &'r M: Approx<Approximation = BTreeSet<FaceApprox>>,
     M: BoundingVolume<3>,
 {
     let args = Args::parse();
@@ -62,20 +67,87 @@ where
         Some(user_defined_tolerance) => user_defined_tolerance,
     };
 
-    let mesh = (model.deref(), tolerance).triangulate();
+    let approx = model.deref().approx(tolerance);
+    let [edges, construction_edges] = [EdgeKind::Real, EdgeKind::Construction]
+        .map(|kind| {
+            approx
+                .iter()
+                .flat_map(|face| face.segments_of_kind(kind))
+                .collect::<Vec<_>>()
+        });
+
+    // Keep the boundaries between separate objects in the mesh, so the viewer
+    // can skip those that are out of view.
+    let mut mesh = Mesh::new();
+    for object in connected_objects(approx) {
+        mesh.start_object();
+        mesh.merge(&object.triangulate());
+    }
 
     if let Some(path) = args.export {
         crate::export::export(&mesh, &path)?;
         return Ok(());
     }
 
-    let model = Model { mesh, aabb };
+    // The mesh is a tighter fit for curved models than the model's geometry.
+    let aabb = mesh.aabb().unwrap_or(aabb);
+    let model = Model {
+        mesh,
+        aabb,
+        edges,
+        construction_edges,
+    };
 
-    crate::window::display(model, false)?;
+    crate::window::display(model, false, WindowConfig::default())?;
 
     Ok(())
 }
 
+/// Sort face approximations into objects whose faces are connected
+///
+/// Faces that share an edge approximate it using the same points, so faces
+/// are connected, if they have a point in common. A model that consists of
+/// separate solids, results in one object per solid.
+fn connected_objects(
+    approx: BTreeSet<FaceApprox>,
+) -> Vec<BTreeSet<FaceApprox>> {
+    let faces = approx.into_iter().collect::<Vec<_>>();
+
+    // Each face starts out in its own object. For each face, this refers to
+    // another face in the same object, or to itself, if it represents it.
+    let mut parents = (0..faces.len()).collect::<Vec<_>>();
+
+    let mut faces_by_point = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        for point in face.points() {
+            let j = *faces_by_point.entry(point.global_form).or_insert(i);
+
+            let (a, b) =
+                (find_object(&mut parents, i), find_object(&mut parents, j));
+            parents[a] = b;
+        }
+    }
+
+    let mut objects = BTreeMap::new();
+    for (i, face) in faces.into_iter().enumerate() {
+        objects
+            .entry(find_object(&mut parents, i))
+            .or_insert_with(BTreeSet::new)
+            .insert(face);
+    }
+
+    objects.into_values().collect()
+}
+
+/// Find the face that represents the object of face `i`
+fn find_object(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
 /// Return value of [`handle_model`]
 pub type Result = std::result::Result<(), Error>;
 
@@ -2,10 +2,11 @@ use fj::{
     core::{
         algorithms::transform::TransformObject,
         objects::Solid,
-        operations::{Insert, Merge},
+        operations::{Insert, Merge, UpdateSolid},
         services::Services,
         storage::Handle,
     },
+    interop::mesh::Material,
     math::{Scalar, Vector},
 };
 
@@ -16,15 +17,24 @@ pub fn model(services: &mut Services) -> Handle<Solid> {
 
     let offset = Vector::from([5., 5., 5.]);
     let axis = Vector::from([1., 1., 1.]).normalize();
-    let angle_rad = Scalar::PI / 6.;
+    let angle_rad = Scalar::from_degrees(30.);
+
+    let steel = Material::new("steel", 0.4, 1.);
+    let brass = Material::new("brass", 0.3, 1.);
 
     let cuboid = cuboid::model(1., 2., 3., services)
+        .set_material(steel, services)
+        .insert(services)
         .translate(offset * 1., services)
         .rotate(axis * angle_rad * 1., services);
     let spacer = spacer::model(2., 1., 1., services)
         .translate(offset * 2., services)
         .rotate(axis * angle_rad * 2., services);
-    let star = star::model(5, 2., 1., 1., services)
+    let star = star::Star::new(5, 2., 1., 1.)
+        .with_jitter(0.3, 0)
+        .build(services)
+        .set_material(brass, services)
+        .insert(services)
         .translate(offset * 3., services)
         .rotate(axis * angle_rad * 3., services);
 
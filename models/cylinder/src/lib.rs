@@ -0,0 +1,26 @@
+use fj::{
+    core::{
+        algorithms::sweep::Sweep,
+        objects::{Region, Sketch, Solid},
+        operations::{BuildRegion, BuildSketch, Insert, UpdateSketch},
+        services::Services,
+        storage::Handle,
+    },
+    math::{Point, Vector},
+};
+
+pub fn model(
+    radius: f64,
+    height: f64,
+    services: &mut Services,
+) -> Handle<Solid> {
+    let sketch = Sketch::empty()
+        .add_region(
+            Region::circle(Point::origin(), radius, services).insert(services),
+        )
+        .insert(services);
+
+    let surface = services.objects.surfaces.xy_plane();
+    let path = Vector::from([0., 0., height]);
+    (sketch, surface).sweep(path, services)
+}
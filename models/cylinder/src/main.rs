@@ -0,0 +1,8 @@
+use fj::{core::services::Services, handle_model};
+
+fn main() -> fj::Result {
+    let mut services = Services::new();
+    let model = cylinder::model(1., 2., &mut services);
+    handle_model(model, services)?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use fj::core::{
+    algorithms::approx::Tolerance,
+    objects::Solid,
+    operations::{BuildSolid, Insert},
+    services::Services,
+    storage::Handle,
+};
+
+pub fn model(radius: f64, services: &mut Services) -> Handle<Solid> {
+    // There's no surface that could represent a sphere exactly yet, so it's
+    // approximated by flat triangles. This tolerance decides how many.
+    let tolerance = Tolerance::relative(0.01)
+        .expect("Relative tolerance must be larger than zero");
+
+    Solid::sphere([0., 0., 0.], radius, tolerance, services).insert(services)
+}
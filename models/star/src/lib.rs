@@ -21,37 +21,225 @@ pub fn model(
     h: f64,
     services: &mut Services,
 ) -> Handle<Solid> {
-    let num_vertices = num_points * 2;
-    let vertex_iter = (0..num_vertices).map(|i| {
-        let angle_rad = 2. * PI / num_vertices as f64 * i as f64;
-        let radius = if i % 2 == 0 { r1 } else { r2 };
-        (angle_rad, radius)
-    });
-
-    let mut outer_points = Vec::new();
-    let mut inner_points = Vec::new();
-
-    for (angle_rad, radius) in vertex_iter {
-        let (sin, cos) = angle_rad.sin_cos();
-
-        let x = cos * radius;
-        let y = sin * radius;
-
-        outer_points.push([x, y]);
-        inner_points.push([x / 2., y / 2.]);
-    }
-
-    let sketch = Sketch::empty()
-        .add_region(
-            Region::polygon(outer_points, services)
-                .add_interiors([Cycle::polygon(inner_points, services)
-                    .reverse(services)
-                    .insert(services)])
-                .insert(services),
-        )
-        .insert(services);
-
-    let surface = services.objects.surfaces.xy_plane();
-    let path = Vector::from([0., 0., h]);
-    (sketch, surface).sweep(path, services)
+    Star::new(num_points, r1, r2, h).build(services)
+}
+
+/// A star, with optional rotation and reproducible jitter of its vertices
+///
+/// The jitter is generated from a seed, so the same parameters always result
+/// in the same star. This is useful for generating test shapes with vertex
+/// positions that are not neatly aligned.
+#[derive(Clone, Copy, Debug)]
+pub struct Star {
+    num_points: u64,
+    r1: f64,
+    r2: f64,
+    h: f64,
+    rotation_rad: f64,
+    jitter: Option<(f64, u64)>,
+}
+
+impl Star {
+    /// Construct a star with `num_points` points
+    ///
+    /// The vertices of the star alternate between the radii `r1` and `r2`, and
+    /// the star is swept to a height of `h`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_points` is less than 2, or if any of the radii is not
+    /// positive.
+    pub fn new(num_points: u64, r1: f64, r2: f64, h: f64) -> Self {
+        assert!(num_points >= 2, "A star needs at least 2 points");
+        assert!(r1 > 0. && r2 > 0., "Radii of a star must be positive");
+
+        Self {
+            num_points,
+            r1,
+            r2,
+            h,
+            rotation_rad: 0.,
+            jitter: None,
+        }
+    }
+
+    /// Rotate the star around its center, by the provided angle in radians
+    pub fn with_rotation(mut self, rotation_rad: f64) -> Self {
+        self.rotation_rad = rotation_rad;
+        self
+    }
+
+    /// Move each vertex by a pseudo-random amount, generated from `seed`
+    ///
+    /// `amount` is relative to the distance between neighboring vertices, and
+    /// to the radius of each vertex. A vertex is never moved past its
+    /// neighbors, so the star stays free of self-intersections.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `amount` is not in the range from `0.` (inclusive) to `1.`
+    /// (exclusive).
+    pub fn with_jitter(mut self, amount: f64, seed: u64) -> Self {
+        assert!(
+            (0. ..1.).contains(&amount),
+            "Jitter amount must be at least 0 and less than 1"
+        );
+
+        self.jitter = Some((amount, seed));
+        self
+    }
+
+    /// Build the star
+    pub fn build(&self, services: &mut Services) -> Handle<Solid> {
+        let outer_points = self.vertices();
+        let inner_points = outer_points
+            .iter()
+            .map(|[x, y]| [x / 2., y / 2.])
+            .collect::<Vec<_>>();
+
+        let sketch = Sketch::empty()
+            .add_region(
+                Region::polygon(outer_points, services)
+                    .add_interiors([Cycle::polygon(inner_points, services)
+                        .reverse(services)
+                        .insert(services)])
+                    .insert(services),
+            )
+            .insert(services);
+
+        let surface = services.objects.surfaces.xy_plane();
+        let path = Vector::from([0., 0., self.h]);
+        (sketch, surface).sweep(path, services)
+    }
+
+    /// Compute the vertices of the star's outline, in counter-clockwise order
+    fn vertices(&self) -> Vec<[f64; 2]> {
+        let num_vertices = self.num_points * 2;
+        let angle_step_rad = 2. * PI / num_vertices as f64;
+
+        let (amount, seed) = self.jitter.unwrap_or((0., 0));
+        let mut rng = SplitMix64(seed);
+
+        (0..num_vertices)
+            .map(|i| {
+                let radius = if i % 2 == 0 { self.r1 } else { self.r2 };
+
+                // Each vertex moves by less than half the step in either
+                // direction, which keeps the angles in order. Since the angle
+                // between neighboring vertices stays below 180 degrees, the
+                // center of the star can see all of its edges, which means the
+                // outline can't intersect itself.
+                let angle_jitter = rng.next_signed() * amount / 2.;
+                let radius_jitter = rng.next_signed() * amount / 2.;
+
+                let angle_rad = self.rotation_rad
+                    + angle_step_rad * (i as f64 + angle_jitter);
+                let radius = radius * (1. + radius_jitter);
+
+                let (sin, cos) = angle_rad.sin_cos();
+                [cos * radius, sin * radius]
+            })
+            .collect()
+    }
+}
+
+/// A minimal pseudo-random number generator
+///
+/// This implements SplitMix64. It's defined here, instead of using a library,
+/// so the generated numbers can't change with a library update.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Generate a number between `-1.` (inclusive) and `1.` (exclusive)
+    fn next_signed(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        // The upper 53 bits fit into the mantissa of an `f64` exactly.
+        let unit = (z >> 11) as f64 / (1_u64 << 53) as f64;
+        unit * 2. - 1.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::core::services::Services;
+
+    use super::Star;
+
+    #[test]
+    fn valid_for_even_and_odd_point_counts() {
+        for num_points in 2..=9 {
+            let stars = [
+                Star::new(num_points, 2., 1., 1.),
+                Star::new(num_points, 1., 2., 1.).with_rotation(0.3),
+                Star::new(num_points, 2., 1., 1.)
+                    .with_rotation(-1.1)
+                    .with_jitter(0.9, num_points),
+            ];
+
+            for star in stars {
+                assert!(!self_intersects(&star.vertices()), "{star:?}");
+
+                let mut services = Services::new();
+                star.build(&mut services);
+                services
+                    .drop_and_validate()
+                    .unwrap_or_else(|err| panic!("{star:?}: {err}"));
+            }
+        }
+    }
+
+    #[test]
+    fn jitter_is_reproducible() {
+        let star = Star::new(5, 2., 1., 1.).with_jitter(0.5, 12345);
+
+        assert_eq!(star.vertices(), star.vertices());
+        assert_ne!(
+            star.vertices(),
+            Star::new(5, 2., 1., 1.).vertices(),
+            "Jitter should move the vertices"
+        );
+        assert_ne!(
+            star.vertices(),
+            Star::new(5, 2., 1., 1.).with_jitter(0.5, 54321).vertices(),
+            "Different seeds should result in different vertices"
+        );
+    }
+
+    fn self_intersects(vertices: &[[f64; 2]]) -> bool {
+        let n = vertices.len();
+        let edge = |i: usize| [vertices[i], vertices[(i + 1) % n]];
+
+        for i in 0..n {
+            // Neighboring edges share a vertex, so only check the others.
+            for j in i + 2..n {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+
+                if segments_intersect(edge(i), edge(j)) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn segments_intersect(
+        [a, b]: [[f64; 2]; 2],
+        [c, d]: [[f64; 2]; 2],
+    ) -> bool {
+        let orient =
+            |[px, py]: [f64; 2], [qx, qy]: [f64; 2], [rx, ry]: [f64; 2]| {
+                ((qx - px) * (ry - py) - (qy - py) * (rx - px)).signum()
+            };
+
+        orient(a, b, c) != orient(a, b, d) && orient(c, d, a) != orient(c, d, b)
+    }
 }